@@ -0,0 +1,47 @@
+//! Criterion benchmarks for `BuffoonBoard::draw_odds`.
+//!
+//! Both cases hold an eight-card hand over the rest of a shuffled 52-card
+//! deck, the board a bot asks about before every discard. The walk grows
+//! with the cards drawn back, so the five-card discard is the worst case a
+//! board can ask for.
+//!
+//! ## Cases
+//!
+//! - `draw_odds_keep_3` — keep three, draw five from 44
+//! - `draw_odds_keep_5` — keep five, draw three from 44
+//!
+//! Run with: `cargo bench --bench odds --features funky`
+
+use cardpack::preludes::funky::*;
+use criterion::{Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+
+fn board() -> BuffoonBoard {
+    let mut board = BuffoonBoard::new(
+        Draws::new(4, 3),
+        Deck::basic_buffoon_pile().shuffled_with_seed(0x00C0_FFEE),
+    );
+    board.deal_to_hand_size();
+    board
+}
+
+fn bench_draw_odds_keep_three(c: &mut Criterion) {
+    let board = board();
+    c.bench_function("draw_odds_keep_3 (8 in hand, 5 from 44)", |b| {
+        b.iter(|| black_box(&board).draw_odds(black_box(&[0, 1, 2])));
+    });
+}
+
+fn bench_draw_odds_keep_five(c: &mut Criterion) {
+    let board = board();
+    c.bench_function("draw_odds_keep_5 (8 in hand, 3 from 44)", |b| {
+        b.iter(|| black_box(&board).draw_odds(black_box(&[0, 1, 2, 3, 4])));
+    });
+}
+
+criterion_group!(
+    benches,
+    bench_draw_odds_keep_three,
+    bench_draw_odds_keep_five
+);
+criterion_main!(benches);
@@ -64,4 +64,8 @@ fn main() {
     println!("Phase 3 (+held x mult)     {after_held}");
     println!("Phase 4 (+jokers) = final  {total}");
     println!("Final score = {}", total.score());
+
+    // The same fold, step by step: every op, its source, and the running score.
+    let (_, trace) = board.score_traced();
+    println!("\nBreakdown:\n{trace}");
 }
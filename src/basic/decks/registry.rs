@@ -434,6 +434,7 @@ mod basic__decks__registry_tests {
 
     #[cfg(not(feature = "yaml"))]
     #[test]
+    #[allow(clippy::needless_collect)]
     fn all__omits_razz_without_yaml() {
         let names: Vec<String> = DeckKind::all().iter().map(|k| k.deck_name()).collect();
         assert!(
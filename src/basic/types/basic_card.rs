@@ -13,8 +13,9 @@ use core::fmt::Display;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 // The `std-io` feature is the crate's one deliberate filesystem seam: it gates
-// `cards_from_yaml_file` and nothing else. It is opt-in and NOT part of `full`,
-// so the pure kernel and the convenience stack both stay I/O-free. The
+// `cards_from_yaml_file` here and the funky `Profile::save`/`load`, and nothing
+// else. It is opt-in and NOT part of `full`, so the pure kernel and the
+// convenience stack both stay I/O-free. The
 // `#[allow]` documents that this `std::fs` use is the intended seam rather than
 // an accidental leak past the kernel purity lint (clippy `disallowed_types`,
 // see clippy.toml). See docs/audit-2026-07-18-domain-kernel.md (Finding 1b).
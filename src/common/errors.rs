@@ -21,20 +21,41 @@ pub enum CardError {
     #[error("Invalid Card Count: `{0}`")]
     InvalidCardCount(usize),
 
+    #[error("Duplicate Effect Id: `{0}` is already registered.")]
+    DuplicateEffectId(String),
+
+    #[error(
+        "Invalid Effect Id: `{0}`. Must be `namespace:name` in lower-case letters, digits, `_` or `-`, at most 63 bytes."
+    )]
+    InvalidEffectId(String),
+
     #[error(
         "Invalid FluentName: `{0}`. Must be alphanumeric with hyphens, en-dashes, or em-dashes."
     )]
     InvalidFluentName(String),
 
+    #[error("Invalid Challenge `{name}`: {reason}")]
+    InvalidChallenge { name: String, reason: String },
+
     #[error("Invalid Index: `{0}`")]
     InvalidIndex(String),
 
+    #[error("Invalid Seed: `{0}`. Must be one to eight letters or digits.")]
+    InvalidSeed(String),
+
     #[error("Not enough cards: `{0}` missing")]
     NotEnoughCards(usize),
 
     #[error("Too many cards: `{0}` extra")]
     TooManyCards(usize),
 
+    /// A custom effect's script failed to compile, or failed while scoring —
+    /// including running past its sandbox limits. Carries the interpreter's
+    /// message as a `String`, for the reason the YAML variants below do.
+    #[cfg(feature = "scripting")]
+    #[error("Script error: {0}")]
+    Script(String),
+
     // The YAML variants below carry only `String`/`usize` payloads. A
     // `#[from] serde_norway::Error` would break both `Eq` and `PartialEq` on
     // this enum *and* leak a format crate into the public API (domain-kernel
@@ -64,6 +85,10 @@ pub enum CardError {
     #[cfg(feature = "yaml")]
     #[error("YAML document is neither a deck envelope nor a card sequence")]
     YamlMalformed,
+
+    #[cfg(feature = "yaml")]
+    #[error("YAML document is format version `{0}`, which this crate does not read")]
+    YamlUnknownVersion(u8),
 }
 
 // The whole module is yaml-gated because every test in it is: gating the
@@ -113,6 +138,10 @@ mod common__errors_tests {
             CardError::YamlMalformed.to_string(),
             "YAML document is neither a deck envelope nor a card sequence"
         );
+        assert_eq!(
+            CardError::YamlUnknownVersion(2).to_string(),
+            "YAML document is format version `2`, which this crate does not read"
+        );
     }
 
     /// Pins the two properties the whole error design depends on: a
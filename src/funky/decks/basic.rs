@@ -174,6 +174,7 @@ pub mod card {
     use crate::funky::types::buffoon_card::{BCardType, BuffoonCard};
     use crate::funky::types::edition::Edition;
     use crate::funky::types::mpip::MPip;
+    use crate::funky::types::sticker::Stickers;
     use crate::prelude::{FrenchRank, FrenchSuit, Pip};
 
     /// A **Stone card** — Balatro's rankless, suitless +50 chip card, and what
@@ -202,6 +203,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
 
     #[must_use]
@@ -243,6 +245,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const KING_SPADES: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::SPADES,
@@ -252,6 +255,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const QUEEN_SPADES: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::SPADES,
@@ -261,6 +265,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const JACK_SPADES: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::SPADES,
@@ -270,6 +275,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const TEN_SPADES: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::SPADES,
@@ -279,6 +285,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const NINE_SPADES: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::SPADES,
@@ -288,6 +295,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const EIGHT_SPADES: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::SPADES,
@@ -297,6 +305,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const SEVEN_SPADES: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::SPADES,
@@ -306,6 +315,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const SIX_SPADES: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::SPADES,
@@ -315,6 +325,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const FIVE_SPADES: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::SPADES,
@@ -324,6 +335,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const FOUR_SPADES: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::SPADES,
@@ -333,6 +345,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const TREY_SPADES: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::SPADES,
@@ -342,6 +355,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const DEUCE_SPADES: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::SPADES,
@@ -351,6 +365,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const ACE_HEARTS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::HEARTS,
@@ -360,6 +375,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const KING_HEARTS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::HEARTS,
@@ -369,6 +385,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const QUEEN_HEARTS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::HEARTS,
@@ -378,6 +395,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const JACK_HEARTS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::HEARTS,
@@ -387,6 +405,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const TEN_HEARTS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::HEARTS,
@@ -396,6 +415,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const NINE_HEARTS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::HEARTS,
@@ -405,6 +425,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const EIGHT_HEARTS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::HEARTS,
@@ -414,6 +435,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const SEVEN_HEARTS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::HEARTS,
@@ -423,6 +445,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const SIX_HEARTS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::HEARTS,
@@ -432,6 +455,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const FIVE_HEARTS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::HEARTS,
@@ -441,6 +465,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const FOUR_HEARTS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::HEARTS,
@@ -450,6 +475,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const TREY_HEARTS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::HEARTS,
@@ -459,6 +485,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const DEUCE_HEARTS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::HEARTS,
@@ -468,6 +495,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const ACE_DIAMONDS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::DIAMONDS,
@@ -477,6 +505,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const KING_DIAMONDS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::DIAMONDS,
@@ -486,6 +515,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const QUEEN_DIAMONDS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::DIAMONDS,
@@ -495,6 +525,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const JACK_DIAMONDS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::DIAMONDS,
@@ -504,6 +535,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const TEN_DIAMONDS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::DIAMONDS,
@@ -513,6 +545,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const NINE_DIAMONDS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::DIAMONDS,
@@ -522,6 +555,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const EIGHT_DIAMONDS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::DIAMONDS,
@@ -531,6 +565,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const SEVEN_DIAMONDS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::DIAMONDS,
@@ -540,6 +575,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const SIX_DIAMONDS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::DIAMONDS,
@@ -549,6 +585,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const FIVE_DIAMONDS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::DIAMONDS,
@@ -558,6 +595,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const FOUR_DIAMONDS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::DIAMONDS,
@@ -567,6 +605,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const TREY_DIAMONDS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::DIAMONDS,
@@ -576,6 +615,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const DEUCE_DIAMONDS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::DIAMONDS,
@@ -585,6 +625,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const ACE_CLUBS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::CLUBS,
@@ -594,6 +635,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const KING_CLUBS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::CLUBS,
@@ -603,6 +645,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const QUEEN_CLUBS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::CLUBS,
@@ -612,6 +655,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const JACK_CLUBS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::CLUBS,
@@ -621,6 +665,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const TEN_CLUBS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::CLUBS,
@@ -630,6 +675,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const NINE_CLUBS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::CLUBS,
@@ -639,6 +685,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const EIGHT_CLUBS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::CLUBS,
@@ -648,6 +695,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const SEVEN_CLUBS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::CLUBS,
@@ -657,6 +705,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const SIX_CLUBS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::CLUBS,
@@ -666,6 +715,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const FIVE_CLUBS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::CLUBS,
@@ -675,6 +725,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const FOUR_CLUBS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::CLUBS,
@@ -684,6 +735,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const TREY_CLUBS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::CLUBS,
@@ -693,6 +745,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const DEUCE_CLUBS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::CLUBS,
@@ -702,6 +755,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
 }
 
@@ -120,7 +120,7 @@ impl Joker {
         BuffoonPile::from(&Self::UNCOMMON_JOKERS[..])
     }
 
-    pub const RARE_JOKERS_SIZE: usize = 10;
+    pub const RARE_JOKERS_SIZE: usize = 12;
 
     pub const RARE_JOKERS: [BuffoonCard; Self::RARE_JOKERS_SIZE] = [
         card::VAGABOND,
@@ -133,6 +133,8 @@ impl Joker {
         card::BARON,
         card::DNA,
         card::BASEBALL_CARD,
+        card::BLUEPRINT,
+        card::BRAINSTORM,
     ];
 
     #[must_use]
@@ -154,12 +156,25 @@ impl Joker {
     pub fn pile_legendary() -> BuffoonPile {
         BuffoonPile::from(&Self::LEGENDARY_JOKERS[..])
     }
+
+    /// The built-in joker whose index is `symbol`, of any rarity.
+    #[must_use]
+    pub fn by_symbol(symbol: char) -> Option<BuffoonCard> {
+        Self::COMMON_JOKERS
+            .iter()
+            .chain(&Self::UNCOMMON_JOKERS)
+            .chain(&Self::RARE_JOKERS)
+            .chain(&Self::LEGENDARY_JOKERS)
+            .find(|joker| joker.rank.index == symbol)
+            .copied()
+    }
 }
 
 pub mod card {
     use crate::funky::types::buffoon_card::{BCardType, BuffoonCard};
     use crate::funky::types::edition::Edition;
     use crate::funky::types::mpip::MPip;
+    use crate::funky::types::sticker::Stickers;
     use crate::prelude::{FrenchSuit, Pip, PipType};
 
     // https://symbl.cc/en/unicode-table/#miscellaneous-symbols
@@ -179,6 +194,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const GREEDY_JOKER: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -194,6 +210,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const LUSTY_JOKER: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -209,6 +226,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const WRATHFUL_JOKER: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -224,6 +242,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const GLUTTONOUS_JOKER: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -239,6 +258,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     /// The `Jolly Joker` is one that has no effect on a single card, and only returns mult
     /// on a specific conditions of cards.
@@ -256,6 +276,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const ZANY_JOKER: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -271,6 +292,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const MAD_JOKER: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -286,6 +308,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const CRAZY_JOKER: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -301,6 +324,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const DROLL_JOKER: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -316,6 +340,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const SLY_JOKER: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -331,6 +356,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const WILY_JOKER: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -346,6 +372,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const CLEVER_JOKER: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -361,6 +388,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const DEVIOUS_JOKER: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -376,6 +404,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const CRAFTY_JOKER: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -391,6 +420,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const HALF_JOKER: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -406,6 +436,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const JOKER_STENCIL: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -421,6 +452,7 @@ pub mod card {
         resell_value: 4,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const FOUR_FINGERS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -436,6 +468,7 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const MIME: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -451,6 +484,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const CREDIT_CARD: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -466,6 +500,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const CEREMONIAL_DAGGER: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -481,6 +516,7 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const BANNER: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -496,6 +532,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const MYSTIC_SUMMIT: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -511,6 +548,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const MARBLE_JOKER: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -526,6 +564,7 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const LOYALTY_CARD: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -541,6 +580,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
 
     // FINISH ME
@@ -558,6 +598,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const MISPRINT: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -573,6 +614,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const DUSK: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -588,6 +630,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const RAISED_FIST: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -603,6 +646,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const CHAOS_THE_CLOWN: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -618,6 +662,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const FIBONACCI: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -633,6 +678,7 @@ pub mod card {
         resell_value: 4,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const STEEL_JOKER: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -648,6 +694,7 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const SCARY_FACE: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -664,6 +711,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const ABSTRACT_JOKER: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -680,6 +728,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const DELAYED_GRATIFICATION: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -697,6 +746,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const HACK: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -712,6 +762,7 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const PAREIDOLIA: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -727,6 +778,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const GROS_MICHEL: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -745,14 +797,15 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const EVEN_STEVEN: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
         rank: Pip {
             weight: 870,
             pip_type: PipType::Joker,
-            index: '▤',
-            symbol: '▤',
+            index: '∔',
+            symbol: '∔',
             value: 4,
         },
         card_type: BCardType::CommonJoker,
@@ -760,6 +813,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const ODD_TODD: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -775,6 +829,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const SCHOLAR: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -790,6 +845,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     /// **DIARY** I am constantly debating in my head how reasonable this all is.
     ///
@@ -815,6 +871,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const SUPERNOVA: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -830,6 +887,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const RIDE_THE_BUS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -845,14 +903,15 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const SPACE_JOKER: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
         rank: Pip {
             weight: 917,
             pip_type: PipType::Joker,
-            index: '∝',
-            symbol: '∝',
+            index: '∗',
+            symbol: '∗',
             value: 5,
         },
         card_type: BCardType::UncommonJoker,
@@ -860,14 +919,15 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const EGG: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
         rank: Pip {
             weight: 918,
             pip_type: PipType::Joker,
-            index: '∞',
-            symbol: '∞',
+            index: '∘',
+            symbol: '∘',
             value: 4,
         },
         card_type: BCardType::CommonJoker,
@@ -875,6 +935,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const BURGLAR: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -892,6 +953,7 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const BLACKBOARD: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -908,6 +970,7 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const RUNNER: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -924,6 +987,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const ICE_CREAM: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -940,6 +1004,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const DNA: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -955,6 +1020,7 @@ pub mod card {
         resell_value: 4,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const SPLASH: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -970,6 +1036,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const BLUE_JOKER: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -986,6 +1053,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const SIXTH_SENSE: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1001,6 +1069,7 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     // 55 Constellation — Uncommon, $6. Was tagged Common / $5 and unpiled.
     pub const CONSTELLATION: BuffoonCard = BuffoonCard {
@@ -1018,6 +1087,7 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const HIKER: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1034,6 +1104,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const FACELESS_JOKER: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1051,6 +1122,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const GREEN_JOKER: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1067,14 +1139,15 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const SUPERPOSITION: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
         rank: Pip {
             weight: 840,
             pip_type: PipType::Joker,
-            index: '∫',
-            symbol: '∫',
+            index: '∓',
+            symbol: '∓',
             value: 4,
         },
         card_type: BCardType::CommonJoker,
@@ -1083,6 +1156,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const TO_DO_LIST: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1098,6 +1172,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const CAVENDISH: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1116,6 +1191,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const CARD_SHARP: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1132,6 +1208,7 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const RED_CARD: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1147,6 +1224,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const MADNESS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1164,6 +1242,7 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const SQUARE_JOKER: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1180,6 +1259,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const SEANCE: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1195,6 +1275,7 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const RIFF_RAFF: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1211,6 +1292,7 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     // 68 Vampire — Uncommon, $7. The Baron/Erosion data-fix pattern again: it was
     // tagged Common / $5 and sat in no rarity pile.
@@ -1230,6 +1312,7 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const SHORTCUT: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1245,6 +1328,7 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     // 70 Hologram — Uncommon, $7. The Baron/Erosion data-fix pattern: it was
     // tagged Common / $5 and sat in no rarity pile.
@@ -1263,6 +1347,7 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const VAGABOND: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1279,6 +1364,7 @@ pub mod card {
         resell_value: 4,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const BARON: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1295,6 +1381,7 @@ pub mod card {
         resell_value: 4,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const CLOUD_9: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1311,6 +1398,7 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const ROCKET: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1327,6 +1415,7 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const EROSION: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1342,6 +1431,7 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const RESERVED_PARKING: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1357,6 +1447,7 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const MAIL_IN_REBATE: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1372,6 +1463,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const TO_THE_MOON: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1389,6 +1481,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const HALLUCINATION: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1404,6 +1497,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     // 86 Fortune Teller — Common, $6. Cost was $5.
     pub const FORTUNE_TELLER: BuffoonCard = BuffoonCard {
@@ -1422,6 +1516,7 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const JUGGLER: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1438,6 +1533,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const DRUNKARD: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1454,6 +1550,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const STONE_JOKER: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1469,6 +1566,7 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const GOLDEN_JOKER: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1487,6 +1585,7 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const LUCKY_CAT: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1498,10 +1597,13 @@ pub mod card {
             value: 6,
         },
         card_type: BCardType::UncommonJoker,
-        enhancement: MPip::Blank,
+        // Lucky Cat: gains ×0.25 mult each time a Lucky card procs. Grown from a
+        // `ScoreTrace`, since the proc happens inside the pure fold.
+        enhancement: MPip::GainMultTimesPerLuckyProc(25),
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const BASEBALL_CARD: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1518,6 +1620,7 @@ pub mod card {
         resell_value: 4,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const BULL: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1533,6 +1636,7 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const DIET_COLA: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1548,6 +1652,7 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const TRADING_CARD: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1563,6 +1668,7 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const FLASH_CARD: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1578,6 +1684,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const POPCORN: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1595,6 +1702,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const SPARE_TROUSERS: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1611,6 +1719,7 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const ANCIENT_JOKER: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1628,6 +1737,7 @@ pub mod card {
         resell_value: 4,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const RAMEN: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1644,6 +1754,7 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const WALKIE_TALKIE: BuffoonCard = BuffoonCard {
         suit: FrenchSuit::JOKER,
@@ -1660,6 +1771,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
 
     // 109 Sock and Buskin — Uncommon, $6. Retrigger all played face cards.
@@ -1677,6 +1789,7 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
 
     // 77 Luchador — Uncommon, $5. Sell it to disable the current Boss Blind.
@@ -1694,6 +1807,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
 
     // 129 Matador — Uncommon, $7. "Earn $8 if played hand triggers the Boss
@@ -1717,6 +1831,7 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
 
     // 102 Seltzer — Uncommon, $6. Retrigger all cards played for the next 10
@@ -1735,6 +1850,7 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
 
     // 113 Smeared Joker — Uncommon, $7. Hearts≡Diamonds, Spades≡Clubs for flushes.
@@ -1752,6 +1868,7 @@ pub mod card {
         resell_value: 3,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
 
     // 126 Oops! All 6s — Uncommon, $4. Doubles all listed probabilities.
@@ -1769,6 +1886,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
 
     // 115 Hanging Chad — Common, $4. Retrigger first played card 2 extra times.
@@ -1786,6 +1904,7 @@ pub mod card {
         resell_value: 2,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
 
     // The "family" of Rare jokers that give ×Mult when the played hand contains
@@ -1806,6 +1925,7 @@ pub mod card {
         resell_value: 4,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
 
     pub const THE_TRIO: BuffoonCard = BuffoonCard {
@@ -1822,6 +1942,7 @@ pub mod card {
         resell_value: 4,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
 
     pub const THE_FAMILY: BuffoonCard = BuffoonCard {
@@ -1838,6 +1959,7 @@ pub mod card {
         resell_value: 4,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
 
     pub const THE_ORDER: BuffoonCard = BuffoonCard {
@@ -1854,6 +1976,7 @@ pub mod card {
         resell_value: 4,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
 
     pub const THE_TRIBE: BuffoonCard = BuffoonCard {
@@ -1870,6 +1993,42 @@ pub mod card {
         resell_value: 4,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
+    };
+    /// Blueprint copies the joker to its right, and Brainstorm the leftmost
+    /// joker — so where they sit is the whole of what they do. See
+    /// `BuffoonBoard::move_joker`.
+    pub const BLUEPRINT: BuffoonCard = BuffoonCard {
+        suit: FrenchSuit::JOKER,
+        rank: Pip {
+            weight: 643,
+            pip_type: PipType::Joker,
+            index: '📘',
+            symbol: '📘',
+            value: 10,
+        },
+        card_type: BCardType::RareJoker,
+        enhancement: MPip::CopyJokerToRight,
+        resell_value: 5,
+        edition: Edition::None,
+        debuffed: false,
+        stickers: Stickers::NONE,
+    };
+    pub const BRAINSTORM: BuffoonCard = BuffoonCard {
+        suit: FrenchSuit::JOKER,
+        rank: Pip {
+            weight: 642,
+            pip_type: PipType::Joker,
+            index: '🧠',
+            symbol: '🧠',
+            value: 10,
+        },
+        card_type: BCardType::RareJoker,
+        enhancement: MPip::CopyLeftmostJoker,
+        resell_value: 5,
+        edition: Edition::None,
+        debuffed: false,
+        stickers: Stickers::NONE,
     };
 
     // Legendary jokers (Balatro #146–150), only obtainable from The Soul card,
@@ -1891,6 +2050,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
 
     pub const TRIBOULET: BuffoonCard = BuffoonCard {
@@ -1908,6 +2068,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
 
     pub const YORICK: BuffoonCard = BuffoonCard {
@@ -1925,6 +2086,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
 
     pub const CHICOT: BuffoonCard = BuffoonCard {
@@ -1942,6 +2104,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
 
     pub const PERKEO: BuffoonCard = BuffoonCard {
@@ -1958,6 +2121,7 @@ pub mod card {
         resell_value: 0,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
 }
 
@@ -2046,7 +2210,9 @@ mod funky__decks__joker_tests {
     use crate::funky::types::board::BuffoonBoard;
     use crate::funky::types::draws::Draws;
     use crate::funky::types::mpip::MPip;
-    use crate::preludes::funky::{BCardType, Blind, BuffoonPile, Deck, Score};
+    use crate::preludes::funky::{
+        BCardType, Blind, BuffoonPile, Deck, ProcEvent, Score, ScoreTrace,
+    };
     use std::collections::{HashMap, HashSet};
 
     /// Every joker const defined in this file, in declaration order. The single
@@ -2054,7 +2220,7 @@ mod funky__decks__joker_tests {
     /// scoring-reachability guard). A joker is only protected by those guards
     /// once it is listed here; `all_jokers__is_superset_of_every_pile` keeps the
     /// four rarity piles from drifting out of it.
-    const ALL_JOKERS: [BuffoonCard; 112] = [
+    const ALL_JOKERS: [BuffoonCard; 114] = [
         card::JOKER,
         card::GREEDY_JOKER,
         card::LUSTY_JOKER,
@@ -2162,6 +2328,8 @@ mod funky__decks__joker_tests {
         card::THE_FAMILY,
         card::THE_ORDER,
         card::THE_TRIBE,
+        card::BLUEPRINT,
+        card::BRAINSTORM,
         card::CANIO,
         card::TRIBOULET,
         card::YORICK,
@@ -2189,6 +2357,14 @@ mod funky__decks__joker_tests {
         }
     }
 
+    #[test]
+    fn by_symbol() {
+        for joker in ALL_JOKERS {
+            assert_eq!(Joker::by_symbol(joker.rank.index), Some(joker));
+        }
+        assert_eq!(Joker::by_symbol('A'), None);
+    }
+
     /// The inverse of the superset guard: every joker in [`ALL_JOKERS`] must
     /// sit in the rarity pile its own `card_type` names. An adrift joker is
     /// invisible to everything that draws from the piles (Riff-Raff already
@@ -2469,11 +2645,22 @@ mod funky__decks__joker_tests {
             // what makes it retroactive. Both add mult once driven.
             | MPip::GainMultTimesPerPlanetUsed(_)
             | MPip::MultPlusPerTarotUsedThisRun(_)
+            // Supernova reads the run's play count for the scoring hand type;
+            // Loyalty Card counts hands in its counter and ×4s every sixth.
+            | MPip::MultPlusOnHandPlays
+            | MPip::MultTimesEveryXHands(_, _)
+            // Lucky Cat: xmult per Lucky proc, grown from a committed
+            // `ScoreTrace`. The probe boards commit one proc so the guard sees
+            // it grown.
+            | MPip::GainMultTimesPerLuckyProc(_)
             // Flash Card: +n mult per shop reroll, grown on ShopRerolled. Red
             // Card: +n mult per booster pack skipped, grown on PackSkipped. Both
             // plain counters like Green Joker, inert (+0) until an event ticks them.
             | MPip::MultPlusPerReroll(_)
             | MPip::MultPlusPerPackSkipped(_)
+            // Ceremonial Dagger: +mult grown by twice the sell value of each
+            // joker it eats at blind select. Inert until it has eaten one.
+            | MPip::MultPlusDoubleValueDestroyJokerOnRight(_)
             // Madness gains its xmult on every non-boss blind selected, so a
             // grown one scores. (The joker it destroys is a side effect, not its
             // contribution.)
@@ -2492,6 +2679,10 @@ mod funky__decks__joker_tests {
             | MPip::RetriggerCardsInHand(_)
             | MPip::RetriggerPlayedCardsInFinalRound
             | MPip::RetriggerAllPlayedForHands(_, _)
+            // Blueprint and Brainstorm score whatever they copy. Seated left of
+            // the probe board's Fibonacci, either runs its +8 mult a second time.
+            | MPip::CopyJokerToRight
+            | MPip::CopyLeftmostJoker
             // Rule modifiers change hand classification (Four Fingers: 4-card
             // straights/flushes; Shortcut: gapped straights; Smeared: merged-suit
             // flushes), so they change the base hand score and can enable
@@ -2586,11 +2777,8 @@ mod funky__decks__joker_tests {
             | MPip::Hanged(_)
             | MPip::JokersValue(_)
             | MPip::Lucky(_, _)
-            | MPip::MultPlusDoubleValueDestroyJokerOnRight(_)
             | MPip::MultPlusOnConsecutiveHandsNo3Ranks(_, _, _)
-            | MPip::MultPlusOnHandPlays
             | MPip::MultPlusRandomTo(_)
-            | MPip::MultTimesEveryXHands(_, _)
             | MPip::Planet(_)
             | MPip::RandomJoker(_)
             | MPip::RandomTarot(_)
@@ -2768,6 +2956,17 @@ mod funky__decks__joker_tests {
             board.reroll_with_rng(&mut rng);
             board.skip_pack(0);
         }
+        // A Lucky card procs once (Lucky Cat). The probe boards play no Lucky
+        // cards and the pure fold never rolls, so the proc is committed from a
+        // hand-built trace — exactly what a traced hand would have reported.
+        {
+            let mut trace = ScoreTrace::new();
+            trace.push(ProcEvent::LuckyProc {
+                index: 0,
+                card: bcards!("7D").iter().next().copied().unwrap(),
+            });
+            board.commit_trace(&trace);
+        }
         // One Planet and one Tarot spent (Constellation, Fortune Teller). The
         // Tarot targets nothing, so it only registers as used — all either joker
         // reads.
@@ -2781,6 +2980,21 @@ mod funky__decks__joker_tests {
         for hand in &growth_hands {
             board.on_hand_played(hand);
         }
+        // Loyalty Card five hands in, so the hand being scored is its sixth.
+        // Poked rather than played: two more hands would end the four-hand round
+        // Dusk reads.
+        let loyal: Vec<(usize, usize)> = board
+            .jokers
+            .iter()
+            .enumerate()
+            .filter_map(|(slot, joker)| match joker.enhancement {
+                MPip::MultTimesEveryXHands(_, every) => Some((slot, every)),
+                _ => None,
+            })
+            .collect();
+        for (slot, every) in loyal {
+            board.joker_state[slot] = i32::try_from(every).unwrap_or(i32::MAX) - 1;
+        }
         board.on_scored();
         board.score()
     }
@@ -2797,8 +3011,13 @@ mod funky__decks__joker_tests {
     fn is_reachable(joker: BuffoonCard) -> bool {
         probe_boards().into_iter().any(|mut board| {
             let baseline = drive_events(&mut board.clone(), None);
+            // Seated leftmost as well as rightmost: a copier only has something
+            // to copy with a joker to its right.
+            let mut leftmost = board.clone();
+            leftmost.push_joker(joker);
+            leftmost.move_joker(leftmost.jokers.len() - 1, 0);
             let probed = drive_events(&mut board, Some(joker));
-            probed != baseline
+            probed != baseline || drive_events(&mut leftmost, None) != baseline
         })
     }
 
@@ -2841,7 +3060,7 @@ mod funky__decks__joker_tests {
     /// are permanent-ish: they wait on subsystems (spectral cards, packs, the
     /// shop) that are deliberately outside this crate's current scope. Delete an
     /// entry only when the joker is wired.
-    const BLANK_WITH_REASON: [(BuffoonCard, &str); 8] = [
+    const BLANK_WITH_REASON: [(BuffoonCard, &str); 7] = [
         // --- Blocked on subsystems that do not exist (EPIC-01a item 5e) ---
         (
             card::DNA,
@@ -2860,14 +3079,6 @@ mod funky__decks__joker_tests {
             "a probabilistic held-card payout — deferred rather than blocked; it \
              could ride the existing seeded-RNG path today",
         ),
-        // --- Blocked on the pure-fold boundary ---
-        (
-            card::LUCKY_CAT,
-            "needs scoring to be mutating: it gains x0.25 per Lucky *proc*, which \
-             happens inside the pure `&self` fold, so no `&mut` hook can see it \
-             without re-rolling the RNG. The same gap `on_scored` already \
-             characterizes for Hiker",
-        ),
         // --- Blocked on boss blind abilities (item 8) ---
         (
             card::MATADOR,
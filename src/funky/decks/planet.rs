@@ -64,6 +64,7 @@ pub mod card {
     use crate::funky::types::edition::Edition;
     use crate::funky::types::hands::HandType;
     use crate::funky::types::mpip::MPip;
+    use crate::funky::types::sticker::Stickers;
     use crate::prelude::{Pip, PipType};
 
     pub const PLANET_SUIT: Pip = Pip {
@@ -88,6 +89,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const MERCURY: BuffoonCard = BuffoonCard {
         suit: PLANET_SUIT,
@@ -103,6 +105,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const URANUS: BuffoonCard = BuffoonCard {
         suit: PLANET_SUIT,
@@ -118,6 +121,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const VENUS: BuffoonCard = BuffoonCard {
         suit: PLANET_SUIT,
@@ -133,6 +137,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const SATURN: BuffoonCard = BuffoonCard {
         suit: PLANET_SUIT,
@@ -148,6 +153,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const JUPITER: BuffoonCard = BuffoonCard {
         suit: PLANET_SUIT,
@@ -163,6 +169,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const EARTH: BuffoonCard = BuffoonCard {
         suit: PLANET_SUIT,
@@ -178,6 +185,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const MARS: BuffoonCard = BuffoonCard {
         suit: PLANET_SUIT,
@@ -193,6 +201,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const NEPTUNE: BuffoonCard = BuffoonCard {
         suit: PLANET_SUIT,
@@ -208,6 +217,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const PLANET_X: BuffoonCard = BuffoonCard {
         suit: PLANET_SUIT,
@@ -223,6 +233,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const CERES: BuffoonCard = BuffoonCard {
         suit: PLANET_SUIT,
@@ -238,6 +249,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const ERIS: BuffoonCard = BuffoonCard {
         suit: PLANET_SUIT,
@@ -253,6 +265,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
 }
 
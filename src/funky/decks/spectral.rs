@@ -39,6 +39,7 @@ pub mod card {
     use crate::funky::types::buffoon_card::{BCardType, BuffoonCard};
     use crate::funky::types::edition::Edition;
     use crate::funky::types::mpip::MPip;
+    use crate::funky::types::sticker::Stickers;
     use crate::prelude::{Pip, PipType};
 
     /// The shared suit every spectral card wears — the spectral analogue of
@@ -75,6 +76,7 @@ pub mod card {
                 resell_value: 1,
                 edition: Edition::None,
                 debuffed: false,
+                stickers: Stickers::NONE,
             };
         };
     }
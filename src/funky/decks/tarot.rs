@@ -34,6 +34,7 @@ pub mod card {
     use crate::funky::types::buffoon_card::{BCardType, BuffoonCard};
     use crate::funky::types::edition::Edition;
     use crate::funky::types::mpip::MPip;
+    use crate::funky::types::sticker::Stickers;
     use crate::prelude::{PipType, TarotRank, TarotSuit};
 
     pub const FOOL: BuffoonCard = BuffoonCard {
@@ -44,6 +45,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const MAGICIAN: BuffoonCard = BuffoonCard {
         suit: TarotSuit::MAJOR_ARCANA,
@@ -53,6 +55,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const HIGH_PRIESTESS: BuffoonCard = BuffoonCard {
         suit: TarotSuit::MAJOR_ARCANA,
@@ -62,6 +65,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const EMPRESS: BuffoonCard = BuffoonCard {
         suit: TarotSuit::MAJOR_ARCANA,
@@ -71,6 +75,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const EMPEROR: BuffoonCard = BuffoonCard {
         suit: TarotSuit::MAJOR_ARCANA,
@@ -80,6 +85,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const HIEROPHANT: BuffoonCard = BuffoonCard {
         suit: TarotSuit::MAJOR_ARCANA,
@@ -89,6 +95,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const LOVERS: BuffoonCard = BuffoonCard {
         suit: TarotSuit::MAJOR_ARCANA,
@@ -98,6 +105,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const THE_CHARIOT: BuffoonCard = BuffoonCard {
         suit: TarotSuit::MAJOR_ARCANA,
@@ -107,6 +115,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const STRENGTH: BuffoonCard = BuffoonCard {
         suit: TarotSuit::MAJOR_ARCANA,
@@ -116,6 +125,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const HERMIT: BuffoonCard = BuffoonCard {
         suit: TarotSuit::MAJOR_ARCANA,
@@ -125,6 +135,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const WHEEL_OF_FORTUNE: BuffoonCard = BuffoonCard {
         suit: TarotSuit::MAJOR_ARCANA,
@@ -134,6 +145,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const JUSTICE: BuffoonCard = BuffoonCard {
         suit: TarotSuit::MAJOR_ARCANA,
@@ -143,6 +155,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const HANGED_MAN: BuffoonCard = BuffoonCard {
         suit: TarotSuit::MAJOR_ARCANA,
@@ -152,6 +165,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const DEATH: BuffoonCard = BuffoonCard {
         suit: TarotSuit::MAJOR_ARCANA,
@@ -161,6 +175,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const TEMPERANCE: BuffoonCard = BuffoonCard {
         suit: TarotSuit::MAJOR_ARCANA,
@@ -170,6 +185,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const DEVIL: BuffoonCard = BuffoonCard {
         suit: TarotSuit::MAJOR_ARCANA,
@@ -179,6 +195,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const TOWER: BuffoonCard = BuffoonCard {
         suit: TarotSuit::MAJOR_ARCANA,
@@ -188,6 +205,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const STAR: BuffoonCard = BuffoonCard {
         suit: TarotSuit::MAJOR_ARCANA,
@@ -197,6 +215,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const MOON: BuffoonCard = BuffoonCard {
         suit: TarotSuit::MAJOR_ARCANA,
@@ -206,6 +225,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const SUN: BuffoonCard = BuffoonCard {
         suit: TarotSuit::MAJOR_ARCANA,
@@ -215,6 +235,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const JUDGEMENT: BuffoonCard = BuffoonCard {
         suit: TarotSuit::MAJOR_ARCANA,
@@ -224,6 +245,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
     pub const WORLD: BuffoonCard = BuffoonCard {
         suit: TarotSuit::MAJOR_ARCANA,
@@ -233,6 +255,7 @@ pub mod card {
         resell_value: 1,
         edition: Edition::None,
         debuffed: false,
+        stickers: Stickers::NONE,
     };
 }
 
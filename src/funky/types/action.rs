@@ -0,0 +1,245 @@
+use crate::funky::types::run::ShopChoice;
+use crate::funky::types::voucher::Voucher;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use thiserror::Error;
+
+/// One thing a player can do to a run — the unit a
+/// [`Run`](crate::funky::types::run::Run) is driven by and recorded in.
+///
+/// An action names *what was asked*, by position, exactly as the board's own
+/// methods take it; it carries no outcome. What happened is the run's
+/// [`Decision`](crate::funky::types::run::Decision) log, and the two together
+/// are a [`RunRecord`](crate::funky::types::run::RunRecord): replaying the
+/// actions from the same board and seed must reproduce the same decisions, or
+/// the replay has diverged.
+///
+/// Refused actions are recorded too. A refusal changes nothing, but leaving it
+/// out would make the log say something other than what the player did.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum Action {
+    /// Select the run's upcoming blind: shuffle, deal, and start the round.
+    SelectBlind,
+    /// Reroll the ante's Boss Blind before selecting the next blind — Director's
+    /// Cut once an ante, Retcon at will, $10 a roll.
+    RerollBoss,
+    /// Play the cards at these positions in hand.
+    PlayHand(Vec<usize>),
+    /// Discard the cards at these positions in hand.
+    Discard(Vec<usize>),
+    BuyStock(usize),
+    Reroll,
+    /// Open the pack in this shop slot; a [`PickFromPack`](Self::PickFromPack)
+    /// must follow.
+    OpenPack(usize),
+    /// Take the choice at this position from the open pack, or nothing.
+    PickFromPack(Option<usize>),
+    SkipPack(usize),
+    RedeemVoucher,
+    /// Use the consumable in this slot on the cards at `targets` in hand.
+    UseConsumable {
+        index: usize,
+        targets: Vec<usize>,
+    },
+    SellJoker(usize),
+    /// Close the shop.
+    LeaveShop,
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SelectBlind => write!(f, "select blind"),
+            Self::RerollBoss => write!(f, "reroll boss"),
+            Self::PlayHand(indices) => write!(f, "play {indices:?}"),
+            Self::Discard(indices) => write!(f, "discard {indices:?}"),
+            Self::BuyStock(index) => write!(f, "buy stock {index}"),
+            Self::Reroll => write!(f, "reroll"),
+            Self::OpenPack(index) => write!(f, "open pack {index}"),
+            Self::PickFromPack(Some(index)) => write!(f, "pick {index}"),
+            Self::PickFromPack(None) => write!(f, "pick nothing"),
+            Self::SkipPack(index) => write!(f, "skip pack {index}"),
+            Self::RedeemVoucher => write!(f, "redeem voucher"),
+            Self::UseConsumable { index, targets } => {
+                write!(f, "use consumable {index} on {targets:?}")
+            }
+            Self::SellJoker(index) => write!(f, "sell joker {index}"),
+            Self::LeaveShop => write!(f, "leave shop"),
+        }
+    }
+}
+
+/// A shop move is an action taken in the shop.
+impl From<ShopChoice> for Action {
+    fn from(choice: ShopChoice) -> Self {
+        match choice {
+            ShopChoice::BuyStock(index) => Self::BuyStock(index),
+            ShopChoice::OpenPack(index) => Self::OpenPack(index),
+            ShopChoice::SkipPack(index) => Self::SkipPack(index),
+            ShopChoice::Reroll => Self::Reroll,
+            ShopChoice::RedeemVoucher => Self::RedeemVoucher,
+            ShopChoice::Leave => Self::LeaveShop,
+        }
+    }
+}
+
+/// Why the board refused an action — what a frontend tells the player.
+///
+/// Returned by the `try_*` twin of every
+/// [`BuffoonBoard`](crate::funky::types::board::BuffoonBoard) action, where the
+/// plain method only says *that* it was refused. A variant carries what its
+/// message needs, [`key`](Self::key) is a stable id to look its translation up
+/// by, and `Display` is the English.
+///
+/// `#[non_exhaustive]`, as [`CardError`](crate::common::errors::CardError) is:
+/// a new refusal is not a breaking change, so match with a wildcard arm.
+#[derive(Clone, Copy, Debug, Eq, Error, Hash, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum ActionError {
+    #[error("The shop is closed.")]
+    ShopClosed,
+
+    #[error("There is no card at position {0}.")]
+    NoSuchCard(usize),
+
+    #[error("There is no joker at position {0}.")]
+    NoSuchJoker(usize),
+
+    #[error("There is no consumable at position {0}.")]
+    NoSuchConsumable(usize),
+
+    /// A tarot's target is not a card in the deck.
+    #[error("There is no card in the deck at position {0} to use the tarot on.")]
+    NoSuchTarget(usize),
+
+    #[error("There is nothing for sale in slot {0}.")]
+    NoSuchStock(usize),
+
+    #[error("There is no booster pack in slot {0}.")]
+    NoSuchPack(usize),
+
+    #[error("The shop offers no voucher.")]
+    NoVoucherOffered,
+
+    #[error("{0} is already redeemed.")]
+    VoucherRedeemed(Voucher),
+
+    #[error("{voucher} needs {base} first.")]
+    VoucherRequires { voucher: Voucher, base: Voucher },
+
+    /// `money` is what the board holds, which a Credit Card lets a purchase
+    /// take below $0.
+    #[error("Not enough money: it costs ${cost}, and there is ${money}.")]
+    NotEnoughMoney { cost: usize, money: isize },
+
+    #[error("There is no room for another joker.")]
+    NoJokerRoom,
+
+    #[error("There is no room for another consumable.")]
+    NoConsumableRoom,
+
+    #[error("There are no hands left this round.")]
+    NoHandsLeft,
+
+    #[error("There are no discards left this round.")]
+    NoDiscardsLeft,
+
+    #[error("The joker at position {0} is Eternal.")]
+    Eternal(usize),
+
+    /// Moving the joker would move this one, which is Pinned.
+    #[error("The joker at position {0} is Pinned.")]
+    Pinned(usize),
+
+    #[error("The blind is not a Boss Blind.")]
+    NotABoss,
+
+    #[error("Rerolling the boss needs Retcon, or Director's Cut once an ante.")]
+    NoBossReroll,
+}
+
+impl ActionError {
+    /// The refusal's id, in the kebab-case a Fluent message id takes —
+    /// `not-enough-money`. Fixed for good: a translation is filed under it,
+    /// as `funky-action-not-enough-money` in every locale's `funky.ftl`.
+    #[must_use]
+    pub const fn key(&self) -> &'static str {
+        match self {
+            Self::ShopClosed => "shop-closed",
+            Self::NoSuchCard(_) => "no-such-card",
+            Self::NoSuchJoker(_) => "no-such-joker",
+            Self::NoSuchConsumable(_) => "no-such-consumable",
+            Self::NoSuchTarget(_) => "no-such-target",
+            Self::NoSuchStock(_) => "no-such-stock",
+            Self::NoSuchPack(_) => "no-such-pack",
+            Self::NoVoucherOffered => "no-voucher-offered",
+            Self::VoucherRedeemed(_) => "voucher-redeemed",
+            Self::VoucherRequires { .. } => "voucher-requires",
+            Self::NotEnoughMoney { .. } => "not-enough-money",
+            Self::NoJokerRoom => "no-joker-room",
+            Self::NoConsumableRoom => "no-consumable-room",
+            Self::NoHandsLeft => "no-hands-left",
+            Self::NoDiscardsLeft => "no-discards-left",
+            Self::Eternal(_) => "eternal",
+            Self::Pinned(_) => "pinned",
+            Self::NotABoss => "not-a-boss",
+            Self::NoBossReroll => "no-boss-reroll",
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod funky__types__action_tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(Action::PlayHand(vec![0, 3]).to_string(), "play [0, 3]");
+        assert_eq!(Action::PickFromPack(None).to_string(), "pick nothing");
+        assert_eq!(
+            Action::UseConsumable {
+                index: 1,
+                targets: vec![2]
+            }
+            .to_string(),
+            "use consumable 1 on [2]"
+        );
+    }
+
+    #[test]
+    fn action_error__display() {
+        assert_eq!(
+            ActionError::NotEnoughMoney { cost: 6, money: 4 }.to_string(),
+            "Not enough money: it costs $6, and there is $4."
+        );
+        assert_eq!(
+            ActionError::VoucherRequires {
+                voucher: Voucher::Liquidation,
+                base: Voucher::ClearanceSale
+            }
+            .to_string(),
+            "Liquidation needs Clearance Sale first."
+        );
+        assert_eq!(
+            ActionError::NoSuchCard(7).to_string(),
+            "There is no card at position 7."
+        );
+    }
+
+    #[test]
+    fn action_error__key() {
+        assert_eq!(
+            ActionError::NotEnoughMoney { cost: 6, money: 4 }.key(),
+            "not-enough-money"
+        );
+        assert_eq!(ActionError::NoJokerRoom.key(), "no-joker-room");
+        assert_eq!(ActionError::Eternal(0).key(), "eternal");
+    }
+
+    #[test]
+    fn from__shop_choice() {
+        assert_eq!(Action::from(ShopChoice::BuyStock(2)), Action::BuyStock(2));
+        assert_eq!(Action::from(ShopChoice::Leave), Action::LeaveShop);
+    }
+}
@@ -12,11 +12,13 @@ use std::fmt::{Display, Formatter};
 /// The rest of the roster is *not* modelled, and the omission is the point: a
 /// boss like The Wall (a larger score requirement) needs a blind score target,
 /// and the suit-debuff bosses (The Club, The Goad, …) need debuffs threaded into
-/// scoring. Neither exists, and inventing a half of either would be the
+/// scoring. Neither existed, and inventing a half of either would be the
 /// silently-wrong scoring EPIC-01a is built to avoid. Add a boss here only once
-/// its ability is genuinely expressible.
+/// its ability is genuinely expressible. Scoring now honours a debuffed suit
+/// ([`BuffoonBoard::is_debuffed`]), though only a challenge sets one so far.
 ///
 /// [`BuffoonBoard::on_blind_selected`]: crate::funky::types::board::BuffoonBoard::on_blind_selected
+/// [`BuffoonBoard::is_debuffed`]: crate::funky::types::board::BuffoonBoard::is_debuffed
 #[derive(
     Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize,
 )]
@@ -38,6 +40,9 @@ pub enum BossBlind {
 }
 
 impl BossBlind {
+    /// Every boss this engine models — the pool a boss reroll draws from.
+    pub const ALL: [Self; 3] = [Self::TheNeedle, Self::TheWater, Self::TheManacle];
+
     /// Apply this boss's ability to a round's [`Draws`].
     ///
     /// Takes and returns `Draws` rather than mutating the board, so it composes
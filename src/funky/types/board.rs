@@ -1,18 +1,28 @@
+use crate::common::errors::CardError;
 use crate::funky::decks::basic;
 use crate::funky::decks::joker::Joker;
 use crate::funky::decks::planet::Planet;
 use crate::funky::decks::spectral::Spectral;
 use crate::funky::decks::tarot::MajorArcana;
-use crate::funky::types::blind::Blind;
+use crate::funky::types::action::ActionError;
+use crate::funky::types::blind::{Blind, BossBlind};
+use crate::funky::types::challenge::ChallengeRules;
 use crate::funky::types::draws::Draws;
 use crate::funky::types::edition::Edition;
-use crate::funky::types::effect::{EffectRegistry, ScoreOp, ScoringContext};
-use crate::funky::types::shop::{BoosterPack, PackKind, Shop};
+use crate::funky::types::effect::{
+    EffectContext, EffectEvent, EffectId, EffectRegistry, ScoreOp, ScoringContext,
+};
+use crate::funky::types::shop::{BoosterPack, PackKind, PackSize, Shop};
+use crate::funky::types::stake::Stake;
+use crate::funky::types::starter_deck::StarterDeck;
+use crate::funky::types::sticker::Stickers;
+use crate::funky::types::trace::{ProcEvent, ScoreTrace, ScoringPhase};
 use crate::funky::types::voucher::Voucher;
 use crate::prelude::{FrenchRank, FrenchSuit, Pip};
 use crate::preludes::funky::{
-    BCardType, BuffoonCard, BuffoonPile, HandRules, HandType, MPip, PokerHands, Score,
+    BCardType, BigScore, BuffoonCard, BuffoonPile, HandRules, HandType, MPip, PokerHands, Score,
 };
+use std::borrow::Cow;
 use std::collections::BTreeMap;
 
 use rand::rngs::StdRng;
@@ -21,6 +31,8 @@ use serde::{Deserialize, Serialize};
 
 /// Balatro's Lucky card grants a flat +20 mult on a successful (1-in-N) roll.
 const LUCKY_MULT: usize = 20;
+/// And $20 on its other roll.
+const LUCKY_MONEY: isize = 20;
 
 /// A lifecycle event that can grow a joker's counter or pay a joker's cash.
 enum GrowthEvent<'a> {
@@ -62,6 +74,12 @@ enum GrowthEvent<'a> {
     /// handled inline in [`BuffoonBoard::open_pack_with_rng`] rather than through
     /// this growth seam, which only carries counter deltas.
     PackSkipped,
+    /// A Lucky card won its roll — Lucky Cat's trigger.
+    ///
+    /// The only event fired from a [`ScoreTrace`] rather than a hook: the proc
+    /// happens inside the pure fold, so [`BuffoonBoard::commit_trace`] replays
+    /// it once per recorded [`ProcEvent::LuckyProc`].
+    LuckyProc,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
@@ -84,9 +102,9 @@ pub struct BuffoonBoard {
     /// round that simply runs until its hands are spent.
     ///
     /// Set by the caller. Balatro derives it from the ante and the blind (Small
-    /// ×1, Big ×1.5, Boss ×2 of an ante base), and **ante progression is not
-    /// modelled here** — so the mechanism lives on the board while the table
-    /// does not. Inventing the table would be a number this engine cannot check.
+    /// ×1, Big ×1.5, Boss ×2 of an ante base); the board holds the mechanism
+    /// and the table lives with the run loop that walks the antes —
+    /// [`Run::blind_target`](crate::funky::types::run::Run::blind_target).
     pub blind_target: usize,
     pub consumables: BuffoonPile,
     pub jokers: BuffoonPile,
@@ -186,6 +204,20 @@ pub struct BuffoonBoard {
     /// on the board, so it is read live from `jokers` and needs no flag. Selling
     /// it therefore restores the boss automatically.
     pub boss_disabled: bool,
+    /// The ante the run is in — Balatro's round of Small, Big and Boss Blind.
+    /// Starts at 1 and advances when a Boss Blind is beaten
+    /// ([`on_round_end`](Self::on_round_end)); Hieroglyph and Petroglyph take
+    /// one back when redeemed.
+    ///
+    /// The ante's *score table* is still the caller's — see
+    /// [`blind_target`](Self::blind_target). This counts antes; it does not
+    /// price them. A board saved before antes were counted reads back at 1.
+    #[serde(default = "BuffoonBoard::first_ante")]
+    pub ante: usize,
+    /// How many times the Boss Blind has been rerolled this ante — Director's
+    /// Cut allows one. Reset when the ante advances.
+    #[serde(default)]
+    pub boss_rerolls_this_ante: usize,
     /// How many Tarot cards the run has used, ever.
     ///
     /// Deliberately **not** a [`joker_state`](Self::joker_state) accumulator:
@@ -219,6 +251,37 @@ pub struct BuffoonBoard {
     /// A persistent run modifier the draw recompute subtracts, the vouchers
     /// shape — 0 by default, so a run that never uses Ectoplasm is unchanged.
     pub spectral_hand_size_penalty: usize,
+    /// The [`StarterDeck`] the run was started with, or `None` for a board
+    /// built directly.
+    ///
+    /// Most of a starter deck is applied once, at
+    /// [`StarterDeck::board_with_rng`], and then is simply board state. This
+    /// field is for the three rules that are read **live**: Green's cash-out,
+    /// Ghost's shop spectrals, and Plasma's balance after the jokers score.
+    /// `None` is inert, so a board built without one is unchanged.
+    pub starter_deck: Option<StarterDeck>,
+    /// The run's [`Stake`], read live: by the draw recompute (Blue's −1
+    /// discard), by cash-out (Red's unpaid Small Blind), and by the shop's
+    /// sticker rolls. The score tables are the run loop's — see
+    /// [`Run::blind_target`](crate::funky::types::run::Run::blind_target).
+    /// White Stake, the default, is inert — and what a board saved before
+    /// stakes existed reads back at.
+    #[serde(default)]
+    pub stake: Stake,
+    /// Jokers added to the rarity pools at runtime — see
+    /// [`add_custom_jokers`](Self::add_custom_jokers). Each joins the pool of
+    /// its own `card_type`, so the shop, the Buffoon packs and the joker
+    /// creators draw it exactly as they draw a built-in. Empty by default,
+    /// which is what a board saved before custom jokers reads back as.
+    #[serde(default)]
+    pub custom_jokers: BuffoonPile,
+    /// The [`Challenge`](crate::funky::types::challenge::Challenge) rules the
+    /// run is played under, read live: by the joker pools and the voucher
+    /// draw (the bans), by scoring (the debuffed suits) and by the run loop
+    /// (no shop). Empty by default, which is inert — and what a board saved
+    /// before challenges reads back as.
+    #[serde(default)]
+    pub challenge_rules: ChallengeRules,
 }
 
 /// An empty board with Balatro's base slot counts.
@@ -238,6 +301,22 @@ impl BuffoonBoard {
     pub const DEFAULT_JOKER_SLOTS: usize = 5;
     /// Balatro's base consumable slot count.
     pub const DEFAULT_CONSUMABLE_SLOTS: usize = 2;
+    /// The playing-card enhancements that keep a rank and suit (so a "face
+    /// card" stays one) — Stone is excluded, since it masks both. What the
+    /// enhanced-card spectrals and an Illusion shop card draw from.
+    const ENHANCEMENTS: [MPip; 5] = [
+        MPip::Chips(30),
+        MPip::MultPlus(4),
+        MPip::Glass(2, 4),
+        MPip::STEEL,
+        MPip::Lucky(5, 15),
+    ];
+
+    /// The ante a run starts in — and the one an older save without an
+    /// [`ante`](Self::ante) reads back in.
+    const fn first_ante() -> usize {
+        1
+    }
 
     #[must_use]
     pub fn new(draws: Draws, deck: BuffoonPile) -> Self {
@@ -269,10 +348,16 @@ impl BuffoonBoard {
             tarots_used: 0,
             blind: Blind::default(),
             boss_disabled: false,
+            ante: Self::first_ante(),
+            boss_rerolls_this_ante: 0,
             joker_state: Vec::new(),
             shop: None,
             vouchers: Vec::new(),
             spectral_hand_size_penalty: 0,
+            starter_deck: None,
+            stake: Stake::White,
+            custom_jokers: BuffoonPile::default(),
+            challenge_rules: ChallengeRules::default(),
         }
     }
 
@@ -328,7 +413,7 @@ impl BuffoonBoard {
     /// [`BuffoonCard::calculate_plus`]: crate::funky::types::buffoon_card::BuffoonCard::calculate_plus
     #[must_use]
     pub fn scoring_phase2_dealt_hand_scoring(&self, running: Score) -> Score {
-        self.fold_played_cards::<StdRng>(running, None, None)
+        self.fold_played_cards::<StdRng>(running, None, None, None)
     }
 
     /// The single played-card fold behind phase 2. Each card adds its chips and
@@ -336,6 +421,12 @@ impl BuffoonBoard {
     /// a Lucky card rolls (if `rng`), a Glass card applies its ×mult, a
     /// `MPip::Custom` card is looked up (if `registry`). Built-in cards are
     /// unaffected by the options.
+    ///
+    /// With a `trace`, the fold also reports what it saw — every trigger, every
+    /// Lucky proc, every RNG draw — and reads the one in-flight mutation it can
+    /// compute without writing: a card's later triggers carry the Hiker chips its
+    /// earlier triggers this hand earned. [`commit_trace`](Self::commit_trace)
+    /// then makes that permanent.
     // Glass's ×mult factor is a small literal from the card data; the same
     // allow the other ×mult seams carry (`builtin_held_op`, `joker_x_mult`).
     #[allow(clippy::cast_precision_loss)]
@@ -344,27 +435,57 @@ impl BuffoonBoard {
         running: Score,
         mut rng: Option<&mut R>,
         registry: Option<&EffectRegistry>,
+        mut trace: Option<&mut ScoreTrace>,
     ) -> Score {
         let mut score = running;
+        let hiker = self.hiker_bump();
 
-        for (index, card) in self.played.iter().enumerate() {
+        for (index, played) in self.played.iter().enumerate() {
+            // A debuffed card still shapes the hand, but scores nothing.
+            if self.is_debuffed(played) {
+                continue;
+            }
             // A card is scored once, plus once more for each retrigger a joker
             // grants it (Hack: each played 2-5; Hanging Chad: the first card).
             // Retriggering re-runs the whole per-card contribution, so a
             // retriggered Lucky card rolls again — matching Balatro. With no
             // retrigger joker this is a single pass.
-            for _ in 0..=self.played_retriggers(index, card) {
+            for pass in 0..=self.played_retriggers(index, played) {
+                // `on_scored` already paid the first trigger's Hiker chips; a
+                // traced fold adds the retriggers' as it goes, so the second
+                // pass scores the card already fattened — Balatro's order.
+                let card = trace
+                    .as_ref()
+                    .map_or(*played, |_| played.add_base_chips(hiker * pass));
+                if let Some(trace) = trace.as_deref_mut() {
+                    trace.push(ProcEvent::CardScored { index, pass, card });
+                }
+
                 // Every played card contributes its built-in chips/mult first,
                 // then its special (probabilistic / custom) effect resolves.
-                score = Self::builtin_played_op(card).apply(score);
+                score = Self::apply_step(
+                    &Self::builtin_played_op(&card),
+                    score,
+                    ScoringPhase::PlayedCards,
+                    Some(card),
+                    trace.as_deref_mut(),
+                );
 
                 let special = match card.enhancement {
-                    MPip::Lucky(mult_odds, _) if mult_odds > 0 => {
-                        // A 1-in-`mult_odds` roll wins on outcomes `0..wins`;
-                        // Oops! All 6s doubles `wins` (capped at certainty).
-                        let wins = self.probability_numerator().min(mult_odds);
+                    MPip::Lucky(mult_odds, money_odds) => {
                         rng.as_deref_mut().map_or(ScoreOp::Nothing, |rng| {
-                            if rng.random_range(0..mult_odds) < wins {
+                            let mut trace = trace.as_deref_mut();
+                            let mult = self.lucky_roll(mult_odds, rng, trace.as_deref_mut());
+                            let money = self.lucky_roll(money_odds, rng, trace.as_deref_mut());
+                            if let Some(trace) = trace {
+                                if money {
+                                    trace.push(ProcEvent::LuckyMoney { index, card });
+                                }
+                                if mult || money {
+                                    trace.push(ProcEvent::LuckyProc { index, card });
+                                }
+                            }
+                            if mult {
                                 ScoreOp::AddMult(LUCKY_MULT)
                             } else {
                                 ScoreOp::Nothing
@@ -380,22 +501,54 @@ impl BuffoonBoard {
                     // destroying a played card needs the deck-mutation seam
                     // wired into a round loop that does not exist yet.
                     MPip::Glass(mult, _odds) => ScoreOp::TimesMult(mult as f32),
-                    MPip::Custom(id) => self.custom_op(*card, id, registry),
+                    MPip::Custom(id) => self.custom_op(card, None, id, registry),
                     _ => ScoreOp::Nothing,
                 };
-                score = special.apply(score);
+                score = Self::apply_step(
+                    &special,
+                    score,
+                    ScoringPhase::PlayedCards,
+                    Some(card),
+                    trace.as_deref_mut(),
+                );
 
                 // The card's edition scores at its own position, after its
                 // chips/mult — so a Polychrome ×1.5 multiplies the running score
                 // here (the Glass shape), and a retriggered card re-applies its
                 // edition each pass, matching Balatro.
-                score = card.edition.score_op().apply(score);
+                score = Self::apply_step(
+                    &card.edition.score_op(),
+                    score,
+                    ScoringPhase::PlayedCards,
+                    Some(card),
+                    trace.as_deref_mut(),
+                );
             }
         }
 
         score
     }
 
+    /// A Lucky card's 1-in-`odds` roll, recorded in `trace`. It wins on
+    /// outcomes `0..wins`, and Oops! All 6s doubles `wins` (capped at
+    /// certainty). Odds of 0 never win, and draw nothing.
+    fn lucky_roll<R: Rng + ?Sized>(
+        &self,
+        odds: usize,
+        rng: &mut R,
+        trace: Option<&mut ScoreTrace>,
+    ) -> bool {
+        if odds == 0 {
+            return false;
+        }
+        let wins = self.probability_numerator().min(odds);
+        let value = rng.random_range(0..odds);
+        if let Some(trace) = trace {
+            trace.push(ProcEvent::RngDraw { range: odds, value });
+        }
+        value < wins
+    }
+
     /// How many *additional* times the played card at `index` is scored, summed
     /// over the board's retrigger jokers. 0 for a board with none (the common
     /// case), so the played-card fold is byte-identical when no retrigger joker
@@ -404,11 +557,9 @@ impl BuffoonBoard {
     ///
     /// Jokers are walked with their `joker_state` slot, since the round-state
     /// retriggers read a counter: Seltzer retriggers only while its 10 hands
-    /// are unspent.
+    /// are unspent. A copier walks as its target, slot and all.
     fn played_retriggers(&self, index: usize, card: &BuffoonCard) -> usize {
-        self.jokers
-            .iter()
-            .enumerate()
+        self.abilities()
             .map(|(slot, joker)| {
                 let counter = self.joker_state.get(slot).copied().unwrap_or(0);
                 match joker.enhancement {
@@ -432,6 +583,23 @@ impl BuffoonBoard {
             .sum()
     }
 
+    /// Apply `op` to the running `score`, and record it as a step of `phase`
+    /// from `source` when the fold is traced. The one place every fold applies
+    /// an op, so a traced and an untraced fold cannot apply different ones.
+    fn apply_step(
+        op: &ScoreOp,
+        score: Score,
+        phase: ScoringPhase,
+        source: Option<BuffoonCard>,
+        trace: Option<&mut ScoreTrace>,
+    ) -> Score {
+        let next = op.apply(score);
+        if let Some(trace) = trace {
+            trace.record(phase, source, op, next);
+        }
+        next
+    }
+
     /// Built-in played-card contribution: base rank chips (+ flat `Chips`) plus
     /// the card's own additive plus-effects, as one additive [`ScoreOp`].
     fn builtin_played_op(card: &BuffoonCard) -> ScoreOp {
@@ -445,7 +613,8 @@ impl BuffoonBoard {
     fn custom_op(
         &self,
         source: BuffoonCard,
-        id: u32,
+        slot: Option<usize>,
+        id: EffectId,
         registry: Option<&EffectRegistry>,
     ) -> ScoreOp {
         registry
@@ -454,6 +623,9 @@ impl BuffoonBoard {
                 let ctx = ScoringContext {
                     board: self,
                     source,
+                    state: slot
+                        .and_then(|slot| self.joker_state.get(slot).copied())
+                        .unwrap_or(0),
                 };
                 effect.score(&ctx)
             })
@@ -469,13 +641,18 @@ impl BuffoonBoard {
     /// no held cards it is the identity.
     #[must_use]
     pub fn scoring_phase3_effects_in_hand(&self, running: Score) -> Score {
-        self.fold_held_cards(running, None)
+        self.fold_held_cards(running, None, None)
     }
 
     /// The single held-card fold behind phase 3. Built-in Steel/`MultTimes`
     /// cards apply their ×mult; a `MPip::Custom` held card is resolved through
-    /// `registry` (if any).
-    fn fold_held_cards(&self, running: Score, registry: Option<&EffectRegistry>) -> Score {
+    /// `registry` (if any). Each application is recorded to `trace` (if any).
+    fn fold_held_cards(
+        &self,
+        running: Score,
+        registry: Option<&EffectRegistry>,
+        mut trace: Option<&mut ScoreTrace>,
+    ) -> Score {
         let mut score = running;
 
         // Mime retriggers held-card abilities: each held card's op applies
@@ -484,12 +661,21 @@ impl BuffoonBoard {
         // leaving the held fold byte-identical.
         let retriggers = self.held_retriggers();
         for card in &self.in_hand {
+            if self.is_debuffed(card) {
+                continue;
+            }
             let op = match card.enhancement {
-                MPip::Custom(id) => self.custom_op(*card, id, registry),
+                MPip::Custom(id) => self.custom_op(*card, None, id, registry),
                 _ => Self::builtin_held_op(card),
             };
             for _ in 0..=retriggers {
-                score = op.apply(score);
+                score = Self::apply_step(
+                    &op,
+                    score,
+                    ScoringPhase::HeldCards,
+                    Some(*card),
+                    trace.as_deref_mut(),
+                );
             }
         }
 
@@ -500,9 +686,8 @@ impl BuffoonBoard {
     /// the board's held-retrigger jokers (Mime). Unlike `played_retriggers` this
     /// is card-independent — Mime retriggers all held cards alike.
     fn held_retriggers(&self) -> usize {
-        self.jokers
-            .iter()
-            .map(|joker| match joker.enhancement {
+        self.abilities()
+            .map(|(_, joker)| match joker.enhancement {
                 MPip::RetriggerCardsInHand(n) => n,
                 _ => 0,
             })
@@ -531,14 +716,14 @@ impl BuffoonBoard {
     /// (`MultTimesOn{Pair,Trips,4OfAKind,Straight,Flush}` — The Duo/Trio/Family/
     /// Order/Tribe — which fire when the played hand *contains* that category).
     ///
-    /// Note: state-dependent ×mult jokers (`MultTimesOnEmptyJokerSlots`,
-    /// `MultTimesEveryXHands`) are not applied yet — they need board/round
-    /// state and their exact factors pinned down.
+    /// State-dependent jokers read the board as it stands: Joker Stencil
+    /// (`MultTimesOnEmptyJokerSlots`) its empty slots, Loyalty Card
+    /// (`MultTimesEveryXHands`) the hands counted in its `joker_state` slot.
     ///
     /// [`BuffoonPile::calculate_plus`]: crate::funky::types::buffoon_pile::BuffoonPile::calculate_plus
     #[must_use]
     pub fn scoring_phase4_joker_scoring(&self, running: Score) -> Score {
-        self.fold_jokers::<StdRng>(running, None, None)
+        self.fold_jokers::<StdRng>(running, None, None, None)
     }
 
     /// The single joker-scoring fold that every phase-4 entry point delegates
@@ -550,7 +735,10 @@ impl BuffoonBoard {
     /// * everything else — additive ([`BuffoonPile::calculate_plus`]).
     ///
     /// The `rng`/`registry` options are what distinguish the pure, seeded, and
-    /// registry entry points — the fold itself lives here once.
+    /// registry entry points — the fold itself lives here once. A `trace`
+    /// records which jokers fired and what the RNG drew, and lends each counter
+    /// joker the growth the hand has already earned but not yet committed — so
+    /// Lucky Cat's ×mult includes the procs rolled moments earlier in phase 2.
     ///
     /// [`BuffoonPile::calculate_plus`]: crate::funky::types::buffoon_pile::BuffoonPile::calculate_plus
     fn fold_jokers<R: Rng + ?Sized>(
@@ -558,29 +746,103 @@ impl BuffoonBoard {
         running: Score,
         mut rng: Option<&mut R>,
         registry: Option<&EffectRegistry>,
+        mut trace: Option<&mut ScoreTrace>,
     ) -> Score {
         let mut score = running;
+        let rules = self.hand_rules();
 
-        for (index, joker) in self.jokers.iter().enumerate() {
+        for (index, seat) in self.jokers.iter().enumerate() {
+            // A debuffed joker is switched off entirely, edition included.
+            if seat.debuffed {
+                continue;
+            }
+            // A copier scores its target's ability against the target's counter,
+            // but as itself: it is the source of the step, and its own edition
+            // (not the target's) lands after.
+            let Some((slot, joker)) = self
+                .ability_slot(index)
+                .and_then(|slot| self.jokers.get(slot).map(|joker| (slot, joker)))
+            else {
+                score = Self::apply_step(
+                    &seat.edition.score_op(),
+                    score,
+                    ScoringPhase::Jokers,
+                    Some(*seat),
+                    trace.as_deref_mut(),
+                );
+                continue;
+            };
             let op = match joker.enhancement {
-                MPip::Custom(id) => self.custom_op(*joker, id, registry),
+                MPip::Custom(id) => self.custom_op(*joker, Some(slot), id, registry),
                 MPip::MultPlusRandomTo(n) if n > 0 => {
                     rng.as_deref_mut().map_or(ScoreOp::Nothing, |rng| {
-                        ScoreOp::AddMult(rng.random_range(0..n))
+                        let value = rng.random_range(0..n);
+                        if let Some(trace) = trace.as_deref_mut() {
+                            trace.push(ProcEvent::RngDraw { range: n, value });
+                        }
+                        ScoreOp::AddMult(value)
                     })
                 }
                 _ => {
-                    let counter = self.joker_state.get(index).copied().unwrap_or(0);
-                    self.counter_joker_op(joker, counter)
+                    let stored = self.joker_state.get(slot).copied().unwrap_or(0);
+                    let pending = trace.as_deref().map_or(0, |trace| {
+                        self.pending_growth(joker.enhancement, trace, rules)
+                    });
+                    self.counter_joker_op(joker, stored + pending)
                         .unwrap_or_else(|| self.builtin_joker_op(joker))
                 }
             };
-            score = op.apply(score);
+            if op != ScoreOp::Nothing {
+                if let Some(trace) = trace.as_deref_mut() {
+                    trace.push(ProcEvent::JokerFired {
+                        index,
+                        joker: *seat,
+                    });
+                }
+            }
+            score = Self::apply_step(
+                &op,
+                score,
+                ScoringPhase::Jokers,
+                Some(*seat),
+                trace.as_deref_mut(),
+            );
 
             // The joker's own edition scores at its position, after its effect —
             // so a Polychrome joker ×1.5s the running score once its +mult/×mult
             // has landed, matching Balatro's left-to-right joker order.
-            score = joker.edition.score_op().apply(score);
+            score = Self::apply_step(
+                &seat.edition.score_op(),
+                score,
+                ScoringPhase::Jokers,
+                Some(*seat),
+                trace.as_deref_mut(),
+            );
+        }
+
+        // Observatory: each held planet for the hand being scored ×1.5s it,
+        // after the jokers — consumables sit to the right of the joker row.
+        if self.vouchers.contains(&Voucher::Observatory) {
+            let hand_type = self.scoring_hand_type();
+            for planet in &self.consumables {
+                if let MPip::ChipsMultPlusOnHand(_, _, target) = planet.enhancement {
+                    if planet.card_type == BCardType::Planet && target == hand_type {
+                        score = Self::apply_step(
+                            &ScoreOp::TimesMult(1.5),
+                            score,
+                            ScoringPhase::Jokers,
+                            Some(*planet),
+                            trace.as_deref_mut(),
+                        );
+                    }
+                }
+            }
+        }
+
+        // The Plasma Deck balances chips and mult once every joker has scored —
+        // the deck is the last thing to touch the hand, as in Balatro.
+        if self.starter_deck == Some(StarterDeck::Plasma) {
+            score = Self::apply_step(&ScoreOp::Balance, score, ScoringPhase::Jokers, None, trace);
         }
 
         score
@@ -605,7 +867,7 @@ impl BuffoonBoard {
                 let faces = self
                     .played
                     .iter()
-                    .filter(|card| self.is_face_card(card))
+                    .filter(|card| self.is_face_card(card) && !self.is_debuffed(card))
                     .count();
                 return ScoreOp::AddChips(n * faces);
             }
@@ -613,7 +875,7 @@ impl BuffoonBoard {
                 let count = self
                     .played
                     .iter()
-                    .filter(|card| ranks.contains(&card.rank.index))
+                    .filter(|card| ranks.contains(&card.rank.index) && !self.is_debuffed(card))
                     .count();
                 return ScoreOp::Add(Score::new(chips * count, mult * count));
             }
@@ -639,6 +901,16 @@ impl BuffoonBoard {
             MPip::MultPlusPerTarotUsedThisRun(n) => {
                 return ScoreOp::AddMult(n * self.tarots_used);
             }
+            // Supernova: +1 mult per time the scoring hand type has been played
+            // this run, this hand included. `on_hand_played` records a hand only
+            // after it scores, hence the `+ 1`.
+            MPip::MultPlusOnHandPlays => {
+                let played = self
+                    .poker_hands
+                    .get(&self.scoring_hand_type())
+                    .map_or(0, |hand| hand.times_played);
+                return ScoreOp::AddMult(played + 1);
+            }
             // Gros Michel: +n mult unconditionally. The destruction half of the
             // variant is inert here — it rolls at end of round, which has no
             // hook yet — but the mult is not conditional on it and scores now.
@@ -649,7 +921,7 @@ impl BuffoonBoard {
                 let count = self
                     .played
                     .iter()
-                    .filter(|card| card.rank.index == rank)
+                    .filter(|card| card.rank.index == rank && !self.is_debuffed(card))
                     .count();
                 return ScoreOp::Add(Score::new(chips * count, mult * count));
             }
@@ -668,18 +940,46 @@ impl BuffoonBoard {
         }
 
         self.joker_x_mult(joker).map_or_else(
-            || ScoreOp::Add(self.played.calculate_plus(joker)),
+            || ScoreOp::Add(self.scored_cards().calculate_plus(joker)),
             ScoreOp::TimesMult,
         )
     }
 
+    /// The played cards as the per-card joker effects see them: each card a
+    /// challenge debuffs is marked so, and earns nothing
+    /// ([`BuffoonCard::calculate_plus`]). Borrowed when no suit is debuffed.
+    ///
+    /// [`BuffoonCard::calculate_plus`]: crate::funky::types::buffoon_card::BuffoonCard::calculate_plus
+    fn scored_cards(&self) -> Cow<'_, BuffoonPile> {
+        if self.challenge_rules.debuffed_suits.is_empty() {
+            return Cow::Borrowed(&self.played);
+        }
+        Cow::Owned(
+            self.played
+                .iter()
+                .map(|card| BuffoonCard {
+                    debuffed: self.is_debuffed(card),
+                    ..*card
+                })
+                .collect(),
+        )
+    }
+
+    /// Whether `card` is debuffed: marked so itself, or of a suit the
+    /// [`challenge_rules`](Self::challenge_rules) debuff. A debuffed card
+    /// still counts toward the hand type; it scores nothing.
+    #[must_use]
+    pub fn is_debuffed(&self, card: &BuffoonCard) -> bool {
+        card.debuffed || self.challenge_rules.debuffs(card)
+    }
+
     /// The straight/flush detection rules in force for this board, loosened by
     /// its rule-modifier jokers: **Four Fingers** drops straights and flushes to
     /// four cards; **Shortcut** allows one-gap straights. Vanilla Balatro
     /// ([`HandRules::default`]) when neither is held, so hand typing is
     /// unchanged. Multiple modifiers stack (Four Fingers + Shortcut → a
     /// four-card gapped straight).
-    fn hand_rules(&self) -> HandRules {
+    pub(crate) fn hand_rules(&self) -> HandRules {
         let mut rules = HandRules::default();
         for joker in &self.jokers {
             match joker.enhancement {
@@ -943,6 +1243,196 @@ impl BuffoonBoard {
         self.scoring_phase4_joker_scoring_with_rng(held, rng)
     }
 
+    /// Like [`score`](Self::score), but also returns the [`ScoreTrace`] of what
+    /// the fold saw — the **mutable scoring pass**, minus the mutation. Its
+    /// steps are the score's breakdown: every op, its source, and the running
+    /// chips and mult after it.
+    ///
+    /// The fold stays `&self`; the trace is its report, and
+    /// [`commit_trace`](Self::commit_trace) is what writes it back. Between the
+    /// two, nothing is rolled twice: the Lucky procs Lucky Cat grows on are the
+    /// ones this fold recorded, not a second draw that might disagree.
+    ///
+    /// Two things differ from [`score`](Self::score), both in the direction of
+    /// Balatro: a retriggered card scores its later triggers with the Hiker chips
+    /// its earlier ones earned, and a counter joker reads the growth the hand has
+    /// earned so far (a Lucky proc in phase 2 reaches Lucky Cat in phase 4).
+    #[must_use]
+    pub fn score_traced(&self) -> (Score, ScoreTrace) {
+        self.score_traced_inner::<StdRng>(None, None)
+    }
+
+    /// [`score_traced`](Self::score_traced) with the probabilistic effects
+    /// rolled from `rng`, as [`score_with_rng`](Self::score_with_rng) rolls
+    /// them. Every draw taken is recorded as a [`ProcEvent::RngDraw`].
+    #[must_use]
+    pub fn score_traced_with_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> (Score, ScoreTrace) {
+        self.score_traced_inner(Some(rng), None)
+    }
+
+    /// The hand at Balatro scale: [`score_traced`](Self::score_traced)'s steps
+    /// replayed over a [`BigScore`], so a ×mult stack that would pin `Score` at
+    /// `usize::MAX` — or round each factor up on the way — is scored exactly.
+    #[must_use]
+    pub fn score_big(&self) -> BigScore {
+        self.score_traced().1.big_score()
+    }
+
+    /// [`score_big`](Self::score_big) with the probabilistic effects rolled
+    /// from `rng`.
+    #[must_use]
+    pub fn score_big_with_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> BigScore {
+        self.score_traced_with_rng(rng).1.big_score()
+    }
+
+    /// [`score_traced`](Self::score_traced) with `MPip::Custom` cards and
+    /// jokers scored through `registry`, as in
+    /// [`score_with_registry`](Self::score_with_registry). A custom effect's
+    /// ops are steps of the trace like any other.
+    #[must_use]
+    pub fn score_traced_with_registry(&self, registry: &EffectRegistry) -> (Score, ScoreTrace) {
+        self.score_traced_inner::<StdRng>(None, Some(registry))
+    }
+
+    /// [`score_traced_with_registry`](Self::score_traced_with_registry) with
+    /// the probabilistic effects rolled from `rng` — the fold
+    /// [`try_play_hand_with_registry`](Self::try_play_hand_with_registry)
+    /// scores a hand with.
+    #[must_use]
+    pub fn score_traced_with_rng_and_registry<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        registry: &EffectRegistry,
+    ) -> (Score, ScoreTrace) {
+        self.score_traced_inner(Some(rng), Some(registry))
+    }
+
+    /// [`score_big`](Self::score_big) with custom effects scored through
+    /// `registry`.
+    #[must_use]
+    pub fn score_big_with_registry(&self, registry: &EffectRegistry) -> BigScore {
+        self.score_traced_with_registry(registry).1.big_score()
+    }
+
+    /// [`score_big_with_rng`](Self::score_big_with_rng) with custom effects
+    /// scored through `registry`.
+    #[must_use]
+    pub fn score_big_with_rng_and_registry<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        registry: &EffectRegistry,
+    ) -> BigScore {
+        self.score_traced_with_rng_and_registry(rng, registry)
+            .1
+            .big_score()
+    }
+
+    pub(crate) fn score_traced_inner<R: Rng + ?Sized>(
+        &self,
+        mut rng: Option<&mut R>,
+        registry: Option<&EffectRegistry>,
+    ) -> (Score, ScoreTrace) {
+        let mut trace = ScoreTrace::new();
+        let base = self.scoring_phase1_pre_scoring_traced(&mut trace);
+        let after_cards =
+            self.fold_played_cards(base, rng.as_deref_mut(), registry, Some(&mut trace));
+        let held = self.fold_held_cards(after_cards, registry, Some(&mut trace));
+        let score = self.fold_jokers(held, rng, registry, Some(&mut trace));
+
+        (score, trace)
+    }
+
+    /// [`scoring_phase1_pre_scoring`](Self::scoring_phase1_pre_scoring),
+    /// recording the hand's base as the trace's first step.
+    #[must_use]
+    pub fn scoring_phase1_pre_scoring_traced(&self, trace: &mut ScoreTrace) -> Score {
+        let base = self.scoring_phase1_pre_scoring();
+        Self::apply_step(
+            &ScoreOp::Add(base),
+            Score::default(),
+            ScoringPhase::PreScoring,
+            None,
+            Some(trace),
+        )
+    }
+
+    /// [`scoring_phase2_dealt_hand_scoring`](Self::scoring_phase2_dealt_hand_scoring),
+    /// recording each played card's ops — per trigger — and the running score
+    /// after each.
+    #[must_use]
+    pub fn scoring_phase2_dealt_hand_scoring_traced(
+        &self,
+        running: Score,
+        trace: &mut ScoreTrace,
+    ) -> Score {
+        self.fold_played_cards::<StdRng>(running, None, None, Some(trace))
+    }
+
+    /// [`scoring_phase3_effects_in_hand`](Self::scoring_phase3_effects_in_hand),
+    /// recording each held card's ×mult as it applies.
+    #[must_use]
+    pub fn scoring_phase3_effects_in_hand_traced(
+        &self,
+        running: Score,
+        trace: &mut ScoreTrace,
+    ) -> Score {
+        self.fold_held_cards(running, None, Some(trace))
+    }
+
+    /// [`scoring_phase4_joker_scoring`](Self::scoring_phase4_joker_scoring),
+    /// recording each joker's op, then its edition's, left to right.
+    #[must_use]
+    pub fn scoring_phase4_joker_scoring_traced(
+        &self,
+        running: Score,
+        trace: &mut ScoreTrace,
+    ) -> Score {
+        self.fold_jokers::<StdRng>(running, None, None, Some(trace))
+    }
+
+    /// Apply the state a traced hand earned — the commit half of
+    /// [`score_traced`](Self::score_traced).
+    ///
+    /// * **Lucky Cat** grows once per [`ProcEvent::LuckyProc`].
+    /// * **Lucky cards** pay $20 per [`ProcEvent::LuckyMoney`].
+    /// * **Hiker** bumps each played card once per *retrigger* it scored
+    ///   ([`on_scored`](Self::on_scored) already paid its first trigger), onto
+    ///   the card and its roster copy alike.
+    ///
+    /// Deterministic: it reads only the trace and the board, never an RNG, so a
+    /// hand scored once and committed once cannot disagree with itself. Call it
+    /// after scoring and before [`on_hand_played`](Self::on_hand_played), while
+    /// [`played`](Self::played) still holds the cards the trace indexes.
+    pub fn commit_trace(&mut self, trace: &ScoreTrace) {
+        for _ in 0..trace.lucky_procs() {
+            self.apply_growth(&GrowthEvent::LuckyProc);
+        }
+        let money = isize::try_from(trace.lucky_money_procs()).unwrap_or(isize::MAX);
+        self.money = self.money.saturating_add(money.saturating_mul(LUCKY_MONEY));
+
+        let hiker = self.hiker_bump();
+        if hiker == 0 {
+            return;
+        }
+        for event in &trace.events {
+            if let ProcEvent::CardScored { index, pass, .. } = *event {
+                if pass > 0 {
+                    if let Some(card) = self.played.get(index).copied() {
+                        self.replace_played_card(index, card.add_base_chips(hiker));
+                    }
+                }
+            }
+        }
+    }
+
+    /// The counter growth a traced hand has earned but not yet committed, for a
+    /// joker wearing `enhancement` — what [`commit_trace`](Self::commit_trace)
+    /// is about to add. Lets the same hand read its own procs.
+    fn pending_growth(&self, enhancement: MPip, trace: &ScoreTrace, rules: HandRules) -> i32 {
+        let procs = i32::try_from(trace.lucky_procs()).unwrap_or(i32::MAX);
+        procs.saturating_mul(self.growth_delta(enhancement, &GrowthEvent::LuckyProc, rules))
+    }
+
     /// Phase 2 with probabilistic played-card effects (Lucky cards) rolled from
     /// `rng`, threaded through the same played-card fold as
     /// [`scoring_phase2_dealt_hand_scoring`](Self::scoring_phase2_dealt_hand_scoring).
@@ -952,7 +1442,7 @@ impl BuffoonBoard {
         running: Score,
         rng: &mut R,
     ) -> Score {
-        self.fold_played_cards(running, Some(rng), None)
+        self.fold_played_cards(running, Some(rng), None, None)
     }
 
     /// Phase 4 with probabilistic joker effects rolled, threaded through the
@@ -966,7 +1456,7 @@ impl BuffoonBoard {
         running: Score,
         rng: &mut R,
     ) -> Score {
-        self.fold_jokers(running, Some(rng), None)
+        self.fold_jokers(running, Some(rng), None, None)
     }
 
     /// Like [`score`](Self::score), but resolves `MPip::Custom(id)` jokers
@@ -987,12 +1477,98 @@ impl BuffoonBoard {
     #[must_use]
     pub fn score_with_registry(&self, registry: &EffectRegistry) -> Score {
         let base = self.scoring_phase1_pre_scoring();
-        let after_cards = self.fold_played_cards::<StdRng>(base, None, Some(registry));
-        let held = self.fold_held_cards(after_cards, Some(registry));
+        let after_cards = self.fold_played_cards::<StdRng>(base, None, Some(registry), None);
+        let held = self.fold_held_cards(after_cards, Some(registry), None);
 
         self.scoring_phase4_joker_scoring_with_registry(held, registry)
     }
 
+    /// Rewrite each legacy effect id on the board to the id `registry`
+    /// [aliases](EffectRegistry::alias) it to — the migration for a board saved
+    /// when custom effects were keyed by a bare `u32`. Returns how many cards
+    /// were rewritten; a legacy id with no alias is left as it is.
+    ///
+    /// Scoring already follows an alias, so a migrated board scores as it did.
+    /// Migrating is what makes the board *save* the new id, after which the
+    /// alias is no longer needed. Every pile is rewritten, the roster and the
+    /// shop's stock included.
+    pub fn migrate_effect_ids(&mut self, registry: &EffectRegistry) -> usize {
+        let migrate = |card: &BuffoonCard| match card.enhancement {
+            MPip::Custom(id) if registry.resolve(id) != id => Some(BuffoonCard {
+                enhancement: MPip::Custom(registry.resolve(id)),
+                ..*card
+            }),
+            _ => None,
+        };
+        let mut rewritten = 0;
+        for pile in [
+            &mut self.deck,
+            &mut self.full_deck,
+            &mut self.in_hand,
+            &mut self.played,
+            &mut self.discarded,
+            &mut self.consumables,
+            &mut self.jokers,
+            &mut self.custom_jokers,
+        ] {
+            for index in 0..pile.len() {
+                if let Some(card) = pile.get(index).and_then(migrate) {
+                    pile.remove(index);
+                    pile.insert(index, card);
+                    rewritten += 1;
+                }
+            }
+        }
+        if let Some(shop) = &mut self.shop {
+            for stocked in &mut shop.stock {
+                if let Some(card) = migrate(stocked) {
+                    *stocked = card;
+                    rewritten += 1;
+                }
+            }
+        }
+        rewritten
+    }
+
+    /// Run every custom joker's lifecycle hook for `event`, left to right — the
+    /// custom half of the hooks the built-in jokers grow and pay on.
+    ///
+    /// The board does not hold the registry, so the `_with_registry` twins of
+    /// its actions — [`try_play_hand_with_registry`](Self::try_play_hand_with_registry),
+    /// [`try_discard_cards_with_registry`](Self::try_discard_cards_with_registry),
+    /// [`try_buy_stock_with_registry`](Self::try_buy_stock_with_registry),
+    /// [`on_blind_selected_with_registry`](Self::on_blind_selected_with_registry)
+    /// and [`on_round_end_with_registry`](Self::on_round_end_with_registry) —
+    /// take it and call this after the built-in hook. A caller driving the
+    /// plain hooks by hand calls it the same way: after
+    /// [`on_discard`](Self::on_discard) fire [`EffectEvent::Discard`], and so
+    /// on. A debuffed joker is skipped,
+    /// and a Blueprint copying a custom joker runs that joker's hook, against
+    /// its counter, as it scores against it. Unregistered ids do nothing.
+    pub fn trigger_effects(&mut self, event: EffectEvent<'_>, registry: &EffectRegistry) {
+        self.ensure_state_len();
+        let seats: Vec<(usize, EffectId)> = self
+            .abilities()
+            .filter_map(|(slot, joker)| match joker.enhancement {
+                MPip::Custom(id) => Some((slot, id)),
+                _ => None,
+            })
+            .collect();
+        for (slot, id) in seats {
+            let Some(effect) = registry.get(id) else {
+                continue;
+            };
+            let mut ctx = EffectContext::new(self, slot);
+            match event {
+                EffectEvent::BlindSelected => effect.on_blind_selected(&mut ctx),
+                EffectEvent::HandPlayed(played) => effect.on_hand_played(&mut ctx, played),
+                EffectEvent::Discard(discarded) => effect.on_discard(&mut ctx, discarded),
+                EffectEvent::RoundEnd => effect.on_round_end(&mut ctx),
+                EffectEvent::Bought(card) => effect.on_buy(&mut ctx, card),
+            }
+        }
+    }
+
     /// Phase 4, resolving `MPip::Custom(id)` jokers through `registry`. Built-in
     /// jokers fold in exactly as [`scoring_phase4_joker_scoring`] does.
     ///
@@ -1003,7 +1579,7 @@ impl BuffoonBoard {
         running: Score,
         registry: &EffectRegistry,
     ) -> Score {
-        self.fold_jokers::<StdRng>(running, None, Some(registry))
+        self.fold_jokers::<StdRng>(running, None, Some(registry), None)
     }
 
     /// Add a joker with a fresh (0) counter, keeping `joker_state` aligned.
@@ -1012,12 +1588,112 @@ impl BuffoonBoard {
         self.joker_state.push(0);
     }
 
-    /// Remove the joker at `index`, dropping its counter with it.
-    pub fn remove_joker(&mut self, index: usize) -> BuffoonCard {
+    /// Remove the joker at `index`, dropping its counter with it. Returns the
+    /// joker, or `None` if `index` is out of bounds or the joker is Eternal.
+    ///
+    /// Every way a joker leaves the board goes through here — selling, Madness,
+    /// Ceremonial Dagger, Hex and Ankh, the destruction rolls, the decay — so
+    /// an Eternal sticker is enforced once rather than at each of them. A
+    /// refused destruction still spent whatever roll chose it: the RNG stream
+    /// does not shift with the stickers on the board.
+    pub fn remove_joker(&mut self, index: usize) -> Option<BuffoonCard> {
+        self.try_remove_joker(index).ok()
+    }
+
+    /// [`remove_joker`](Self::remove_joker), saying why it was refused.
+    ///
+    /// # Errors
+    ///
+    /// [`ActionError::NoSuchJoker`] or [`ActionError::Eternal`].
+    pub fn try_remove_joker(&mut self, index: usize) -> Result<BuffoonCard, ActionError> {
+        let joker = self
+            .jokers
+            .get(index)
+            .ok_or(ActionError::NoSuchJoker(index))?;
+        if joker.stickers.eternal {
+            return Err(ActionError::Eternal(index));
+        }
         if index < self.joker_state.len() {
             self.joker_state.remove(index);
         }
-        self.jokers.remove(index)
+        Ok(self.jokers.remove(index))
+    }
+
+    /// Move the joker at `from` to slot `to`, shifting the jokers between; its
+    /// counter moves with it. Returns whether it happened — `false` if either
+    /// slot is out of range, or if the move would shift a Pinned joker
+    /// ([`Stickers`]).
+    ///
+    /// Order is part of the board: jokers score left to right, and Blueprint
+    /// and Brainstorm copy by position.
+    pub fn move_joker(&mut self, from: usize, to: usize) -> bool {
+        self.try_move_joker(from, to).is_ok()
+    }
+
+    /// [`move_joker`](Self::move_joker), saying why it was refused.
+    ///
+    /// # Errors
+    ///
+    /// [`ActionError::NoSuchJoker`], naming `from` or `to`, or
+    /// [`ActionError::Pinned`], naming the first Pinned joker from `from` to
+    /// `to`.
+    pub fn try_move_joker(&mut self, from: usize, to: usize) -> Result<(), ActionError> {
+        if let Some(index) = [from, to].into_iter().find(|i| *i >= self.jokers.len()) {
+            return Err(ActionError::NoSuchJoker(index));
+        }
+        if from != to {
+            let pinned = |&i: &usize| self.jokers.get(i).is_some_and(|j| j.stickers.pinned);
+            if let Some(index) = (from.min(to)..=from.max(to)).find(pinned) {
+                return Err(ActionError::Pinned(index));
+            }
+        }
+        self.ensure_state_len();
+        let joker = self.jokers.remove(from);
+        let counter = self.joker_state.remove(from);
+        self.jokers.insert(to, joker);
+        self.joker_state.insert(to, counter);
+        Ok(())
+    }
+
+    /// The slot whose ability the joker at `index` runs: its own, or — for
+    /// Blueprint and Brainstorm — the slot the copy resolves to. `None` when
+    /// `index` is out of range or a copy has nothing to land on: a rightmost
+    /// Blueprint, a leftmost Brainstorm, or a chain of copiers that loops. A
+    /// debuffed joker has no ability, and copying one copies nothing.
+    ///
+    /// Chains are followed, so a Blueprint to the left of a Blueprint copies
+    /// what that one copies. A board has no more distinct slots than jokers, so
+    /// a chain still unresolved after that many hops has looped.
+    fn ability_slot(&self, index: usize) -> Option<usize> {
+        let mut slot = index;
+        for _ in 0..=self.jokers.len() {
+            let joker = self.jokers.get(slot)?;
+            if joker.debuffed {
+                return None;
+            }
+            slot = match joker.enhancement {
+                MPip::CopyJokerToRight => slot + 1,
+                MPip::CopyLeftmostJoker => 0,
+                _ => return Some(slot),
+            };
+        }
+        None
+    }
+
+    /// Every joker's ability, left to right, as the slot it is read from and
+    /// the joker there: each joker itself, and for a copier the joker it
+    /// resolves to (see [`ability_slot`](Self::ability_slot)). A copier with
+    /// nothing to copy is left out.
+    ///
+    /// The hooks that **run** an ability — retriggers, payouts, creations —
+    /// read the board through this, so a copier fires them too. The passive
+    /// rule reads (`hand_rules`, Oops! All 6s, Chicot, Credit Card, the draw
+    /// modifiers) keep reading `jokers`: Balatro does not let a copy grant them.
+    fn abilities(&self) -> impl Iterator<Item = (usize, BuffoonCard)> + '_ {
+        (0..self.jokers.len()).filter_map(|index| {
+            let slot = self.ability_slot(index)?;
+            self.jokers.get(slot).map(|joker| (slot, *joker))
+        })
     }
 
     /// Add a card to the run's deck: the run now **owns** it (it joins
@@ -1054,15 +1730,25 @@ impl BuffoonBoard {
     /// reading the board sees the post-destruction deck — consistent with
     /// Erosion, which scores the shortfall this call just widened.
     pub fn destroy_deck_card(&mut self, index: usize) -> Option<BuffoonCard> {
+        self.try_destroy_deck_card(index).ok()
+    }
+
+    /// [`destroy_deck_card`](Self::destroy_deck_card), saying why it was
+    /// refused.
+    ///
+    /// # Errors
+    ///
+    /// [`ActionError::NoSuchCard`].
+    pub fn try_destroy_deck_card(&mut self, index: usize) -> Result<BuffoonCard, ActionError> {
         if index >= self.full_deck.len() {
-            return None;
+            return Err(ActionError::NoSuchCard(index));
         }
         let card = self.full_deck.remove(index);
         if let Some(undealt) = self.deck.iter().position(|c| *c == card) {
             self.deck.remove(undealt);
         }
         self.apply_growth(&GrowthEvent::CardDestroyed(card));
-        Some(card)
+        Ok(card)
     }
 
     /// Replace the roster card at `index` with `replacement`, keeping the
@@ -1073,16 +1759,32 @@ impl BuffoonBoard {
     /// deck card to Steel or Stone, Hiker's `+4` chips, a tarot's rank/suit
     /// change. Same value-matching rule as [`destroy_deck_card`](Self::destroy_deck_card).
     pub fn replace_deck_card(&mut self, index: usize, replacement: BuffoonCard) -> bool {
-        let Some(old) = self.full_deck.get(index).copied() else {
-            return false;
-        };
+        self.try_replace_deck_card(index, replacement).is_ok()
+    }
+
+    /// [`replace_deck_card`](Self::replace_deck_card), returning the card it
+    /// replaced or saying why it was refused.
+    ///
+    /// # Errors
+    ///
+    /// [`ActionError::NoSuchCard`].
+    pub fn try_replace_deck_card(
+        &mut self,
+        index: usize,
+        replacement: BuffoonCard,
+    ) -> Result<BuffoonCard, ActionError> {
+        let old = self
+            .full_deck
+            .get(index)
+            .copied()
+            .ok_or(ActionError::NoSuchCard(index))?;
         self.full_deck.remove(index);
         self.full_deck.insert(index, replacement);
         if let Some(undealt) = self.deck.iter().position(|c| *c == old) {
             self.deck.remove(undealt);
             self.deck.insert(undealt, replacement);
         }
-        true
+        Ok(old)
     }
 
     /// The `in_hand` mirror of the deck-mutation seam — the three primitives the
@@ -1098,16 +1800,32 @@ impl BuffoonBoard {
     /// `index` is out of bounds. Aura (stamp an edition) and Sigil / Ouija
     /// (rewrite suit / rank) ride this.
     pub fn replace_in_hand(&mut self, index: usize, replacement: BuffoonCard) -> bool {
-        let Some(old) = self.in_hand.get(index).copied() else {
-            return false;
-        };
+        self.try_replace_in_hand(index, replacement).is_ok()
+    }
+
+    /// [`replace_in_hand`](Self::replace_in_hand), returning the card it
+    /// replaced or saying why it was refused.
+    ///
+    /// # Errors
+    ///
+    /// [`ActionError::NoSuchCard`].
+    pub fn try_replace_in_hand(
+        &mut self,
+        index: usize,
+        replacement: BuffoonCard,
+    ) -> Result<BuffoonCard, ActionError> {
+        let old = self
+            .in_hand
+            .get(index)
+            .copied()
+            .ok_or(ActionError::NoSuchCard(index))?;
         self.in_hand.remove(index);
         self.in_hand.insert(index, replacement);
         if let Some(slot) = self.full_deck.iter().position(|c| *c == old) {
             self.full_deck.remove(slot);
             self.full_deck.insert(slot, replacement);
         }
-        true
+        Ok(old)
     }
 
     /// Destroy the held card at `index`: it leaves the run, so it also leaves the
@@ -1116,15 +1834,24 @@ impl BuffoonBoard {
     /// if `index` is out of bounds. Immolate and the Familiar/Grim/Incantation
     /// trio ride this.
     pub fn destroy_in_hand(&mut self, index: usize) -> Option<BuffoonCard> {
+        self.try_destroy_in_hand(index).ok()
+    }
+
+    /// [`destroy_in_hand`](Self::destroy_in_hand), saying why it was refused.
+    ///
+    /// # Errors
+    ///
+    /// [`ActionError::NoSuchCard`].
+    pub fn try_destroy_in_hand(&mut self, index: usize) -> Result<BuffoonCard, ActionError> {
         if index >= self.in_hand.len() {
-            return None;
+            return Err(ActionError::NoSuchCard(index));
         }
         let card = self.in_hand.remove(index);
         if let Some(slot) = self.full_deck.iter().position(|c| *c == card) {
             self.full_deck.remove(slot);
         }
         self.apply_growth(&GrowthEvent::CardDestroyed(card));
-        Some(card)
+        Ok(card)
     }
 
     /// Destroy one held card chosen at random (Immolate and the Familiar / Grim /
@@ -1149,19 +1876,10 @@ impl BuffoonBoard {
             FrenchSuit::DIAMONDS,
             FrenchSuit::CLUBS,
         ];
-        // The playing-card enhancements that keep a rank and suit (so a "face
-        // card" stays one) — Stone is excluded, since it masks both.
-        const ENHANCEMENTS: [MPip; 5] = [
-            MPip::Chips(30),
-            MPip::MultPlus(4),
-            MPip::Glass(2, 4),
-            MPip::STEEL,
-            MPip::Lucky(5, 15),
-        ];
         for _ in 0..count {
             let rank = ranks[rng.random_range(0..ranks.len())];
             let suit = SUITS[rng.random_range(0..SUITS.len())];
-            let enhancement = ENHANCEMENTS[rng.random_range(0..ENHANCEMENTS.len())];
+            let enhancement = Self::ENHANCEMENTS[rng.random_range(0..Self::ENHANCEMENTS.len())];
             self.add_to_hand(BuffoonCard {
                 suit,
                 rank,
@@ -1170,6 +1888,7 @@ impl BuffoonBoard {
                 edition: Edition::None,
                 resell_value: 0,
                 debuffed: false,
+                stickers: Stickers::NONE,
             });
         }
     }
@@ -1237,11 +1956,21 @@ impl BuffoonBoard {
     /// A **Negative** consumable takes no slot, so it always lands — the rule
     /// Perkeo's copy relies on.
     pub fn create_consumable(&mut self, card: BuffoonCard) -> bool {
+        self.try_create_consumable(card).is_ok()
+    }
+
+    /// [`create_consumable`](Self::create_consumable), saying why it was
+    /// refused.
+    ///
+    /// # Errors
+    ///
+    /// [`ActionError::NoConsumableRoom`].
+    pub fn try_create_consumable(&mut self, card: BuffoonCard) -> Result<(), ActionError> {
         if !card.edition.is_negative() && !self.has_consumable_room() {
-            return false;
+            return Err(ActionError::NoConsumableRoom);
         }
         self.consumables.push(card);
-        true
+        Ok(())
     }
 
     /// Spend the consumable at `index`, apply its effect, and record the use.
@@ -1255,7 +1984,8 @@ impl BuffoonBoard {
     ///   [`full_deck`](Self::full_deck)), through
     ///   [`BuffoonCard::enhance`] and the [`replace_deck_card`](Self::replace_deck_card)
     ///   seam, so the change persists on the run's own copy. Pass an empty
-    ///   `targets` for a tarot that takes none.
+    ///   `targets` for a tarot that takes none. A target past the end of the
+    ///   deck refuses the whole use, and the tarot is kept.
     ///
     /// Either way the card leaves `consumables` and fires the `ConsumableUsed`
     /// growth event, which is what Constellation and Fortune Teller read.
@@ -1263,15 +1993,33 @@ impl BuffoonBoard {
     /// # Known gap: run-level tarots
     ///
     /// The **card-enhancing** tarots are applied here. The ones that act on the
-    /// *run* rather than on a card — Death, Judgement, The Hermit, The Wheel of
-    /// Fortune — pass through [`BuffoonCard::enhance`] unchanged, so this counts
-    /// them as used (correctly, for Fortune Teller) while their real effects stay
-    /// out of scope, exactly as EPIC-01a item 5e leaves them. Their systems
-    /// (spectral cards, the shop, run-level RNG) are EPIC-01 Story 3's, not this
-    /// seam's — using one here is a no-op rather than a wrong effect.
+    /// *run* rather than on a card — Death, Judgement, The Hermit — pass through
+    /// [`BuffoonCard::enhance`] unchanged, so this counts them as used
+    /// (correctly, for Fortune Teller) while their real effects stay out of
+    /// scope, exactly as EPIC-01a item 5e leaves them. Their systems (spectral
+    /// cards, the shop, run-level RNG) are EPIC-01 Story 3's, not this seam's —
+    /// using one here is a no-op rather than a wrong effect. The Wheel of
+    /// Fortune is the exception: it rolls an edition onto a joker, on the seeded
+    /// path only.
     ///
     /// [`PokerHands::increment`]: crate::funky::types::hands::PokerHands::increment
     pub fn use_consumable(&mut self, index: usize, targets: &[usize]) -> Option<BuffoonCard> {
+        self.use_consumable_inner::<StdRng>(index, targets, None)
+            .ok()
+    }
+
+    /// [`use_consumable`](Self::use_consumable), saying why it was refused.
+    ///
+    /// # Errors
+    ///
+    /// [`ActionError::NoSuchConsumable`], or [`ActionError::NoSuchTarget`] for
+    /// a tarot aimed past the end of the deck — refused whole, so the tarot is
+    /// kept.
+    pub fn try_use_consumable(
+        &mut self,
+        index: usize,
+        targets: &[usize],
+    ) -> Result<BuffoonCard, ActionError> {
         self.use_consumable_inner::<StdRng>(index, targets, None)
     }
 
@@ -1286,6 +2034,21 @@ impl BuffoonBoard {
         targets: &[usize],
         rng: &mut R,
     ) -> Option<BuffoonCard> {
+        self.use_consumable_inner(index, targets, Some(rng)).ok()
+    }
+
+    /// [`use_consumable_with_rng`](Self::use_consumable_with_rng), saying why
+    /// it was refused.
+    ///
+    /// # Errors
+    ///
+    /// [`ActionError::NoSuchConsumable`] or [`ActionError::NoSuchTarget`].
+    pub fn try_use_consumable_with_rng<R: Rng + ?Sized>(
+        &mut self,
+        index: usize,
+        targets: &[usize],
+        rng: &mut R,
+    ) -> Result<BuffoonCard, ActionError> {
         self.use_consumable_inner(index, targets, Some(rng))
     }
 
@@ -1293,21 +2056,30 @@ impl BuffoonBoard {
         &mut self,
         index: usize,
         targets: &[usize],
-        rng: Option<&mut R>,
-    ) -> Option<BuffoonCard> {
-        if index >= self.consumables.len() {
-            return None;
+        mut rng: Option<&mut R>,
+    ) -> Result<BuffoonCard, ActionError> {
+        let Some(card) = self.consumables.get(index).copied() else {
+            return Err(ActionError::NoSuchConsumable(index));
+        };
+        if card.card_type == BCardType::Tarot {
+            if let Some(&slot) = targets.iter().find(|&&slot| slot >= self.full_deck.len()) {
+                return Err(ActionError::NoSuchTarget(slot));
+            }
         }
-        let card = self.consumables.remove(index);
+        self.consumables.remove(index);
 
         match card.card_type {
             BCardType::Planet => self.poker_hands.increment(card),
             BCardType::Tarot => {
                 for &slot in targets {
-                    let Some(target) = self.full_deck.get(slot).copied() else {
-                        continue;
-                    };
-                    self.replace_deck_card(slot, target.enhance(card));
+                    if let Some(target) = self.full_deck.get(slot).copied() {
+                        self.replace_deck_card(slot, target.enhance(card));
+                    }
+                }
+                if card.enhancement == MPip::WHEEL_OF_FORTUNE {
+                    if let Some(rng) = rng.as_deref_mut() {
+                        self.spin_the_wheel(rng);
+                    }
                 }
                 self.tarots_used += 1;
             }
@@ -1316,11 +2088,36 @@ impl BuffoonBoard {
         }
 
         self.apply_growth(&GrowthEvent::ConsumableUsed(card));
-        Some(card)
+        Ok(card)
     }
 
-    /// Apply a spectral card's effect. Deterministic effects (Black Hole) apply
-    /// on either path; the rolling ones are inert without `rng`, the way a Lucky
+    /// **The Wheel of Fortune**: a 1-in-4 roll (scaled by the board's shared
+    /// odds seam, so Oops! All 6s doubles it) to stamp a guaranteed edition on a
+    /// random joker that has none. A board with no unedited joker spends no
+    /// draw — Balatro will not let the card be used then.
+    fn spin_the_wheel<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        let MPip::Odds1in(odds) = MPip::WHEEL_OF_FORTUNE else {
+            return;
+        };
+        let plain: Vec<usize> = self
+            .jokers
+            .iter()
+            .enumerate()
+            .filter(|(_, joker)| joker.edition == Edition::None)
+            .map(|(index, _)| index)
+            .collect();
+        if plain.is_empty() {
+            return;
+        }
+        let wins = self.probability_numerator().min(odds);
+        if rng.random_range(0..odds) < wins {
+            let index = plain[rng.random_range(0..plain.len())];
+            self.set_joker_edition(index, Edition::roll_guaranteed(rng));
+        }
+    }
+
+    /// Apply a spectral card's effect. Deterministic effects (Black Hole) apply
+    /// on either path; the rolling ones are inert without `rng`, the way a Lucky
     /// card is in the pure `score`.
     fn apply_spectral<R: Rng + ?Sized>(
         &mut self,
@@ -1387,13 +2184,12 @@ impl BuffoonBoard {
         rng: &mut R,
     ) {
         match effect {
-            // Aura: a random edition (Foil/Holo/Poly) onto the selected hand card
-            // (`targets[0]`), persisted to the roster through `replace_in_hand`.
+            // Aura: a guaranteed edition (Foil/Holo/Poly) onto the selected hand
+            // card (`targets[0]`), persisted to the roster through
+            // `replace_in_hand`.
             MPip::SpectralEditionRandomHandCard => {
                 if let Some((target, card)) = self.selected_hand_card(targets) {
-                    const EDITIONS: [Edition; 3] =
-                        [Edition::Foil, Edition::Holographic, Edition::Polychrome];
-                    let edition = EDITIONS[rng.random_range(0..EDITIONS.len())];
+                    let edition = Edition::roll_guaranteed(rng);
                     self.replace_in_hand(target, card.with_edition(edition));
                 }
             }
@@ -1499,6 +2295,7 @@ impl BuffoonBoard {
 
     /// Destroy every joker except the one at `keep` — Hex and Ankh's cleanup.
     /// Removed high-to-low so `keep` stays valid until it is the last one.
+    /// Eternal jokers survive it.
     fn destroy_other_jokers(&mut self, keep: usize) {
         for index in (0..self.jokers.len()).rev() {
             if index != keep {
@@ -1511,7 +2308,7 @@ impl BuffoonBoard {
     /// there is room — the shared body of the spectral joker creators (The Soul,
     /// Wraith), the same pattern Riff-Raff uses at blind select.
     fn create_random_joker<R: Rng + ?Sized>(&mut self, rarity: BCardType, rng: &mut R) {
-        let Some(pool) = Self::joker_pool(rarity) else {
+        let Some(pool) = self.joker_pool(rarity) else {
             return;
         };
         if !pool.is_empty() && self.has_joker_room() {
@@ -1556,6 +2353,8 @@ impl BuffoonBoard {
                 i32::try_from(d.len()).unwrap_or(i32::MAX)
             }
             (MPip::LoseChipsPerHand(_, _), GrowthEvent::HandPlayed(_)) => 1,
+            // Loyalty Card counts every hand played since it was acquired.
+            (MPip::MultTimesEveryXHands(_, _), GrowthEvent::HandPlayed(_)) => 1,
             (MPip::GainChipsPerCardCountHand(_, n), GrowthEvent::HandPlayed(p)) if p.len() == n => {
                 1
             }
@@ -1622,6 +2421,8 @@ impl BuffoonBoard {
             // Red Card: one tick per booster pack skipped, the same counter
             // shape on the skip event.
             (MPip::MultPlusPerPackSkipped(_), GrowthEvent::PackSkipped) => 1,
+            // Lucky Cat: one tick per Lucky proc, replayed from a trace.
+            (MPip::GainMultTimesPerLuckyProc(_), GrowthEvent::LuckyProc) => 1,
             _ => 0,
         }
     }
@@ -1647,6 +2448,8 @@ impl BuffoonBoard {
         let hand_type =
             Self::normalise_hand_type(played.determine_hand_type_with(self.hand_rules()));
         *self.hands_by_type_this_round.entry(hand_type).or_insert(0) += 1;
+        // And for the run (Supernova, Telescope's most played hand).
+        self.poker_hands.play_hand(&hand_type);
         self.melt_emptied_jokers();
     }
 
@@ -1702,7 +2505,7 @@ impl BuffoonBoard {
     /// them in hand order. `None` if any index is out of bounds, in which case
     /// the hand is left untouched — a partial move would be worse than a
     /// refusal.
-    fn take_from_hand(&mut self, indices: &[usize]) -> Option<BuffoonPile> {
+    pub(crate) fn take_from_hand(&mut self, indices: &[usize]) -> Option<BuffoonPile> {
         let mut slots: Vec<usize> = indices.to_vec();
         slots.sort_unstable();
         slots.dedup();
@@ -1730,8 +2533,10 @@ impl BuffoonBoard {
     /// 1. the cards move from `in_hand` to `played`;
     /// 2. [`on_scored`](Self::on_scored) — pre-scoring mutations (Hiker fattens,
     ///    Vampire eats), which the hand about to score must see;
-    /// 3. [`score`](Self::score) — the pure four-phase fold, and the result is
-    ///    added to [`round_score`](Self::round_score);
+    /// 3. [`score_traced`](Self::score_traced) — the four-phase fold, whose
+    ///    result is added to [`round_score`](Self::round_score) and whose trace
+    ///    is [committed](Self::commit_trace) (Lucky Cat grows, retriggered cards
+    ///    keep their extra Hiker chips);
     /// 4. [`on_hand_played`](Self::on_hand_played) — the hand is *recorded*,
     ///    which is why the counters that read it (Ice Cream, Card Sharp) see the
     ///    hand behind them rather than the one they just scored;
@@ -1742,7 +2547,17 @@ impl BuffoonBoard {
     /// never procs, Superposition never creates — exactly as [`score`](Self::score)
     /// does. Use [`play_hand_with_rng`](Self::play_hand_with_rng) to drive those.
     pub fn play_hand(&mut self, indices: &[usize]) -> Option<Score> {
-        self.play_hand_inner::<StdRng>(indices, None)
+        self.play_hand_inner::<StdRng>(indices, None, None).ok()
+    }
+
+    /// [`play_hand`](Self::play_hand), saying why it was refused.
+    ///
+    /// # Errors
+    ///
+    /// [`ActionError::NoHandsLeft`], or [`ActionError::NoSuchCard`] naming the
+    /// first index past the end of the hand.
+    pub fn try_play_hand(&mut self, indices: &[usize]) -> Result<Score, ActionError> {
+        self.play_hand_inner::<StdRng>(indices, None, None)
     }
 
     /// [`play_hand`](Self::play_hand), with the probabilistic effects live: Lucky
@@ -1752,18 +2567,52 @@ impl BuffoonBoard {
         indices: &[usize],
         rng: &mut R,
     ) -> Option<Score> {
-        self.play_hand_inner(indices, Some(rng))
+        self.play_hand_inner(indices, Some(rng), None).ok()
+    }
+
+    /// [`play_hand_with_rng`](Self::play_hand_with_rng), saying why it was
+    /// refused.
+    ///
+    /// # Errors
+    ///
+    /// As [`try_play_hand`](Self::try_play_hand).
+    pub fn try_play_hand_with_rng<R: Rng + ?Sized>(
+        &mut self,
+        indices: &[usize],
+        rng: &mut R,
+    ) -> Result<Score, ActionError> {
+        self.play_hand_inner(indices, Some(rng), None)
+    }
+
+    /// [`try_play_hand_with_rng`](Self::try_play_hand_with_rng), with the
+    /// custom jokers live too: a `MPip::Custom` card or joker scores through
+    /// `registry`, as in [`score_with_registry`](Self::score_with_registry),
+    /// and each custom joker's [`EffectEvent::HandPlayed`] hook fires after
+    /// [`on_hand_played`](Self::on_hand_played).
+    ///
+    /// # Errors
+    ///
+    /// As [`try_play_hand`](Self::try_play_hand).
+    pub fn try_play_hand_with_registry<R: Rng + ?Sized>(
+        &mut self,
+        indices: &[usize],
+        rng: &mut R,
+        registry: &EffectRegistry,
+    ) -> Result<Score, ActionError> {
+        self.play_hand_inner(indices, Some(rng), Some(registry))
     }
 
     fn play_hand_inner<R: Rng + ?Sized>(
         &mut self,
         indices: &[usize],
         mut rng: Option<&mut R>,
-    ) -> Option<Score> {
+        registry: Option<&EffectRegistry>,
+    ) -> Result<Score, ActionError> {
         if self.hands_remaining() == 0 {
-            return None;
+            return Err(ActionError::NoHandsLeft);
         }
-        self.played = self.take_from_hand(indices)?;
+        self.check_hand(indices)?;
+        self.played = self.take_from_hand(indices).unwrap_or_default();
 
         match rng.as_deref_mut() {
             Some(rng) => self.on_scored_with_rng(rng),
@@ -1772,14 +2621,28 @@ impl BuffoonBoard {
         // `on_scored` may have mutated the played cards (Hiker, Vampire), so the
         // hand that scores and is recorded is the board's, not the one taken.
         let scored = self.played.clone();
-        let score = rng.map_or_else(|| self.score(), |rng| self.score_with_rng(rng));
+        let (score, trace) = self.score_traced_inner(rng, registry);
         self.round_score = self.round_score.saturating_add(score.score());
+        self.commit_trace(&trace);
         self.on_hand_played(&scored);
+        if let Some(registry) = registry {
+            self.trigger_effects(EffectEvent::HandPlayed(&scored), registry);
+        }
 
         self.discarded.extend(&self.played);
         self.played.clear();
         self.deal_to_hand_size();
-        Some(score)
+        Ok(score)
+    }
+
+    /// `Err(NoSuchCard)` naming the first of `indices` past the end of the
+    /// hand — the check [`take_from_hand`](Self::take_from_hand) makes, with
+    /// its culprit.
+    fn check_hand(&self, indices: &[usize]) -> Result<(), ActionError> {
+        indices
+            .iter()
+            .find(|index| **index >= self.in_hand.len())
+            .map_or(Ok(()), |index| Err(ActionError::NoSuchCard(*index)))
     }
 
     /// Discard the cards at `indices` from the hand and refill. Returns whether
@@ -1790,16 +2653,51 @@ impl BuffoonBoard {
     /// see it (Faceless Joker pays, Ramen and Yorick grow) and the round's
     /// remaining-discard count drops — which Banner and Mystic Summit read.
     pub fn discard_cards(&mut self, indices: &[usize]) -> bool {
+        self.try_discard_cards(indices).is_ok()
+    }
+
+    /// [`discard_cards`](Self::discard_cards), saying why it was refused.
+    ///
+    /// # Errors
+    ///
+    /// [`ActionError::NoDiscardsLeft`], or [`ActionError::NoSuchCard`] naming
+    /// the first index past the end of the hand.
+    pub fn try_discard_cards(&mut self, indices: &[usize]) -> Result<(), ActionError> {
+        self.discard_cards_inner(indices, None)
+    }
+
+    /// [`try_discard_cards`](Self::try_discard_cards), firing each custom
+    /// joker's [`EffectEvent::Discard`] hook through `registry` after
+    /// [`on_discard`](Self::on_discard).
+    ///
+    /// # Errors
+    ///
+    /// As [`try_discard_cards`](Self::try_discard_cards).
+    pub fn try_discard_cards_with_registry(
+        &mut self,
+        indices: &[usize],
+        registry: &EffectRegistry,
+    ) -> Result<(), ActionError> {
+        self.discard_cards_inner(indices, Some(registry))
+    }
+
+    fn discard_cards_inner(
+        &mut self,
+        indices: &[usize],
+        registry: Option<&EffectRegistry>,
+    ) -> Result<(), ActionError> {
         if self.discards_remaining() == 0 {
-            return false;
+            return Err(ActionError::NoDiscardsLeft);
         }
-        let Some(discarded) = self.take_from_hand(indices) else {
-            return false;
-        };
+        self.check_hand(indices)?;
+        let discarded = self.take_from_hand(indices).unwrap_or_default();
         self.on_discard(&discarded);
+        if let Some(registry) = registry {
+            self.trigger_effects(EffectEvent::Discard(&discarded), registry);
+        }
         self.discarded.extend(&discarded);
         self.deal_to_hand_size();
-        true
+        Ok(())
     }
 
     /// How many discards the round has **left**: what it granted, minus what has
@@ -1868,6 +2766,7 @@ impl BuffoonBoard {
         // A new blind is a fresh boss: whatever Luchador switched off last round
         // is back on.
         self.boss_disabled = false;
+        self.daggers_destroy_to_the_right();
         self.recompute_draws();
         self.apply_growth(&GrowthEvent::BlindSelected(self.blind));
         self.discards_used = 0;
@@ -1878,9 +2777,8 @@ impl BuffoonBoard {
         // Marble Joker: one Stone card into the deck per copy. Collected first
         // so the deck can be mutated without holding a borrow on `jokers`.
         let additions: Vec<BCardType> = self
-            .jokers
-            .iter()
-            .filter_map(|joker| match joker.enhancement {
+            .abilities()
+            .filter_map(|(_, joker)| match joker.enhancement {
                 MPip::AddCardTypeWhenBlindSelected(card_type) => Some(card_type),
                 _ => None,
             })
@@ -1892,6 +2790,34 @@ impl BuffoonBoard {
         }
     }
 
+    /// Each Ceremonial Dagger destroys the joker to its right and adds double
+    /// that joker's [`resell_value`](BuffoonCard::resell_value) to its own +mult
+    /// counter, for good.
+    ///
+    /// Walked left to right, so in a row of two Daggers the left one eats the
+    /// right one before it can act. Nothing happens with no joker to the right,
+    /// or an Eternal one. Deterministic, so it runs in the pure
+    /// [`on_blind_selected`](Self::on_blind_selected) — before the draw
+    /// recompute, so a destroyed Juggler takes its hand size with it.
+    fn daggers_destroy_to_the_right(&mut self) {
+        self.ensure_state_len();
+        let mut index = 0;
+        while index + 1 < self.jokers.len() {
+            let is_dagger = self.ability_slot(index) == Some(index)
+                && matches!(
+                    self.jokers.get(index).map(|joker| joker.enhancement),
+                    Some(MPip::MultPlusDoubleValueDestroyJokerOnRight(_))
+                );
+            if is_dagger {
+                if let Some(victim) = self.remove_joker(index + 1) {
+                    let gain = i32::try_from(victim.resell_value * 2).unwrap_or(i32::MAX);
+                    self.joker_state[index] = self.joker_state[index].saturating_add(gain);
+                }
+            }
+            index += 1;
+        }
+    }
+
     /// Whether the current Boss Blind's **ability** is in force.
     ///
     /// Three ways it is not: the blind is not a boss at all; Luchador was sold
@@ -1913,6 +2839,72 @@ impl BuffoonBoard {
             .any(|joker| matches!(joker.enhancement, MPip::DisablesAllBossBlinds))
     }
 
+    /// Reroll the Boss Blind for **$10**: the board's boss becomes a different
+    /// one, drawn from [`BossBlind::ALL`]. Returns whether it happened.
+    ///
+    /// Needs **Retcon** (any number of rolls) or **Director's Cut** (one roll
+    /// per ante, counted in
+    /// [`boss_rerolls_this_ante`](Self::boss_rerolls_this_ante)). Refused —
+    /// leaving the board untouched — when the blind is not a boss, when neither
+    /// voucher allows it, or when the $10 would drop [`money`](Self::money)
+    /// below the debt floor. The draws are recomputed, so the new boss's
+    /// ability replaces the old one's at once.
+    pub fn reroll_boss_with_rng<R: Rng + ?Sized>(&mut self, rng: &mut R) -> bool {
+        self.try_reroll_boss_with_rng(rng).is_ok()
+    }
+
+    /// [`reroll_boss_with_rng`](Self::reroll_boss_with_rng), returning the new
+    /// boss or saying why it was refused.
+    ///
+    /// # Errors
+    ///
+    /// [`ActionError::NotABoss`], [`ActionError::NoBossReroll`] or
+    /// [`ActionError::NotEnoughMoney`].
+    pub fn try_reroll_boss_with_rng<R: Rng + ?Sized>(
+        &mut self,
+        rng: &mut R,
+    ) -> Result<BossBlind, ActionError> {
+        let Blind::Boss(current) = self.blind else {
+            return Err(ActionError::NotABoss);
+        };
+        let boss = self.try_reroll_upcoming_boss_with_rng(current, rng)?;
+        self.blind = Blind::Boss(boss);
+        self.recompute_draws();
+        Ok(boss)
+    }
+
+    /// Reroll a Boss Blind that is still **ahead** — `current`, the one the
+    /// ante will end on — from the blind-select screen, where the board's own
+    /// [`blind`](Self::blind) is still the last one played. Returns the new
+    /// boss for the caller to put in `current`'s place; the board only pays,
+    /// and counts the roll.
+    ///
+    /// The rules are [`reroll_boss_with_rng`](Self::reroll_boss_with_rng)'s:
+    /// Retcon or an unused Director's Cut, and $10.
+    ///
+    /// # Errors
+    ///
+    /// [`ActionError::NoBossReroll`] or [`ActionError::NotEnoughMoney`].
+    pub fn try_reroll_upcoming_boss_with_rng<R: Rng + ?Sized>(
+        &mut self,
+        current: BossBlind,
+        rng: &mut R,
+    ) -> Result<BossBlind, ActionError> {
+        let allowed = self.vouchers.contains(&Voucher::Retcon)
+            || (self.vouchers.contains(&Voucher::DirectorsCut) && self.boss_rerolls_this_ante == 0);
+        if !allowed {
+            return Err(ActionError::NoBossReroll);
+        }
+        let price = self.afford(10)?;
+        let others: Vec<BossBlind> = BossBlind::ALL
+            .into_iter()
+            .filter(|boss| *boss != current)
+            .collect();
+        self.money = self.money.saturating_sub(price);
+        self.boss_rerolls_this_ante += 1;
+        Ok(others[rng.random_range(0..others.len())])
+    }
+
     /// Recompute the round's [`draws`](Self::draws) from
     /// [`starting_draws`](Self::starting_draws), the board's draw-modifier
     /// jokers, and the Boss Blind's ability (if it is in force).
@@ -1950,6 +2942,10 @@ impl BuffoonBoard {
                 Voucher::Grabber | Voucher::NachoTong => draws.hands_to_play += 1,
                 Voucher::Wasteful | Voucher::Recyclomancy => draws.discards += 1,
                 Voucher::PaintBrush | Voucher::Palette => draws.hand_size += 1,
+                Voucher::Hieroglyph => {
+                    draws.hands_to_play = draws.hands_to_play.saturating_sub(1);
+                }
+                Voucher::Petroglyph => draws.discards = draws.discards.saturating_sub(1),
                 _ => {}
             }
         }
@@ -1958,6 +2954,8 @@ impl BuffoonBoard {
         draws.hand_size = draws
             .hand_size
             .saturating_sub(self.spectral_hand_size_penalty);
+        // Blue Stake's −1 discard, a run modifier like the one above.
+        draws.discards = draws.discards.saturating_sub(self.stake.discard_penalty());
         if lose_discards {
             draws.discards = 0;
         }
@@ -1972,7 +2970,8 @@ impl BuffoonBoard {
     /// Sell the joker at `index`: it leaves the board, its
     /// [`resell_value`](BuffoonCard::resell_value) is paid into
     /// [`money`](Self::money), and the round's draws are recomputed. Returns the
-    /// joker sold, or `None` if `index` is out of bounds.
+    /// joker sold, or `None` if `index` is out of bounds or the joker is
+    /// Eternal.
     ///
     /// Selling **Luchador** disables the current Boss Blind, which is its whole
     /// effect. The recompute is what makes that observable: the boss's grip on
@@ -1984,10 +2983,17 @@ impl BuffoonBoard {
     /// [`discards_used`](Self::discards_used)) are deliberately left alone — a
     /// sale happens *mid*-round and must not reset it.
     pub fn sell_joker(&mut self, index: usize) -> Option<BuffoonCard> {
-        if index >= self.jokers.len() {
-            return None;
-        }
-        let joker = self.remove_joker(index);
+        self.try_sell_joker(index).ok()
+    }
+
+    /// [`sell_joker`](Self::sell_joker), saying why it was refused.
+    ///
+    /// # Errors
+    ///
+    /// As [`try_remove_joker`](Self::try_remove_joker): an Eternal joker
+    /// cannot be sold.
+    pub fn try_sell_joker(&mut self, index: usize) -> Result<BuffoonCard, ActionError> {
+        let joker = self.try_remove_joker(index)?;
         self.money = self
             .money
             .saturating_add(isize::try_from(joker.resell_value).unwrap_or(0));
@@ -1995,49 +3001,96 @@ impl BuffoonBoard {
             self.boss_disabled = true;
         }
         self.recompute_draws();
-        Some(joker)
+        Ok(joker)
     }
 
     // ---- Shop (EPIC-01b Phase 2) -----------------------------------------
 
     /// What a stock card costs to buy.
     ///
-    /// Tarots and Planets are a flat **$3** (Balatro's base consumable price);
-    /// every joker is priced by its [`rank.value`](crate::prelude::Pip::value),
-    /// the same number [`sell_joker`](Self::sell_joker) halves for the resale.
+    /// Tarots and Planets are a flat **$3** (Balatro's base consumable price),
+    /// Spectrals **$4**, playing cards **$1**; every joker is priced by its
+    /// [`rank.value`](crate::prelude::Pip::value), the same number
+    /// [`sell_joker`](Self::sell_joker) halves for the resale. An edition adds
+    /// its [`extra_cost`](Edition::extra_cost) on top. A Rental joker is a
+    /// flat **$1**, edition and all — the rent is the price.
     #[must_use]
     fn stock_price(card: BuffoonCard) -> usize {
-        match card.card_type {
+        if card.stickers.rental {
+            return 1;
+        }
+        let base = match card.card_type {
             BCardType::Tarot | BCardType::Planet => 3,
+            BCardType::Spectral => 4,
+            BCardType::Basic | BCardType::Stone => 1,
             _ => card.rank.value,
-        }
+        };
+        base + card.edition.extra_cost()
     }
 
     /// Draw one joker at the shop's rarity odds — **70% Common / 25% Uncommon /
     /// 5% Rare**, Legendary never. Every pick comes from the rarity piles the
     /// 2026-07-16 sweep made a trustworthy partition, so a drawn joker is always
-    /// a piled one — never a parallel catalog. Shared by the card slots and by a
-    /// Buffoon pack's choices.
-    fn draw_shop_joker<R: Rng + ?Sized>(rng: &mut R) -> BuffoonCard {
-        let rarity = rng.random_range(0..100);
-        let pool: &[BuffoonCard] = if rarity < 70 {
-            &Joker::COMMON_JOKERS
-        } else if rarity < 95 {
-            &Joker::UNCOMMON_JOKERS
+    /// a piled one — never a parallel catalog. A custom joker is in the pile of
+    /// its rarity, and is drawn as often as any built-in beside it. Shared by
+    /// the card slots and by a Buffoon pack's choices.
+    ///
+    /// The joker then rolls its edition through [`Edition::roll`] at the
+    /// [`edition_rate`](Self::edition_rate) the vouchers set — the one place a
+    /// run acquires an edition without spending a consumable on it; a custom
+    /// joker defined with an edition keeps it, though the roll is still drawn
+    /// — and its
+    /// stickers through [`Stake::roll_stickers`], which draws nothing below
+    /// Black Stake.
+    ///
+    /// `None` if the challenge rules ban every joker of the rarity rolled; the
+    /// slot or the pack choice is then left out, rather than filled with a
+    /// banned joker.
+    fn draw_shop_joker<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<BuffoonCard> {
+        let roll = rng.random_range(0..100);
+        let rarity = if roll < 70 {
+            BCardType::CommonJoker
+        } else if roll < 95 {
+            BCardType::UncommonJoker
         } else {
-            &Joker::RARE_JOKERS
+            BCardType::RareJoker
         };
-        pool[rng.random_range(0..pool.len())]
+        let pool = self.joker_pool(rarity).unwrap_or_default();
+        if pool.is_empty() {
+            return None;
+        }
+        let mut joker = pool[rng.random_range(0..pool.len())];
+        let edition = Edition::roll(rng, self.edition_rate());
+        if joker.edition == Edition::None {
+            joker = joker.with_edition(edition);
+        }
+        joker.stickers = self.stake.roll_stickers(rng);
+        Some(joker)
+    }
+
+    /// How much the edition vouchers scale the scoring editions' odds: **×2**
+    /// with Hone, **×4** with Glow Up (which requires Hone and supersedes it).
+    fn edition_rate(&self) -> usize {
+        if self.vouchers.contains(&Voucher::GlowUp) {
+            4
+        } else if self.vouchers.contains(&Voucher::Hone) {
+            2
+        } else {
+            1
+        }
     }
 
-    /// The shop's card-slot weights `(joker, tarot, planet)`, read **live**.
+    /// The shop's card-slot weights `(joker, tarot, planet, spectral, playing)`,
+    /// read **live**.
     ///
-    /// Base **20 / 4 / 4**; a Tarot Merchant doubles the tarot band and a Tarot
-    /// Tycoon quadruples it (the Tycoon requires the Merchant and supersedes it,
-    /// so the multiplier is 1/2/4, not stacked), and the same for planets. The
-    /// joker band and the rarity partition inside it are untouched — only the
-    /// consumable bands move.
-    fn stock_weights(&self) -> (usize, usize, usize) {
+    /// Base **20 / 4 / 4 / 0**; a Tarot Merchant doubles the tarot band and a
+    /// Tarot Tycoon quadruples it (the Tycoon requires the Merchant and
+    /// supersedes it, so the multiplier is 1/2/4, not stacked), and the same for
+    /// planets. The joker band and the rarity partition inside it are untouched
+    /// — only the consumable bands move. The spectral band is **2** on a Ghost
+    /// Deck run and empty otherwise; the playing-card band is **4** with Magic
+    /// Trick and empty otherwise.
+    fn stock_weights(&self) -> (usize, usize, usize, usize, usize) {
         let mult = |merchant, tycoon| {
             if self.vouchers.contains(&tycoon) {
                 4
@@ -2049,39 +3102,81 @@ impl BuffoonBoard {
         };
         let tarot = 4 * mult(Voucher::TarotMerchant, Voucher::TarotTycoon);
         let planet = 4 * mult(Voucher::PlanetMerchant, Voucher::PlanetTycoon);
-        (20, tarot, planet)
+        let spectral = if self.starter_deck == Some(StarterDeck::Ghost) {
+            2
+        } else {
+            0
+        };
+        let playing = if self.vouchers.contains(&Voucher::MagicTrick) {
+            4
+        } else {
+            0
+        };
+        (20, tarot, planet, spectral, playing)
     }
 
     /// Draw one card slot at the shop's [`stock_weights`](Self::stock_weights): a
     /// joker (then rolled through [`draw_shop_joker`](Self::draw_shop_joker)), a
-    /// tarot, or a planet. With no Merchant/Tycoon voucher the weights are the
-    /// base 20/4/4 out of 28, so an un-vouchered draw is byte-identical to before.
-    fn draw_stock_card<R: Rng + ?Sized>(&self, rng: &mut R) -> BuffoonCard {
-        let (joker, tarot, planet) = self.stock_weights();
-        let total = joker + tarot + planet;
+    /// tarot, a planet, or — on a Ghost Deck — a spectral, or — with Magic
+    /// Trick — a playing card. With no Merchant/Tycoon voucher the weights are
+    /// the base 20/4/4 out of 28. `None` only for a joker slot with no joker
+    /// left to draw.
+    fn draw_stock_card<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<BuffoonCard> {
+        let (joker, tarot, planet, spectral, playing) = self.stock_weights();
+        let total = joker + tarot + planet + spectral + playing;
         let roll = rng.random_range(0..total);
         if roll < joker {
-            Self::draw_shop_joker(rng)
-        } else if roll < joker + tarot {
+            return self.draw_shop_joker(rng);
+        }
+        Some(if roll < joker + tarot {
             MajorArcana::DECK[rng.random_range(0..MajorArcana::DECK.len())]
-        } else {
+        } else if roll < joker + tarot + planet {
             Planet::DECK[rng.random_range(0..Planet::DECK.len())]
-        }
+        } else if roll < joker + tarot + planet + spectral {
+            Spectral::DECK[rng.random_range(0..Spectral::DECK.len())]
+        } else {
+            self.draw_shop_playing_card(rng)
+        })
     }
 
-    /// Draw one booster-pack slot: a uniformly-chosen [`PackKind`] at the base
-    /// **$4** tier.
+    /// Draw one playing card for a Magic Trick shop slot: a uniformly random
+    /// card of the standard 52.
     ///
-    /// Balatro's real pack-appearance weights differ by kind and tier; this
-    /// engine draws the three base packs it can fill (Buffoon / Arcana /
-    /// Celestial) with equal odds, which is enough for a run loop to spend in.
+    /// With **Illusion** it may arrive dressed, at Balatro's rates: a 40% chance
+    /// of a random enhancement (one of [`ENHANCEMENTS`](Self::ENHANCEMENTS)) and
+    /// a 20% chance of a guaranteed edition (Foil/Holo/Poly). Illusion's seals
+    /// are not modelled.
+    fn draw_shop_playing_card<R: Rng + ?Sized>(&self, rng: &mut R) -> BuffoonCard {
+        let mut card = basic::Deck::DECK[rng.random_range(0..basic::Deck::DECK_SIZE)];
+        if self.vouchers.contains(&Voucher::Illusion) {
+            if rng.random_range(0..10) < 4 {
+                card.enhancement =
+                    Self::ENHANCEMENTS[rng.random_range(0..Self::ENHANCEMENTS.len())];
+            }
+            if rng.random_range(0..10) < 2 {
+                card = card.with_edition(Edition::roll_guaranteed(rng));
+            }
+        }
+        card
+    }
+
+    /// Draw one booster-pack slot at Balatro's
+    /// [`WEIGHTS`](BoosterPack::WEIGHTS): a kind and a tier in one roll, priced
+    /// at the tier's cost. Most slots are a Normal Arcana, Celestial or
+    /// Standard pack; a Mega Spectral pack turns up about once in 320.
     fn draw_pack<R: Rng + ?Sized>(rng: &mut R) -> BoosterPack {
-        let kind = match rng.random_range(0..3) {
-            0 => PackKind::Buffoon,
-            1 => PackKind::Arcana,
-            _ => PackKind::Celestial,
-        };
-        BoosterPack { kind, cost: 4 }
+        let total: usize = BoosterPack::WEIGHTS
+            .iter()
+            .map(|(_, _, weight)| weight)
+            .sum();
+        let mut roll = rng.random_range(0..total);
+        for (kind, size, weight) in BoosterPack::WEIGHTS {
+            if roll < weight {
+                return BoosterPack::new(kind, size);
+            }
+            roll -= weight;
+        }
+        BoosterPack::new(PackKind::Arcana, PackSize::Normal)
     }
 
     /// Open the [`Shop`], drawing its two card slots and two pack slots at the
@@ -2092,7 +3187,9 @@ impl BuffoonBoard {
     /// exists for Riff-Raff. A fresh shop has rerolled nothing.
     pub fn open_shop_with_rng<R: Rng + ?Sized>(&mut self, rng: &mut R) {
         let slots = 2 + self.overstock_bonus();
-        let stock = (0..slots).map(|_| self.draw_stock_card(rng)).collect();
+        let stock = (0..slots)
+            .filter_map(|_| self.draw_stock_card(rng))
+            .collect();
         let packs = vec![Self::draw_pack(rng), Self::draw_pack(rng)];
         let eligible = self.eligible_vouchers();
         let voucher = if eligible.is_empty() {
@@ -2163,12 +3260,14 @@ impl BuffoonBoard {
     }
 
     /// The vouchers the shop may offer: those **not yet redeemed** whose
-    /// **base-tier prerequisite** (if any) is already held. Empty once every
-    /// modelled voucher is redeemed — the shop then offers no voucher.
+    /// **base-tier prerequisite** (if any) is already held, and that no
+    /// challenge bans. Empty once every modelled voucher is redeemed — the
+    /// shop then offers no voucher.
     fn eligible_vouchers(&self) -> Vec<Voucher> {
         Voucher::ALL
             .into_iter()
             .filter(|voucher| !self.vouchers.contains(voucher))
+            .filter(|voucher| !self.challenge_rules.banned_vouchers.contains(voucher))
             .filter(|voucher| {
                 voucher
                     .requires()
@@ -2187,40 +3286,62 @@ impl BuffoonBoard {
     /// voucher joins the run and the slot is cleared — a voucher is redeemed once
     /// and never returns to the pool.
     ///
-    /// The board-slot vouchers apply their **permanent** bump here: Crystal Ball
+    /// The board-slot vouchers apply their **permanent** bump (through
+    /// `apply_voucher`, which the starter decks share): Crystal Ball
     /// grows [`consumable_slots`](Self::consumable_slots), Antimatter
     /// [`joker_slots`](Self::joker_slots). Unlike the Draws vouchers (recomputed
     /// live each blind), the slot fields have no recompute pass, and a redeem
     /// happens once and is guarded — so a one-time bump cannot stack. (Overstock
     /// is not here: it sizes the *shop's* card slots, read live at open, with no
-    /// board field to bump.)
+    /// board field to bump.) Hieroglyph and Petroglyph take their −1 ante here
+    /// for the same reason; their −1 hand / −1 discard is a Draws voucher.
     pub fn redeem_shop_voucher(&mut self) -> bool {
-        let Some(voucher) = self.shop.as_ref().and_then(|shop| shop.voucher) else {
-            return false;
-        };
+        self.try_redeem_shop_voucher().is_ok()
+    }
+
+    /// [`redeem_shop_voucher`](Self::redeem_shop_voucher), returning the
+    /// voucher or saying why it was refused.
+    ///
+    /// # Errors
+    ///
+    /// [`ActionError::ShopClosed`], [`ActionError::NoVoucherOffered`],
+    /// [`ActionError::VoucherRedeemed`], [`ActionError::VoucherRequires`] or
+    /// [`ActionError::NotEnoughMoney`].
+    pub fn try_redeem_shop_voucher(&mut self) -> Result<Voucher, ActionError> {
+        let shop = self.shop.as_ref().ok_or(ActionError::ShopClosed)?;
+        let voucher = shop.voucher.ok_or(ActionError::NoVoucherOffered)?;
         if self.vouchers.contains(&voucher) {
-            return false;
+            return Err(ActionError::VoucherRedeemed(voucher));
         }
         if let Some(base) = voucher.requires() {
             if !self.vouchers.contains(&base) {
-                return false;
+                return Err(ActionError::VoucherRequires { voucher, base });
             }
         }
-        let price = 10;
-        if self.money.saturating_sub(price) < self.debt_floor() {
-            return false;
-        }
+        let price = self.afford(10)?;
         self.money = self.money.saturating_sub(price);
+        self.apply_voucher(voucher);
+        if let Some(shop) = self.shop.as_mut() {
+            shop.voucher = None;
+        }
+        Ok(voucher)
+    }
+
+    /// Add `voucher` to [`vouchers`](Self::vouchers) and apply its one-time
+    /// board bump — the free half of a redeem, shared by the shop and the
+    /// starter decks that open a run already holding a voucher.
+    ///
+    /// Unguarded: callers check the voucher is not already held.
+    pub(crate) fn apply_voucher(&mut self, voucher: Voucher) {
         self.vouchers.push(voucher);
         match voucher {
             Voucher::CrystalBall => self.consumable_slots += 1,
             Voucher::Antimatter => self.joker_slots += 1,
+            Voucher::Hieroglyph | Voucher::Petroglyph => {
+                self.ante = self.ante.saturating_sub(1);
+            }
             _ => {}
         }
-        if let Some(shop) = self.shop.as_mut() {
-            shop.voucher = None;
-        }
-        true
     }
 
     /// The lowest [`money`](Self::money) a purchase may leave the board at.
@@ -2242,6 +3363,30 @@ impl BuffoonBoard {
         -isize::try_from(credit).unwrap_or(0)
     }
 
+    /// `cost` as the signed amount to take off [`money`](Self::money), if
+    /// paying it keeps the board at or above the [debt floor](Self::debt_floor).
+    fn afford(&self, cost: usize) -> Result<isize, ActionError> {
+        let price = isize::try_from(cost).unwrap_or(isize::MAX);
+        if self.money.saturating_sub(price) < self.debt_floor() {
+            return Err(ActionError::NotEnoughMoney {
+                cost,
+                money: self.money,
+            });
+        }
+        Ok(price)
+    }
+
+    /// What buying the stock at `index` would cost — its price after the
+    /// shop-discount vouchers, the number [`buy_stock`](Self::buy_stock)
+    /// charges. `None` with no shop open or no such slot.
+    #[must_use]
+    pub fn stock_cost(&self, index: usize) -> Option<usize> {
+        self.shop
+            .as_ref()
+            .and_then(|shop| shop.stock.get(index))
+            .map(|card| self.discounted(Self::stock_price(*card)))
+    }
+
     /// Buy the stock at `index`, routing it onto the board. Returns whether the
     /// purchase happened.
     ///
@@ -2257,35 +3402,67 @@ impl BuffoonBoard {
     /// [`add_card_to_deck`](Self::add_card_to_deck): it is not a playing card
     /// joining the deck, so no `CardAdded` fires and Hologram stays still.
     pub fn buy_stock(&mut self, index: usize) -> bool {
-        let Some(card) = self
-            .shop
-            .as_ref()
-            .and_then(|shop| shop.stock.get(index).copied())
-        else {
-            return false;
-        };
-        let price = isize::try_from(self.discounted(Self::stock_price(card))).unwrap_or(isize::MAX);
-        if self.money.saturating_sub(price) < self.debt_floor() {
-            return false;
-        }
-        let placed = if card.is_joker() {
-            if self.has_joker_room() {
-                self.push_joker(card);
-                true
-            } else {
-                false
+        self.try_buy_stock(index).is_ok()
+    }
+
+    /// [`buy_stock`](Self::buy_stock), returning the card bought or saying
+    /// why it was refused.
+    ///
+    /// # Errors
+    ///
+    /// [`ActionError::ShopClosed`], [`ActionError::NoSuchStock`],
+    /// [`ActionError::NotEnoughMoney`], [`ActionError::NoJokerRoom`] or
+    /// [`ActionError::NoConsumableRoom`].
+    pub fn try_buy_stock(&mut self, index: usize) -> Result<BuffoonCard, ActionError> {
+        self.buy_stock_inner(index, None)
+    }
+
+    /// [`try_buy_stock`](Self::try_buy_stock), firing each custom joker's
+    /// [`EffectEvent::Bought`] hook through `registry` once the card has
+    /// landed — a bought custom joker's own hook included.
+    ///
+    /// # Errors
+    ///
+    /// As [`try_buy_stock`](Self::try_buy_stock).
+    pub fn try_buy_stock_with_registry(
+        &mut self,
+        index: usize,
+        registry: &EffectRegistry,
+    ) -> Result<BuffoonCard, ActionError> {
+        self.buy_stock_inner(index, Some(registry))
+    }
+
+    fn buy_stock_inner(
+        &mut self,
+        index: usize,
+        registry: Option<&EffectRegistry>,
+    ) -> Result<BuffoonCard, ActionError> {
+        let shop = self.shop.as_ref().ok_or(ActionError::ShopClosed)?;
+        let card = shop
+            .stock
+            .get(index)
+            .copied()
+            .ok_or(ActionError::NoSuchStock(index))?;
+        let price = self.afford(self.discounted(Self::stock_price(card)))?;
+        if card.is_joker() {
+            if !self.has_joker_room() {
+                return Err(ActionError::NoJokerRoom);
             }
+            self.push_joker(card);
+        } else if matches!(card.card_type, BCardType::Basic | BCardType::Stone) {
+            // A Magic Trick playing card joins the deck like any added card.
+            self.add_card_to_deck(card);
         } else {
-            self.create_consumable(card)
-        };
-        if !placed {
-            return false;
+            self.try_create_consumable(card)?;
         }
         self.money = self.money.saturating_sub(price);
         if let Some(shop) = self.shop.as_mut() {
             shop.stock.remove(index);
         }
-        true
+        if let Some(registry) = registry {
+            self.trigger_effects(EffectEvent::Bought(card), registry);
+        }
+        Ok(card)
     }
 
     /// How many free rerolls the board is granted this shop — the sum of every
@@ -2326,24 +3503,32 @@ impl BuffoonBoard {
     /// alone. Fires the `ShopRerolled` growth event, which is where **Flash
     /// Card** gains its `+2` mult.
     pub fn reroll_with_rng<R: Rng + ?Sized>(&mut self, rng: &mut R) -> bool {
+        self.try_reroll_with_rng(rng).is_ok()
+    }
+
+    /// [`reroll_with_rng`](Self::reroll_with_rng), saying why it was refused.
+    ///
+    /// # Errors
+    ///
+    /// [`ActionError::ShopClosed`] or [`ActionError::NotEnoughMoney`].
+    pub fn try_reroll_with_rng<R: Rng + ?Sized>(&mut self, rng: &mut R) -> Result<(), ActionError> {
         if self.shop.is_none() {
-            return false;
-        }
-        let cost = isize::try_from(self.reroll_cost()).unwrap_or(isize::MAX);
-        if self.money.saturating_sub(cost) < self.debt_floor() {
-            return false;
+            return Err(ActionError::ShopClosed);
         }
+        let cost = self.afford(self.reroll_cost())?;
         self.money = self.money.saturating_sub(cost);
         // Redraw the same number of card slots the shop offers — Overstock
         // widens the reroll too, matching `open_shop_with_rng`.
         let slots = 2 + self.overstock_bonus();
-        let stock = (0..slots).map(|_| self.draw_stock_card(rng)).collect();
+        let stock = (0..slots)
+            .filter_map(|_| self.draw_stock_card(rng))
+            .collect();
         if let Some(shop) = self.shop.as_mut() {
             shop.stock = stock;
             shop.rerolls_used += 1;
         }
         self.apply_growth(&GrowthEvent::ShopRerolled);
-        true
+        Ok(())
     }
 
     /// Skip the booster pack at `index`, taking it off the shop for free.
@@ -2353,68 +3538,148 @@ impl BuffoonBoard {
     /// mult. Skipping costs nothing (unlike opening); it is the free way to
     /// clear a pack slot.
     pub fn skip_pack(&mut self, index: usize) -> bool {
-        let present = self
-            .shop
-            .as_ref()
-            .is_some_and(|shop| index < shop.packs.len());
-        if !present {
-            return false;
-        }
-        if let Some(shop) = self.shop.as_mut() {
-            shop.packs.remove(index);
+        self.try_skip_pack(index).is_ok()
+    }
+
+    /// [`skip_pack`](Self::skip_pack), returning the pack skipped or saying
+    /// why it was refused.
+    ///
+    /// # Errors
+    ///
+    /// [`ActionError::ShopClosed`] or [`ActionError::NoSuchPack`].
+    pub fn try_skip_pack(&mut self, index: usize) -> Result<BoosterPack, ActionError> {
+        let shop = self.shop.as_mut().ok_or(ActionError::ShopClosed)?;
+        if index >= shop.packs.len() {
+            return Err(ActionError::NoSuchPack(index));
         }
+        let pack = shop.packs.remove(index);
         self.apply_growth(&GrowthEvent::PackSkipped);
-        true
+        Ok(pack)
     }
 
     /// Open the booster pack at `index`, paying its cost and returning the
     /// choices it offers — jokers for a Buffoon pack, tarots for Arcana, planets
-    /// for Celestial. `None` if there is no such pack or the cost would drop
-    /// [`money`](Self::money) below the debt floor.
+    /// for Celestial, spectrals for Spectral, playing cards for Standard; as
+    /// many as [`BoosterPack::choices`]. `None` if there is no such pack or the
+    /// cost would drop [`money`](Self::money) below the debt floor.
     ///
     /// The returned cards are the pack's *offer*; placing the player's pick is
     /// the caller's, through the same [`push_joker`](Self::push_joker) /
-    /// [`create_consumable`](Self::create_consumable) seams buying uses — a full
-    /// choose-and-place flow is a feature of its own and is not built here.
+    /// [`create_consumable`](Self::create_consumable) /
+    /// [`add_card_to_deck`](Self::add_card_to_deck) seams buying uses, up to
+    /// the pack's [`picks`](BoosterPack::picks). [`Run`] is one such
+    /// choose-and-place flow.
     ///
     /// **Hallucination** fires as a side effect: for each one held, a rolled
     /// `1-in-2` (scaled by the board's shared odds seam, so Oops! All 6s doubles
     /// it) creates a Tarot when there is consumable room.
+    ///
+    /// [`Run`]: crate::funky::types::run::Run
     pub fn open_pack_with_rng<R: Rng + ?Sized>(
         &mut self,
         index: usize,
         rng: &mut R,
     ) -> Option<Vec<BuffoonCard>> {
-        let pack = self
-            .shop
-            .as_ref()
-            .and_then(|shop| shop.packs.get(index).copied())?;
-        let cost = isize::try_from(self.discounted(pack.cost)).unwrap_or(isize::MAX);
-        if self.money.saturating_sub(cost) < self.debt_floor() {
-            return None;
-        }
+        self.try_open_pack_with_rng(index, rng).ok()
+    }
+
+    /// [`open_pack_with_rng`](Self::open_pack_with_rng), saying why it was
+    /// refused.
+    ///
+    /// # Errors
+    ///
+    /// [`ActionError::ShopClosed`], [`ActionError::NoSuchPack`] or
+    /// [`ActionError::NotEnoughMoney`].
+    pub fn try_open_pack_with_rng<R: Rng + ?Sized>(
+        &mut self,
+        index: usize,
+        rng: &mut R,
+    ) -> Result<Vec<BuffoonCard>, ActionError> {
+        let shop = self.shop.as_ref().ok_or(ActionError::ShopClosed)?;
+        let pack = shop
+            .packs
+            .get(index)
+            .copied()
+            .ok_or(ActionError::NoSuchPack(index))?;
+        let cost = self.afford(self.discounted(pack.cost))?;
         self.money = self.money.saturating_sub(cost);
         if let Some(shop) = self.shop.as_mut() {
             shop.packs.remove(index);
         }
-        let choices = Self::draw_pack_choices(pack.kind, rng);
+        let choices = self.draw_pack_choices(pack, rng);
         self.hallucinate(rng);
-        Some(choices)
+        Ok(choices)
     }
 
-    /// The cards a pack of `kind` offers: two jokers for a Buffoon pack, three
-    /// tarots for Arcana, three planets for Celestial — the base-tier choice
-    /// counts, drawn from the same piles and decks the shop stocks.
-    fn draw_pack_choices<R: Rng + ?Sized>(kind: PackKind, rng: &mut R) -> Vec<BuffoonCard> {
-        match kind {
-            PackKind::Buffoon => (0..2).map(|_| Self::draw_shop_joker(rng)).collect(),
-            PackKind::Arcana => (0..3)
+    /// The cards `pack` offers, [`choices`](BoosterPack::choices) of them,
+    /// drawn from the same piles and decks the shop stocks. A Standard pack's
+    /// cards come from [`draw_pack_playing_card`](Self::draw_pack_playing_card).
+    ///
+    /// With **Telescope**, a Celestial pack's first choice is always the planet
+    /// for the [`most_played_hand`](Self::most_played_hand).
+    fn draw_pack_choices<R: Rng + ?Sized>(
+        &self,
+        pack: BoosterPack,
+        rng: &mut R,
+    ) -> Vec<BuffoonCard> {
+        let telescope = self
+            .vouchers
+            .contains(&Voucher::Telescope)
+            .then(|| self.most_played_hand())
+            .flatten()
+            .and_then(|hand_type| {
+                Planet::DECK.into_iter().find(|planet| {
+                    matches!(planet.enhancement, MPip::ChipsMultPlusOnHand(_, _, target) if target == hand_type)
+                })
+            });
+        let choices = 0..pack.choices();
+        match pack.kind {
+            PackKind::Buffoon => choices.filter_map(|_| self.draw_shop_joker(rng)).collect(),
+            PackKind::Arcana => choices
                 .map(|_| MajorArcana::DECK[rng.random_range(0..MajorArcana::DECK.len())])
                 .collect(),
-            PackKind::Celestial => (0..3)
-                .map(|_| Planet::DECK[rng.random_range(0..Planet::DECK.len())])
+            PackKind::Celestial => choices
+                .map(|choice| match telescope {
+                    Some(planet) if choice == 0 => planet,
+                    _ => Planet::DECK[rng.random_range(0..Planet::DECK.len())],
+                })
+                .collect(),
+            PackKind::Spectral => choices
+                .map(|_| Spectral::DECK[rng.random_range(0..Spectral::DECK.len())])
                 .collect(),
+            PackKind::Standard => choices.map(|_| Self::draw_pack_playing_card(rng)).collect(),
+        }
+    }
+
+    /// Draw one Standard pack card: a uniformly random card of the standard
+    /// 52, with a **40%** chance of a random enhancement (one of
+    /// [`ENHANCEMENTS`](Self::ENHANCEMENTS)) and an edition rolled at twice the
+    /// joker rates, Negative excepted — about 7% arrive edited.
+    ///
+    /// Balatro also seals 20% of them. The crate has no seals yet — the same
+    /// gap that leaves the seal spectrals `Blank` — so these cards never carry
+    /// one; the CHANGELOG records it.
+    fn draw_pack_playing_card<R: Rng + ?Sized>(rng: &mut R) -> BuffoonCard {
+        let mut card = basic::Deck::DECK[rng.random_range(0..basic::Deck::DECK_SIZE)];
+        if rng.random_range(0..10) < 4 {
+            card.enhancement = Self::ENHANCEMENTS[rng.random_range(0..Self::ENHANCEMENTS.len())];
         }
+        match Edition::roll(rng, 2) {
+            Edition::Negative => card,
+            edition => card.with_edition(edition),
+        }
+    }
+
+    /// The hand type played most often this run, or `None` before any hand is
+    /// played. A tie goes to the higher hand, as in Balatro.
+    #[must_use]
+    pub fn most_played_hand(&self) -> Option<HandType> {
+        self.poker_hands
+            .hands
+            .values()
+            .filter(|hand| hand.times_played > 0)
+            .max_by_key(|hand| (hand.times_played, hand.hand_type))
+            .map(|hand| hand.hand_type)
     }
 
     /// Roll every held Hallucination's tarot chance for one pack opening.
@@ -2429,9 +3694,8 @@ impl BuffoonBoard {
     fn hallucinate<R: Rng + ?Sized>(&mut self, rng: &mut R) {
         let scale = self.probability_numerator();
         let rolls: Vec<(usize, usize)> = self
-            .jokers
-            .iter()
-            .filter_map(|joker| match joker.enhancement {
+            .abilities()
+            .filter_map(|(_, joker)| match joker.enhancement {
                 MPip::CreateTarotOnPackOpen(num, den) => Some((num, den)),
                 _ => None,
             })
@@ -2462,26 +3726,83 @@ impl BuffoonBoard {
     }
 
     /// The joker pool a rarity draws from, or `None` if the `BCardType` is not a
-    /// rarity.
-    fn joker_pool(rarity: BCardType) -> Option<&'static [BuffoonCard]> {
-        match rarity {
-            BCardType::CommonJoker => Some(&Joker::COMMON_JOKERS),
-            BCardType::UncommonJoker => Some(&Joker::UNCOMMON_JOKERS),
-            BCardType::RareJoker => Some(&Joker::RARE_JOKERS),
-            BCardType::LegendaryJoker => Some(&Joker::LEGENDARY_JOKERS),
-            _ => None,
-        }
+    /// rarity: the built-in jokers of that rarity, then the board's
+    /// [`custom_jokers`](Self::custom_jokers) of it, less any joker the
+    /// [`challenge_rules`](Self::challenge_rules) ban. Without custom jokers
+    /// or bans the pool, and so every draw from it, is what it always was.
+    fn joker_pool(&self, rarity: BCardType) -> Option<Vec<BuffoonCard>> {
+        let built_in: &[BuffoonCard] = match rarity {
+            BCardType::CommonJoker => &Joker::COMMON_JOKERS,
+            BCardType::UncommonJoker => &Joker::UNCOMMON_JOKERS,
+            BCardType::RareJoker => &Joker::RARE_JOKERS,
+            BCardType::LegendaryJoker => &Joker::LEGENDARY_JOKERS,
+            _ => return None,
+        };
+        let custom = self
+            .custom_jokers
+            .iter()
+            .filter(|joker| joker.card_type == rarity);
+        Some(
+            built_in
+                .iter()
+                .chain(custom)
+                .filter(|joker| !self.challenge_rules.bans_joker(joker))
+                .copied()
+                .collect(),
+        )
     }
 
-    /// Everything [`on_blind_selected`](Self::on_blind_selected) does, then the
-    /// random blind-select effects:
+    /// Add `jokers` to the rarity pools, each to the pool of its `card_type`.
     ///
-    /// * **Madness** destroys one random *other* joker, on a Small or Big Blind
-    ///   only. Its ×0.5 gain is not here — that is deterministic and already
-    ///   applied by the pure hook, because Balatro grants it whether or not
-    ///   anything was destroyed.
-    /// * **Riff-Raff** draws 2 Common Jokers from the rarity pool, stopping at
-    ///   [`joker_slots`](Self::joker_slots) — checked per joker, so a board with
+    /// A joker is known by its symbol — the `rank.index` that `bcard!`, the
+    /// challenge bans and the profile's joker list all key on — so a symbol
+    /// already taken by a built-in, by a custom joker already on the board, or
+    /// by an earlier one of `jokers` is refused, whatever the rest of the card
+    /// says. All or nothing: every card is checked before any is added.
+    ///
+    /// The cards usually come from a
+    /// [`JokerYaml`](crate::funky::types::custom_joker::JokerYaml) file, which
+    /// has already checked them against the built-ins and each other; a card
+    /// built by hand gets the same checks here.
+    ///
+    /// # Errors
+    ///
+    /// [`CardError::InvalidCard`], naming the symbol of the first card that is
+    /// not a rarity-pool joker or whose symbol is taken.
+    pub fn add_custom_jokers(
+        &mut self,
+        jokers: impl IntoIterator<Item = BuffoonCard>,
+    ) -> Result<(), CardError> {
+        let jokers: Vec<BuffoonCard> = jokers.into_iter().collect();
+        for (index, joker) in jokers.iter().enumerate() {
+            let symbol = joker.rank.index;
+            let taken = Joker::by_symbol(symbol).is_some()
+                || self
+                    .custom_jokers
+                    .iter()
+                    .any(|custom| custom.rank.index == symbol)
+                || jokers[..index]
+                    .iter()
+                    .any(|earlier| earlier.rank.index == symbol);
+            if taken || !joker.is_joker() || self.joker_pool(joker.card_type).is_none() {
+                return Err(CardError::InvalidCard(symbol.to_string()));
+            }
+        }
+        for joker in jokers {
+            self.custom_jokers.push(joker);
+        }
+        Ok(())
+    }
+
+    /// Everything [`on_blind_selected`](Self::on_blind_selected) does, then the
+    /// random blind-select effects:
+    ///
+    /// * **Madness** destroys one random *other* joker, on a Small or Big Blind
+    ///   only. Its ×0.5 gain is not here — that is deterministic and already
+    ///   applied by the pure hook, because Balatro grants it whether or not
+    ///   anything was destroyed.
+    /// * **Riff-Raff** draws 2 Common Jokers from the rarity pool, stopping at
+    ///   [`joker_slots`](Self::joker_slots) — checked per joker, so a board with
     ///   one free slot gets one of the two and a full board gets none, which is
     ///   Balatro's "(Must have room)".
     ///
@@ -2491,15 +3812,14 @@ impl BuffoonBoard {
         self.madness_destroys_a_joker(rng);
 
         let creations: Vec<(usize, BCardType)> = self
-            .jokers
-            .iter()
-            .filter_map(|joker| match joker.enhancement {
+            .abilities()
+            .filter_map(|(_, joker)| match joker.enhancement {
                 MPip::CreateJokersWhenBlindSelected(n, rarity) => Some((n, rarity)),
                 _ => None,
             })
             .collect();
         for (count, rarity) in creations {
-            let Some(pool) = Self::joker_pool(rarity) else {
+            let Some(pool) = self.joker_pool(rarity) else {
                 continue;
             };
             if pool.is_empty() {
@@ -2515,8 +3835,21 @@ impl BuffoonBoard {
         }
     }
 
-    /// Each Madness on the board destroys one random joker — never itself, and
-    /// never on a Boss Blind.
+    /// [`on_blind_selected_with_rng`](Self::on_blind_selected_with_rng), then
+    /// each custom joker's [`EffectEvent::BlindSelected`] hook through
+    /// `registry` — after Riff-Raff, so a custom joker it creates hooks in at
+    /// once.
+    pub fn on_blind_selected_with_registry<R: Rng + ?Sized>(
+        &mut self,
+        rng: &mut R,
+        registry: &EffectRegistry,
+    ) {
+        self.on_blind_selected_with_rng(rng);
+        self.trigger_effects(EffectEvent::BlindSelected, registry);
+    }
+
+    /// Each Madness on the board destroys one random joker — never itself, never
+    /// an Eternal one, and never on a Boss Blind.
     ///
     /// Victims are picked one Madness at a time, re-reading the board each pass,
     /// so two Madnesses cannot both target the same slot and a Madness can eat
@@ -2552,9 +3885,14 @@ impl BuffoonBoard {
             if !alive.contains(&source) {
                 continue; // an earlier Madness already ate this one
             }
-            let victims: Vec<usize> = alive.iter().copied().filter(|s| *s != source).collect();
+            let victims: Vec<usize> = alive
+                .iter()
+                .copied()
+                .filter(|slot| *slot != source)
+                .filter(|slot| self.jokers.get(*slot).is_some_and(|j| !j.stickers.eternal))
+                .collect();
             if victims.is_empty() {
-                continue; // it is alone, and cannot destroy itself
+                continue; // nothing it may destroy: it is alone, or among Eternals
             }
             let victim = victims[rng.random_range(0..victims.len())];
             alive.retain(|slot| *slot != victim);
@@ -2576,13 +3914,16 @@ impl BuffoonBoard {
     /// * **interest** — $1 per full $5 held, capped at $5, so money above $25
     ///   earns nothing and debt earns nothing.
     ///
+    /// A **Green Deck** run replaces the last two: $2 per unused hand, $1 per
+    /// unused discard, and no interest at all.
+    ///
     /// **Gated on [`round_is_won`](Self::round_is_won)** — all three lines or
     /// none. In Balatro you cash out by *beating* a blind; a round that merely
     /// runs out of hands is a loss, and losses pay nothing. Since `round_is_won`
     /// is false whenever [`blind_target`](Self::blind_target) is 0, an
     /// untargeted round — the mode every board ran in before the shop existed —
-    /// is unaffected, and cash-out is opt-in through the target that ante
-    /// progression will one day set.
+    /// is unaffected, and cash-out is opt-in through the target a caller (or
+    /// a [`Run`](crate::funky::types::run::Run)) sets.
     ///
     /// Takes `&self` and returns the delta rather than paying itself, so
     /// [`on_round_end`](Self::on_round_end) can apply it against the **same
@@ -2593,11 +3934,18 @@ impl BuffoonBoard {
             return 0;
         }
         let reward: isize = match self.blind {
+            Blind::Small if !self.stake.small_blind_pays() => 0,
             Blind::Small => 3,
             Blind::Big => 4,
             Blind::Boss(_) => 5,
         };
         let per_hand = isize::try_from(self.hands_remaining()).unwrap_or(isize::MAX);
+        if self.starter_deck == Some(StarterDeck::Green) {
+            let per_discard = isize::try_from(self.discards_remaining()).unwrap_or(isize::MAX);
+            return reward
+                .saturating_add(per_hand.saturating_mul(2))
+                .saturating_add(per_discard);
+        }
         // The same shape as To the Moon's `ExtraInterest` steps in
         // `payout_delta`, deliberately: they are the same rule, and the clamp's
         // lower bound is what keeps debt from charging negative interest; the
@@ -2610,7 +3958,9 @@ impl BuffoonBoard {
     /// (Popcorn's decay, Rocket's boss tally), pay the round-end `+$` jokers and
     /// the round's cash-out into [`money`](Self::money), grow each Egg's resell
     /// value, destroy anything the decay emptied, and reset the round's
-    /// counters. Inert on a board without those jokers.
+    /// counters. Inert on a board without those jokers. A won Boss Blind also
+    /// advances the [`ante`](Self::ante), and the joker [`Stickers`] tick: a
+    /// Rental joker charges its rent, a Perishable one counts down a round.
     ///
     /// The order is load-bearing at three points:
     ///
@@ -2637,9 +3987,20 @@ impl BuffoonBoard {
     /// `score`/`score_with_rng` split: with no RNG the rolls are simply
     /// skipped, the way a Lucky card stays inert in the pure [`score`](Self::score).
     pub fn on_round_end(&mut self) {
+        self.round_end_payouts();
+        self.reset_round();
+    }
+
+    /// [`on_round_end`](Self::on_round_end) up to its reset: everything that
+    /// reads the round as it was played.
+    fn round_end_payouts(&mut self) {
         // Read before anything mutates `money`; applied below, after the payouts
         // have read that same balance.
         let cash_out = self.cash_out();
+        if self.round_is_won() && self.blind.is_boss() {
+            self.ante += 1;
+            self.boss_rerolls_this_ante = 0;
+        }
         self.apply_growth(&GrowthEvent::RoundEnd);
         self.apply_payouts(&GrowthEvent::RoundEnd);
         self.money = self.money.saturating_add(cash_out);
@@ -2656,6 +4017,12 @@ impl BuffoonBoard {
                 self.jokers.insert(index, grown);
             }
         }
+    }
+
+    /// The end of [`on_round_end`](Self::on_round_end): stickers tick, and the
+    /// round's counters start over.
+    fn reset_round(&mut self) {
+        self.tick_stickers();
         self.discards_used = 0;
         self.hands_played = 0;
         self.hands_by_type_this_round.clear();
@@ -2677,6 +4044,28 @@ impl BuffoonBoard {
     /// `joker_state` aligned.
     pub fn on_round_end_with_rng<R: Rng + ?Sized>(&mut self, rng: &mut R) {
         self.on_round_end();
+        self.round_end_rolls(rng);
+    }
+
+    /// [`on_round_end_with_rng`](Self::on_round_end_with_rng), firing each
+    /// custom joker's [`EffectEvent::RoundEnd`] hook through `registry` after
+    /// the built-in payouts and before the round is reset — so the hook reads
+    /// the round's score and hands played as a built-in does — and before the
+    /// destruction pass, so a custom joker that pays, pays the round it dies.
+    pub fn on_round_end_with_registry<R: Rng + ?Sized>(
+        &mut self,
+        rng: &mut R,
+        registry: &EffectRegistry,
+    ) {
+        self.round_end_payouts();
+        self.trigger_effects(EffectEvent::RoundEnd, registry);
+        self.reset_round();
+        self.round_end_rolls(rng);
+    }
+
+    /// The probabilistic half of the round's end: the destruction rolls, then
+    /// Ancient Joker's suit and Perkeo's copies.
+    fn round_end_rolls<R: Rng + ?Sized>(&mut self, rng: &mut R) {
         let scale = self.probability_numerator();
         let destroyed: Vec<usize> = self
             .jokers
@@ -2715,9 +4104,8 @@ impl BuffoonBoard {
     /// Balatro, where the copy is a consumable like any other.
     fn perkeo_copies<R: Rng + ?Sized>(&mut self, rng: &mut R) {
         let perkeos = self
-            .jokers
-            .iter()
-            .filter(|joker| matches!(joker.enhancement, MPip::CreateNegativeConsumableCopy))
+            .abilities()
+            .filter(|(_, joker)| matches!(joker.enhancement, MPip::CreateNegativeConsumableCopy))
             .count();
         for _ in 0..perkeos {
             if self.consumables.is_empty() {
@@ -2788,12 +4176,10 @@ impl BuffoonBoard {
             // nothing has been used, where granted and remaining coincide), but
             // it keeps `draws.discards` from having any "remaining" readers left
             // to imitate.
-            (MPip::CashPerDiscardIfNoneUsed(n), GrowthEvent::RoundEnd) => {
-                if self.discards_used == 0 {
-                    cash(n * self.discards_remaining())
-                } else {
-                    0
-                }
+            (MPip::CashPerDiscardIfNoneUsed(n), GrowthEvent::RoundEnd)
+                if self.discards_used == 0 =>
+            {
+                cash(n * self.discards_remaining())
             }
             // Cloud 9: $n per matching rank in the full deck — the roster, so
             // destroyed 9s stop paying and added ones start.
@@ -2838,9 +4224,7 @@ impl BuffoonBoard {
     /// amount rather than its previous one.
     fn apply_payouts(&mut self, event: &GrowthEvent) {
         let total: isize = self
-            .jokers
-            .iter()
-            .enumerate()
+            .abilities()
             .map(|(slot, joker)| {
                 let counter = self.joker_state.get(slot).copied().unwrap_or(0);
                 self.payout_delta(joker.enhancement, event, counter)
@@ -2869,6 +4253,28 @@ impl BuffoonBoard {
         Some(base.saturating_sub(per.saturating_mul(ticks)) == 0)
     }
 
+    /// Count the joker stickers down a round: each Rental joker charges
+    /// [`RENTAL_COST`](Stickers::RENTAL_COST), debt and all, and each Perishable
+    /// one loses a round — debuffed for good once it has none left. Part of
+    /// [`on_round_end`](Self::on_round_end), after the cash-out, so the rent is
+    /// paid from the round's takings.
+    fn tick_stickers(&mut self) {
+        for index in 0..self.jokers.len() {
+            let Some(mut joker) = self.jokers.get(index).copied() else {
+                continue;
+            };
+            if joker.stickers.rental {
+                self.money = self.money.saturating_sub(Stickers::RENTAL_COST);
+            }
+            if let Some(rounds) = joker.stickers.perishable {
+                joker.stickers.perishable = Some(rounds.saturating_sub(1));
+                joker.debuffed |= joker.stickers.has_perished();
+                self.jokers.remove(index);
+                self.jokers.insert(index, joker);
+            }
+        }
+    }
+
     /// Remove every decaying joker whose decay has consumed its base: Ice Cream
     /// (`LoseChipsPerHand`, emptied by hands played) and Popcorn
     /// (`LoseMultPerRound`, emptied by rounds ended). Each is destroyed **by the
@@ -2925,27 +4331,20 @@ impl BuffoonBoard {
     ///
     /// # Known gap: retriggers
     ///
-    /// Each played card is bumped **once per hand**, not once per scoring
-    /// trigger. Balatro fires Hiker on every trigger, so a card retriggered by
-    /// Hack would gain `+4` twice, with the second trigger scoring the
-    /// already-fattened card. Getting that exact needs the bump interleaved into
-    /// the played-card fold, which is a pure `&self` fold and cannot mutate — so
-    /// it waits on scoring becoming mutating.
-    /// Boards without a retrigger joker (every board today except Hack, Sock and
-    /// Buskin, and Hanging Chad ones) are exact.
+    /// On its own this bumps each played card **once per hand**, not once per
+    /// scoring trigger. Balatro fires Hiker on every trigger, so a card
+    /// retriggered by Hack gains `+4` twice, with the second trigger scoring the
+    /// already-fattened card. The retriggers' share is paid by the traced fold
+    /// and [`commit_trace`](Self::commit_trace), which is what
+    /// [`play_hand`](Self::play_hand) drives; a caller pairing this with the
+    /// pure [`score`](Self::score) gets the once-per-hand approximation.
+    /// Boards without a retrigger joker are exact either way.
     pub fn on_scored(&mut self) {
         // Vampire counts the enhancements *before* anything eats them.
         let played = self.played.clone();
         self.apply_growth(&GrowthEvent::Scored(&played));
 
-        let bump: usize = self
-            .jokers
-            .iter()
-            .map(|joker| match joker.enhancement {
-                MPip::GainChipsOnScored(n) => n,
-                _ => 0,
-            })
-            .sum();
+        let bump = self.hiker_bump();
         let eats_enhancements = self
             .jokers
             .iter()
@@ -2962,17 +4361,38 @@ impl BuffoonBoard {
             if eats_enhancements {
                 mutated.enhancement = MPip::Blank;
             }
-            if mutated == card {
-                continue;
-            }
-            self.played.remove(index);
-            self.played.insert(index, mutated);
-            if let Some(slot) = self.full_deck_index_of(card) {
-                self.replace_deck_card(slot, mutated);
+            if mutated != card {
+                self.replace_played_card(index, mutated);
             }
         }
     }
 
+    /// The chips every scoring trigger adds to a played card, summed over the
+    /// board's Hikers. 0 without one.
+    fn hiker_bump(&self) -> usize {
+        self.abilities()
+            .map(|(_, joker)| match joker.enhancement {
+                MPip::GainChipsOnScored(n) => n,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    /// Swap the played card at `index` for `mutated`, and its roster copy with
+    /// it, so the change outlives the hand. A card the roster does not hold
+    /// still changes in [`played`](Self::played); only the persistence is
+    /// skipped.
+    fn replace_played_card(&mut self, index: usize, mutated: BuffoonCard) {
+        let Some(card) = self.played.get(index).copied() else {
+            return;
+        };
+        self.played.remove(index);
+        self.played.insert(index, mutated);
+        if let Some(slot) = self.full_deck_index_of(card) {
+            self.replace_deck_card(slot, mutated);
+        }
+    }
+
     /// The random half of [`on_scored`](Self::on_scored): the **Tarot creators**
     /// that fire on the hand being played.
     ///
@@ -3006,18 +4426,17 @@ impl BuffoonBoard {
         let money = self.money;
 
         let creators: Vec<MPip> = self
-            .jokers
-            .iter()
-            .filter(|joker| {
+            .abilities()
+            .map(|(_, joker)| joker.enhancement)
+            .filter(|enhancement| {
                 matches!(
-                    joker.enhancement,
+                    enhancement,
                     MPip::CreateTarotOnAceStraight
                         | MPip::CreateTarotOnLowMoney(_)
                         | MPip::CreateSpectralOnStraightFlush
                         | MPip::CreateSpectralOnFirstSingleSix
                 )
             })
-            .map(|joker| joker.enhancement)
             .collect();
 
         for enhancement in creators {
@@ -3121,6 +4540,13 @@ impl BuffoonBoard {
                 #[allow(clippy::cast_sign_loss)]
                 Some(ScoreOp::AddChips(rate * counter.max(0) as usize))
             }
+            // Ceremonial Dagger: its base plus everything it has eaten — the
+            // counter holds the mult itself, not a tick count.
+            MPip::MultPlusDoubleValueDestroyJokerOnRight(base) =>
+            {
+                #[allow(clippy::cast_sign_loss)]
+                Some(ScoreOp::AddMult(base + counter.max(0) as usize))
+            }
             MPip::LoseMultTimesPerDiscard(base, per) => {
                 #[allow(clippy::cast_precision_loss, clippy::cast_sign_loss)]
                 let raw = (per as f32).mul_add(-(counter.max(0) as f32), base as f32) / 100.0;
@@ -3131,6 +4557,19 @@ impl BuffoonBoard {
                 let hands = counter.max(0) as usize;
                 Some(ScoreOp::AddChips(base.saturating_sub(per * hands)))
             }
+            // Loyalty Card: ×`factor` on every `every`th hand. The counter is
+            // hands completed, so the hand being scored is number `counter + 1`.
+            MPip::MultTimesEveryXHands(factor, every) if every > 0 => {
+                #[allow(clippy::cast_sign_loss)]
+                let hand = counter.max(0) as usize + 1;
+                #[allow(clippy::cast_precision_loss)]
+                let op = if hand % every == 0 {
+                    ScoreOp::TimesMult(factor as f32)
+                } else {
+                    ScoreOp::Nothing
+                };
+                Some(op)
+            }
             // Popcorn: Ice Cream's decay on the mult side, per round rather
             // than per hand. Floors at 0 — the round that empties it also
             // destroys it (`melt_emptied_jokers`).
@@ -3154,7 +4593,8 @@ impl BuffoonBoard {
             | MPip::GainMultTimesPerFaceDestroyed(rate)
             | MPip::GainMultTimesPerEnhancedPlayed(rate)
             | MPip::GainMultTimesPerPlanetUsed(rate)
-            | MPip::GainMultTimesOnNonBossBlindDestroyingJoker(rate) => {
+            | MPip::GainMultTimesOnNonBossBlindDestroyingJoker(rate)
+            | MPip::GainMultTimesPerLuckyProc(rate) => {
                 #[allow(clippy::cast_sign_loss)]
                 let ticks = counter.max(0) as usize;
                 Some(ScoreOp::TimesMult(Self::gain_x_mult(rate, ticks)))
@@ -3188,8 +4628,9 @@ mod funky__types__board__buffoon_board_tests {
     use crate::funky::decks::planet::card as planet_card;
     use crate::funky::decks::spectral::card as spectral_card;
     use crate::funky::decks::tarot::card as tarot_card;
-    use crate::funky::types::effect::{Effect, ScoreOp};
+    use crate::funky::types::effect::{Effect, EffectContext, EffectEvent, ScoreOp};
     use crate::funky::types::hands::HandType;
+    use crate::funky::types::magnitude::Magnitude;
     use crate::funky::types::mpip::MPip;
     use crate::preludes::funky::{Blind, BossBlind};
     use crate::preludes::funky::{BuffoonCard, Deck};
@@ -3803,6 +5244,8 @@ mod funky__types__board__buffoon_board_tests {
     // A mod-defined effect that reads the board through the context: ×2 mult
     // when the played hand is a flush. Adding it required NO change to any core
     // `MPip` match arm — only registering it under an id.
+    const FLUSH_DOUBLER: EffectId = EffectId::new("test:flush_doubler");
+
     struct FlushDoubler;
     impl Effect for FlushDoubler {
         fn score(&self, ctx: &ScoringContext<'_>) -> ScoreOp {
@@ -3830,9 +5273,9 @@ mod funky__types__board__buffoon_board_tests {
 
     #[test]
     fn score_with_registry__custom_played_card_scores() {
-        const ID: u32 = 7001;
+        const ID: EffectId = EffectId::new("test:add_chips");
         let mut registry = EffectRegistry::new();
-        registry.register(ID, AddChips(50));
+        registry.register(ID, AddChips(50)).unwrap();
 
         let mut board = board_playing("2S");
         board.played = BuffoonPile::from(vec![enhanced(basic::ACE_SPADES, MPip::Custom(ID))]);
@@ -3847,9 +5290,9 @@ mod funky__types__board__buffoon_board_tests {
     fn score_with_registry__custom_played_xmult_multiplies_running_base() {
         // The reason phase 2 takes a running score: a played ×mult must scale
         // the score so far (including the phase-1 base), in card order.
-        const ID: u32 = 7002;
+        const ID: EffectId = EffectId::new("test:double_mult");
         let mut registry = EffectRegistry::new();
-        registry.register(ID, DoubleMult);
+        registry.register(ID, DoubleMult).unwrap();
 
         let mut board = board_playing("2S");
         board.played = BuffoonPile::from(vec![enhanced(basic::ACE_SPADES, MPip::Custom(ID))]);
@@ -3861,9 +5304,9 @@ mod funky__types__board__buffoon_board_tests {
 
     #[test]
     fn score_with_registry__custom_held_card_scores() {
-        const ID: u32 = 7003;
+        const ID: EffectId = EffectId::new("test:double_mult");
         let mut registry = EffectRegistry::new();
-        registry.register(ID, DoubleMult);
+        registry.register(ID, DoubleMult).unwrap();
 
         let mut board = board_playing("AS KS QS JS TS"); // royal flush
         board.in_hand = BuffoonPile::from(vec![enhanced(basic::KING_HEARTS, MPip::Custom(ID))]);
@@ -3875,9 +5318,8 @@ mod funky__types__board__buffoon_board_tests {
 
     #[test]
     fn score_with_registry__custom_joker_scores() {
-        const FLUSH_DOUBLER: u32 = 9001;
         let mut registry = EffectRegistry::new();
-        registry.register(FLUSH_DOUBLER, FlushDoubler);
+        registry.register(FLUSH_DOUBLER, FlushDoubler).unwrap();
 
         let mut board = board_playing("AS KS QS JS 9S"); // flush, not a straight
         board
@@ -3896,9 +5338,8 @@ mod funky__types__board__buffoon_board_tests {
 
     #[test]
     fn score_with_registry__custom_effect_is_hand_aware() {
-        const FLUSH_DOUBLER: u32 = 9001;
         let mut registry = EffectRegistry::new();
-        registry.register(FLUSH_DOUBLER, FlushDoubler);
+        registry.register(FLUSH_DOUBLER, FlushDoubler).unwrap();
 
         // Same custom joker, but a non-flush hand -> the effect returns Nothing.
         let mut board = board_playing("AS KD QC JS 9H"); // high card
@@ -3913,11 +5354,258 @@ mod funky__types__board__buffoon_board_tests {
     fn score_with_registry__unregistered_custom_is_inert() {
         let registry = EffectRegistry::new(); // empty
         let mut board = board_playing("AS KS QS JS 9S");
-        board.jokers.push(enhanced(card::JOKER, MPip::Custom(404)));
+        board.jokers.push(enhanced(
+            card::JOKER,
+            MPip::Custom(EffectId::new("test:unregistered")),
+        ));
         // Unknown id -> no contribution.
         assert_eq!(board.score_with_registry(&registry), Score::new(85, 4));
     }
 
+    #[test]
+    fn score_with_registry__a_legacy_id_scores_through_its_alias() {
+        // A save from when ids were numbers: `{"Custom": 9001}`.
+        let legacy: MPip = serde_json::from_str(r#"{"Custom":9001}"#).unwrap();
+        assert_eq!(legacy, MPip::Custom(EffectId::legacy(9001)));
+        let mut registry = EffectRegistry::new();
+        registry.register(FLUSH_DOUBLER, FlushDoubler).unwrap();
+        let mut board = board_playing("AS KS QS JS 9S");
+        board.jokers.push(enhanced(card::JOKER, legacy));
+        assert_eq!(board.score_with_registry(&registry), Score::new(85, 4));
+
+        registry.alias(9001, FLUSH_DOUBLER).unwrap();
+
+        assert_eq!(board.score_with_registry(&registry), Score::new(85, 8));
+    }
+
+    #[test]
+    fn migrate_effect_ids__rewrites_aliased_ids_everywhere() {
+        let mut registry = EffectRegistry::new();
+        registry.register(FLUSH_DOUBLER, FlushDoubler).unwrap();
+        registry.alias(9001, FLUSH_DOUBLER).unwrap();
+        let legacy = enhanced(card::JOKER, MPip::Custom(EffectId::legacy(9001)));
+        let unknown = enhanced(card::JOKER, MPip::Custom(EffectId::legacy(9002)));
+        let mut board = board_playing("AS KS QS JS 9S");
+        board.jokers.push(legacy);
+        board.jokers.push(unknown);
+        board.shop = Some(crate::funky::types::shop::Shop::with_stock(vec![legacy]));
+
+        assert_eq!(board.migrate_effect_ids(&registry), 2);
+
+        let migrated = enhanced(card::JOKER, MPip::Custom(FLUSH_DOUBLER));
+        assert_eq!(board.jokers.get(0), Some(&migrated));
+        assert_eq!(board.jokers.get(1), Some(&unknown), "no alias, left alone");
+        assert_eq!(board.shop.as_ref().unwrap().stock, vec![migrated]);
+        assert_eq!(board.migrate_effect_ids(&registry), 0, "nothing left");
+        assert!(
+            serde_json::to_string(&board)
+                .unwrap()
+                .contains("test:flush_doubler")
+        );
+    }
+
+    #[test]
+    fn deserialize__a_baseline_save_loads_migrates_and_scores() {
+        // Written by the last release whose ids were numbers: a five-card deck
+        // and one joker carrying `{"Custom":9001}`, and none of the fields
+        // added since.
+        const DOUBLER: EffectId = EffectId::new("balance_team:flush_doubler");
+        let json = include_str!("../../../tests/fixtures/funky/baseline_board.json");
+        let mut board: BuffoonBoard = serde_json::from_str(json).unwrap();
+        assert_eq!(board.ante, 1);
+        assert_eq!(board.stake, Stake::White);
+        assert_eq!(
+            board.jokers.get(0).unwrap().enhancement,
+            MPip::Custom(EffectId::legacy(9001))
+        );
+        let mut registry = EffectRegistry::new();
+        registry.register(DOUBLER, DoubleMult).unwrap();
+        registry.alias(9001, DOUBLER).unwrap();
+
+        assert_eq!(board.migrate_effect_ids(&registry), 1);
+
+        assert_eq!(
+            board.jokers.get(0).unwrap().enhancement,
+            MPip::Custom(DOUBLER)
+        );
+        board.played = board.deck.clone();
+        // Pair (10,2) + cards (11+11+10+9+2 = 43) = (53,2), and the joker's ×2.
+        assert_eq!(board.score_with_registry(&registry), Score::new(53, 4));
+    }
+
+    const HOARDER: EffectId = EffectId::new("test:hoarder");
+
+    /// +1 mult per card it has seen discarded, $2 at round end, and a Fool
+    /// for every purchase.
+    struct Hoarder;
+    impl Effect for Hoarder {
+        fn score(&self, ctx: &ScoringContext<'_>) -> ScoreOp {
+            ScoreOp::AddMult(usize::try_from(ctx.state).unwrap_or(0))
+        }
+
+        fn on_discard(&self, ctx: &mut EffectContext<'_>, discarded: &BuffoonPile) {
+            ctx.add_state(i32::try_from(discarded.len()).unwrap_or(0));
+        }
+
+        fn on_round_end(&self, ctx: &mut EffectContext<'_>) {
+            ctx.earn(2);
+        }
+
+        fn on_buy(&self, ctx: &mut EffectContext<'_>, _bought: BuffoonCard) {
+            ctx.create_consumable(tarot_card::FOOL);
+        }
+    }
+
+    fn hoarder_registry() -> EffectRegistry {
+        let mut registry = EffectRegistry::new();
+        registry.register(HOARDER, Hoarder).unwrap();
+        registry
+    }
+
+    #[test]
+    fn trigger_effects__a_custom_joker_grows_and_scores_its_counter() {
+        let registry = hoarder_registry();
+        let mut board = board_playing("AS KD QC JS 9H");
+        board.push_joker(enhanced(card::JOKER, MPip::Custom(HOARDER)));
+        let discarded = BuffoonPile::forgiving_from_str("2S 3S 4S");
+
+        board.trigger_effects(EffectEvent::Discard(&discarded), &registry);
+
+        assert_eq!(board.joker_state, vec![3]);
+        // High Card 5/1 + cards 50 chips; the Hoarder adds +3 mult.
+        assert_eq!(board.score_with_registry(&registry), Score::new(55, 4));
+    }
+
+    #[test]
+    fn trigger_effects__a_custom_joker_pays_and_creates() {
+        let registry = hoarder_registry();
+        let mut board = board_for_a_round();
+        board.push_joker(enhanced(card::JOKER, MPip::Custom(HOARDER)));
+
+        board.trigger_effects(EffectEvent::RoundEnd, &registry);
+        board.trigger_effects(EffectEvent::Bought(card::JOKER), &registry);
+        board.trigger_effects(EffectEvent::BlindSelected, &registry);
+
+        assert_eq!(board.money, 2);
+        assert_eq!(board.consumables.len(), 1);
+        assert_eq!(board.joker_state, vec![0], "no hook for blind select");
+    }
+
+    #[test]
+    fn trigger_effects__a_blueprint_runs_the_hook_it_copies() {
+        let registry = hoarder_registry();
+        let mut board = board_for_a_round();
+        board.push_joker(card::BLUEPRINT);
+        board.push_joker(enhanced(card::JOKER, MPip::Custom(HOARDER)));
+
+        board.trigger_effects(EffectEvent::RoundEnd, &registry);
+
+        assert_eq!(board.money, 4);
+    }
+
+    #[test]
+    fn trigger_effects__skips_debuffed_and_unregistered_jokers() {
+        let registry = hoarder_registry();
+        let mut board = board_for_a_round();
+        let mut debuffed = enhanced(card::JOKER, MPip::Custom(HOARDER));
+        debuffed.debuffed = true;
+        board.push_joker(debuffed);
+        board.push_joker(enhanced(
+            card::JOKER,
+            MPip::Custom(EffectId::new("test:unregistered")),
+        ));
+
+        board.trigger_effects(EffectEvent::RoundEnd, &registry);
+
+        assert_eq!(board.money, 0);
+    }
+
+    #[test]
+    fn try_play_hand_with_registry__scores_custom_jokers() {
+        let registry = hoarder_registry();
+        let mut board = board_for_a_round();
+        board.push_joker(enhanced(card::JOKER, MPip::Custom(HOARDER)));
+        board.joker_state = vec![3];
+        board.deal_to_hand_size();
+        let mut plain = board.clone();
+
+        let custom = board
+            .try_play_hand_with_registry(&[0], &mut StdRng::seed_from_u64(1), &registry)
+            .unwrap();
+        let without = plain
+            .try_play_hand_with_rng(&[0], &mut StdRng::seed_from_u64(1))
+            .unwrap();
+
+        assert_eq!(custom.chips, without.chips);
+        assert_eq!(custom.mult, without.mult + 3);
+        assert_eq!(board.round_score, custom.score());
+    }
+
+    #[test]
+    fn try_discard_cards_with_registry__fires_the_discard_hook() {
+        let registry = hoarder_registry();
+        let mut board = board_for_a_round();
+        board.push_joker(enhanced(card::JOKER, MPip::Custom(HOARDER)));
+        board.deal_to_hand_size();
+
+        board
+            .try_discard_cards_with_registry(&[0, 1], &registry)
+            .unwrap();
+
+        assert_eq!(board.joker_state, vec![2]);
+        assert_eq!(board.discards_used, 1);
+    }
+
+    #[test]
+    fn try_buy_stock_with_registry__fires_the_buy_hook_of_the_card_bought() {
+        let registry = hoarder_registry();
+        let hoarder = enhanced(card::JOKER, MPip::Custom(HOARDER));
+        let mut board = board_with_stock(vec![hoarder]);
+        board.money = 10;
+
+        assert_eq!(board.try_buy_stock_with_registry(0, &registry), Ok(hoarder));
+
+        assert_eq!(board.consumables.len(), 1, "its own purchase");
+    }
+
+    #[test]
+    fn on_round_end_with_registry__pays_the_custom_jokers() {
+        let registry = hoarder_registry();
+        let mut board = board_for_a_round();
+        board.push_joker(enhanced(card::JOKER, MPip::Custom(HOARDER)));
+        let mut plain = board.clone();
+
+        board.on_round_end_with_registry(&mut StdRng::seed_from_u64(1), &registry);
+        plain.on_round_end_with_rng(&mut StdRng::seed_from_u64(1));
+
+        assert_eq!(board.money, plain.money + 2);
+    }
+
+    #[test]
+    fn on_round_end_with_registry__hooks_read_the_round_before_it_resets() {
+        const TALLY: EffectId = EffectId::new("test:tally");
+        struct Tally;
+        impl Effect for Tally {
+            fn on_round_end(&self, ctx: &mut EffectContext<'_>) {
+                let board = ctx.board();
+                let hands = i32::try_from(board.hands_played).unwrap_or(0);
+                let score = i32::try_from(board.round_score).unwrap_or(0);
+                ctx.set_state(hands * 1_000 + score);
+            }
+        }
+        let mut registry = EffectRegistry::new();
+        registry.register(TALLY, Tally).unwrap();
+        let mut board = board_for_a_round();
+        board.push_joker(enhanced(card::JOKER, MPip::Custom(TALLY)));
+        board.hands_played = 2;
+        board.round_score = 300;
+
+        board.on_round_end_with_registry(&mut StdRng::seed_from_u64(1), &registry);
+
+        assert_eq!(board.joker_state, vec![2_300]);
+        assert_eq!((board.hands_played, board.round_score), (0, 0));
+    }
+
     fn lucky_ace_board() -> BuffoonBoard {
         let mut board = board_playing("2S");
         board.played = BuffoonPile::from(vec![enhanced(basic::ACE_SPADES, MPip::Lucky(5, 15))]);
@@ -3999,7 +5687,7 @@ mod funky__types__board__buffoon_board_tests {
         // Grow the second joker's counter, then remove the first.
         board.joker_state[1] = 7;
         let removed = board.remove_joker(0);
-        assert_eq!(removed, card::JOKER);
+        assert_eq!(removed, Some(card::JOKER));
         assert_eq!(board.jokers.len(), 1);
         // The survivor keeps its counter, now at index 0.
         assert_eq!(board.joker_state, vec![7]);
@@ -4468,6 +6156,25 @@ mod funky__types__board__buffoon_board_tests {
         assert_eq!(board.tarots_used, 1, "one Tarot, however many targets");
     }
 
+    #[test]
+    fn try_use_consumable__a_tarot_with_a_missing_target_is_refused_and_kept() {
+        let mut board = board_playing("2S 5D 8C TS KH");
+        board.create_consumable(tarot_card::JUSTICE);
+        let past_the_end = board.full_deck.len();
+
+        assert_eq!(
+            board.try_use_consumable(0, &[0, past_the_end]),
+            Err(ActionError::NoSuchTarget(past_the_end))
+        );
+
+        assert_eq!(board.consumables.len(), 1, "the tarot is kept");
+        assert_ne!(
+            board.full_deck.get(0).unwrap().enhancement,
+            MPip::Glass(2, 4)
+        );
+        assert_eq!(board.tarots_used, 0);
+    }
+
     #[test]
     fn score__constellation_gains_x_mult_per_planet_used() {
         // Constellation: gains ×0.1 Mult per Planet card used; base ×1.
@@ -5276,6 +6983,25 @@ mod funky__types__board__buffoon_board_tests {
         assert_eq!(board.hands_remaining(), 4, "and spends nothing");
     }
 
+    #[test]
+    fn try_play_hand__says_why() {
+        let mut board = board_for_a_round();
+        board.deal_to_hand_size();
+
+        assert_eq!(
+            board.try_play_hand(&[0, 99]),
+            Err(ActionError::NoSuchCard(99))
+        );
+        assert_eq!(
+            board.try_discard_cards(&[42]),
+            Err(ActionError::NoSuchCard(42))
+        );
+        for _ in 0..4 {
+            assert!(board.try_play_hand(&[0]).is_ok());
+        }
+        assert_eq!(board.try_play_hand(&[0]), Err(ActionError::NoHandsLeft));
+    }
+
     #[test]
     fn discard_cards__spends_a_discard_and_refills() {
         let mut board = board_for_a_round();
@@ -5885,7 +7611,10 @@ mod funky__types__board__buffoon_board_tests {
         for seed in 0..400 {
             let mut board = board_for_a_round();
             board.open_shop_with_rng(&mut StdRng::seed_from_u64(seed));
-            for card in &board.shop.as_ref().unwrap().stock {
+            for stocked in &board.shop.as_ref().unwrap().stock {
+                // A joker may arrive wearing an edition; the partition is of the
+                // jokers themselves, so compare them unedited.
+                let card = &stocked.with_edition(Edition::None);
                 total += 1;
                 match card.card_type {
                     BCardType::CommonJoker => {
@@ -5992,6 +7721,26 @@ mod funky__types__board__buffoon_board_tests {
         assert_eq!(board.money, 100);
     }
 
+    #[test]
+    fn try_buy_stock__says_why() {
+        let mut board = board_with_stock(vec![card::BLUE_JOKER]);
+        board.money = 1;
+        assert_eq!(
+            board.try_buy_stock(0),
+            Err(ActionError::NotEnoughMoney { cost: 5, money: 1 })
+        );
+
+        board.money = 100;
+        assert_eq!(board.try_buy_stock(5), Err(ActionError::NoSuchStock(5)));
+        for _ in 0..board.joker_slots {
+            board.push_joker(card::JOKER);
+        }
+        assert_eq!(board.try_buy_stock(0), Err(ActionError::NoJokerRoom));
+
+        board.shop = None;
+        assert_eq!(board.try_buy_stock(0), Err(ActionError::ShopClosed));
+    }
+
     #[test]
     fn buy_stock__credit_card_lets_a_buy_go_into_debt() {
         // Credit Card carries MPip::Credit(20): the buy floor drops to -$20, so
@@ -6262,6 +8011,20 @@ mod funky__types__board__buffoon_board_tests {
         }
     }
 
+    #[test]
+    fn open_shop_with_rng__never_offers_a_banned_voucher() {
+        let mut board = board_for_a_round();
+        board.challenge_rules.banned_vouchers = vec![Voucher::Grabber];
+        for seed in 0..64 {
+            board.open_shop_with_rng(&mut StdRng::seed_from_u64(seed));
+            assert_ne!(
+                board.shop.as_ref().unwrap().voucher,
+                Some(Voucher::Grabber),
+                "seed {seed}"
+            );
+        }
+    }
+
     #[test]
     fn open_shop_with_rng__offers_an_upgrade_once_its_base_is_held() {
         // With Grabber held, Nacho Tong becomes eligible; across seeds it does
@@ -6408,9 +8171,11 @@ mod funky__types__board__buffoon_board_tests {
     fn redeem_shop_voucher__antimatter_adds_a_joker_slot() {
         let mut board = board_offering_voucher(Voucher::Antimatter);
         board.money = 20;
+        board.vouchers.push(Voucher::Blank); // Antimatter's prerequisite
 
         assert!(board.redeem_shop_voucher());
         assert_eq!(board.joker_slots, BuffoonBoard::DEFAULT_JOKER_SLOTS + 1);
+        assert_eq!(board.money, 10, "a $10 voucher like any other");
         assert_eq!(
             board.consumable_slots,
             BuffoonBoard::DEFAULT_CONSUMABLE_SLOTS
@@ -6552,7 +8317,7 @@ mod funky__types__board__buffoon_board_tests {
         }
         let mut rng = StdRng::seed_from_u64(42);
         (0..draws)
-            .filter(|_| board.draw_stock_card(&mut rng).card_type == kind)
+            .filter(|_| board.draw_stock_card(&mut rng).unwrap().card_type == kind)
             .count()
     }
 
@@ -6592,7 +8357,10 @@ mod funky__types__board__buffoon_board_tests {
         board.vouchers.push(Voucher::TarotTycoon);
         let mut rng = StdRng::seed_from_u64(7);
         for _ in 0..2000 {
-            let card = board.draw_stock_card(&mut rng);
+            let card = board
+                .draw_stock_card(&mut rng)
+                .unwrap()
+                .with_edition(Edition::None);
             if card.is_joker() {
                 assert!(
                     Joker::COMMON_JOKERS.contains(&card)
@@ -7351,10 +9119,7 @@ mod funky__types__board__buffoon_board_tests {
     }
 
     fn buffoon_pack() -> BoosterPack {
-        BoosterPack {
-            kind: crate::funky::types::shop::PackKind::Buffoon,
-            cost: 4,
-        }
+        BoosterPack::new(PackKind::Buffoon, PackSize::Normal)
     }
 
     #[test]
@@ -7363,14 +9128,99 @@ mod funky__types__board__buffoon_board_tests {
         board.open_shop_with_rng(&mut StdRng::seed_from_u64(1));
         let packs = &board.shop.as_ref().unwrap().packs;
         assert_eq!(packs.len(), 2, "two pack slots");
-        assert!(packs.iter().all(|p| p.cost == 4), "base tier is $4");
+        assert!(
+            packs.iter().all(|p| p.cost == p.size.cost()),
+            "priced by tier"
+        );
     }
 
     #[test]
-    fn skip_pack__removes_the_pack() {
-        let mut board = board_with_packs(vec![buffoon_pack(), buffoon_pack()]);
-        assert!(board.skip_pack(0));
-        assert_eq!(board.shop.as_ref().unwrap().packs.len(), 1, "one skipped");
+    fn open_shop_with_rng__stocks_packs_at_their_weights() {
+        let mut kinds = std::collections::HashMap::new();
+        let mut sizes = std::collections::HashMap::new();
+        for seed in 0..500 {
+            let mut board = board_for_a_round();
+            board.open_shop_with_rng(&mut StdRng::seed_from_u64(seed));
+            for pack in &board.shop.unwrap().packs {
+                *kinds.entry(pack.kind).or_insert(0) += 1;
+                *sizes.entry(pack.size).or_insert(0) += 1;
+            }
+        }
+
+        // 1000 packs: Arcana, Celestial and Standard ~290 each, Buffoon ~87,
+        // Spectral ~43; Normal ~580, Jumbo ~290, Mega ~70.
+        for kind in [PackKind::Arcana, PackKind::Celestial, PackKind::Standard] {
+            assert!(kinds[&kind] > kinds[&PackKind::Buffoon], "{kind:?}");
+        }
+        assert!(kinds[&PackKind::Buffoon] > kinds[&PackKind::Spectral]);
+        assert!(kinds[&PackKind::Spectral] > 0);
+        assert!(sizes[&PackSize::Normal] > sizes[&PackSize::Jumbo]);
+        assert!(sizes[&PackSize::Jumbo] > sizes[&PackSize::Mega]);
+        assert!(sizes[&PackSize::Mega] > 0);
+    }
+
+    #[test]
+    fn open_pack_with_rng__offers_the_pack_s_choices() {
+        for (kind, size, count) in [
+            (PackKind::Buffoon, PackSize::Normal, 2),
+            (PackKind::Buffoon, PackSize::Mega, 4),
+            (PackKind::Arcana, PackSize::Jumbo, 5),
+            (PackKind::Celestial, PackSize::Normal, 3),
+            (PackKind::Spectral, PackSize::Jumbo, 4),
+            (PackKind::Standard, PackSize::Mega, 5),
+        ] {
+            let mut board = board_with_packs(vec![BoosterPack::new(kind, size)]);
+            board.money = 8;
+            let choices = board
+                .open_pack_with_rng(0, &mut StdRng::seed_from_u64(1))
+                .unwrap();
+
+            assert_eq!(choices.len(), count, "{kind:?} {size:?}");
+            assert_eq!(board.money, 8 - size.cost().cast_signed());
+        }
+    }
+
+    #[test]
+    fn open_pack_with_rng__spectral_pack_offers_spectrals() {
+        let mut board =
+            board_with_packs(vec![BoosterPack::new(PackKind::Spectral, PackSize::Normal)]);
+        board.money = 4;
+        let choices = board
+            .open_pack_with_rng(0, &mut StdRng::seed_from_u64(1))
+            .unwrap();
+
+        assert!(choices.iter().all(|c| c.card_type == BCardType::Spectral));
+    }
+
+    #[test]
+    fn open_pack_with_rng__standard_pack_cards_can_arrive_dressed() {
+        let mut enhanced = 0;
+        let mut edited = 0;
+        for seed in 0..100 {
+            let mut board =
+                board_with_packs(vec![BoosterPack::new(PackKind::Standard, PackSize::Jumbo)]);
+            board.money = 6;
+            for card in board
+                .open_pack_with_rng(0, &mut StdRng::seed_from_u64(seed))
+                .unwrap()
+            {
+                assert_eq!(card.card_type, BCardType::Basic);
+                assert_ne!(card.edition, Edition::Negative);
+                enhanced += usize::from(card.enhancement != MPip::Blank);
+                edited += usize::from(card.edition != Edition::None);
+            }
+        }
+
+        // 500 cards: ~200 enhanced, ~37 edited.
+        assert!((150..250).contains(&enhanced), "{enhanced}");
+        assert!((15..70).contains(&edited), "{edited}");
+    }
+
+    #[test]
+    fn skip_pack__removes_the_pack() {
+        let mut board = board_with_packs(vec![buffoon_pack(), buffoon_pack()]);
+        assert!(board.skip_pack(0));
+        assert_eq!(board.shop.as_ref().unwrap().packs.len(), 1, "one skipped");
     }
 
     #[test]
@@ -8311,12 +10161,12 @@ mod funky__types__board__buffoon_board_tests {
 
     #[test]
     fn on_scored__bumps_once_per_hand_even_when_a_card_is_retriggered() {
-        // Characterization of a known gap, not an endorsement. Balatro fires
-        // Hiker per scoring *trigger*, so Hack's retriggered 5 would gain +4
-        // twice and score the second time already fattened. `on_scored` runs
-        // before the pure fold, so it bumps once per hand instead. Pinned here
-        // so the deviation is visible and this test fails the day scoring
-        // becomes mutating and the gap can be closed properly.
+        // Characterization of the *pure* path, not an endorsement. Balatro
+        // fires Hiker per scoring *trigger*, so Hack's retriggered 5 would gain
+        // +4 twice and score the second time already fattened. `on_scored` runs
+        // before the pure fold, so on its own it bumps once per hand. The traced
+        // fold pays the retriggers -- see
+        // `score_traced__hiker_fattens_a_card_between_its_triggers`.
         let mut board = board_playing("5H 5S 5D"); // Trips
         board.push_joker(card::HIKER);
         board.push_joker(card::HACK); // retriggers each played 2-5
@@ -8339,4 +10189,1369 @@ mod funky__types__board__buffoon_board_tests {
 
         assert_eq!(board, before);
     }
+
+    /// The first seed whose single 1-in-5 Lucky roll on `board` procs.
+    fn lucky_proc_seed(board: &BuffoonBoard) -> u64 {
+        (0..64)
+            .find(|seed| board.score_with_seed(*seed).mult > 1)
+            .expect("a 1-in-5 roll hits within 64 seeds")
+    }
+
+    #[test]
+    fn score_traced__matches_score_and_records_each_card() {
+        let mut board = board_playing("2S 5D 8C TS KH");
+        board.push_joker(card::JOKER); // +4 mult, fires
+        let (score, trace) = board.score_traced();
+
+        assert_eq!(score, board.score());
+        for index in 0..5 {
+            assert_eq!(trace.triggers_of(index), 1);
+        }
+        assert!(trace.events.contains(&ProcEvent::JokerFired {
+            index: 0,
+            joker: card::JOKER,
+        }));
+        assert_eq!(trace.rng_draws(), 0);
+    }
+
+    #[test]
+    fn score_traced_with_rng__agrees_with_score_with_rng_and_counts_its_draws() {
+        let mut board = lucky_ace_board();
+        board.jokers.push(card::MISPRINT);
+        for seed in 0..16 {
+            let (score, trace) = board.score_traced_with_rng(&mut StdRng::seed_from_u64(seed));
+            assert_eq!(score, board.score_with_seed(seed));
+            // Two Lucky rolls (mult, then money), one Misprint roll.
+            assert_eq!(trace.rng_draws(), 3);
+        }
+    }
+
+    #[test]
+    fn score_traced_with_rng__lucky_cat_reads_the_proc_it_was_rolled_beside() {
+        let mut board = lucky_ace_board();
+        let seed = lucky_proc_seed(&board);
+        board.push_joker(card::LUCKY_CAT);
+
+        // Untraced, Lucky Cat has nothing committed and is x1: 16 x 21.
+        assert_eq!(board.score_with_seed(seed), Score::new(16, 21));
+
+        // Traced, the proc reaches it in the same hand: 21 x 1.25 = 26.25 -> 27.
+        let (score, trace) = board.score_traced_with_rng(&mut StdRng::seed_from_u64(seed));
+        assert_eq!(trace.lucky_procs(), 1);
+        assert_eq!(score, Score::new(16, 27));
+    }
+
+    #[test]
+    fn commit_trace__grows_lucky_cat_once_per_proc() {
+        let mut board = lucky_ace_board();
+        let seed = lucky_proc_seed(&board);
+        board.push_joker(card::LUCKY_CAT);
+
+        let (_, trace) = board.score_traced_with_rng(&mut StdRng::seed_from_u64(seed));
+        board.commit_trace(&trace);
+
+        assert_eq!(board.joker_state, vec![1]);
+        // Committed, the growth is visible to the pure fold too: 16 x 1.25 -> 2.
+        assert_eq!(board.score(), Score::new(16, 2));
+    }
+
+    #[test]
+    fn commit_trace__a_money_only_lucky_proc_pays_and_grows_lucky_cat() {
+        let mut board = board_playing("2S");
+        // Never the mult, always the money.
+        board.played = BuffoonPile::from(vec![enhanced(basic::ACE_SPADES, MPip::Lucky(0, 1))]);
+        board.push_joker(card::LUCKY_CAT);
+        let money = board.money;
+
+        let (score, trace) = board.score_traced_with_rng(&mut StdRng::seed_from_u64(0));
+        assert_eq!(trace.rng_draws(), 1, "odds of 0 draw nothing");
+        assert_eq!(trace.lucky_money_procs(), 1);
+        assert_eq!(trace.lucky_procs(), 1);
+        assert_eq!(score, Score::new(16, 2), "no +20 mult; Lucky Cat's x1.25");
+
+        board.commit_trace(&trace);
+        assert_eq!(board.money, money + 20);
+        assert_eq!(board.joker_state, vec![1]);
+    }
+
+    #[test]
+    fn commit_trace__is_inert_for_a_trace_with_no_procs() {
+        let mut board = board_playing("2S 5D 8C TS KH");
+        board.push_joker(card::LUCKY_CAT);
+        board.push_joker(card::HIKER);
+        let before = board.clone();
+
+        let (_, trace) = board.score_traced();
+        board.commit_trace(&trace);
+
+        assert_eq!(board, before);
+    }
+
+    #[test]
+    fn score_traced__hiker_fattens_a_card_between_its_triggers() {
+        // The gap `on_scored__bumps_once_per_hand_even_when_a_card_is_retriggered`
+        // pins for the pure path, closed: each 5 scores 9 and then 13.
+        let mut board = board_playing("5H 5S 5D"); // Trips
+        board.push_joker(card::HIKER);
+        board.push_joker(card::HACK);
+        board.on_scored();
+
+        let (score, trace) = board.score_traced();
+        assert_eq!(score, Score::new(96, 3));
+
+        board.commit_trace(&trace);
+        for card in &board.played {
+            assert_eq!(card.get_chips(), 13);
+        }
+    }
+
+    #[test]
+    fn play_hand_with_rng__grows_lucky_cat_from_the_hand_it_scored() {
+        let lucky = enhanced(basic::ACE_SPADES, MPip::Lucky(5, 15));
+        let mut board = BuffoonBoard::new(Draws::new(4, 3), BuffoonPile::default());
+        board.push_joker(card::LUCKY_CAT);
+
+        let seed = lucky_proc_seed(&lucky_ace_board());
+        board.in_hand = BuffoonPile::from(vec![lucky]);
+        let score = board.play_hand_with_rng(&[0], &mut StdRng::seed_from_u64(seed));
+
+        assert_eq!(score, Some(Score::new(16, 27)));
+        assert_eq!(board.joker_state, vec![1]);
+    }
+
+    #[test]
+    fn score_traced__scores_custom_effects_through_a_registry() {
+        let mut registry = EffectRegistry::new();
+        registry.register(FLUSH_DOUBLER, FlushDoubler).unwrap();
+        let mut board = board_playing("AS KS QS JS 9S");
+        board
+            .jokers
+            .push(enhanced(card::JOKER, MPip::Custom(FLUSH_DOUBLER)));
+
+        let (score, trace) = board.score_traced_with_registry(&registry);
+        assert_eq!(score, Score::new(85, 8));
+        assert_eq!(score, board.score_with_registry(&registry));
+        assert_eq!(trace.steps.last().map(|step| step.running), Some(score));
+        assert_eq!(board.score_traced().0, Score::new(85, 4));
+        assert_eq!(
+            board
+                .score_traced_with_rng_and_registry(&mut StdRng::seed_from_u64(1), &registry)
+                .0,
+            score
+        );
+    }
+
+    #[test]
+    fn score_traced__steps_break_the_score_down() {
+        // Pair of Aces 10/2, a held Steel King, and Joker (+4 mult).
+        let mut board = board_playing("AS AH");
+        let steel_king = enhanced(basic::KING_SPADES, MPip::STEEL);
+        board.in_hand = BuffoonPile::from(vec![steel_king]);
+        board.push_joker(card::JOKER);
+
+        let (score, trace) = board.score_traced();
+
+        assert_eq!(score, board.score());
+        assert_eq!(trace.steps.last().map(|step| step.running), Some(score));
+        let expected = format!(
+            "base: +10 chips +2 mult -> 10 x 2\n\
+             played AS: +11 chips -> 21 x 2\n\
+             played AH: +11 chips -> 32 x 2\n\
+             held {steel_king}: ×1.5 mult -> 32 x 3\n\
+             joker {}: +4 mult -> 32 x 7",
+            card::JOKER
+        );
+        assert_eq!(trace.to_string(), expected);
+    }
+
+    #[test]
+    fn scoring_phases_traced__compose_to_score() {
+        let mut board = board_playing("9H TH JH QH KH");
+        board.played = BuffoonPile::from(vec![
+            enhanced(basic::NINE_HEARTS, MPip::Glass(2, 4)),
+            basic::TEN_HEARTS,
+            basic::JACK_HEARTS,
+            basic::QUEEN_HEARTS,
+            basic::KING_HEARTS,
+        ]);
+        board.push_joker(card::THE_TRIBE);
+        let mut trace = ScoreTrace::new();
+
+        let base = board.scoring_phase1_pre_scoring_traced(&mut trace);
+        let played = board.scoring_phase2_dealt_hand_scoring_traced(base, &mut trace);
+        let held = board.scoring_phase3_effects_in_hand_traced(played, &mut trace);
+        let score = board.scoring_phase4_joker_scoring_traced(held, &mut trace);
+
+        assert_eq!(score, board.score());
+        // Five chip steps and one Glass step; nothing held; The Tribe's ×2.
+        assert_eq!(trace.steps_in(ScoringPhase::PlayedCards).count(), 6);
+        assert_eq!(trace.steps_in(ScoringPhase::HeldCards).count(), 0);
+        let joker_steps: Vec<_> = trace.steps_in(ScoringPhase::Jokers).collect();
+        assert_eq!(joker_steps.len(), 1);
+        assert_eq!(joker_steps[0].op, ScoreOp::TimesMult(2.0));
+    }
+
+    #[test]
+    fn score_traced__records_editions_as_their_own_steps() {
+        let mut board = board_playing("2S");
+        let mut joker = card::JOKER;
+        joker.edition = Edition::Polychrome;
+        board.push_joker(joker);
+
+        let (_, trace) = board.score_traced();
+        let ops: Vec<_> = trace
+            .steps_in(ScoringPhase::Jokers)
+            .map(|step| step.op.clone())
+            .collect();
+
+        assert_eq!(
+            ops,
+            vec![ScoreOp::Add(Score::new(0, 4)), ScoreOp::TimesMult(1.5)]
+        );
+    }
+
+    // ---- Starter decks -----------------------------------------------------
+
+    #[test]
+    fn cash_out__green_deck_pays_per_hand_and_discard_and_no_interest() {
+        let mut board = board_that_won_a_round(2); // 3 discards, none used
+        board.starter_deck = Some(StarterDeck::Green);
+        board.money = 60;
+        board.on_round_end();
+        // $60 + $3 reward + 2 hands × $2 + 3 discards × $1, and no interest.
+        assert_eq!(board.money, 70);
+    }
+
+    #[test]
+    fn open_shop_with_rng__ghost_deck_stocks_spectrals() {
+        let mut ghost = board_for_a_round();
+        ghost.starter_deck = Some(StarterDeck::Ghost);
+        let mut plain = board_for_a_round();
+        let mut spectrals = 0;
+        for seed in 0..64 {
+            ghost.open_shop_with_rng(&mut StdRng::seed_from_u64(seed));
+            plain.open_shop_with_rng(&mut StdRng::seed_from_u64(seed));
+            let is_spectral = |card: &BuffoonCard| card.card_type == BCardType::Spectral;
+            spectrals += ghost
+                .shop
+                .as_ref()
+                .unwrap()
+                .stock
+                .iter()
+                .filter(|c| is_spectral(c))
+                .count();
+            assert!(
+                !plain.shop.as_ref().unwrap().stock.iter().any(is_spectral),
+                "only a Ghost Deck shop stocks spectrals (seed {seed})"
+            );
+        }
+        assert!(spectrals > 0, "a Ghost Deck shop does stock them");
+    }
+
+    #[test]
+    fn score__plasma_deck_balances_after_the_jokers() {
+        let mut board = board_playing("AS AD");
+        assert_eq!(board.score(), Score::new(32, 2));
+
+        board.starter_deck = Some(StarterDeck::Plasma);
+        assert_eq!(board.score(), Score::new(17, 17));
+        assert_eq!(board.score_with_seed(1), Score::new(17, 17));
+
+        let (score, trace) = board.score_traced();
+        assert_eq!(score, Score::new(17, 17));
+        let last = trace.steps.last().unwrap();
+        assert_eq!(last.op, ScoreOp::Balance);
+        assert_eq!(last.source, None, "the deck, not a joker");
+    }
+
+    // ---- Edition sourcing ----------------------------------------------------
+
+    /// How many of `draws` shop jokers arrive wearing an edition.
+    fn edited_jokers(vouchers: &[Voucher], draws: usize) -> usize {
+        let mut board = board_for_a_round();
+        board.vouchers.extend_from_slice(vouchers);
+        let mut rng = StdRng::seed_from_u64(29);
+        (0..draws)
+            .filter(|_| board.draw_shop_joker(&mut rng).unwrap().edition != Edition::None)
+            .count()
+    }
+
+    #[test]
+    fn draw_shop_joker__rolls_editions_at_the_base_rate() {
+        // 4% of jokers are edited at the base rate: about 160 of 4000.
+        let edited = edited_jokers(&[], 4000);
+        assert!(
+            (80..=240).contains(&edited),
+            "about 4% edited, was {edited}"
+        );
+    }
+
+    #[test]
+    fn draw_shop_joker__hone_and_glow_up_raise_the_rate() {
+        let base = edited_jokers(&[], 4000);
+        let hone = edited_jokers(&[Voucher::Hone], 4000);
+        let glow_up = edited_jokers(&[Voucher::Hone, Voucher::GlowUp], 4000);
+        assert!(hone > base, "Hone ({hone}) over base ({base})");
+        assert!(glow_up > hone, "Glow Up ({glow_up}) over Hone ({hone})");
+    }
+
+    #[test]
+    fn open_pack_with_rng__buffoon_jokers_can_arrive_edited() {
+        let edited = (0..400).any(|seed| {
+            let mut board = board_with_packs(vec![buffoon_pack()]);
+            board.money = 10;
+            board
+                .open_pack_with_rng(0, &mut StdRng::seed_from_u64(seed))
+                .unwrap()
+                .iter()
+                .any(|joker| joker.edition != Edition::None)
+        });
+        assert!(edited, "a Buffoon pack rolls editions like the shop");
+    }
+
+    #[test]
+    fn buy_stock__charges_for_the_edition() {
+        // The Joker is $2; Foil adds $2.
+        let mut board = board_for_a_round();
+        board.money = 10;
+        board.shop = Some(crate::funky::types::shop::Shop::with_stock(vec![
+            card::JOKER.with_edition(Edition::Foil),
+        ]));
+        assert!(board.buy_stock(0));
+        assert_eq!(board.money, 10 - 2 - 2);
+    }
+
+    /// A board holding `jokers` and a Wheel of Fortune, used through `seed`.
+    fn spin(jokers: &[BuffoonCard], seed: u64) -> BuffoonBoard {
+        let mut board = board_for_a_round();
+        for joker in jokers {
+            board.push_joker(*joker);
+        }
+        board.create_consumable(tarot_card::WHEEL_OF_FORTUNE);
+        board.use_consumable_with_rng(0, &[], &mut StdRng::seed_from_u64(seed));
+        board
+    }
+
+    #[test]
+    fn use_consumable_with_rng__wheel_of_fortune_stamps_a_joker_one_time_in_four() {
+        let hits = (0..400)
+            .filter(|seed| {
+                spin(&[card::JOKER], *seed).jokers.get(0).unwrap().edition != Edition::None
+            })
+            .count();
+        assert!((60..=140).contains(&hits), "about 1 in 4, was {hits}/400");
+        assert_eq!(
+            spin(&[card::JOKER], 0).tarots_used,
+            1,
+            "a spin counts as a Tarot used, hit or miss"
+        );
+    }
+
+    #[test]
+    fn use_consumable_with_rng__wheel_of_fortune_skips_edited_jokers() {
+        let polychrome = card::JOKER.with_edition(Edition::Polychrome);
+        for seed in 0..64 {
+            let board = spin(&[polychrome, card::JOKER], seed);
+            assert_eq!(
+                board.jokers.get(0).unwrap().edition,
+                Edition::Polychrome,
+                "an edited joker is never re-rolled (seed {seed})"
+            );
+        }
+    }
+
+    #[test]
+    fn use_consumable__wheel_of_fortune_is_inert_without_rng() {
+        let mut board = board_for_a_round();
+        board.push_joker(card::JOKER);
+        board.create_consumable(tarot_card::WHEEL_OF_FORTUNE);
+        board.use_consumable(0, &[]);
+        assert_eq!(board.jokers.get(0).unwrap().edition, Edition::None);
+        assert_eq!(board.tarots_used, 1);
+    }
+
+    // ---- The ante, pack and boss vouchers ----------------------------------
+
+    #[test]
+    fn redeem_shop_voucher__antimatter_needs_blank() {
+        let mut board = board_offering_voucher(Voucher::Antimatter);
+        board.money = 20;
+        assert!(!board.redeem_shop_voucher(), "Blank is Antimatter's base");
+        assert_eq!(board.joker_slots, BuffoonBoard::DEFAULT_JOKER_SLOTS);
+    }
+
+    #[test]
+    fn redeem_shop_voucher__blank_does_nothing_but_cost_ten() {
+        let mut board = board_offering_voucher(Voucher::Blank);
+        board.money = 20;
+        let before = (
+            board.draws,
+            board.joker_slots,
+            board.consumable_slots,
+            board.ante,
+        );
+        assert!(board.redeem_shop_voucher());
+        board.on_blind_selected();
+        assert_eq!(
+            (
+                board.draws,
+                board.joker_slots,
+                board.consumable_slots,
+                board.ante
+            ),
+            before
+        );
+        assert_eq!(board.money, 10);
+    }
+
+    #[test]
+    fn redeem_shop_voucher__hieroglyph_takes_an_ante_and_a_hand() {
+        let mut board = board_offering_voucher(Voucher::Hieroglyph);
+        board.money = 20;
+        board.ante = 3;
+        assert!(board.redeem_shop_voucher());
+        assert_eq!(board.ante, 2);
+        board.on_blind_selected();
+        assert_eq!(board.draws.hands_to_play, 3, "4 hands, −1");
+    }
+
+    #[test]
+    fn redeem_shop_voucher__petroglyph_takes_an_ante_and_a_discard() {
+        let mut board = board_offering_voucher(Voucher::Petroglyph);
+        board.money = 20;
+        board.ante = 3;
+        board.vouchers.push(Voucher::Hieroglyph);
+        assert!(board.redeem_shop_voucher());
+        assert_eq!(board.ante, 2);
+        board.on_blind_selected();
+        assert_eq!(board.draws.discards, 2, "3 discards, −1");
+        assert_eq!(board.draws.hands_to_play, 3, "and Hieroglyph's −1 hand");
+    }
+
+    #[test]
+    fn on_round_end__a_won_boss_advances_the_ante() {
+        let mut small = board_that_won_a_round(0);
+        small.on_round_end();
+        assert_eq!(small.ante, 1, "only a boss ends the ante");
+
+        let mut boss = board_that_won_a_round(0);
+        boss.blind = Blind::Boss(BossBlind::TheWater);
+        boss.boss_rerolls_this_ante = 1;
+        boss.on_round_end();
+        assert_eq!(boss.ante, 2);
+        assert_eq!(boss.boss_rerolls_this_ante, 0, "a fresh ante's reroll");
+    }
+
+    fn board_facing_the_needle(vouchers: &[Voucher]) -> BuffoonBoard {
+        let mut board = board_for_a_round();
+        board.blind = Blind::Boss(BossBlind::TheNeedle);
+        board.vouchers.extend_from_slice(vouchers);
+        board.money = 50;
+        board.on_blind_selected();
+        board
+    }
+
+    #[test]
+    fn reroll_boss_with_rng__needs_a_voucher() {
+        let mut board = board_facing_the_needle(&[]);
+        assert!(!board.reroll_boss_with_rng(&mut StdRng::seed_from_u64(1)));
+        assert_eq!(board.blind, Blind::Boss(BossBlind::TheNeedle));
+        assert_eq!(board.money, 50);
+    }
+
+    #[test]
+    fn reroll_boss_with_rng__directors_cut_rerolls_once_per_ante() {
+        let mut board = board_facing_the_needle(&[Voucher::DirectorsCut]);
+        let mut rng = StdRng::seed_from_u64(1);
+        assert!(board.reroll_boss_with_rng(&mut rng));
+        assert_ne!(board.blind, Blind::Boss(BossBlind::TheNeedle), "a new boss");
+        assert_eq!(board.money, 40, "$10 a roll");
+        assert_eq!(board.draws.hands_to_play, 4, "The Needle's grip is gone");
+        assert!(!board.reroll_boss_with_rng(&mut rng), "once per ante");
+    }
+
+    #[test]
+    fn reroll_boss_with_rng__retcon_rerolls_freely() {
+        let mut board = board_facing_the_needle(&[Voucher::DirectorsCut, Voucher::Retcon]);
+        let mut rng = StdRng::seed_from_u64(1);
+        for _ in 0..3 {
+            assert!(board.reroll_boss_with_rng(&mut rng));
+        }
+        assert_eq!(board.money, 20);
+    }
+
+    #[test]
+    fn reroll_boss_with_rng__refuses_a_small_blind() {
+        let mut board = board_for_a_round();
+        board.vouchers.push(Voucher::Retcon);
+        board.money = 50;
+        assert!(!board.reroll_boss_with_rng(&mut StdRng::seed_from_u64(1)));
+    }
+
+    #[test]
+    fn try_reroll_upcoming_boss_with_rng__leaves_the_blind_alone() {
+        let mut board = board_for_a_round();
+        board.vouchers.push(Voucher::DirectorsCut);
+        board.money = 50;
+        let mut rng = StdRng::seed_from_u64(1);
+
+        let boss = board
+            .try_reroll_upcoming_boss_with_rng(BossBlind::TheNeedle, &mut rng)
+            .unwrap();
+
+        assert_ne!(boss, BossBlind::TheNeedle);
+        assert_eq!(board.blind, Blind::Small);
+        assert_eq!(board.money, 40);
+        assert_eq!(
+            board.try_reroll_upcoming_boss_with_rng(boss, &mut rng),
+            Err(ActionError::NoBossReroll)
+        );
+    }
+
+    #[test]
+    fn deserialize__a_board_saved_before_antes_starts_at_ante_1() {
+        let mut json = serde_json::to_value(BuffoonBoard::default()).unwrap();
+        let fields = json.as_object_mut().unwrap();
+        fields.remove("ante");
+        fields.remove("boss_rerolls_this_ante");
+
+        let board: BuffoonBoard = serde_json::from_value(json).unwrap();
+
+        assert_eq!(board.ante, 1);
+        assert_eq!(board.boss_rerolls_this_ante, 0);
+    }
+
+    #[test]
+    fn deserialize__a_board_saved_before_stakes_is_white_and_unstickered() {
+        let mut board = BuffoonBoard::default();
+        board.push_joker(card::JOKER);
+        let mut json = serde_json::to_value(&board).unwrap();
+        let fields = json.as_object_mut().unwrap();
+        fields.remove("stake");
+        for joker in fields["jokers"].as_array_mut().unwrap() {
+            joker.as_object_mut().unwrap().remove("stickers");
+        }
+
+        let read: BuffoonBoard = serde_json::from_value(json).unwrap();
+
+        assert_eq!(read.stake, Stake::White);
+        assert_eq!(read.jokers.get(0).unwrap().stickers, Stickers::NONE);
+        assert_eq!(read, board);
+    }
+
+    #[test]
+    fn most_played_hand__prefers_the_count_then_the_higher_hand() {
+        let mut board = board_for_a_round();
+        assert_eq!(board.most_played_hand(), None, "nothing played yet");
+        board.poker_hands.play_hand(&HandType::Pair);
+        board.poker_hands.play_hand(&HandType::Flush);
+        assert_eq!(board.most_played_hand(), Some(HandType::Flush), "a tie");
+        board.poker_hands.play_hand(&HandType::Pair);
+        assert_eq!(board.most_played_hand(), Some(HandType::Pair));
+    }
+
+    #[test]
+    fn open_pack_with_rng__telescope_offers_the_most_played_planet() {
+        for seed in 0..32 {
+            let mut board = board_with_packs(vec![BoosterPack::new(
+                PackKind::Celestial,
+                PackSize::Normal,
+            )]);
+            board.money = 10;
+            board.vouchers.push(Voucher::Telescope);
+            board.poker_hands.play_hand(&HandType::Flush);
+            let choices = board
+                .open_pack_with_rng(0, &mut StdRng::seed_from_u64(seed))
+                .unwrap();
+            assert_eq!(choices[0], planet_card::JUPITER, "seed {seed}");
+            assert_eq!(choices.len(), 3);
+        }
+    }
+
+    #[test]
+    fn score__observatory_multiplies_for_a_held_planet_of_the_hand() {
+        let mut board = board_playing("AS AD");
+        let plain = board.score();
+        board.vouchers.push(Voucher::Observatory);
+        board.create_consumable(planet_card::MERCURY); // Pair
+        board.create_consumable(planet_card::JUPITER); // Flush — not this hand
+        assert_eq!(board.score(), plain.multi_mult(1.5));
+
+        let (_, trace) = board.score_traced();
+        assert_eq!(
+            trace.steps.last().unwrap().source,
+            Some(planet_card::MERCURY)
+        );
+    }
+
+    #[test]
+    fn score__a_held_planet_is_inert_without_observatory() {
+        let mut board = board_playing("AS AD");
+        let plain = board.score();
+        board.create_consumable(planet_card::MERCURY);
+        assert_eq!(board.score(), plain);
+    }
+
+    #[test]
+    fn open_shop_with_rng__magic_trick_stocks_playing_cards() {
+        let playing = |vouchers: &[Voucher]| {
+            let mut board = board_for_a_round();
+            board.vouchers.extend_from_slice(vouchers);
+            let mut rng = StdRng::seed_from_u64(3);
+            (0..2000)
+                .filter_map(|_| board.draw_stock_card(&mut rng))
+                .filter(|card| card.card_type == BCardType::Basic)
+                .collect::<Vec<_>>()
+        };
+        assert!(playing(&[]).is_empty(), "no playing cards without it");
+        let magic = playing(&[Voucher::MagicTrick]);
+        assert!(!magic.is_empty());
+        assert!(
+            magic
+                .iter()
+                .all(|card| card.enhancement == MPip::Blank && card.edition == Edition::None),
+            "plain without Illusion"
+        );
+        let illusion = playing(&[Voucher::MagicTrick, Voucher::Illusion]);
+        assert!(illusion.iter().any(|card| card.enhancement != MPip::Blank));
+        assert!(illusion.iter().any(|card| card.edition != Edition::None));
+    }
+
+    #[test]
+    fn buy_stock__a_playing_card_joins_the_deck_for_a_dollar() {
+        let mut board = board_for_a_round();
+        board.money = 5;
+        board.push_joker(card::HOLOGRAM);
+        board.shop = Some(crate::funky::types::shop::Shop::with_stock(vec![
+            basic::ACE_SPADES,
+        ]));
+        let roster = board.full_deck.len();
+        assert!(board.buy_stock(0));
+        assert_eq!(board.money, 4);
+        assert_eq!(board.full_deck.len(), roster + 1);
+        assert!(board.consumables.is_empty(), "not a consumable");
+        assert_eq!(board.joker_state[0], 1, "Hologram saw the card added");
+    }
+
+    fn board_with_jokers(jokers: &[BuffoonCard]) -> BuffoonBoard {
+        let mut board = board_playing("2S 5D 8C TS KH");
+        for joker in jokers {
+            board.push_joker(*joker);
+        }
+        board
+    }
+
+    #[test]
+    fn score__blueprint_copies_the_joker_to_its_right() {
+        let running = Score::new(10, 1);
+        let copied = board_with_jokers(&[card::BLUEPRINT, card::JOKER]);
+        assert_eq!(
+            copied.scoring_phase4_joker_scoring(running),
+            Score::new(10, 9)
+        );
+
+        let rightmost = board_with_jokers(&[card::JOKER, card::BLUEPRINT]);
+        assert_eq!(
+            rightmost.scoring_phase4_joker_scoring(running),
+            Score::new(10, 5),
+            "nothing to its right"
+        );
+    }
+
+    #[test]
+    fn score__brainstorm_copies_the_leftmost_joker() {
+        let running = Score::new(10, 1);
+        let copied = board_with_jokers(&[card::JOKER, card::JOLLY_JOKER, card::BRAINSTORM]);
+        assert_eq!(
+            copied.scoring_phase4_joker_scoring(running),
+            Score::new(10, 9)
+        );
+
+        let leftmost = board_with_jokers(&[card::BRAINSTORM, card::JOKER]);
+        assert_eq!(
+            leftmost.scoring_phase4_joker_scoring(running),
+            Score::new(10, 5),
+            "it is the leftmost joker"
+        );
+    }
+
+    #[test]
+    fn score__a_chain_of_copies_resolves_to_its_end() {
+        let running = Score::new(10, 1);
+        let blueprints = board_with_jokers(&[card::BLUEPRINT, card::BLUEPRINT, card::JOKER]);
+        assert_eq!(
+            blueprints.scoring_phase4_joker_scoring(running),
+            Score::new(10, 13),
+            "both Blueprints land on the Joker"
+        );
+
+        // Brainstorm copies the leftmost Blueprint, which copies the Joker.
+        let mixed = board_with_jokers(&[card::BLUEPRINT, card::JOKER, card::BRAINSTORM]);
+        assert_eq!(
+            mixed.scoring_phase4_joker_scoring(running),
+            Score::new(10, 13)
+        );
+    }
+
+    #[test]
+    fn score__a_loop_of_copies_copies_nothing() {
+        // Blueprint → Brainstorm → leftmost, which is the Blueprint again.
+        let running = Score::new(10, 1);
+        let looped = board_with_jokers(&[card::BLUEPRINT, card::BRAINSTORM, card::JOKER]);
+        assert_eq!(
+            looped.scoring_phase4_joker_scoring(running),
+            Score::new(10, 5),
+            "only the Joker scores"
+        );
+    }
+
+    #[test]
+    fn score__a_copy_reads_its_targets_counter_and_edition_stays_its_own() {
+        let running = Score::new(10, 1);
+        let mut board = board_with_jokers(&[
+            card::BLUEPRINT.with_edition(Edition::Foil),
+            card::GREEN_JOKER,
+        ]);
+        board.joker_state[1] = 3; // Green Joker at +3 mult
+
+        // Green's +3 twice, and the Blueprint's own Foil.
+        assert_eq!(
+            board.scoring_phase4_joker_scoring(running),
+            Score::new(60, 7)
+        );
+    }
+
+    #[test]
+    fn score_traced__a_copy_is_the_source_of_its_step() {
+        let board = board_with_jokers(&[card::BLUEPRINT, card::JOKER]);
+        let (_, trace) = board.score_traced();
+        let sources: Vec<_> = trace
+            .steps_in(ScoringPhase::Jokers)
+            .filter_map(|step| step.source)
+            .collect();
+        assert_eq!(sources, vec![card::BLUEPRINT, card::JOKER]);
+    }
+
+    #[test]
+    fn score__blueprint_copies_a_retrigger() {
+        // Hanging Chad retriggers the first card twice; copied, twice more.
+        let board = board_with_jokers(&[card::BLUEPRINT, card::HANGING_CHAD]);
+        let (_, trace) = board.score_traced();
+        assert_eq!(trace.triggers_of(0), 5);
+    }
+
+    #[test]
+    fn on_round_end__blueprint_copies_a_payout() {
+        let paid = |jokers: &[BuffoonCard]| {
+            let mut board = board_with_jokers(jokers);
+            board.on_round_end();
+            board.money
+        };
+        assert_eq!(
+            paid(&[card::BLUEPRINT, card::GOLDEN_JOKER]),
+            paid(&[card::GOLDEN_JOKER]) + 4
+        );
+    }
+
+    #[test]
+    fn move_joker__reorders_and_carries_the_counter() {
+        let mut board = board_with_jokers(&[card::JOKER, card::GREEN_JOKER, card::BLUEPRINT]);
+        board.joker_state[1] = 3;
+
+        assert!(board.move_joker(1, 0));
+        assert_eq!(
+            board.jokers.iter().copied().collect::<Vec<_>>(),
+            vec![card::GREEN_JOKER, card::JOKER, card::BLUEPRINT]
+        );
+        assert_eq!(board.joker_state, vec![3, 0, 0]);
+
+        assert!(!board.move_joker(3, 0), "out of range");
+        assert!(!board.move_joker(0, 3), "out of range");
+    }
+
+    #[test]
+    fn try_move_joker__a_pinned_joker_stays_in_its_slot() {
+        let mut dagger = card::CEREMONIAL_DAGGER;
+        dagger.stickers = Stickers::pinned();
+        let mut board = board_with_jokers(&[dagger, card::JOKER, card::BLUEPRINT]);
+
+        assert_eq!(board.try_move_joker(0, 2), Err(ActionError::Pinned(0)));
+        assert_eq!(
+            board.try_move_joker(2, 0),
+            Err(ActionError::Pinned(0)),
+            "nothing moves past it"
+        );
+        assert_eq!(board.try_move_joker(2, 1), Ok(()));
+        assert_eq!(
+            board.jokers.iter().copied().collect::<Vec<_>>(),
+            vec![dagger, card::BLUEPRINT, card::JOKER]
+        );
+    }
+
+    #[test]
+    fn move_joker__changes_what_a_copy_copies() {
+        let running = Score::new(10, 1);
+        let mut board = board_with_jokers(&[card::JOKER, card::BLUEPRINT]);
+        assert_eq!(
+            board.scoring_phase4_joker_scoring(running),
+            Score::new(10, 5)
+        );
+
+        assert!(board.move_joker(1, 0));
+        assert_eq!(
+            board.scoring_phase4_joker_scoring(running),
+            Score::new(10, 9)
+        );
+    }
+
+    #[test]
+    fn score__supernova_adds_a_mult_per_time_the_hand_was_played() {
+        let running = Score::new(10, 1);
+        let mut board = board_with_jokers(&[card::SUPERNOVA]);
+        assert_eq!(
+            board.scoring_phase4_joker_scoring(running),
+            Score::new(10, 2),
+            "the first High Card counts itself"
+        );
+
+        let played = board.played.clone();
+        board.on_hand_played(&played);
+        board.on_hand_played(&played);
+        assert_eq!(
+            board
+                .poker_hands
+                .get(&HandType::HighCard)
+                .unwrap()
+                .times_played,
+            2,
+            "a played hand is recorded for the run"
+        );
+        assert_eq!(
+            board.scoring_phase4_joker_scoring(running),
+            Score::new(10, 4)
+        );
+    }
+
+    #[test]
+    fn score__loyalty_card_x4_every_sixth_hand() {
+        let running = Score::new(10, 2);
+        let mut board = board_with_jokers(&[card::LOYALTY_CARD]);
+        let played = board.played.clone();
+        let mut fired = Vec::new();
+        for _ in 0..12 {
+            fired.push(board.scoring_phase4_joker_scoring(running).mult == 8);
+            board.on_hand_played(&played);
+        }
+        let sixth: Vec<bool> = (1..=12).map(|hand| hand % 6 == 0).collect();
+        assert_eq!(fired, sixth);
+    }
+
+    #[test]
+    fn score__loyalty_card_counts_from_when_it_was_acquired() {
+        let running = Score::new(10, 2);
+        let mut board = board_with_jokers(&[]);
+        let played = board.played.clone();
+        for _ in 0..5 {
+            board.on_hand_played(&played);
+        }
+        board.push_joker(card::LOYALTY_CARD);
+        assert_eq!(
+            board.scoring_phase4_joker_scoring(running),
+            running,
+            "its first hand, not the board's sixth"
+        );
+    }
+
+    #[test]
+    fn score_big__is_score_for_an_ordinary_hand() {
+        let board = board_with_jokers(&[card::JOKER, card::HANGING_CHAD]);
+        assert_eq!(board.score_big(), BigScore::from(board.score()));
+    }
+
+    #[test]
+    fn score_big__scores_custom_effects_through_a_registry() {
+        let mut registry = EffectRegistry::new();
+        registry.register(FLUSH_DOUBLER, FlushDoubler).unwrap();
+        let mut board = board_playing("AS KS QS JS 9S");
+        board
+            .jokers
+            .push(enhanced(card::JOKER, MPip::Custom(FLUSH_DOUBLER)));
+
+        assert_eq!(
+            board.score_big_with_registry(&registry),
+            BigScore::from(Score::new(85, 8))
+        );
+        assert_eq!(board.score_big(), BigScore::from(Score::new(85, 4)));
+        assert_eq!(
+            board.score_big_with_rng_and_registry(&mut StdRng::seed_from_u64(1), &registry),
+            board.score_big_with_registry(&registry)
+        );
+    }
+
+    #[test]
+    fn score_big__neither_overflows_nor_rounds() {
+        let x1000 = enhanced(card::JOKER, MPip::MultTimes(1000));
+        let board = board_with_jokers(&[x1000; 8]);
+        assert_eq!(board.score().mult, usize::MAX, "Score pins");
+        let big = board.score_big();
+        assert_eq!(big.mult, Magnitude::new(1, 24));
+        assert_eq!(big.score().to_string(), "4e25");
+
+        // Five Polychromes on a 1-mult High Card: 1.5^5, not rounded up per step.
+        let polychrome = enhanced(card::JOKER, MPip::Blank).with_edition(Edition::Polychrome);
+        let board = board_with_jokers(&[polychrome; 5]);
+        assert_eq!(board.score_big().mult, Magnitude::new(759_375, -5));
+    }
+
+    // ---- Stakes and stickers -----------------------------------------------
+
+    use crate::funky::types::stake::Stake;
+    use crate::funky::types::sticker::Stickers;
+
+    fn with_stickers(joker: BuffoonCard, stickers: Stickers) -> BuffoonCard {
+        BuffoonCard { stickers, ..joker }
+    }
+
+    #[test]
+    fn stake__white_is_inert() {
+        let mut board = board_for_a_round();
+        board.on_blind_selected();
+        assert_eq!(board.draws.discards, 3);
+
+        let mut board = board_that_won_a_round(0);
+        board.on_round_end();
+        assert_eq!(board.money, 3);
+    }
+
+    #[test]
+    fn cash_out__red_stake_small_blind_pays_no_reward() {
+        for (blind, reward) in [
+            (Blind::Small, 0),
+            (Blind::Big, 4),
+            (Blind::Boss(BossBlind::TheNeedle), 5),
+        ] {
+            let mut board = board_that_won_a_round(0);
+            board.stake = Stake::Red;
+            board.blind = blind;
+            board.on_round_end();
+            assert_eq!(board.money, reward, "{blind} pays ${reward}");
+        }
+    }
+
+    #[test]
+    fn recompute_draws__blue_stake_takes_a_discard() {
+        let mut board = board_for_a_round();
+        board.stake = Stake::Blue;
+        board.on_blind_selected();
+
+        assert_eq!(board.draws.discards, 2);
+    }
+
+    #[test]
+    fn open_shop_with_rng__gold_stake_rolls_stickers() {
+        let mut counts = [0; 3];
+        for seed in 0..200 {
+            let mut board = board_for_a_round();
+            board.stake = Stake::Gold;
+            board.open_shop_with_rng(&mut StdRng::seed_from_u64(seed));
+            for card in &board.shop.unwrap().stock {
+                assert!(card.is_joker() || card.stickers.is_empty());
+                counts[0] += usize::from(card.stickers.eternal);
+                counts[1] += usize::from(card.stickers.perishable.is_some());
+                counts[2] += usize::from(card.stickers.rental);
+            }
+        }
+
+        assert!(counts.iter().all(|count| *count > 0), "{counts:?}");
+    }
+
+    #[test]
+    fn open_shop_with_rng__white_stake_rolls_no_stickers() {
+        for seed in 0..50 {
+            let mut board = board_for_a_round();
+            board.open_shop_with_rng(&mut StdRng::seed_from_u64(seed));
+            let shop = board.shop.unwrap();
+            assert!(shop.stock.iter().all(|card| card.stickers.is_empty()));
+        }
+    }
+
+    #[test]
+    fn buy_stock__a_rental_joker_costs_one_dollar() {
+        let rental =
+            with_stickers(card::JOKER, Stickers::rental()).with_edition(Edition::Polychrome);
+        let mut board = board_for_a_round();
+        board.shop = Some(crate::funky::types::shop::Shop::with_stock(vec![rental]));
+        board.money = 1;
+
+        assert_eq!(board.stock_cost(0), Some(1));
+        assert!(board.buy_stock(0));
+        assert_eq!(board.money, 0);
+    }
+
+    #[test]
+    fn sell_joker__refuses_an_eternal_joker() {
+        let mut board = board_for_a_round();
+        board.push_joker(with_stickers(card::JOKER, Stickers::eternal()));
+
+        assert!(board.sell_joker(0).is_none());
+        assert_eq!(board.jokers.len(), 1);
+        assert_eq!(board.money, 0);
+    }
+
+    #[test]
+    fn try_sell_joker__says_eternal_or_missing() {
+        let mut board = board_for_a_round();
+        board.push_joker(with_stickers(card::JOKER, Stickers::eternal()));
+
+        assert_eq!(board.try_sell_joker(0), Err(ActionError::Eternal(0)));
+        assert_eq!(board.try_sell_joker(3), Err(ActionError::NoSuchJoker(3)));
+    }
+
+    #[test]
+    fn on_round_end__a_rental_joker_charges_three_dollars() {
+        let mut board = board_for_a_round();
+        board.push_joker(with_stickers(card::JOKER, Stickers::rental()));
+        board.push_joker(with_stickers(card::JOKER, Stickers::rental()));
+        board.on_round_end();
+
+        assert_eq!(board.money, -6, "rent is charged into debt");
+    }
+
+    #[test]
+    fn on_round_end__a_perishable_joker_is_debuffed_after_five_rounds() {
+        let mut board = board_playing_joker(
+            "2S 5D 8C TS KH",
+            with_stickers(card::JOKER, Stickers::perishable()),
+        );
+        let scored = board.score();
+        for round in 1..Stickers::PERISHABLE_ROUNDS {
+            board.on_round_end();
+            assert!(!board.jokers.get(0).unwrap().debuffed, "round {round}");
+        }
+        assert_eq!(board.score(), scored);
+
+        board.on_round_end();
+        let joker = board.jokers.get(0).copied().unwrap();
+        assert!(joker.debuffed);
+        assert!(joker.stickers.has_perished());
+        assert_eq!(board.score().mult, scored.mult - 4, "+4 mult is gone");
+    }
+
+    #[test]
+    fn score__a_debuffed_joker_is_not_copied() {
+        let mut debuffed = card::JOKER;
+        debuffed.debuffed = true;
+        let mut board = board_playing_joker("2S 5D 8C TS KH", card::BLUEPRINT);
+        board.push_joker(debuffed);
+
+        assert_eq!(board.score(), board_playing("2S 5D 8C TS KH").score());
+    }
+
+    #[test]
+    fn score__a_debuffed_suit_makes_the_hand_but_scores_nothing() {
+        let mut board = board_playing_joker("AS AH", card::LUSTY_JOKER);
+        let full = board.score();
+        board.challenge_rules.debuffed_suits = vec!['H'];
+
+        let debuffed = board.score();
+
+        assert_eq!(board.scoring_hand_type(), HandType::Pair);
+        assert_eq!(debuffed.chips, full.chips - 11, "the Ace of Hearts' chips");
+        assert_eq!(debuffed.mult, full.mult - 3, "Lusty Joker's +3");
+    }
+
+    #[test]
+    fn remove_joker__refuses_an_eternal_joker() {
+        let eternal = with_stickers(card::JOKER, Stickers::eternal());
+        let mut board = board_for_a_round();
+        board.push_joker(eternal);
+        board.push_joker(card::BANNER);
+
+        assert_eq!(board.remove_joker(0), None);
+        assert_eq!(board.remove_joker(2), None, "out of bounds");
+        assert_eq!(board.remove_joker(1), Some(card::BANNER));
+        assert_eq!(board.jokers.len(), 1);
+        assert_eq!(board.joker_state.len(), 1);
+    }
+
+    #[test]
+    fn on_blind_selected_with_rng__madness_never_destroys_an_eternal_joker() {
+        for seed in 0..20 {
+            let mut board = board_playing("2S 5D 8C TS KH");
+            board.push_joker(card::MADNESS);
+            board.push_joker(with_stickers(card::JOKER, Stickers::eternal()));
+            board.push_joker(card::BANNER);
+            board.blind = Blind::Small;
+
+            board.on_blind_selected_with_rng(&mut StdRng::seed_from_u64(seed));
+
+            assert_eq!(board.jokers.len(), 2, "seed {seed}");
+            assert!(board.jokers.iter().all(|joker| *joker != card::BANNER));
+        }
+    }
+
+    #[test]
+    fn on_blind_selected_with_rng__madness_among_eternals_destroys_nothing() {
+        let mut board = board_playing("2S 5D 8C TS KH");
+        board.push_joker(card::MADNESS);
+        board.push_joker(with_stickers(card::JOKER, Stickers::eternal()));
+        board.blind = Blind::Small;
+
+        board.on_blind_selected_with_rng(&mut StdRng::seed_from_u64(5));
+
+        assert_eq!(board.jokers.len(), 2);
+        assert_eq!(board.joker_state[0], 1, "it still gained");
+    }
+
+    #[test]
+    fn on_blind_selected__ceremonial_dagger_eats_the_joker_to_its_right() {
+        let mut board = board_playing("2S 5D 8C TS KH");
+        let base = board.score();
+        board.push_joker(card::CEREMONIAL_DAGGER);
+        board.push_joker(card::GROS_MICHEL);
+        board.push_joker(card::BANNER);
+
+        board.on_blind_selected();
+
+        assert_eq!(board.jokers.len(), 2);
+        assert_eq!(board.jokers.get(1), Some(&card::BANNER));
+        assert_eq!(board.joker_state, vec![4, 0], "double Gros Michel's $2");
+
+        board.remove_joker(1);
+        assert_eq!(board.score().mult, base.mult + 4);
+    }
+
+    #[test]
+    fn on_blind_selected__ceremonial_dagger_cannot_eat_an_eternal_joker() {
+        let mut board = board_playing("2S 5D 8C TS KH");
+        board.push_joker(card::CEREMONIAL_DAGGER);
+        board.push_joker(with_stickers(card::GROS_MICHEL, Stickers::eternal()));
+
+        board.on_blind_selected();
+
+        assert_eq!(board.jokers.len(), 2);
+        assert_eq!(board.joker_state, vec![0, 0]);
+    }
+
+    #[test]
+    fn on_blind_selected__ceremonial_dagger_with_nothing_to_its_right() {
+        let mut board = board_playing("2S 5D 8C TS KH");
+        board.push_joker(card::CEREMONIAL_DAGGER);
+
+        board.on_blind_selected();
+
+        assert_eq!(board.jokers.len(), 1);
+        assert_eq!(board.joker_state, vec![0]);
+    }
+
+    #[test]
+    fn on_round_end_with_rng__an_eternal_joker_survives_its_destruction_roll() {
+        let doomed = BuffoonCard {
+            enhancement: MPip::ChanceDestroyed(1, 1),
+            ..card::GROS_MICHEL
+        };
+        let mut board = board_for_a_round();
+        board.push_joker(with_stickers(doomed, Stickers::eternal()));
+        board.push_joker(doomed);
+
+        board.on_round_end_with_rng(&mut StdRng::seed_from_u64(3));
+
+        assert_eq!(board.jokers.len(), 1);
+        assert!(board.jokers.get(0).unwrap().stickers.eternal);
+    }
+
+    #[test]
+    fn use_consumable_with_rng__hex_and_ankh_spare_eternal_jokers() {
+        for spectral in [spectral_card::HEX, spectral_card::ANKH] {
+            for seed in 0..10 {
+                let mut board = board_for_a_round();
+                board.push_joker(card::BANNER);
+                board.push_joker(with_stickers(card::JOKER, Stickers::eternal()));
+                board.push_joker(card::GROS_MICHEL);
+                use_spectral(&mut board, spectral, &[], seed);
+
+                assert!(
+                    board.jokers.iter().any(|joker| joker.stickers.eternal),
+                    "{spectral} seed {seed}"
+                );
+            }
+        }
+    }
+
+    // ---- Custom jokers -----------------------------------------------------
+
+    use crate::funky::types::custom_joker::CustomJoker;
+
+    fn custom_joker(symbol: char, rarity: BCardType, edition: Edition) -> BuffoonCard {
+        CustomJoker {
+            name: format!("Custom {symbol}"),
+            symbol,
+            rarity,
+            cost: 6,
+            effect: MPip::MultPlus(8),
+            edition,
+        }
+        .card()
+        .unwrap()
+    }
+
+    #[test]
+    fn add_custom_jokers__joins_the_pool_of_its_rarity() {
+        let mut board = board_for_a_round();
+        let mime = custom_joker('🤹', BCardType::CommonJoker, Edition::None);
+
+        assert_eq!(board.add_custom_jokers([mime]), Ok(()));
+
+        assert_eq!(board.custom_jokers.len(), 1);
+        assert!(
+            board
+                .joker_pool(BCardType::CommonJoker)
+                .unwrap()
+                .contains(&mime)
+        );
+        assert!(
+            !board
+                .joker_pool(BCardType::RareJoker)
+                .unwrap()
+                .contains(&mime)
+        );
+    }
+
+    #[test]
+    fn add_custom_jokers__refuses_a_taken_symbol() {
+        let mut board = board_for_a_round();
+        let mime = custom_joker('🤹', BCardType::CommonJoker, Edition::None);
+        let rare_mime = custom_joker('🤹', BCardType::RareJoker, Edition::Foil);
+        let built_in = BuffoonCard {
+            card_type: BCardType::RareJoker,
+            ..card::JOKER
+        };
+        let refused = |symbol: char| Err(CardError::InvalidCard(symbol.to_string()));
+
+        assert_eq!(
+            board.add_custom_jokers([built_in]),
+            refused(card::JOKER.rank.index),
+            "a built-in's symbol, in another rarity"
+        );
+        assert_eq!(board.add_custom_jokers([mime, rare_mime]), refused('🤹'));
+        assert!(board.custom_jokers.is_empty(), "all or nothing");
+        board.add_custom_jokers([mime]).unwrap();
+        assert_eq!(
+            board.add_custom_jokers([rare_mime]),
+            refused('🤹'),
+            "already on the board"
+        );
+        assert_eq!(board.custom_jokers.len(), 1);
+    }
+
+    #[test]
+    fn add_custom_jokers__refuses_a_card_that_is_not_a_pool_joker() {
+        let mut board = board_for_a_round();
+
+        assert_eq!(
+            board.add_custom_jokers([tarot_card::FOOL]),
+            Err(CardError::InvalidCard(
+                tarot_card::FOOL.rank.index.to_string()
+            ))
+        );
+        assert!(board.custom_jokers.is_empty());
+    }
+
+    #[test]
+    fn joker_pool__leaves_out_banned_jokers() {
+        let mut board = board_for_a_round();
+        board.challenge_rules.banned_jokers = vec![card::JOKER.rank.index];
+
+        let pool = board.joker_pool(BCardType::CommonJoker).unwrap();
+
+        assert!(!pool.contains(&card::JOKER));
+        assert_eq!(pool.len(), Joker::COMMON_JOKERS_SIZE - 1);
+    }
+
+    #[test]
+    fn draw_shop_joker__skips_a_rarity_with_every_joker_banned() {
+        let mut board = board_for_a_round();
+        board.challenge_rules.banned_jokers = Joker::COMMON_JOKERS
+            .iter()
+            .chain(&Joker::UNCOMMON_JOKERS)
+            .chain(&Joker::RARE_JOKERS)
+            .map(|joker| joker.rank.index)
+            .collect();
+        let mut rng = StdRng::seed_from_u64(41);
+
+        assert!((0..100).all(|_| board.draw_shop_joker(&mut rng).is_none()));
+
+        board.open_shop_with_rng(&mut rng);
+        let shop = board.shop.as_ref().unwrap();
+        assert!(shop.stock.iter().all(|card| !card.is_joker()));
+    }
+
+    #[test]
+    fn draw_shop_joker__draws_custom_jokers_at_their_rarity_weight() {
+        // A Common custom joker is one of 57 Commons: about 70% / 57 of draws,
+        // roughly 49 in 4000.
+        let mut board = board_for_a_round();
+        let mime = custom_joker('🤹', BCardType::CommonJoker, Edition::None);
+        board.add_custom_jokers([mime]).unwrap();
+        let mut rng = StdRng::seed_from_u64(41);
+
+        let drawn = (0..4000)
+            .filter(|_| {
+                board
+                    .draw_shop_joker(&mut rng)
+                    .unwrap()
+                    .with_edition(Edition::None)
+                    == mime
+            })
+            .count();
+
+        assert!((20..=90).contains(&drawn), "about 49, was {drawn}");
+    }
+
+    #[test]
+    fn draw_shop_joker__a_custom_edition_survives_the_roll() {
+        let mut board = board_for_a_round();
+        board
+            .add_custom_jokers([custom_joker('🎈', BCardType::CommonJoker, Edition::Foil)])
+            .unwrap();
+        let mut rng = StdRng::seed_from_u64(41);
+
+        let ringmaster = (0..4000)
+            .filter_map(|_| board.draw_shop_joker(&mut rng))
+            .find(|joker| joker.rank.index == '🎈')
+            .expect("drawn at least once");
+
+        assert_eq!(ringmaster.edition, Edition::Foil);
+    }
+
+    #[test]
+    fn draw_shop_joker__custom_jokers_do_not_move_the_stream_without_them() {
+        let board = board_for_a_round();
+        let mut with_rare = board_for_a_round();
+        with_rare
+            .add_custom_jokers([custom_joker('🎈', BCardType::LegendaryJoker, Edition::None)])
+            .unwrap();
+        let mut a = StdRng::seed_from_u64(3);
+        let mut b = StdRng::seed_from_u64(3);
+
+        for _ in 0..200 {
+            assert_eq!(
+                board.draw_shop_joker(&mut a),
+                with_rare.draw_shop_joker(&mut b)
+            );
+        }
+    }
+
+    #[test]
+    fn use_consumable_with_rng__the_soul_can_create_a_custom_legendary() {
+        let legend = custom_joker('🦄', BCardType::LegendaryJoker, Edition::None);
+        let created = (0..200).any(|seed| {
+            let mut board = board_for_a_round();
+            board.add_custom_jokers([legend]).unwrap();
+            use_spectral(&mut board, spectral_card::THE_SOUL, &[], seed);
+            board.jokers.get(0) == Some(&legend)
+        });
+
+        assert!(created, "one Legendary in six");
+    }
 }
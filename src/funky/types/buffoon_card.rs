@@ -1,6 +1,7 @@
 use crate::funky::decks::{basic, tarot};
 use crate::funky::types::edition::Edition;
 use crate::funky::types::mpip::MPip;
+use crate::funky::types::sticker::Stickers;
 use crate::prelude::{BasicCard, CardError, FrenchSuit, Pip, PipType};
 use crate::preludes::funky::Score;
 use serde::{Deserialize, Serialize};
@@ -86,6 +87,11 @@ pub struct BuffoonCard {
     pub edition: Edition,
     pub resell_value: usize,
     pub debuffed: bool,
+    /// Eternal, Perishable and Rental — joker-only, and empty on everything a
+    /// shop at White Stake sells. A card saved before stickers existed reads
+    /// back with [`Stickers::NONE`].
+    #[serde(default)]
+    pub stickers: Stickers,
 }
 
 impl BuffoonCard {
@@ -131,8 +137,13 @@ impl BuffoonCard {
         }
     }
 
+    /// What `enhancer`'s effect earns this card. A debuffed card earns
+    /// nothing, here and in the two halves below.
     #[must_use]
     pub fn calculate_plus(&self, enhancer: &Self) -> Score {
+        if self.debuffed {
+            return Score::default();
+        }
         match enhancer.enhancement {
             MPip::MultPlusChipsOnRank(mult, chips, rank_char) => {
                 if self.rank.index == rank_char {
@@ -150,6 +161,9 @@ impl BuffoonCard {
 
     #[must_use]
     pub fn calculate_plus_chips(&self, enhancer: &Self) -> usize {
+        if self.debuffed {
+            return 0;
+        }
         match enhancer.enhancement {
             MPip::ChipsPlusOn5Ranks(value, ranks) if ranks.contains(&self.rank.index) => value,
             _ => 0,
@@ -158,6 +172,9 @@ impl BuffoonCard {
 
     #[must_use]
     pub fn calculate_plus_mult(&self, enhancer: &Self) -> usize {
+        if self.debuffed {
+            return 0;
+        }
         match enhancer.enhancement {
             MPip::MultPlus(value) => value,
             MPip::MultPlusOnSuit(value, suit) if self.suit.index == suit => value,
@@ -448,6 +465,15 @@ mod funky__types__buffoon_card_tests {
             0
         );
     }
+    #[test]
+    fn calculate_plus__debuffed_earns_nothing() {
+        let mut card = bcard!(JD);
+        card.debuffed = true;
+
+        assert_eq!(card.calculate_plus_mult(&bcard!(GREEDY)), 0);
+        assert_eq!(card.calculate_plus(&bcard!(GREEDY)), Score::default());
+    }
+
     #[test]
     fn calculate_plus_mult__lusty_joker() {
         assert_eq!(bcard!(JH).calculate_plus_mult(&joker::card::LUSTY_JOKER), 3);
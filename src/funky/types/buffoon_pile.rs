@@ -2,6 +2,7 @@ use crate::funky::types::buffoon_card::BuffoonCard;
 use crate::funky::types::hands::HandType;
 use crate::prelude::{BasicPile, CardError, FrenchRank, Pip, Ranged};
 use crate::preludes::funky::{MPip, Score};
+use itertools::Itertools;
 use rand::prelude::SliceRandom;
 use rand::rngs::StdRng;
 use rand::{Rng, SeedableRng, rng};
@@ -497,6 +498,27 @@ impl BuffoonPile {
         self.has_x_of_a_kind(3)
     }
 
+    /// Every way to pick `k` cards from the pile, as **positions** into it, in
+    /// lexicographic order.
+    ///
+    /// [`Ranged::combos`] for a board: the same enumeration, but it hands back
+    /// where the cards sit rather than copies of them, and it keeps duplicates.
+    /// A play is made by position (`play_hand(&[0, 3])`), and two `K♠`s that
+    /// differ only in their enhancement or seal are different plays — the
+    /// `BasicPile` dedupe `combos` does would silently merge them.
+    ///
+    /// ```
+    /// use cardpack::preludes::funky::*;
+    ///
+    /// let pile = bcards!("AS KS QS");
+    /// assert_eq!(pile.index_combos(2), vec![vec![0, 1], vec![0, 2], vec![1, 2]]);
+    /// assert!(pile.index_combos(4).is_empty());
+    /// ```
+    #[must_use]
+    pub fn index_combos(&self, k: usize) -> Vec<Vec<usize>> {
+        (0..self.len()).combinations(k).collect()
+    }
+
     /// Inserts `card` at `position`, shifting everything at or after it right.
     ///
     /// # Panics
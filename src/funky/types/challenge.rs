@@ -0,0 +1,617 @@
+//! Runs started from a set position and played under rules of their own.
+//!
+//! A [`Challenge`] is a [`StarterDeck`] written as data: the deck it builds
+//! on, the cards that deck holds, the jokers already on the board, the
+//! [`Draws`], the money and the joker slots — plus [`ChallengeRules`] that
+//! hold for the whole run. The rules go onto the board as
+//! [`challenge_rules`](BuffoonBoard::challenge_rules) and are read live, the
+//! way [`stake`](BuffoonBoard::stake) is.
+//!
+//! [`Challenge::balatro`] ships a handful of Balatro's own. A player's
+//! challenge is the same type, shared as YAML (`to_yaml` and `from_yaml`,
+//! under `yaml`), and checked as it is loaded — see [`Challenge::validate`].
+
+use crate::common::errors::CardError;
+use crate::funky::decks::basic::Deck;
+use crate::funky::decks::joker::{Joker, card};
+use crate::funky::types::board::BuffoonBoard;
+use crate::funky::types::buffoon_card::BuffoonCard;
+use crate::funky::types::buffoon_pile::BuffoonPile;
+use crate::funky::types::draws::Draws;
+use crate::funky::types::starter_deck::StarterDeck;
+use crate::funky::types::voucher::Voucher;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "yaml")]
+use std::error::Error;
+use std::str::FromStr;
+
+/// A joker a challenge starts on the board, by the symbol of a built-in joker.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct StartingJoker {
+    pub symbol: char,
+    /// Whether it carries the Eternal sticker — can't be sold or destroyed.
+    #[serde(default)]
+    pub eternal: bool,
+    /// Whether it is Pinned — stays in its slot, with no joker moved past it.
+    #[serde(default)]
+    pub pinned: bool,
+}
+
+impl StartingJoker {
+    #[must_use]
+    pub const fn new(joker: BuffoonCard) -> Self {
+        Self {
+            symbol: joker.rank.index,
+            eternal: false,
+            pinned: false,
+        }
+    }
+
+    #[must_use]
+    pub const fn eternal(joker: BuffoonCard) -> Self {
+        Self {
+            eternal: true,
+            ..Self::new(joker)
+        }
+    }
+}
+
+/// The part of a [`Challenge`] that outlasts run start. Every field may be
+/// left out of a file, and the default bans nothing and changes nothing.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ChallengeRules {
+    /// Jokers, by symbol, that the shop, the packs and the joker creators
+    /// never draw.
+    #[serde(default)]
+    pub banned_jokers: Vec<char>,
+    /// Vouchers the shop never offers.
+    #[serde(default)]
+    pub banned_vouchers: Vec<Voucher>,
+    /// Tags, by name. **Not enforced**: tags are not modelled (see
+    /// [`StarterDeck::Anaglyph`]), so there is nothing to ban them from. Kept
+    /// so a challenge file says what it meant.
+    #[serde(default)]
+    pub banned_tags: Vec<String>,
+    /// Each won blind goes straight to the next, with no shop between.
+    #[serde(default)]
+    pub no_shop: bool,
+    /// Suits, by index (`'S'`, `'H'`, `'D'`, `'C'`), whose cards are debuffed:
+    /// they still make the hand, but score nothing. A Stone card has no suit,
+    /// and is spared.
+    #[serde(default)]
+    pub debuffed_suits: Vec<char>,
+}
+
+impl ChallengeRules {
+    /// The suit indexes [`debuffed_suits`](Self::debuffed_suits) may name.
+    pub const SUITS: [char; 4] = ['S', 'H', 'D', 'C'];
+
+    #[must_use]
+    pub fn bans_joker(&self, joker: &BuffoonCard) -> bool {
+        self.banned_jokers.contains(&joker.rank.index)
+    }
+
+    #[must_use]
+    pub fn debuffs(&self, card: &BuffoonCard) -> bool {
+        !card.is_stone() && self.debuffed_suits.contains(&card.suit.index)
+    }
+}
+
+/// A run-start position and the rules it is played under.
+///
+/// Only `name` is required in a file: everything left out keeps what the
+/// deck gives, and a challenge without a `deck` builds on Balatro's plain
+/// challenge deck — the standard 52 at [`StarterDeck::BASE_DRAWS`].
+///
+/// ```
+/// use cardpack::funky::types::challenge::{Challenge, ChallengeRules};
+/// use cardpack::preludes::funky::*;
+///
+/// let challenge = Challenge {
+///     cards: Some("AS KS QS JS TS 9S 8S 7S AH KH QH JH TH 9H 8H 7H".to_string()),
+///     draws: Some(Draws::new(3, 3)),
+///     rules: ChallengeRules {
+///         no_shop: true,
+///         debuffed_suits: vec!['H'],
+///         ..ChallengeRules::default()
+///     },
+///     ..Challenge::new("Short Stack".to_string())
+/// };
+///
+/// let run = Run::with_challenge(&challenge, 1).unwrap();
+/// assert_eq!(run.board().full_deck.len(), 16);
+/// assert_eq!(run.board().starting_draws, Draws::new(3, 3));
+/// assert!(run.board().is_debuffed(&bcard!(AH)));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Challenge {
+    pub name: String,
+    /// The starter deck to build on, or `None` for the plain challenge deck.
+    pub deck: Option<StarterDeck>,
+    /// The cards the deck holds, by index (`"AS KS QS"`), each one of
+    /// [`Deck::DECK`]'s and repeats allowed. `None` keeps the deck's own.
+    pub cards: Option<String>,
+    #[serde(default)]
+    pub jokers: Vec<StartingJoker>,
+    /// The round configuration, in place of the deck's.
+    pub draws: Option<Draws>,
+    /// The money the run starts with, in place of the deck's bonus and
+    /// Balatro's $4.
+    pub money: Option<isize>,
+    /// How many jokers the board has room for, in place of the deck's.
+    pub joker_slots: Option<usize>,
+    #[serde(default)]
+    pub rules: ChallengeRules,
+}
+
+impl Challenge {
+    /// A challenge that changes nothing: the plain challenge deck, under no
+    /// rules.
+    #[must_use]
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            deck: None,
+            cards: None,
+            jokers: Vec::new(),
+            draws: None,
+            money: None,
+            joker_slots: None,
+            rules: ChallengeRules::default(),
+        }
+    }
+
+    /// A handful of Balatro's challenges, as far as the crate models them.
+    ///
+    /// * **The Omelette** — five Eternal Eggs; no way to earn money faster.
+    ///   Balatro also bans Satellite, which is not modelled.
+    /// * **Golden Needle** — one hand, six discards, $10.
+    /// * **Jokerless** — no joker slots, and no voucher to add one.
+    /// * **On a Knife's Edge** — an Eternal Ceremonial Dagger, Pinned leftmost.
+    #[must_use]
+    pub fn balatro() -> Vec<Self> {
+        vec![
+            Self {
+                jokers: vec![StartingJoker::eternal(card::EGG); 5],
+                rules: ChallengeRules {
+                    banned_jokers: vec![
+                        card::TO_THE_MOON.rank.index,
+                        card::ROCKET.rank.index,
+                        card::GOLDEN_JOKER.rank.index,
+                    ],
+                    banned_vouchers: vec![Voucher::SeedMoney, Voucher::MoneyTree],
+                    banned_tags: vec!["Economy".to_string()],
+                    ..ChallengeRules::default()
+                },
+                ..Self::new("The Omelette".to_string())
+            },
+            Self {
+                draws: Some(Draws::new(1, 6)),
+                money: Some(10),
+                ..Self::new("Golden Needle".to_string())
+            },
+            Self {
+                joker_slots: Some(0),
+                rules: ChallengeRules {
+                    banned_vouchers: vec![Voucher::Blank, Voucher::Antimatter],
+                    ..ChallengeRules::default()
+                },
+                ..Self::new("Jokerless".to_string())
+            },
+            Self {
+                jokers: vec![StartingJoker {
+                    pinned: true,
+                    ..StartingJoker::eternal(card::CEREMONIAL_DAGGER)
+                }],
+                ..Self::new("On a Knife's Edge".to_string())
+            },
+        ]
+    }
+
+    /// Check the challenge holds up on its own, whatever it is played with.
+    ///
+    /// # Errors
+    ///
+    /// [`CardError::InvalidChallenge`], naming the challenge and the reason: a
+    /// starting or banned joker that is not a built-in joker, bans that leave
+    /// a rarity with no joker to draw, a debuffed suit that is not one of
+    /// [`ChallengeRules::SUITS`], draws with no hand to play or no hand size,
+    /// more starting jokers than the [`joker_slots`](Self::joker_slots) it
+    /// sets, or cards that are not all playing cards, or are none.
+    pub fn validate(&self) -> Result<(), CardError> {
+        let unknown = |symbol: &char| Joker::by_symbol(*symbol).is_none();
+        if let Some(joker) = self.jokers.iter().find(|joker| unknown(&joker.symbol)) {
+            return Err(self.invalid(format!("unknown joker `{}`", joker.symbol)));
+        }
+        if let Some(symbol) = self
+            .rules
+            .banned_jokers
+            .iter()
+            .find(|symbol| unknown(symbol))
+        {
+            return Err(self.invalid(format!("unknown banned joker `{symbol}`")));
+        }
+        for pool in [
+            &Joker::COMMON_JOKERS[..],
+            &Joker::UNCOMMON_JOKERS[..],
+            &Joker::RARE_JOKERS[..],
+            &Joker::LEGENDARY_JOKERS[..],
+        ] {
+            if pool.iter().all(|joker| self.rules.bans_joker(joker)) {
+                return Err(self.invalid(format!("bans every {:?}", pool[0].card_type)));
+            }
+        }
+        if let Some(suit) = self
+            .rules
+            .debuffed_suits
+            .iter()
+            .find(|suit| !ChallengeRules::SUITS.contains(suit))
+        {
+            return Err(self.invalid(format!("unknown suit `{suit}`")));
+        }
+        if self
+            .draws
+            .is_some_and(|draws| draws.hands_to_play == 0 || draws.hand_size == 0)
+        {
+            return Err(self.invalid("no hand to play".to_string()));
+        }
+        if let Some(slots) = self.joker_slots.filter(|slots| self.jokers.len() > *slots) {
+            return Err(self.invalid(format!(
+                "{} starting jokers for {slots} slots",
+                self.jokers.len()
+            )));
+        }
+        self.pile()?;
+        Ok(())
+    }
+
+    /// The board the challenge starts on. Only an Erratic `deck` without
+    /// `cards` draws from `rng` — the
+    /// [`StarterDeck::board_with_rng`] rule.
+    ///
+    /// # Errors
+    ///
+    /// [`CardError::InvalidChallenge`] if the challenge does not
+    /// [`validate`](Self::validate), or if it starts more jokers than its
+    /// deck has slots for.
+    pub fn board_with_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<BuffoonBoard, CardError> {
+        self.validate()?;
+        let mut board = self.deck.map_or_else(
+            || BuffoonBoard::new(StarterDeck::BASE_DRAWS, Deck::basic_buffoon_pile()),
+            |deck| deck.board_with_rng(rng),
+        );
+        if let Some(pile) = self.pile()? {
+            board.starting_deck_size = pile.len();
+            board.full_deck = pile.clone();
+            board.deck = pile;
+        }
+        if let Some(draws) = self.draws {
+            board.draws = draws;
+            board.starting_draws = draws;
+        }
+        if let Some(money) = self.money {
+            board.money = money;
+        }
+        if let Some(slots) = self.joker_slots {
+            board.joker_slots = slots;
+        }
+        if self.jokers.len() > board.joker_slots {
+            return Err(self.invalid(format!(
+                "{} starting jokers for {} slots",
+                self.jokers.len(),
+                board.joker_slots
+            )));
+        }
+        for starting in &self.jokers {
+            if let Some(mut joker) = Joker::by_symbol(starting.symbol) {
+                joker.stickers.eternal = starting.eternal;
+                joker.stickers.pinned = starting.pinned;
+                board.push_joker(joker);
+            }
+        }
+        board.challenge_rules = self.rules.clone();
+        Ok(board)
+    }
+
+    /// The [`cards`](Self::cards) as a pile, or `None` if the deck keeps its
+    /// own.
+    fn pile(&self) -> Result<Option<BuffoonPile>, CardError> {
+        let Some(cards) = &self.cards else {
+            return Ok(None);
+        };
+        let mut pile = BuffoonPile::default();
+        for index in cards.split_whitespace() {
+            let card = BuffoonCard::from_str(index)
+                .ok()
+                .filter(|card| Deck::DECK.contains(card))
+                .ok_or_else(|| self.invalid(format!("`{index}` is not a playing card")))?;
+            pile.push(card);
+        }
+        if pile.is_empty() {
+            return Err(self.invalid("no cards".to_string()));
+        }
+        Ok(Some(pile))
+    }
+
+    fn invalid(&self, reason: String) -> CardError {
+        CardError::InvalidChallenge {
+            name: self.name.clone(),
+            reason,
+        }
+    }
+
+    /// Serialize to a YAML document.
+    ///
+    /// # Errors
+    ///
+    /// Propagates the serializer's error, boxed as `DeckYaml` boxes it.
+    #[cfg(feature = "yaml")]
+    pub fn to_yaml(&self) -> Result<String, Box<dyn Error>> {
+        Ok(serde_norway::to_string(self)?)
+    }
+
+    /// Parse a YAML document, and [`validate`](Self::validate) what it says.
+    ///
+    /// # Errors
+    ///
+    /// Malformed YAML, a document that is not a mapping
+    /// ([`CardError::YamlMalformed`]), or a challenge that does not hold up
+    /// ([`CardError::InvalidChallenge`]).
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(yaml_str: &str) -> Result<Self, Box<dyn Error>> {
+        let value: serde_norway::Value = serde_norway::from_str(yaml_str)?;
+        if !value.is_mapping() {
+            return Err(Box::new(CardError::YamlMalformed));
+        }
+        let challenge: Self = serde_norway::from_value(value)?;
+        challenge.validate()?;
+        Ok(challenge)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod funky__types__challenge_tests {
+    use super::*;
+    use crate::preludes::funky::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    fn board(challenge: &Challenge) -> Result<BuffoonBoard, CardError> {
+        challenge.board_with_rng(&mut StdRng::seed_from_u64(0))
+    }
+
+    fn reason(challenge: &Challenge) -> String {
+        match challenge.validate() {
+            Err(CardError::InvalidChallenge { reason, .. }) => reason,
+            other => panic!("{other:?}"),
+        }
+    }
+
+    fn balatro(name: &str) -> Challenge {
+        Challenge::balatro()
+            .into_iter()
+            .find(|challenge| challenge.name == name)
+            .unwrap()
+    }
+
+    #[test]
+    fn balatro__every_challenge_holds_up() {
+        for challenge in Challenge::balatro() {
+            assert!(board(&challenge).is_ok(), "{}", challenge.name);
+        }
+    }
+
+    #[test]
+    fn board_with_rng__plain_challenge_deck() {
+        let board = board(&Challenge::new("plain".to_string())).unwrap();
+
+        assert_eq!(board.starter_deck, None);
+        assert_eq!(board.full_deck.len(), Deck::DECK_SIZE);
+        assert_eq!(board.starting_draws, StarterDeck::BASE_DRAWS);
+        assert_eq!(board.challenge_rules, ChallengeRules::default());
+    }
+
+    #[test]
+    fn board_with_rng__the_omelette() {
+        let board = board(&balatro("The Omelette")).unwrap();
+
+        assert_eq!(board.jokers.len(), 5);
+        assert!(
+            board
+                .jokers
+                .iter()
+                .all(|joker| joker.rank.index == EGG.rank.index)
+        );
+        assert!(board.jokers.iter().all(|joker| joker.stickers.eternal));
+        assert_eq!(board.joker_state.len(), 5);
+        assert!(board.challenge_rules.bans_joker(&ROCKET));
+    }
+
+    #[test]
+    fn board_with_rng__on_a_knifes_edge_pins_the_dagger() {
+        let mut board = board(&balatro("On a Knife's Edge")).unwrap();
+        board.push_joker(JOKER);
+
+        assert!(board.jokers.get(0).unwrap().stickers.pinned);
+        assert!(!board.move_joker(1, 0), "nothing moves past it");
+        assert!(!board.move_joker(0, 1));
+    }
+
+    #[test]
+    fn board_with_rng__golden_needle() {
+        let board = board(&balatro("Golden Needle")).unwrap();
+
+        assert_eq!(board.starting_draws, Draws::new(1, 6));
+        assert_eq!(board.draws, Draws::new(1, 6));
+        assert_eq!(board.money, 10);
+    }
+
+    #[test]
+    fn board_with_rng__builds_on_the_deck() {
+        let challenge = Challenge {
+            deck: Some(StarterDeck::Yellow),
+            joker_slots: Some(1),
+            ..Challenge::new("yellow".to_string())
+        };
+
+        let board = board(&challenge).unwrap();
+
+        assert_eq!(board.starter_deck, Some(StarterDeck::Yellow));
+        assert_eq!(board.money, 10);
+        assert_eq!(board.joker_slots, 1);
+    }
+
+    #[test]
+    fn board_with_rng__cards() {
+        let challenge = Challenge {
+            deck: Some(StarterDeck::Erratic),
+            cards: Some("AS AS KH".to_string()),
+            ..Challenge::new("aces".to_string())
+        };
+
+        let board = board(&challenge).unwrap();
+
+        assert_eq!(board.full_deck, bcards!("AS AS KH"));
+        assert_eq!(board.deck, bcards!("AS AS KH"));
+        assert_eq!(board.starting_deck_size, 3);
+    }
+
+    #[test]
+    fn board_with_rng__more_jokers_than_slots() {
+        let challenge = Challenge {
+            deck: Some(StarterDeck::Painted),
+            ..balatro("The Omelette")
+        };
+
+        assert_eq!(
+            board(&challenge).unwrap_err(),
+            CardError::InvalidChallenge {
+                name: "The Omelette".to_string(),
+                reason: "5 starting jokers for 4 slots".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn validate__says_why() {
+        let plain = || Challenge::new("bad".to_string());
+        let rules = |rules: ChallengeRules| Challenge { rules, ..plain() };
+
+        assert_eq!(
+            reason(&Challenge {
+                jokers: vec![StartingJoker {
+                    symbol: 'A',
+                    ..StartingJoker::new(EGG)
+                }],
+                ..plain()
+            }),
+            "unknown joker `A`"
+        );
+        assert_eq!(
+            reason(&rules(ChallengeRules {
+                banned_jokers: vec!['A'],
+                ..ChallengeRules::default()
+            })),
+            "unknown banned joker `A`"
+        );
+        assert_eq!(
+            reason(&rules(ChallengeRules {
+                banned_jokers: Joker::LEGENDARY_JOKERS
+                    .map(|joker| joker.rank.index)
+                    .to_vec(),
+                ..ChallengeRules::default()
+            })),
+            "bans every LegendaryJoker"
+        );
+        assert_eq!(
+            reason(&rules(ChallengeRules {
+                debuffed_suits: vec!['♠'],
+                ..ChallengeRules::default()
+            })),
+            "unknown suit `♠`"
+        );
+        assert_eq!(
+            reason(&Challenge {
+                draws: Some(Draws::new(0, 3)),
+                ..plain()
+            }),
+            "no hand to play"
+        );
+        assert_eq!(
+            reason(&Challenge {
+                joker_slots: Some(4),
+                ..balatro("The Omelette")
+            }),
+            "5 starting jokers for 4 slots"
+        );
+        assert_eq!(
+            reason(&Challenge {
+                cards: Some(String::new()),
+                ..plain()
+            }),
+            "no cards"
+        );
+        assert_eq!(
+            reason(&Challenge {
+                cards: Some("AS XX".to_string()),
+                ..plain()
+            }),
+            "`XX` is not a playing card"
+        );
+        assert_eq!(
+            reason(&Challenge {
+                cards: Some("AS FOOL".to_string()),
+                ..plain()
+            }),
+            "`FOOL` is not a playing card"
+        );
+    }
+
+    #[test]
+    fn debuffs__spares_stone_cards() {
+        let rules = ChallengeRules {
+            debuffed_suits: vec!['S'],
+            ..ChallengeRules::default()
+        };
+        let mut stone = bcard!(AS);
+        stone.enhancement = MPip::Stone(50);
+
+        assert!(rules.debuffs(&bcard!(AS)));
+        assert!(!rules.debuffs(&bcard!(AH)));
+        assert!(!rules.debuffs(&stone));
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn yaml__roundtrips() {
+        for challenge in Challenge::balatro() {
+            let yml = challenge.to_yaml().unwrap();
+
+            assert_eq!(Challenge::from_yaml(&yml).unwrap(), challenge);
+        }
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn from_yaml__only_a_name_is_required() {
+        let challenge = Challenge::from_yaml("name: plain").unwrap();
+
+        assert_eq!(challenge, Challenge::new("plain".to_string()));
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn from_yaml__validates() {
+        let err = Challenge::from_yaml("name: bad\njokers:\n  - symbol: A\n").unwrap_err();
+
+        assert_eq!(
+            *err.downcast_ref::<CardError>().unwrap(),
+            CardError::InvalidChallenge {
+                name: "bad".to_string(),
+                reason: "unknown joker `A`".to_string(),
+            }
+        );
+    }
+}
@@ -0,0 +1,346 @@
+//! Jokers defined as data rather than as `BuffoonCard` consts.
+//!
+//! A built-in joker is a const in [`joker`](crate::funky::decks::joker) plus a
+//! `bcard!` arm. A [`CustomJoker`] is the same card described by its name,
+//! symbol, rarity, cost, [`MPip`] effect and edition, and [`JokerYaml`] is a
+//! file of them in the versioned envelope shape
+//! [`DeckYaml`](crate::basic::types::deck_yaml::DeckYaml) uses for decks.
+//! Loaded onto a board with
+//! [`BuffoonBoard::add_custom_jokers`](crate::funky::types::board::BuffoonBoard::add_custom_jokers),
+//! they join the rarity pools the shop, the Buffoon packs and the joker
+//! creators draw from.
+
+use crate::basic::types::pips::{Pip, PipType};
+use crate::common::errors::CardError;
+use crate::funky::decks::joker::Joker;
+use crate::funky::types::buffoon_card::{BCardType, BuffoonCard};
+use crate::funky::types::edition::Edition;
+use crate::funky::types::mpip::MPip;
+use crate::funky::types::sticker::Stickers;
+use crate::prelude::FrenchSuit;
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "yaml")]
+use std::error::Error;
+
+/// One joker, as a modder writes it.
+///
+/// Only `name` is not carried onto the card: a [`BuffoonCard`] is `Copy` and
+/// identified by its symbol, so the name lives here, for the file and for
+/// errors. `edition` may be left out, and defaults to [`Edition::None`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CustomJoker {
+    pub name: String,
+    /// The joker's index and symbol. Must not be a built-in joker's.
+    pub symbol: char,
+    /// One of the four joker rarities — the pool the joker joins.
+    pub rarity: BCardType,
+    /// What the joker costs in the shop. It sells for half, at least $1.
+    pub cost: usize,
+    pub effect: MPip,
+    #[serde(default)]
+    pub edition: Edition,
+}
+
+impl CustomJoker {
+    /// The rank weight every custom joker carries. Lower than any built-in, so
+    /// a sorted pile lists them after the jokers that ship with the crate.
+    pub const WEIGHT: usize = 0;
+
+    /// The joker as a card.
+    ///
+    /// ```
+    /// use cardpack::funky::types::custom_joker::CustomJoker;
+    /// use cardpack::preludes::funky::*;
+    ///
+    /// let joker = CustomJoker {
+    ///     name: "Mime Artist".to_string(),
+    ///     symbol: '🤹',
+    ///     rarity: BCardType::UncommonJoker,
+    ///     cost: 7,
+    ///     effect: MPip::MultPlus(8),
+    ///     edition: Edition::None,
+    /// }
+    /// .card()
+    /// .unwrap();
+    ///
+    /// assert_eq!(joker.card_type, BCardType::UncommonJoker);
+    /// assert_eq!(joker.rank.value, 7);
+    /// assert_eq!(joker.resell_value, 3);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// [`CardError::InvalidCard`], naming the joker, if its rarity is not a
+    /// joker rarity or its symbol is already a built-in joker's.
+    pub fn card(&self) -> Result<BuffoonCard, CardError> {
+        let is_joker = matches!(
+            self.rarity,
+            BCardType::CommonJoker
+                | BCardType::UncommonJoker
+                | BCardType::RareJoker
+                | BCardType::LegendaryJoker
+        );
+        if !is_joker || Joker::by_symbol(self.symbol).is_some() {
+            return Err(CardError::InvalidCard(self.name.clone()));
+        }
+        Ok(BuffoonCard {
+            suit: FrenchSuit::JOKER,
+            rank: Pip {
+                weight: Self::WEIGHT,
+                pip_type: PipType::Joker,
+                index: self.symbol,
+                symbol: self.symbol,
+                value: self.cost,
+            },
+            card_type: self.rarity,
+            enhancement: self.effect,
+            resell_value: (self.cost / 2).max(1),
+            edition: self.edition,
+            debuffed: false,
+            stickers: Stickers::NONE,
+        })
+    }
+}
+
+/// A file of [`CustomJoker`]s: a metadata header plus the jokers, the
+/// [`DeckYaml`](crate::basic::types::deck_yaml::DeckYaml) envelope with
+/// jokers in place of cards.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct JokerYaml {
+    /// Format version. `1` for every document this crate writes.
+    pub version: u8,
+    /// What the modder calls the set, e.g. `"Circus"`.
+    pub name: String,
+    /// Redundant with `jokers.len()` on purpose: a truncation guard.
+    pub count: usize,
+    pub jokers: Vec<CustomJoker>,
+}
+
+impl JokerYaml {
+    /// The only format version this crate writes.
+    pub const VERSION: u8 = 1;
+
+    /// Build an envelope. `count` is derived from `jokers`, never passed in.
+    #[must_use]
+    pub fn new(name: String, jokers: Vec<CustomJoker>) -> Self {
+        Self {
+            version: Self::VERSION,
+            name,
+            count: jokers.len(),
+            jokers,
+        }
+    }
+
+    /// Every joker as a card, in file order.
+    ///
+    /// # Errors
+    ///
+    /// [`CardError::InvalidCard`], naming the first joker that is not valid on
+    /// its own (see [`CustomJoker::card`]) or that reuses an earlier joker's
+    /// symbol.
+    pub fn cards(&self) -> Result<Vec<BuffoonCard>, CardError> {
+        let mut cards: Vec<BuffoonCard> = Vec::with_capacity(self.jokers.len());
+        for joker in &self.jokers {
+            let card = joker.card()?;
+            if cards.iter().any(|c| c.rank.index == card.rank.index) {
+                return Err(CardError::InvalidCard(joker.name.clone()));
+            }
+            cards.push(card);
+        }
+        Ok(cards)
+    }
+
+    /// Serialize to a YAML envelope document.
+    ///
+    /// # Errors
+    ///
+    /// Propagates the serializer's error, boxed as `DeckYaml` boxes it.
+    #[cfg(feature = "yaml")]
+    pub fn to_yaml(&self) -> Result<String, Box<dyn Error>> {
+        Ok(serde_norway::to_string(self)?)
+    }
+
+    /// Parse a YAML envelope document.
+    ///
+    /// Unlike a deck there is no legacy bare form to accept: the first
+    /// document this reads is the first one that was written.
+    ///
+    /// # Errors
+    ///
+    /// Malformed YAML, a document that is not a mapping
+    /// ([`CardError::YamlMalformed`]), a `count` that disagrees with
+    /// `jokers.len()` ([`CardError::YamlCountMismatch`]), or a joker that is
+    /// not valid ([`CardError::InvalidCard`]).
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(yaml_str: &str) -> Result<Self, Box<dyn Error>> {
+        let value: serde_norway::Value = serde_norway::from_str(yaml_str)?;
+        if !value.is_mapping() {
+            return Err(Box::new(CardError::YamlMalformed));
+        }
+        let joker_yaml: Self = serde_norway::from_value(value)?;
+
+        if joker_yaml.count != joker_yaml.jokers.len() {
+            return Err(Box::new(CardError::YamlCountMismatch {
+                declared: joker_yaml.count,
+                actual: joker_yaml.jokers.len(),
+            }));
+        }
+        joker_yaml.cards()?;
+
+        Ok(joker_yaml)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod funky__types__custom_joker_tests {
+    use super::*;
+
+    fn mime() -> CustomJoker {
+        CustomJoker {
+            name: "Mime Artist".to_string(),
+            symbol: '🤹',
+            rarity: BCardType::UncommonJoker,
+            cost: 7,
+            effect: MPip::MultPlus(8),
+            edition: Edition::None,
+        }
+    }
+
+    fn circus() -> JokerYaml {
+        JokerYaml::new(
+            "Circus".to_string(),
+            vec![
+                mime(),
+                CustomJoker {
+                    name: "Ringmaster".to_string(),
+                    symbol: '🎈',
+                    rarity: BCardType::RareJoker,
+                    cost: 1,
+                    effect: MPip::MultTimes(3),
+                    edition: Edition::Foil,
+                },
+            ],
+        )
+    }
+
+    #[test]
+    fn card() {
+        let card = mime().card().unwrap();
+
+        assert_eq!(card.rank.index, '🤹');
+        assert_eq!(card.rank.weight, CustomJoker::WEIGHT);
+        assert_eq!(card.enhancement, MPip::MultPlus(8));
+        assert_eq!(card.resell_value, 3);
+        assert!(card.stickers.is_empty());
+    }
+
+    #[test]
+    fn card__sells_for_at_least_a_dollar() {
+        let cards = circus().cards().unwrap();
+
+        assert_eq!(cards[1].resell_value, 1);
+        assert_eq!(cards[1].edition, Edition::Foil);
+    }
+
+    #[test]
+    fn card__refuses_a_rarity_that_is_not_a_joker() {
+        let joker = CustomJoker {
+            rarity: BCardType::Tarot,
+            ..mime()
+        };
+
+        assert_eq!(
+            joker.card(),
+            Err(CardError::InvalidCard("Mime Artist".to_string()))
+        );
+    }
+
+    #[test]
+    fn card__refuses_a_built_in_symbol() {
+        let joker = CustomJoker {
+            symbol: '🗡',
+            ..mime()
+        };
+
+        assert!(joker.card().is_err());
+    }
+
+    #[test]
+    fn cards__refuses_a_repeated_symbol() {
+        let joker_yaml = JokerYaml::new(
+            "Twins".to_string(),
+            vec![
+                mime(),
+                CustomJoker {
+                    name: "Understudy".to_string(),
+                    ..mime()
+                },
+            ],
+        );
+
+        assert_eq!(
+            joker_yaml.cards(),
+            Err(CardError::InvalidCard("Understudy".to_string()))
+        );
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn envelope__roundtrips() {
+        let yml = circus().to_yaml().unwrap();
+
+        assert!(yml.starts_with("version: 1\nname: Circus\ncount: 2\n"));
+        assert_eq!(JokerYaml::from_yaml(&yml).unwrap(), circus());
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn from_yaml__edition_is_optional() {
+        let yml = "version: 1\nname: Solo\ncount: 1\njokers:\n\
+                   - name: Mime Artist\n  symbol: 🤹\n  rarity: UncommonJoker\n  \
+                   cost: 7\n  effect: !MultPlus 8\n";
+
+        assert_eq!(JokerYaml::from_yaml(yml).unwrap().jokers, vec![mime()]);
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn from_yaml__count_mismatch() {
+        let mut joker_yaml = circus();
+        joker_yaml.count = 3;
+        let err = JokerYaml::from_yaml(&joker_yaml.to_yaml().unwrap()).unwrap_err();
+
+        assert_eq!(
+            *err.downcast_ref::<CardError>().unwrap(),
+            CardError::YamlCountMismatch {
+                declared: 3,
+                actual: 2
+            }
+        );
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn from_yaml__refuses_an_invalid_joker() {
+        let mut joker_yaml = circus();
+        joker_yaml.jokers[1].symbol = '🤹';
+        let err = JokerYaml::from_yaml(&joker_yaml.to_yaml().unwrap()).unwrap_err();
+
+        assert_eq!(
+            *err.downcast_ref::<CardError>().unwrap(),
+            CardError::InvalidCard("Ringmaster".to_string())
+        );
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn from_yaml__a_sequence_is_malformed() {
+        let err = JokerYaml::from_yaml("- Mime Artist").unwrap_err();
+
+        assert_eq!(
+            *err.downcast_ref::<CardError>().unwrap(),
+            CardError::YamlMalformed
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use crate::funky::types::effect::ScoreOp;
+use rand::{Rng, RngExt};
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 
@@ -49,6 +50,57 @@ impl Edition {
         }
     }
 
+    /// Roll the edition a shop or pack joker arrives with, at Balatro's base
+    /// rates out of 1000: **Negative 3**, **Polychrome 3**, **Holographic 14**,
+    /// **Foil 20** — so 96.0% of jokers are unedited.
+    ///
+    /// `rate` scales the three scoring editions (Hone's ×2, Glow Up's ×4) and
+    /// leaves Negative alone, as in Balatro. Always one draw, whatever the
+    /// result, so a run's RNG stream does not shift with the vouchers held.
+    #[must_use]
+    pub fn roll<R: Rng + ?Sized>(rng: &mut R, rate: usize) -> Self {
+        let roll = rng.random_range(0..1000);
+        let negative = 3;
+        let polychrome = negative + 3 * rate;
+        let holographic = polychrome + 14 * rate;
+        let foil = holographic + 20 * rate;
+        if roll < negative {
+            Self::Negative
+        } else if roll < polychrome {
+            Self::Polychrome
+        } else if roll < holographic {
+            Self::Holographic
+        } else if roll < foil {
+            Self::Foil
+        } else {
+            Self::None
+        }
+    }
+
+    /// Roll a **guaranteed** scoring edition — **Foil 50%**, **Holographic
+    /// 35%**, **Polychrome 15%**, never Negative. What The Wheel of Fortune and
+    /// Aura stamp.
+    #[must_use]
+    pub fn roll_guaranteed<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        match rng.random_range(0..100) {
+            0..50 => Self::Foil,
+            50..85 => Self::Holographic,
+            _ => Self::Polychrome,
+        }
+    }
+
+    /// What the edition adds to a shop price: Foil **$2**, Holographic **$3**,
+    /// Polychrome and Negative **$5**.
+    #[must_use]
+    pub fn extra_cost(self) -> usize {
+        match self {
+            Self::None => 0,
+            Self::Foil => 2,
+            Self::Holographic => 3,
+            Self::Polychrome | Self::Negative => 5,
+        }
+    }
+
     /// Whether this edition exempts its bearer from a slot limit.
     #[must_use]
     pub fn is_negative(self) -> bool {
@@ -74,6 +126,66 @@ impl Display for Edition {
 mod funky__types__edition_tests {
     use super::*;
     use crate::funky::types::score::Score;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    /// How many of 10,000 seeded rolls at `rate` land on each edition.
+    fn tally(rate: usize) -> [usize; 5] {
+        let mut rng = StdRng::seed_from_u64(29);
+        let mut counts = [0; 5];
+        for _ in 0..10_000 {
+            let slot = match Edition::roll(&mut rng, rate) {
+                Edition::None => 0,
+                Edition::Foil => 1,
+                Edition::Holographic => 2,
+                Edition::Polychrome => 3,
+                Edition::Negative => 4,
+            };
+            counts[slot] += 1;
+        }
+        counts
+    }
+
+    #[test]
+    fn roll__mostly_none_at_the_base_rate() {
+        let [none, foil, holo, poly, negative] = tally(1);
+        assert!(none > 9_400, "96% unedited, was {none}");
+        assert!(foil > holo, "Foil is the commonest edition");
+        assert!(holo > poly);
+        assert!(negative > 0 && poly > 0);
+    }
+
+    #[test]
+    fn roll__rate_scales_the_scoring_editions_not_negative() {
+        let base = tally(1);
+        let glow_up = tally(4);
+        assert!(glow_up[1] > 3 * base[1], "Foil about ×4");
+        assert!(glow_up[0] < base[0], "fewer unedited");
+        // Negative is the lowest band and does not move, so the same seed lands
+        // on it exactly as often.
+        assert_eq!(glow_up[4], base[4]);
+    }
+
+    #[test]
+    fn roll_guaranteed__is_never_none_or_negative() {
+        let mut rng = StdRng::seed_from_u64(1);
+        for _ in 0..200 {
+            let edition = Edition::roll_guaranteed(&mut rng);
+            assert!(matches!(
+                edition,
+                Edition::Foil | Edition::Holographic | Edition::Polychrome
+            ));
+        }
+    }
+
+    #[test]
+    fn extra_cost__by_edition() {
+        assert_eq!(Edition::None.extra_cost(), 0);
+        assert_eq!(Edition::Foil.extra_cost(), 2);
+        assert_eq!(Edition::Holographic.extra_cost(), 3);
+        assert_eq!(Edition::Polychrome.extra_cost(), 5);
+        assert_eq!(Edition::Negative.extra_cost(), 5);
+    }
 
     #[test]
     fn default__is_none() {
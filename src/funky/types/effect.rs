@@ -2,26 +2,38 @@
 //!
 //! Card effects are **data** (`MPip`), interpreted at scoring time. The built-in
 //! effects are matched directly; a custom effect from a mod is identified by
-//! `MPip::Custom(u32)` and resolved through an [`EffectRegistry`] the mod
+//! `MPip::Custom(EffectId)` and resolved through an [`EffectRegistry`] the mod
 //! populates at startup — so a mod can add scoring behaviour without editing
 //! funky source.
 //!
 //! Because [`BuffoonCard`] is
 //! `Copy`, `const`-constructible and `Serialize`, effects on cards cannot be
 //! boxed trait objects. The indirection lives in the *registry* (keyed by a
-//! plain, serializable `u32`) instead of on the card.
+//! namespaced [`EffectId`], a `Copy` handle to its string) instead of on the
+//! card.
 
+use crate::common::errors::CardError;
 use crate::funky::types::board::BuffoonBoard;
 use crate::funky::types::buffoon_card::BuffoonCard;
-use crate::funky::types::score::Score;
-use std::collections::HashMap;
+use crate::funky::types::buffoon_pile::BuffoonPile;
+use crate::funky::types::magnitude::Magnitude;
+use crate::funky::types::score::{BigScore, Score};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
+use std::sync::{Mutex, PoisonError};
 
 /// A declarative description of an effect's contribution to a running [`Score`].
 ///
 /// Effects return a `ScoreOp` rather than mutating a `Score` directly, so the
 /// scoring pipeline stays in control of ordering (additive vs multiplicative)
 /// and the operation is easy to test in isolation.
+///
+/// `#[non_exhaustive]`: a new scoring rule (Plasma's balance was the first)
+/// may need a new op, and that should not break every downstream `match`.
 #[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ScoreOp {
     /// Contribute nothing.
     Nothing,
@@ -35,6 +47,9 @@ pub enum ScoreOp {
     TimesMult(f32),
     /// Apply several operations in order.
     Seq(Vec<Self>),
+    /// Average chips and mult, setting both to the mean (rounded down) —
+    /// the Plasma Deck's balance.
+    Balance,
 }
 
 impl ScoreOp {
@@ -48,6 +63,249 @@ impl ScoreOp {
             Self::Add(delta) => score + *delta,
             Self::TimesMult(factor) => score.multi_mult(*factor),
             Self::Seq(ops) => ops.iter().fold(score, |acc, op| op.apply(acc)),
+            Self::Balance => {
+                let mean = usize::midpoint(score.chips, score.mult);
+                Score::new(mean, mean)
+            }
+        }
+    }
+
+    /// [`apply`](Self::apply) at Balatro scale: the same operation on a
+    /// [`BigScore`], with none of `Score`'s rounding. A ×mult multiplies by the
+    /// exact decimal its factor was written as, and is not rounded up; a
+    /// balance is the exact mean.
+    #[must_use]
+    pub fn apply_big(&self, score: BigScore) -> BigScore {
+        match self {
+            Self::Nothing => score,
+            Self::AddChips(chips) => {
+                BigScore::new(score.chips + Magnitude::from(*chips), score.mult)
+            }
+            Self::AddMult(mult) => BigScore::new(score.chips, score.mult + Magnitude::from(*mult)),
+            Self::Add(delta) => score + BigScore::from(*delta),
+            Self::TimesMult(factor) => {
+                BigScore::new(score.chips, score.mult * Magnitude::from_factor(*factor))
+            }
+            Self::Seq(ops) => ops.iter().fold(score, |acc, op| op.apply_big(acc)),
+            Self::Balance => {
+                let mean = (score.chips + score.mult) * Magnitude::new(5, -1);
+                BigScore::new(mean, mean)
+            }
+        }
+    }
+}
+
+/// Balatro's own shorthand for a contribution — `+30 chips`, `+4 mult`,
+/// `×1.5 mult` — which is what a [`ScoreTrace`] step list reads as.
+///
+/// [`ScoreTrace`]: crate::funky::types::trace::ScoreTrace
+impl Display for ScoreOp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Nothing => write!(f, "nothing"),
+            Self::AddChips(chips) => write!(f, "+{chips} chips"),
+            Self::AddMult(mult) => write!(f, "+{mult} mult"),
+            // A whole-score add usually carries one side only (a plain card's
+            // chips, a `+mult` joker), so the empty side is left unsaid.
+            Self::Add(delta) if delta.mult == 0 => write!(f, "+{} chips", delta.chips),
+            Self::Add(delta) if delta.chips == 0 => write!(f, "+{} mult", delta.mult),
+            Self::Add(delta) => write!(f, "+{} chips +{} mult", delta.chips, delta.mult),
+            Self::TimesMult(factor) => write!(f, "×{factor} mult"),
+            Self::Seq(ops) => {
+                for (index, op) in ops.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{op}")?;
+                }
+                Ok(())
+            }
+            Self::Balance => write!(f, "balance"),
+        }
+    }
+}
+
+/// The id of a custom [`Effect`]: a namespace and a name, like
+/// `mymod:lucky_seven`.
+///
+/// The namespace is what keeps two mods apart — each picks its own, so their
+/// effects cannot collide the way two bare integers could — and the id is
+/// serialized as that string, so a saved board means the same effect whatever
+/// order the mods registered in. Both halves are lower-case letters, digits,
+/// `_` or `-`, and the whole id is at most [`MAX_LEN`](Self::MAX_LEN) bytes —
+/// room for a descriptive mod and effect name (`balance_team:flush_doubler`
+/// is 26). The card holds only a `&'static str` to it: a literal in
+/// [`new`](Self::new), or the interned copy a parsed id is stored as, once per
+/// distinct id. So a card carrying one stays `Copy` and `const`, and no larger
+/// than it was when the id was a `u32`.
+///
+/// Boards saved when custom effects were keyed by a bare `u32` still load: a
+/// number deserializes as an id in the `legacy` namespace (`7001` becomes
+/// `legacy:7001`), and [`EffectRegistry::alias`] points it at the effect that
+/// now owns it.
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(into = "String")]
+pub struct EffectId(&'static str);
+
+/// Every id parsed at runtime, stored once and never freed, so an
+/// [`EffectId`] can point at it.
+static INTERNED: Mutex<BTreeSet<&'static str>> = Mutex::new(BTreeSet::new());
+
+impl EffectId {
+    /// The longest id, in bytes.
+    pub const MAX_LEN: usize = 63;
+    /// The namespace numeric ids are read into.
+    pub const LEGACY_NAMESPACE: &'static str = "legacy";
+
+    /// The id `namespace:name`, for use in a `const`.
+    ///
+    /// ```
+    /// use cardpack::funky::types::effect::EffectId;
+    ///
+    /// const LUCKY_SEVEN: EffectId = EffectId::new("mymod:lucky_seven");
+    ///
+    /// assert_eq!(LUCKY_SEVEN.namespace(), "mymod");
+    /// assert_eq!(LUCKY_SEVEN.name(), "lucky_seven");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `id` is not a valid id. Use [`FromStr`] for ids that are not known
+    /// at compile time.
+    #[must_use]
+    pub const fn new(id: &'static str) -> Self {
+        if Self::is_valid(id) {
+            Self(id)
+        } else {
+            panic!("an effect id is `namespace:name`, in [a-z0-9_-], at most 63 bytes")
+        }
+    }
+
+    /// The id a numeric effect id from an older save is read as.
+    #[must_use]
+    pub fn legacy(id: u32) -> Self {
+        Self::interned(&format!("{}:{id}", Self::LEGACY_NAMESPACE))
+    }
+
+    /// Whether `id` is a valid id. A `const fn`, so [`new`](Self::new) can
+    /// reject a bad id at compile time.
+    const fn is_valid(id: &str) -> bool {
+        let source = id.as_bytes();
+        if source.is_empty() || source.len() > Self::MAX_LEN {
+            return false;
+        }
+        let mut colon = None;
+        let mut i = 0;
+        while i < source.len() {
+            let b = source[i];
+            if b == b':' {
+                if colon.is_some() {
+                    return false;
+                }
+                colon = Some(i);
+            } else if !(b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_' || b == b'-') {
+                return false;
+            }
+            i += 1;
+        }
+        matches!(colon, Some(at) if at > 0 && at + 1 < source.len())
+    }
+
+    /// The id for a valid `id` known only at runtime, pointing at its
+    /// interned copy.
+    fn interned(id: &str) -> Self {
+        let mut interned = INTERNED.lock().unwrap_or_else(PoisonError::into_inner);
+        let stored = interned
+            .get(id)
+            .copied()
+            .unwrap_or_else(|| Box::leak(id.to_string().into_boxed_str()));
+        interned.insert(stored);
+        drop(interned);
+        Self(stored)
+    }
+
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        self.0
+    }
+
+    /// The part before the `:` — the mod that owns the effect.
+    #[must_use]
+    pub fn namespace(&self) -> &str {
+        self.as_str()
+            .split_once(':')
+            .map_or("", |(namespace, _)| namespace)
+    }
+
+    /// The part after the `:`.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        self.as_str().split_once(':').map_or("", |(_, name)| name)
+    }
+
+    /// Whether this id was read from an older save's numeric id.
+    #[must_use]
+    pub fn is_legacy(&self) -> bool {
+        self.namespace() == Self::LEGACY_NAMESPACE
+    }
+}
+
+impl Debug for EffectId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EffectId({:?})", self.as_str())
+    }
+}
+
+impl Display for EffectId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for EffectId {
+    type Err = CardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if Self::is_valid(s) {
+            Ok(Self::interned(s))
+        } else {
+            Err(CardError::InvalidEffectId(s.to_string()))
+        }
+    }
+}
+
+impl From<EffectId> for String {
+    fn from(id: EffectId) -> Self {
+        id.as_str().to_string()
+    }
+}
+
+/// What an [`EffectId`] is deserialized from: the string it is written as
+/// now, or the bare number an older save carries.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawEffectId {
+    Named(String),
+    Legacy(u32),
+}
+
+/// By hand rather than `#[serde(try_from)]`, which would tie the
+/// deserializer's lifetime to the `'static` the id points at.
+impl<'de> Deserialize<'de> for EffectId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        RawEffectId::deserialize(deserializer)?
+            .try_into()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+impl TryFrom<RawEffectId> for EffectId {
+    type Error = CardError;
+
+    fn try_from(raw: RawEffectId) -> Result<Self, Self::Error> {
+        match raw {
+            RawEffectId::Named(id) => id.parse(),
+            RawEffectId::Legacy(id) => Ok(Self::legacy(id)),
         }
     }
 }
@@ -60,15 +318,126 @@ pub struct ScoringContext<'a> {
     pub board: &'a BuffoonBoard,
     /// The card (or joker) whose effect is firing.
     pub source: BuffoonCard,
+    /// The joker's counter — what its lifecycle hooks have grown through
+    /// [`EffectContext::add_state`]. Always `0` for a playing card.
+    pub state: i32,
 }
 
-/// A custom, moddable scoring effect. Implement this in a mod crate and register
-/// it under a `u32` id; attach the effect to a card via `MPip::Custom(id)`.
+/// A moment in the round a custom [`Effect`] can react to: the events the
+/// built-in jokers grow and pay on. Passed to
+/// [`BuffoonBoard::trigger_effects`], which calls the matching hook.
 ///
-/// Kept object-safe so the registry can hold `Box<dyn Effect>`.
-pub trait Effect {
+/// [`BuffoonBoard::trigger_effects`]: crate::funky::types::board::BuffoonBoard::trigger_effects
+#[derive(Clone, Copy, Debug)]
+pub enum EffectEvent<'a> {
+    /// A blind was selected — [`Effect::on_blind_selected`].
+    BlindSelected,
+    /// A hand was played and scored — [`Effect::on_hand_played`].
+    HandPlayed(&'a BuffoonPile),
+    /// Cards were discarded — [`Effect::on_discard`].
+    Discard(&'a BuffoonPile),
+    /// The round ended — [`Effect::on_round_end`].
+    RoundEnd,
+    /// A card was bought from the shop — [`Effect::on_buy`].
+    Bought(BuffoonCard),
+}
+
+/// What a custom [`Effect`]'s lifecycle hook may change.
+///
+/// That is the run's money, its own joker's counter, and the consumables.
+/// Everything else on the board is read-only through [`board`](Self::board) —
+/// the same reach a built-in joker's growth and payouts have, and no more.
+pub struct EffectContext<'a> {
+    board: &'a mut BuffoonBoard,
+    slot: usize,
+}
+
+impl<'a> EffectContext<'a> {
+    /// The context for the joker at `slot`.
+    pub(crate) const fn new(board: &'a mut BuffoonBoard, slot: usize) -> Self {
+        Self { board, slot }
+    }
+
+    #[must_use]
+    pub fn board(&self) -> &BuffoonBoard {
+        self.board
+    }
+
+    /// The joker whose hook is running.
+    #[must_use]
+    pub fn source(&self) -> BuffoonCard {
+        self.board
+            .jokers
+            .get(self.slot)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    #[must_use]
+    pub const fn money(&self) -> isize {
+        self.board.money
+    }
+
+    /// Add `dollars` to the run's money; a negative amount spends it.
+    pub const fn earn(&mut self, dollars: isize) {
+        self.board.money = self.board.money.saturating_add(dollars);
+    }
+
+    /// The joker's counter, which its [`score`](Effect::score) reads back as
+    /// [`ScoringContext::state`].
+    #[must_use]
+    pub fn state(&self) -> i32 {
+        self.board.joker_state.get(self.slot).copied().unwrap_or(0)
+    }
+
+    /// Grow (or, negative, shrink) the joker's counter.
+    pub fn add_state(&mut self, delta: i32) {
+        self.set_state(self.state().saturating_add(delta));
+    }
+
+    pub fn set_state(&mut self, value: i32) {
+        if let Some(state) = self.board.joker_state.get_mut(self.slot) {
+            *state = value;
+        }
+    }
+
+    /// Put `card` in a consumable slot. Returns whether there was room — see
+    /// [`BuffoonBoard::create_consumable`].
+    pub fn create_consumable(&mut self, card: BuffoonCard) -> bool {
+        self.board.create_consumable(card)
+    }
+}
+
+/// A custom, moddable effect. Implement this in a mod crate and register it
+/// under an [`EffectId`]; attach the effect to a card via `MPip::Custom(id)`.
+///
+/// [`score`](Self::score) is read while scoring, on a played card, a held card
+/// or a joker. The lifecycle hooks run on jokers only, when the caller fires
+/// the event through
+/// [`BuffoonBoard::trigger_effects`](crate::funky::types::board::BuffoonBoard::trigger_effects);
+/// they are where a custom joker grows its counter or pays out, as a built-in
+/// does in `on_discard` and `on_round_end`. Every method has a do-nothing
+/// default, so an effect implements only what it reacts to.
+///
+/// Kept object-safe so the registry can hold `Box<dyn Effect>`, and
+/// `Send + Sync` so a registry can be shared by runs on other threads.
+pub trait Effect: Send + Sync {
     /// The contribution this effect makes given the current scoring context.
-    fn score(&self, ctx: &ScoringContext<'_>) -> ScoreOp;
+    fn score(&self, _ctx: &ScoringContext<'_>) -> ScoreOp {
+        ScoreOp::Nothing
+    }
+
+    fn on_blind_selected(&self, _ctx: &mut EffectContext<'_>) {}
+
+    /// After a hand is played and scored, with the cards that were played.
+    fn on_hand_played(&self, _ctx: &mut EffectContext<'_>, _played: &BuffoonPile) {}
+
+    fn on_discard(&self, _ctx: &mut EffectContext<'_>, _discarded: &BuffoonPile) {}
+
+    fn on_round_end(&self, _ctx: &mut EffectContext<'_>) {}
+
+    /// After a card is bought from the shop — the joker's own purchase included.
+    fn on_buy(&self, _ctx: &mut EffectContext<'_>, _bought: BuffoonCard) {}
 }
 
 /// Maps `MPip::Custom` ids to their handlers. A mod builds one at startup and
@@ -77,7 +446,9 @@ pub trait Effect {
 /// [`BuffoonBoard::score_with_registry`]: crate::funky::types::board::BuffoonBoard::score_with_registry
 #[derive(Default)]
 pub struct EffectRegistry {
-    handlers: HashMap<u32, Box<dyn Effect>>,
+    handlers: HashMap<EffectId, Box<dyn Effect>>,
+    /// Legacy ids, each pointing at the effect that now owns its number.
+    aliases: HashMap<EffectId, EffectId>,
 }
 
 impl EffectRegistry {
@@ -87,21 +458,80 @@ impl EffectRegistry {
         Self::default()
     }
 
-    /// Registers `effect` under `id`, replacing any existing handler for it.
-    pub fn register(&mut self, id: u32, effect: impl Effect + 'static) {
+    /// Registers `effect` under `id`.
+    ///
+    /// # Errors
+    ///
+    /// [`CardError::DuplicateEffectId`] if `id` is already registered or is an
+    /// alias: two mods claiming one id is an error when they load, not a
+    /// silent replacement the first time a card scores.
+    pub fn register(
+        &mut self,
+        id: EffectId,
+        effect: impl Effect + 'static,
+    ) -> Result<(), CardError> {
+        if self.is_taken(id) {
+            return Err(CardError::DuplicateEffectId(id.to_string()));
+        }
         self.handlers.insert(id, Box::new(effect));
+        Ok(())
     }
 
-    /// Looks up the handler for `id`, if any.
+    /// Points the numeric id `legacy` — what an older save carries as
+    /// `legacy:<n>` — at the effect registered as `id`. A board that still
+    /// holds the number then scores as if it held `id`, and
+    /// [`BuffoonBoard::migrate_effect_ids`] rewrites it for good.
+    ///
+    /// # Errors
+    ///
+    /// [`CardError::DuplicateEffectId`] if the legacy id is already registered
+    /// or aliased.
+    ///
+    /// [`BuffoonBoard::migrate_effect_ids`]: crate::funky::types::board::BuffoonBoard::migrate_effect_ids
+    pub fn alias(&mut self, legacy: u32, id: EffectId) -> Result<(), CardError> {
+        let legacy = EffectId::legacy(legacy);
+        if self.is_taken(legacy) {
+            return Err(CardError::DuplicateEffectId(legacy.to_string()));
+        }
+        self.aliases.insert(legacy, id);
+        Ok(())
+    }
+
+    /// The id `id` resolves to: its alias target if it has one, else itself.
     #[must_use]
-    pub fn get(&self, id: u32) -> Option<&dyn Effect> {
-        self.handlers.get(&id).map(|effect| &**effect)
+    pub fn resolve(&self, id: EffectId) -> EffectId {
+        self.aliases.get(&id).copied().unwrap_or(id)
     }
 
-    /// Whether an effect is registered for `id`.
+    /// Looks up the handler for `id`, following an alias, if any.
     #[must_use]
-    pub fn contains(&self, id: u32) -> bool {
-        self.handlers.contains_key(&id)
+    pub fn get(&self, id: EffectId) -> Option<&dyn Effect> {
+        self.handlers.get(&self.resolve(id)).map(|effect| &**effect)
+    }
+
+    /// Whether an effect is registered for `id`, following an alias.
+    #[must_use]
+    pub fn contains(&self, id: EffectId) -> bool {
+        self.handlers.contains_key(&self.resolve(id))
+    }
+
+    fn is_taken(&self, id: EffectId) -> bool {
+        self.handlers.contains_key(&id) || self.aliases.contains_key(&id)
+    }
+}
+
+/// The ids a registry answers to, sorted — the effects themselves are trait
+/// objects, with nothing to print.
+impl Debug for EffectRegistry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut handlers: Vec<&EffectId> = self.handlers.keys().collect();
+        handlers.sort();
+        let mut aliases: Vec<(&EffectId, &EffectId)> = self.aliases.iter().collect();
+        aliases.sort();
+        f.debug_struct("EffectRegistry")
+            .field("handlers", &handlers)
+            .field("aliases", &aliases)
+            .finish()
     }
 }
 
@@ -109,6 +539,9 @@ impl EffectRegistry {
 #[allow(non_snake_case)]
 mod funky__types__effect_tests {
     use super::*;
+    use crate::funky::types::mpip::MPip;
+    use crate::funky::types::sticker::Stickers;
+    use rstest::rstest;
 
     #[test]
     fn score_op__apply() {
@@ -130,6 +563,66 @@ mod funky__types__effect_tests {
         assert_eq!(op.apply(Score::new(0, 4)), Score::new(0, 18));
     }
 
+    #[test]
+    fn score_op__display() {
+        assert_eq!(ScoreOp::Nothing.to_string(), "nothing");
+        assert_eq!(ScoreOp::AddChips(30).to_string(), "+30 chips");
+        assert_eq!(ScoreOp::AddMult(4).to_string(), "+4 mult");
+        assert_eq!(
+            ScoreOp::Add(Score::new(20, 4)).to_string(),
+            "+20 chips +4 mult"
+        );
+        assert_eq!(ScoreOp::Add(Score::new(11, 0)).to_string(), "+11 chips");
+        assert_eq!(ScoreOp::Add(Score::new(0, 4)).to_string(), "+4 mult");
+        assert_eq!(ScoreOp::TimesMult(1.5).to_string(), "×1.5 mult");
+        assert_eq!(
+            ScoreOp::Seq(vec![ScoreOp::AddMult(2), ScoreOp::TimesMult(3.0)]).to_string(),
+            "+2 mult, ×3 mult"
+        );
+        assert_eq!(ScoreOp::Balance.to_string(), "balance");
+    }
+
+    #[test]
+    fn score_op__balance_averages_chips_and_mult() {
+        assert_eq!(
+            ScoreOp::Balance.apply(Score::new(100, 10)),
+            Score::new(55, 55)
+        );
+        assert_eq!(ScoreOp::Balance.apply(Score::new(3, 0)), Score::new(1, 1));
+    }
+
+    #[test]
+    fn score_op__apply_big_matches_apply_without_rounding() {
+        let base = Score::new(10, 4);
+        for op in [
+            ScoreOp::Nothing,
+            ScoreOp::AddChips(5),
+            ScoreOp::AddMult(3),
+            ScoreOp::Add(Score::new(5, 3)),
+            ScoreOp::TimesMult(2.0),
+            ScoreOp::Seq(vec![ScoreOp::AddMult(2), ScoreOp::TimesMult(3.0)]),
+        ] {
+            assert_eq!(
+                op.apply_big(BigScore::from(base)),
+                BigScore::from(op.apply(base)),
+                "{op}"
+            );
+        }
+
+        // Where `Score` rounds, `BigScore` does not.
+        let odd = BigScore::from(Score::new(3, 3));
+        assert_eq!(
+            ScoreOp::TimesMult(1.5).apply_big(odd).mult,
+            Magnitude::new(45, -1)
+        );
+        assert_eq!(
+            ScoreOp::Balance
+                .apply_big(BigScore::from(Score::new(3, 0)))
+                .mult,
+            Magnitude::new(15, -1)
+        );
+    }
+
     struct FlatMult(usize);
     impl Effect for FlatMult {
         fn score(&self, _ctx: &ScoringContext<'_>) -> ScoreOp {
@@ -137,16 +630,119 @@ mod funky__types__effect_tests {
         }
     }
 
+    const FLAT: EffectId = EffectId::new("test:flat_mult");
+
     #[test]
     fn registry__register_and_get() {
         let mut registry = EffectRegistry::new();
-        assert!(!registry.contains(1));
-        registry.register(1, FlatMult(7));
-        assert!(registry.contains(1));
-        assert!(registry.get(2).is_none());
+        assert!(!registry.contains(FLAT));
+        registry.register(FLAT, FlatMult(7)).unwrap();
+        assert!(registry.contains(FLAT));
+        assert!(registry.get(EffectId::new("test:other")).is_none());
 
         // The handler resolves; exercising it needs a ScoringContext, which the
         // board-level tests cover end to end.
-        assert!(registry.get(1).is_some());
+        assert!(registry.get(FLAT).is_some());
+    }
+
+    #[test]
+    fn registry__register_refuses_a_collision() {
+        let mut registry = EffectRegistry::new();
+        registry.register(FLAT, FlatMult(7)).unwrap();
+
+        assert_eq!(
+            registry.register(FLAT, FlatMult(8)),
+            Err(CardError::DuplicateEffectId("test:flat_mult".to_string()))
+        );
+    }
+
+    #[test]
+    fn registry__alias() {
+        let mut registry = EffectRegistry::new();
+        registry.register(FLAT, FlatMult(7)).unwrap();
+        registry.alias(7001, FLAT).unwrap();
+
+        assert_eq!(registry.resolve(EffectId::legacy(7001)), FLAT);
+        assert!(registry.contains(EffectId::legacy(7001)));
+        assert!(!registry.contains(EffectId::legacy(7002)));
+        assert!(registry.alias(7001, FLAT).is_err());
+        assert!(
+            registry
+                .register(EffectId::legacy(7001), FlatMult(1))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn effect_id__parts() {
+        assert_eq!(FLAT.as_str(), "test:flat_mult");
+        assert_eq!(FLAT.namespace(), "test");
+        assert_eq!(FLAT.name(), "flat_mult");
+        assert_eq!(FLAT.to_string(), "test:flat_mult");
+        assert_eq!(format!("{FLAT:?}"), "EffectId(\"test:flat_mult\")");
+        assert!(!FLAT.is_legacy());
+        assert!(EffectId::legacy(7).is_legacy());
+    }
+
+    #[rstest]
+    #[case("mymod:lucky_seven")]
+    #[case("a:b")]
+    #[case("my-mod:x2")]
+    #[case("balance_team:flush_doubler")]
+    #[case("abcdefghijklmnopqrstuvwxyz012345:abcdefghijklmnopqrstuvwxyz0123")]
+    fn effect_id__from_str(#[case] id: &str) {
+        assert_eq!(id.parse::<EffectId>().unwrap().as_str(), id);
+    }
+
+    #[test]
+    fn effect_id__a_parsed_id_is_interned_once() {
+        let first = "balance_team:flush_doubler".parse::<EffectId>().unwrap();
+        let second = "balance_team:flush_doubler".parse::<EffectId>().unwrap();
+
+        assert_eq!(first, second);
+        assert!(std::ptr::eq(first.as_str(), second.as_str()));
+        assert_eq!(first, EffectId::new("balance_team:flush_doubler"));
+    }
+
+    #[test]
+    fn effect_id__a_card_is_no_larger_than_with_a_u32_id() {
+        assert_eq!(size_of::<EffectId>(), 16);
+        assert_eq!(size_of::<MPip>(), 32);
+        assert_eq!(size_of::<BuffoonCard>(), 112 + size_of::<Stickers>());
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("lucky_seven")]
+    #[case(":lucky_seven")]
+    #[case("mymod:")]
+    #[case("my:mod:seven")]
+    #[case("MyMod:seven")]
+    #[case("mymod:lucky seven")]
+    #[case("abcdefghijklmnopqrstuvwxyz012345:abcdefghijklmnopqrstuvwxyz01234")]
+    fn effect_id__from_str__invalid(#[case] id: &str) {
+        assert_eq!(
+            id.parse::<EffectId>(),
+            Err(CardError::InvalidEffectId(id.to_string()))
+        );
+    }
+
+    #[test]
+    fn effect_id__serializes_as_its_string() {
+        assert_eq!(serde_json::to_string(&FLAT).unwrap(), "\"test:flat_mult\"");
+        assert_eq!(
+            serde_json::from_str::<EffectId>("\"test:flat_mult\"").unwrap(),
+            FLAT
+        );
+        assert!(serde_json::from_str::<EffectId>("\"Test\"").is_err());
+    }
+
+    #[test]
+    fn effect_id__reads_a_number_as_legacy() {
+        assert_eq!(
+            serde_json::from_str::<EffectId>("7001").unwrap(),
+            EffectId::legacy(7001)
+        );
+        assert_eq!(EffectId::legacy(7001).as_str(), "legacy:7001");
     }
 }
@@ -0,0 +1,414 @@
+use crate::funky::types::board::BuffoonBoard;
+use crate::funky::types::buffoon_card::BuffoonCard;
+use crate::funky::types::score::Score;
+use crate::funky::types::seed::{RunSeed, Stream};
+use rand::TryRng;
+use rand::rngs::ChaCha12Rng;
+use std::convert::Infallible;
+
+/// A seeded RNG that knows how far it has been drawn.
+///
+/// The run's [`Stream::Roll`] generator — a [`ChaCha12Rng`], which is not
+/// `Clone` but can be told where it is and sent back there. How far it has
+/// been drawn is a [`position`](Self::position), counted in the 32-bit words
+/// it has handed out: [`at`](Self::at) the same seed and position draws
+/// exactly what the original would draw next, and
+/// [`seek`](Self::seek) puts it back at an earlier one. Both are a jump, not a
+/// replay, however many draws the run has taken.
+///
+/// ```
+/// use cardpack::funky::types::history::SeededRng;
+/// use rand::RngExt;
+///
+/// let mut rng = SeededRng::new(7);
+/// let _: u64 = rng.random();
+/// let mut copy = SeededRng::at(7, rng.position());
+///
+/// assert_eq!(rng.random::<u32>(), copy.random::<u32>());
+/// ```
+#[derive(Debug)]
+pub struct SeededRng {
+    seed: RunSeed,
+    rng: ChaCha12Rng,
+}
+
+impl SeededRng {
+    #[must_use]
+    pub fn new(seed: impl Into<RunSeed>) -> Self {
+        Self::at(seed, 0)
+    }
+
+    /// The RNG seeded `seed`, drawn `position` words in.
+    #[must_use]
+    pub fn at(seed: impl Into<RunSeed>, position: u64) -> Self {
+        let seed = seed.into();
+        let mut rng = Self {
+            rng: seed.rng(Stream::Roll),
+            seed,
+        };
+        rng.seek(position);
+        rng
+    }
+
+    #[must_use]
+    pub const fn seed(&self) -> &RunSeed {
+        &self.seed
+    }
+
+    /// How many 32-bit words have been drawn since the seed.
+    #[must_use]
+    pub fn position(&self) -> u64 {
+        u64::try_from(self.rng.get_word_pos()).unwrap_or(u64::MAX)
+    }
+
+    /// Put the RNG `position` words in, as if it had been drawn that far.
+    pub fn seek(&mut self, position: u64) {
+        self.rng.set_word_pos(u128::from(position));
+    }
+}
+
+/// Re-seeded and sent to the same position — the generator itself is not
+/// `Clone`.
+impl Clone for SeededRng {
+    fn clone(&self) -> Self {
+        Self::at(self.seed.clone(), self.position())
+    }
+}
+
+impl PartialEq for SeededRng {
+    fn eq(&self, other: &Self) -> bool {
+        self.seed == other.seed && self.position() == other.position()
+    }
+}
+
+impl Eq for SeededRng {}
+
+impl TryRng for SeededRng {
+    type Error = Infallible;
+
+    fn try_next_u32(&mut self) -> Result<u32, Infallible> {
+        self.rng.try_next_u32()
+    }
+
+    fn try_next_u64(&mut self) -> Result<u64, Infallible> {
+        self.rng.try_next_u64()
+    }
+
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Infallible> {
+        self.rng.try_fill_bytes(dst)
+    }
+}
+
+/// The board as it stood at one point in a [`BoardHistory`], and how far its
+/// RNG had been drawn.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Snapshot {
+    board: BuffoonBoard,
+    position: u64,
+}
+
+/// A [`BuffoonBoard`] with undo and redo, for interactive play.
+///
+/// Each move — [`play_hand`](Self::play_hand),
+/// [`discard_cards`](Self::discard_cards), [`buy_stock`](Self::buy_stock),
+/// [`sell_joker`](Self::sell_joker),
+/// [`use_consumable`](Self::use_consumable), or any board call through
+/// [`apply`](Self::apply) — snapshots the board and the position of its
+/// [`SeededRng`] first. [`undo`](Self::undo) puts both back, so undoing a
+/// play and making it again rolls the same Lucky cards: undo cannot be used to
+/// reroll luck. A move that changes nothing (a refused buy, a discard with none
+/// left) is not recorded, and a new move after an undo drops the redo stack,
+/// as in any editor.
+///
+/// [`branch`](Self::branch) copies the history as it stands, to explore a
+/// what-if without touching the original — both branches draw the same rolls
+/// from there until their moves differ.
+///
+/// Snapshots are whole boards. A board is a few piles of `Copy` cards, so a
+/// snapshot is a handful of small allocations, and there is no diff to get
+/// wrong when a new board field lands.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BoardHistory {
+    board: BuffoonBoard,
+    rng: SeededRng,
+    undo: Vec<Snapshot>,
+    redo: Vec<Snapshot>,
+}
+
+impl BoardHistory {
+    /// A history starting at `board`, drawing from `seed`'s
+    /// [`Stream::Roll`] — the rolls a [`Run`](crate::funky::types::run::Run)
+    /// of the same seed draws from.
+    #[must_use]
+    pub fn new(board: BuffoonBoard, seed: impl Into<RunSeed>) -> Self {
+        Self {
+            board,
+            rng: SeededRng::new(seed),
+            undo: Vec::new(),
+            redo: Vec::new(),
+        }
+    }
+
+    /// The board as it stands.
+    #[must_use]
+    pub const fn board(&self) -> &BuffoonBoard {
+        &self.board
+    }
+
+    #[must_use]
+    pub const fn rng(&self) -> &SeededRng {
+        &self.rng
+    }
+
+    /// How many moves [`undo`](Self::undo) can take back.
+    #[must_use]
+    pub fn undo_depth(&self) -> usize {
+        self.undo.len()
+    }
+
+    /// How many undone moves [`redo`](Self::redo) can make again.
+    #[must_use]
+    pub fn redo_depth(&self) -> usize {
+        self.redo.len()
+    }
+
+    /// Make any move on the board, recorded for undo. `f` gets the board and
+    /// the history's RNG, which is what a `*_with_rng` call should draw from.
+    pub fn apply<T>(&mut self, f: impl FnOnce(&mut BuffoonBoard, &mut SeededRng) -> T) -> T {
+        let before = self.snapshot();
+        let result = f(&mut self.board, &mut self.rng);
+        if before.board != self.board || before.position != self.rng.position() {
+            self.undo.push(before);
+            self.redo.clear();
+        }
+        result
+    }
+
+    /// [`BuffoonBoard::play_hand_with_rng`], recorded.
+    pub fn play_hand(&mut self, indices: &[usize]) -> Option<Score> {
+        self.apply(|board, rng| board.play_hand_with_rng(indices, rng))
+    }
+
+    /// [`BuffoonBoard::discard_cards`], recorded.
+    pub fn discard_cards(&mut self, indices: &[usize]) -> bool {
+        self.apply(|board, _| board.discard_cards(indices))
+    }
+
+    /// [`BuffoonBoard::buy_stock`], recorded.
+    pub fn buy_stock(&mut self, index: usize) -> bool {
+        self.apply(|board, _| board.buy_stock(index))
+    }
+
+    /// [`BuffoonBoard::sell_joker`], recorded.
+    pub fn sell_joker(&mut self, index: usize) -> Option<BuffoonCard> {
+        self.apply(|board, _| board.sell_joker(index))
+    }
+
+    /// [`BuffoonBoard::use_consumable_with_rng`], recorded.
+    pub fn use_consumable(&mut self, index: usize, targets: &[usize]) -> Option<BuffoonCard> {
+        self.apply(|board, rng| board.use_consumable_with_rng(index, targets, rng))
+    }
+
+    /// Take back the last move, board and RNG both. `false` if there is none.
+    pub fn undo(&mut self) -> bool {
+        let Some(snapshot) = self.undo.pop() else {
+            return false;
+        };
+        let current = self.restore(snapshot);
+        self.redo.push(current);
+        true
+    }
+
+    /// Make the last undone move again. `false` if there is none.
+    pub fn redo(&mut self) -> bool {
+        let Some(snapshot) = self.redo.pop() else {
+            return false;
+        };
+        let current = self.restore(snapshot);
+        self.undo.push(current);
+        true
+    }
+
+    /// An independent copy of the history as it stands — undo and redo
+    /// included — to try a line of play on without touching this one.
+    #[must_use]
+    pub fn branch(&self) -> Self {
+        self.clone()
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            board: self.board.clone(),
+            position: self.rng.position(),
+        }
+    }
+
+    /// Put `snapshot` in place, returning what it replaced.
+    fn restore(&mut self, snapshot: Snapshot) -> Snapshot {
+        let current = self.snapshot();
+        self.board = snapshot.board;
+        self.rng.seek(snapshot.position);
+        current
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod funky__types__history_tests {
+    use super::*;
+    use crate::funky::types::draws::Draws;
+    use crate::funky::types::mpip::MPip;
+    use crate::preludes::funky::*;
+    use rand::{Rng, RngExt};
+
+    fn history_holding(index: &str) -> BoardHistory {
+        let mut board = BuffoonBoard::new(Draws::new(4, 3), Deck::basic_buffoon_pile());
+        board.in_hand = bcards!(index);
+        BoardHistory::new(board, 42)
+    }
+
+    #[test]
+    fn seeded_rng__at_draws_what_the_original_draws_next() {
+        let mut rng = SeededRng::new(3);
+        let _: u32 = rng.random();
+        let _: u64 = rng.random();
+        let mut bytes = [0u8; 7];
+        rng.fill_bytes(&mut bytes);
+        assert_eq!(rng.position(), 5);
+
+        let mut copy = SeededRng::at(3, rng.position());
+        let ahead: Vec<u64> = (0..4).map(|_| rng.random()).collect();
+        let again: Vec<u64> = (0..4).map(|_| copy.random()).collect();
+
+        assert_eq!(ahead, again);
+    }
+
+    #[test]
+    fn seeded_rng__skips_past_a_block() {
+        let mut rng = SeededRng::new(3);
+        for _ in 0..1_000 {
+            let _: u32 = rng.random();
+        }
+        let mut copy = rng.clone();
+
+        assert_eq!(rng.random::<u64>(), copy.random::<u64>());
+    }
+
+    #[test]
+    fn seeded_rng__seek_goes_back() {
+        let mut rng = SeededRng::new(3);
+        let first: Vec<u32> = (0..10).map(|_| rng.random()).collect();
+        rng.seek(0);
+
+        assert_eq!(rng.position(), 0);
+        assert_eq!(first, (0..10).map(|_| rng.random()).collect::<Vec<u32>>());
+        assert_eq!(rng, SeededRng::at(3, 10));
+    }
+
+    #[test]
+    fn seeded_rng__draws_the_run_s_rolls() {
+        let seed: RunSeed = "7LB2WVPK".parse().unwrap();
+        let mut rng = SeededRng::new(seed.clone());
+
+        assert_eq!(rng.seed(), &seed);
+        assert_eq!(rng.random::<u64>(), seed.rng(Stream::Roll).random::<u64>());
+    }
+
+    #[test]
+    fn undo__restores_the_board() {
+        let mut history = history_holding("KS KH 2C 5D 9S");
+        let before = history.board().clone();
+
+        assert!(history.play_hand(&[0, 1]).is_some());
+        assert_ne!(history.board(), &before);
+        assert!(history.undo());
+        assert_eq!(history.board(), &before);
+        assert!(!history.undo());
+    }
+
+    #[test]
+    fn undo__restores_the_rng() {
+        let lucky = BuffoonCard {
+            enhancement: MPip::Lucky(5, 15),
+            ..FIVE_HEARTS
+        };
+        let mut history = history_holding("2C 3D");
+        let mut board = history.board().clone();
+        board.in_hand.insert(0, lucky);
+        history = BoardHistory::new(board, 42);
+
+        let first = history.play_hand(&[0]).unwrap();
+        let position = history.rng().position();
+        assert!(position > 0);
+        history.undo();
+        assert_eq!(history.rng().position(), 0);
+        let again = history.play_hand(&[0]).unwrap();
+
+        assert_eq!(first, again, "undo cannot reroll luck");
+        assert_eq!(history.rng().position(), position);
+    }
+
+    #[test]
+    fn redo__makes_the_move_again() {
+        let mut history = history_holding("KS KH 2C 5D 9S");
+        history.discard_cards(&[2, 3]);
+        let after = history.clone();
+
+        history.undo();
+        assert_eq!(history.redo_depth(), 1);
+        assert!(history.redo());
+        assert_eq!(history.board(), after.board());
+        assert_eq!(history.rng(), after.rng());
+        assert!(!history.redo());
+    }
+
+    #[test]
+    fn apply__a_new_move_drops_the_redo_stack() {
+        let mut history = history_holding("KS KH 2C 5D 9S");
+        history.discard_cards(&[2]);
+        history.undo();
+        history.discard_cards(&[3]);
+
+        assert_eq!(history.undo_depth(), 1);
+        assert_eq!(history.redo_depth(), 0);
+    }
+
+    #[test]
+    fn apply__a_refused_move_is_not_recorded() {
+        let mut history = history_holding("KS KH 2C 5D 9S");
+
+        assert!(history.sell_joker(0).is_none());
+        assert!(!history.buy_stock(0));
+        assert!(history.use_consumable(0, &[]).is_none());
+        assert_eq!(history.undo_depth(), 0);
+    }
+
+    #[test]
+    fn sell_joker__undone() {
+        let mut history = history_holding("KS KH");
+        history.apply(|board, _| board.push_joker(JOKER));
+        let money = history.board().money;
+
+        assert!(history.sell_joker(0).is_some());
+        assert!(history.board().jokers.is_empty());
+        history.undo();
+        assert_eq!(history.board().jokers.len(), 1);
+        assert_eq!(history.board().money, money);
+    }
+
+    #[test]
+    fn branch__is_independent() {
+        let mut history = history_holding("KS KH 2C 5D 9S");
+        let mut what_if = history.branch();
+
+        what_if.play_hand(&[0, 1]);
+        history.discard_cards(&[2]);
+
+        assert_eq!(what_if.board().hands_remaining(), 3);
+        assert_eq!(what_if.board().discards_remaining(), 3);
+        assert_eq!(history.board().hands_remaining(), 4);
+        assert_eq!(history.board().discards_remaining(), 2);
+        assert!(what_if.undo());
+        assert_eq!(what_if.board().hands_remaining(), 4);
+    }
+}
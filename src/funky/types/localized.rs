@@ -0,0 +1,1154 @@
+//! Localized names and effect descriptions for the funky types.
+//!
+//! The basic decks are named through [`Named`]; the funky ones through
+//! [`Localized`], from a `funky.ftl` in every locale under the same
+//! [`LOCALES`]. Each card, voucher and boss is one Fluent message: its value is
+//! the name, and its `.description` attribute, where it has one, the
+//! description.
+//!
+//! A card without a `.description` is described by its [`MPip`]. There is a
+//! message per effect — or per family of effects, so the five `MultPlusOn*`
+//! hands share `funky-effect-mult-plus-on-hand` — and its variables are filled
+//! from the payload: `MPip::MultPlusOnPair(8)` reads as "+8 Mult if played hand
+//! contains a Pair". Ranks, suits and hands are themselves looked up in the
+//! requested locale before they are passed in.
+//!
+//! As with the basic decks, a locale missing a message falls back to `en-US`.
+
+use crate::funky::decks::{joker::card as joker, planet::card as planet};
+use crate::funky::decks::{spectral::card as spectral, tarot::card as tarot};
+use crate::funky::types::action::ActionError;
+use crate::funky::types::blind::BossBlind;
+use crate::funky::types::buffoon_card::{BCardType, BuffoonCard};
+use crate::funky::types::hands::HandType;
+use crate::funky::types::mpip::MPip;
+use crate::funky::types::voucher::Voucher;
+use crate::localization::{FluentName, LOCALES, Named};
+use crate::prelude::PipType;
+use fluent_templates::fluent_bundle::FluentValue;
+use fluent_templates::{LanguageIdentifier, Loader};
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+/// The variables a Fluent message is formatted with, as
+/// [`Loader::lookup_with_args`] takes them.
+pub type FluentArgs = HashMap<Cow<'static, str>, FluentValue<'static>>;
+
+/// Localized names and descriptions for the funky types.
+///
+/// ```
+/// use cardpack::funky::types::localized::Localized;
+/// use cardpack::localization::{FluentName, Named};
+/// use cardpack::preludes::funky::*;
+///
+/// assert_eq!(GREEDY_JOKER.fluent_name_default(), "Greedy Joker");
+/// assert_eq!(GREEDY_JOKER.fluent_name(&FluentName::DEUTSCH), "Gieriger Joker");
+/// assert_eq!(
+///     GREEDY_JOKER.fluent_description_default(),
+///     "Played cards with Diamonds suit give +3 Mult when scored"
+/// );
+/// assert_eq!(Voucher::SeedMoney.fluent_name(&FluentName::DEUTSCH), "Startkapital");
+/// ```
+pub trait Localized {
+    /// The id of the Fluent message naming this, e.g. `funky-joker-greedy-joker`.
+    fn fluent_key(&self) -> String;
+
+    /// The variables the name and the description are formatted with. None by
+    /// default.
+    fn fluent_args(&self, _lid: &LanguageIdentifier) -> FluentArgs {
+        FluentArgs::new()
+    }
+
+    #[must_use]
+    fn fluent_name(&self, lid: &LanguageIdentifier) -> String {
+        LOCALES.lookup_with_args(lid, &self.fluent_key(), &self.fluent_args(lid))
+    }
+
+    #[must_use]
+    fn fluent_name_default(&self) -> String {
+        self.fluent_name(&FluentName::US_ENGLISH)
+    }
+
+    /// The message's `.description` attribute.
+    #[must_use]
+    fn fluent_description(&self, lid: &LanguageIdentifier) -> String {
+        let id = format!("{}.description", self.fluent_key());
+        LOCALES.lookup_with_args(lid, &id, &self.fluent_args(lid))
+    }
+
+    #[must_use]
+    fn fluent_description_default(&self) -> String {
+        self.fluent_description(&FluentName::US_ENGLISH)
+    }
+}
+
+/// A card is named by its kind and rank index: jokers, tarots, planets and
+/// spectrals by the const they are, a playing card by its rank and suit. A
+/// joker that is not built in — a
+/// [`CustomJoker`](crate::funky::types::custom_joker::CustomJoker) — is named
+/// by its symbol.
+impl Localized for BuffoonCard {
+    fn fluent_key(&self) -> String {
+        let (kind, cards) = match self.card_type {
+            BCardType::Basic => return "funky-playing-card".to_string(),
+            BCardType::Stone => return "funky-stone-card".to_string(),
+            BCardType::Tarot => ("tarot", TAROTS),
+            BCardType::Planet => ("planet", PLANETS),
+            BCardType::Spectral => ("spectral", SPECTRALS),
+            BCardType::CommonJoker
+            | BCardType::UncommonJoker
+            | BCardType::RareJoker
+            | BCardType::LegendaryJoker => ("joker", JOKERS),
+            BCardType::Voucher => ("card", &[][..]),
+        };
+        cards
+            .iter()
+            .find(|(card, _)| card.rank.index == self.rank.index)
+            .map_or_else(
+                || format!("funky-{kind}-custom"),
+                |(_, slug)| format!("funky-{kind}-{slug}"),
+            )
+    }
+
+    /// The effect's variables, plus `$card-rank` and `$card-suit` for a playing
+    /// card and `$symbol` for anything else.
+    fn fluent_args(&self, lid: &LanguageIdentifier) -> FluentArgs {
+        let (_, mut args) = effect(&self.enhancement, lid);
+        if self.is_basic() {
+            args.insert("card-rank".into(), rank_name(self.rank.index, lid).into());
+            args.insert("card-suit".into(), suit_name(self.suit.index, lid).into());
+        } else {
+            args.insert("symbol".into(), self.rank.symbol.to_string().into());
+        }
+        args
+    }
+
+    /// The card's own `.description` where it has one — a tarot saying what it
+    /// enhances, or a joker whose effect is not an `MPip` — and its effect's
+    /// otherwise.
+    fn fluent_description(&self, lid: &LanguageIdentifier) -> String {
+        let args = self.fluent_args(lid);
+        let id = format!("{}.description", self.fluent_key());
+        LOCALES
+            .try_lookup_with_args(lid, &id, &args)
+            .unwrap_or_else(|| {
+                let (id, _) = effect(&self.enhancement, lid);
+                LOCALES.lookup_with_args(lid, id, &args)
+            })
+    }
+}
+
+impl Localized for Voucher {
+    fn fluent_key(&self) -> String {
+        format!("funky-voucher-{}", kebab(&format!("{self:?}")))
+    }
+}
+
+impl Localized for BossBlind {
+    fn fluent_key(&self) -> String {
+        format!("funky-boss-{}", kebab(&format!("{self:?}")))
+    }
+}
+
+/// A refusal is `funky-action-` and its [`key`](ActionError::key), with
+/// `$position`, `$voucher` and `$base`, or `$cost` and `$money`, from its
+/// payload.
+impl Localized for ActionError {
+    fn fluent_key(&self) -> String {
+        format!("funky-action-{}", self.key())
+    }
+
+    fn fluent_args(&self, lid: &LanguageIdentifier) -> FluentArgs {
+        let args = Args::new(lid);
+        match *self {
+            Self::NoSuchCard(index)
+            | Self::NoSuchJoker(index)
+            | Self::NoSuchConsumable(index)
+            | Self::NoSuchTarget(index)
+            | Self::NoSuchStock(index)
+            | Self::NoSuchPack(index)
+            | Self::Eternal(index)
+            | Self::Pinned(index) => args.n("position", index),
+            Self::VoucherRedeemed(voucher) => args.text("voucher", &voucher.fluent_name(lid)),
+            Self::VoucherRequires { voucher, base } => args
+                .text("voucher", &voucher.fluent_name(lid))
+                .text("base", &base.fluent_name(lid)),
+            Self::NotEnoughMoney { cost, money } => {
+                args.n("cost", cost).with("money", money.into())
+            }
+            _ => args,
+        }
+        .done()
+    }
+}
+
+impl MPip {
+    /// What the effect does, with its payload filled in.
+    ///
+    /// ```
+    /// use cardpack::localization::{FluentName, Named};
+    /// use cardpack::preludes::funky::*;
+    ///
+    /// assert_eq!(
+    ///     MPip::MultPlusOnPair(8).fluent_description_default(),
+    ///     "+8 Mult if played hand contains a Pair"
+    /// );
+    /// assert_eq!(
+    ///     MPip::MultPlusOnPair(8).fluent_description(&FluentName::DEUTSCH),
+    ///     "+8 Mult, wenn die gespielte Hand ein Paar enthält"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn fluent_description(&self, lid: &LanguageIdentifier) -> String {
+        let (id, args) = effect(self, lid);
+        LOCALES.lookup_with_args(lid, id, &args)
+    }
+
+    #[must_use]
+    pub fn fluent_description_default(&self) -> String {
+        self.fluent_description(&FluentName::US_ENGLISH)
+    }
+}
+
+/// The message describing `mpip`, and its variables.
+///
+/// One arm per variant, a family of variants sharing a message where only the
+/// hand or suit differs. `/10` and `/100` payloads are passed as the factor
+/// they stand for, so `MultTimes1Dot(15)` is `$mult` 1.5.
+#[allow(clippy::too_many_lines)]
+fn effect(mpip: &MPip, lid: &LanguageIdentifier) -> (&'static str, FluentArgs) {
+    let args = Args::new(lid);
+    let (id, args) = match *mpip {
+        MPip::Blank => ("funky-effect-blank", args),
+        MPip::AddBaseChips(chips) => ("funky-effect-add-base-chips", args.n("chips", chips)),
+        MPip::AddCardTypeWhenBlindSelected(card_type) => (
+            "funky-effect-add-card-type-when-blind-selected",
+            args.card_type("card-type", card_type),
+        ),
+        MPip::ChanceDestroyed(numerator, denominator) => (
+            "funky-effect-chance-destroyed",
+            args.n("numerator", numerator).n("denominator", denominator),
+        ),
+        MPip::Chips(chips) => ("funky-effect-chips", args.n("chips", chips)),
+        MPip::ChipsMultPlus(chips, mult) => (
+            "funky-effect-chips-mult-plus",
+            args.n("chips", chips).n("mult", mult),
+        ),
+        MPip::ChipsMultPlusOnHand(chips, mult, hand) => (
+            "funky-effect-chips-mult-plus-on-hand",
+            args.n("chips", chips).n("mult", mult).hand("hand", hand),
+        ),
+        MPip::ChipsOnPair(chips) => chips_on(args, chips, HandType::Pair),
+        MPip::ChipsOn2Pair(chips) => chips_on(args, chips, HandType::TwoPair),
+        MPip::ChipsOnTrips(chips) => chips_on(args, chips, HandType::ThreeOfAKind),
+        MPip::ChipsOnStraight(chips) => chips_on(args, chips, HandType::Straight),
+        MPip::ChipsOnFlush(chips) => chips_on(args, chips, HandType::Flush),
+        MPip::ChipsPerRemainingDiscard(chips) => (
+            "funky-effect-chips-per-remaining-discard",
+            args.n("chips", chips),
+        ),
+        MPip::ChipsPlusOn5Ranks(chips, ranks) => (
+            "funky-effect-chips-plus-on-ranks",
+            args.n("chips", chips).ranks("ranks", &ranks),
+        ),
+        MPip::CreateCardOnRankPlay(odds, rank, card_type) => (
+            "funky-effect-create-card-on-rank-play",
+            args.n("odds", odds)
+                .rank("rank", rank)
+                .card_type("card-type", card_type),
+        ),
+        MPip::Credit(money) => ("funky-effect-credit", args.n("money", money)),
+        MPip::Death(count) => ("funky-effect-death", args.n("count", count)),
+        MPip::DoubleMoney(money) => ("funky-effect-double-money", args.n("money", money)),
+        MPip::FourFlushAndStraight => ("funky-effect-four-flush-and-straight", args),
+        MPip::GappedStraight => ("funky-effect-gapped-straight", args),
+        MPip::AllCardsAreFaces => ("funky-effect-all-cards-are-faces", args),
+        MPip::SmearedSuits => ("funky-effect-smeared-suits", args),
+        MPip::AllPlayedCardsScore => ("funky-effect-all-played-cards-score", args),
+        MPip::DoubleOdds => ("funky-effect-double-odds", args),
+        MPip::FreeReroll(count) => ("funky-effect-free-reroll", args.n("count", count)),
+        MPip::Glass(mult, odds) => ("funky-effect-glass", args.n("mult", mult).n("odds", odds)),
+        MPip::Gold(money) => ("funky-effect-gold", args.n("money", money)),
+        MPip::Hanged(count) => ("funky-effect-hanged", args.n("count", count)),
+        MPip::JokersValue(money) => ("funky-effect-jokers-value", args.n("money", money)),
+        MPip::Lucky(mult_odds, money_odds) => (
+            "funky-effect-lucky",
+            args.n("mult-odds", mult_odds).n("money-odds", money_odds),
+        ),
+        MPip::MultPlus(mult) => ("funky-effect-mult-plus", args.n("mult", mult)),
+        MPip::MultPlusChanceDestroyed(mult, numerator, denominator) => (
+            "funky-effect-mult-plus-chance-destroyed",
+            args.n("mult", mult)
+                .n("numerator", numerator)
+                .n("denominator", denominator),
+        ),
+        MPip::MultTimesChanceDestroyed(mult, numerator, denominator) => (
+            "funky-effect-mult-times-chance-destroyed",
+            args.n("mult", mult)
+                .n("numerator", numerator)
+                .n("denominator", denominator),
+        ),
+        MPip::MultPlusChipsOnRank(mult, chips, rank) => (
+            "funky-effect-mult-plus-chips-on-rank",
+            args.n("mult", mult).n("chips", chips).rank("rank", rank),
+        ),
+        MPip::MultPlusDoubleValueDestroyJokerOnRight(mult) => (
+            "funky-effect-mult-plus-double-value-destroy-joker-on-right",
+            args.n("mult", mult),
+        ),
+        MPip::MultPlusOn5Ranks(mult, ranks) => (
+            "funky-effect-mult-plus-on-ranks",
+            args.n("mult", mult).ranks("ranks", &ranks),
+        ),
+        MPip::MultPlusOnConsecutiveHandsNo3Ranks(mult, increment, ranks) => (
+            "funky-effect-mult-plus-on-consecutive-hands-without-ranks",
+            args.n("mult", mult)
+                .n("increment", increment)
+                .ranks("ranks", &ranks),
+        ),
+        MPip::MultPlusOnHandPlays => ("funky-effect-mult-plus-on-hand-plays", args),
+        MPip::MultPlusOnPair(mult) => mult_plus_on(args, mult, HandType::Pair),
+        MPip::MultPlusOn2Pair(mult) => mult_plus_on(args, mult, HandType::TwoPair),
+        MPip::MultPlusOnTrips(mult) => mult_plus_on(args, mult, HandType::ThreeOfAKind),
+        MPip::MultPlusOnStraight(mult) => mult_plus_on(args, mult, HandType::Straight),
+        MPip::MultPlusOnFlush(mult) => mult_plus_on(args, mult, HandType::Flush),
+        MPip::MultPlusOnSuit(mult, suit) => (
+            "funky-effect-mult-plus-on-suit",
+            args.n("mult", mult).suit("suit", suit),
+        ),
+        MPip::MultPlusOnUpToXCards(mult, count) => (
+            "funky-effect-mult-plus-on-up-to-x-cards",
+            args.n("mult", mult).n("count", count),
+        ),
+        MPip::MultPlusOnZeroDiscards(mult) | MPip::MultPlusZeroDiscards(mult) => (
+            "funky-effect-mult-plus-on-zero-discards",
+            args.n("mult", mult),
+        ),
+        MPip::MultPlusXOnLowestRankInHand(factor) => (
+            "funky-effect-mult-plus-x-on-lowest-rank-in-hand",
+            args.n("factor", factor),
+        ),
+        MPip::MultPlusRandomTo(mult) => ("funky-effect-mult-plus-random-to", args.n("mult", mult)),
+        MPip::MultTimes(mult) => ("funky-effect-mult-times", args.n("mult", mult)),
+        MPip::MultTimesEveryXHands(mult, hands) => (
+            "funky-effect-mult-times-every-x-hands",
+            args.n("mult", mult).n("hands", hands),
+        ),
+        MPip::MultTimesOnEmptyJokerSlots(mult) => (
+            "funky-effect-mult-times-on-empty-joker-slots",
+            args.n("mult", mult),
+        ),
+        MPip::MultTimes1Dot(tenths) => (
+            "funky-effect-mult-times-held",
+            args.fraction("mult", tenths, 10),
+        ),
+        MPip::MultTimesOnPair(mult) => mult_times_on(args, mult, HandType::Pair),
+        MPip::MultTimesOnTrips(mult) => mult_times_on(args, mult, HandType::ThreeOfAKind),
+        MPip::MultTimesOn4OfAKind(mult) => mult_times_on(args, mult, HandType::FourOfAKind),
+        MPip::MultTimesOnStraight(mult) => mult_times_on(args, mult, HandType::Straight),
+        MPip::MultTimesOnFlush(mult) => mult_times_on(args, mult, HandType::Flush),
+        MPip::MultTimesPerScoredRank(mult, [first, second]) => (
+            "funky-effect-mult-times-per-scored-rank",
+            args.n("mult", mult)
+                .rank("first", first)
+                .rank("second", second),
+        ),
+        MPip::MultPlusPerJoker(mult) => ("funky-effect-mult-plus-per-joker", args.n("mult", mult)),
+        MPip::ChipsPerDeckCard(chips) => {
+            ("funky-effect-chips-per-deck-card", args.n("chips", chips))
+        }
+        MPip::MultTimesPlusPerFullDeckSteel(tenths) => (
+            "funky-effect-mult-times-plus-per-full-deck-steel",
+            args.fraction("mult", tenths, 10),
+        ),
+        MPip::ChipsPerFullDeckStone(chips) => (
+            "funky-effect-chips-per-full-deck-stone",
+            args.n("chips", chips),
+        ),
+        MPip::MultPlusPerMissingDeckCard(mult) => (
+            "funky-effect-mult-plus-per-missing-deck-card",
+            args.n("mult", mult),
+        ),
+        MPip::MultTimesPerHeldRank(tenths, rank) => (
+            "funky-effect-mult-times-per-held-rank",
+            args.fraction("mult", tenths, 10).rank("rank", rank),
+        ),
+        MPip::ChipsPlusPerScoredFace(chips) => (
+            "funky-effect-chips-plus-per-scored-face",
+            args.n("chips", chips),
+        ),
+        MPip::ChipsMultPlusPerScoredRanks(chips, mult, [first, second]) => (
+            "funky-effect-chips-mult-plus-per-scored-ranks",
+            args.n("chips", chips)
+                .n("mult", mult)
+                .rank("first", first)
+                .rank("second", second),
+        ),
+        MPip::MultTimesPerUncommonJoker(tenths) => (
+            "funky-effect-mult-times-per-uncommon-joker",
+            args.fraction("mult", tenths, 10),
+        ),
+        MPip::MultTimesIfHeldAllSuits(mult, [first, second]) => (
+            "funky-effect-mult-times-if-held-all-suits",
+            args.n("mult", mult)
+                .suit("first", first)
+                .suit("second", second),
+        ),
+        MPip::ChipsPerDollar(chips) => ("funky-effect-chips-per-dollar", args.n("chips", chips)),
+        MPip::CashOnRoundEnd(money) => ("funky-effect-cash-on-round-end", args.n("money", money)),
+        MPip::CashPerDiscardIfNoneUsed(money) => (
+            "funky-effect-cash-per-discard-if-none-used",
+            args.n("money", money),
+        ),
+        MPip::CashPerFullDeckRank(money, rank) => (
+            "funky-effect-cash-per-full-deck-rank",
+            args.n("money", money).rank("rank", rank),
+        ),
+        MPip::ExtraInterest(money) => ("funky-effect-extra-interest", args.n("money", money)),
+        MPip::CashOnFacesDiscarded(money, count) => (
+            "funky-effect-cash-on-faces-discarded",
+            args.n("money", money).n("count", count),
+        ),
+        MPip::HandSizeIncrement(count) => {
+            ("funky-effect-hand-size-increment", args.n("count", count))
+        }
+        MPip::DiscardIncrement(count) => ("funky-effect-discard-increment", args.n("count", count)),
+        MPip::GainHandsLoseDiscardsWhenBlindSelected(count) => (
+            "funky-effect-gain-hands-lose-discards-when-blind-selected",
+            args.n("count", count),
+        ),
+        MPip::GainMultPerHandLessDiscard(mult) => (
+            "funky-effect-gain-mult-per-hand-less-discard",
+            args.n("mult", mult),
+        ),
+        MPip::LoseMultTimesPerDiscard(base, per) => (
+            "funky-effect-lose-mult-times-per-discard",
+            args.fraction("mult", base, 100).fraction("loss", per, 100),
+        ),
+        MPip::LoseChipsPerHand(base, per) => (
+            "funky-effect-lose-chips-per-hand",
+            args.n("chips", base).n("loss", per),
+        ),
+        MPip::GainChipsPerCardCountHand(chips, count) => (
+            "funky-effect-gain-chips-per-card-count-hand",
+            args.n("chips", chips).n("count", count),
+        ),
+        MPip::GainMultPerTwoPairHand(mult) => (
+            "funky-effect-gain-mult-per-hand",
+            args.n("mult", mult).hand("hand", HandType::TwoPair),
+        ),
+        MPip::GainChipsPerStraightHand(chips) => (
+            "funky-effect-gain-chips-per-hand",
+            args.n("chips", chips).hand("hand", HandType::Straight),
+        ),
+        MPip::GainChipsOnScored(chips) => {
+            ("funky-effect-gain-chips-on-scored", args.n("chips", chips))
+        }
+        MPip::LoseMultPerRound(base, per) => (
+            "funky-effect-lose-mult-per-round",
+            args.n("mult", base).n("loss", per),
+        ),
+        MPip::GainMultTimesPerDiscardedCards(rate, count) => (
+            "funky-effect-gain-mult-times-per-discarded-cards",
+            args.fraction("mult", rate, 100).n("count", count),
+        ),
+        MPip::GainMultTimesPerCardAdded(rate) => (
+            "funky-effect-gain-mult-times-per-card-added",
+            args.fraction("mult", rate, 100),
+        ),
+        MPip::GainMultTimesPerFaceDestroyed(rate) => (
+            "funky-effect-gain-mult-times-per-face-destroyed",
+            args.fraction("mult", rate, 100),
+        ),
+        MPip::CreateJokersWhenBlindSelected(count, card_type) => (
+            "funky-effect-create-jokers-when-blind-selected",
+            args.n("count", count).card_type("card-type", card_type),
+        ),
+        MPip::CreateTarotOnAceStraight => ("funky-effect-create-tarot-on-ace-straight", args),
+        MPip::CreateTarotOnLowMoney(money) => (
+            "funky-effect-create-tarot-on-low-money",
+            args.n("money", money),
+        ),
+        MPip::CreateSpectralOnStraightFlush => {
+            ("funky-effect-create-spectral-on-straight-flush", args)
+        }
+        MPip::CreateSpectralOnFirstSingleSix => {
+            ("funky-effect-create-spectral-on-first-single-six", args)
+        }
+        MPip::SpectralLevelAllHands => ("funky-effect-spectral-level-all-hands", args),
+        MPip::SpectralCreateLegendaryJoker => {
+            ("funky-effect-spectral-create-legendary-joker", args)
+        }
+        MPip::SpectralCreateRareJokerZeroMoney => {
+            ("funky-effect-spectral-create-rare-joker-zero-money", args)
+        }
+        MPip::SpectralNegativeRandomJokerMinusHandSize => (
+            "funky-effect-spectral-negative-random-joker-minus-hand-size",
+            args,
+        ),
+        MPip::SpectralPolychromeRandomJokerDestroyOthers => (
+            "funky-effect-spectral-polychrome-random-joker-destroy-others",
+            args,
+        ),
+        MPip::SpectralCopyRandomJokerDestroyOthers => (
+            "funky-effect-spectral-copy-random-joker-destroy-others",
+            args,
+        ),
+        MPip::SpectralEditionRandomHandCard => {
+            ("funky-effect-spectral-edition-random-hand-card", args)
+        }
+        MPip::SpectralHandToRandomSuit => ("funky-effect-spectral-hand-to-random-suit", args),
+        MPip::SpectralHandToRandomRankMinusHandSize => (
+            "funky-effect-spectral-hand-to-random-rank-minus-hand-size",
+            args,
+        ),
+        MPip::SpectralDestroyRandomHandGainMoney(count, money) => (
+            "funky-effect-spectral-destroy-random-hand-gain-money",
+            args.n("count", count).n("money", money),
+        ),
+        MPip::SpectralDestroyOneAddEnhancedFaces(count) => (
+            "funky-effect-spectral-destroy-one-add-enhanced-faces",
+            args.n("count", count),
+        ),
+        MPip::SpectralDestroyOneAddEnhancedAces(count) => (
+            "funky-effect-spectral-destroy-one-add-enhanced-aces",
+            args.n("count", count),
+        ),
+        MPip::SpectralDestroyOneAddEnhancedNumbered(count) => (
+            "funky-effect-spectral-destroy-one-add-enhanced-numbered",
+            args.n("count", count),
+        ),
+        MPip::SpectralCopySelectedHandCard(count) => (
+            "funky-effect-spectral-copy-selected-hand-card",
+            args.n("count", count),
+        ),
+        MPip::MultTimesOnRepeatedHandThisRound(mult) => (
+            "funky-effect-mult-times-on-repeated-hand-this-round",
+            args.n("mult", mult),
+        ),
+        MPip::MultTimesPerScoredAncientSuit(tenths) => (
+            "funky-effect-mult-times-per-scored-ancient-suit",
+            args.fraction("mult", tenths, 10),
+        ),
+        MPip::GainMultTimesOnNonBossBlindDestroyingJoker(rate) => (
+            "funky-effect-gain-mult-times-on-non-boss-blind-destroying-joker",
+            args.fraction("mult", rate, 100),
+        ),
+        MPip::DisableBossBlindOnSell => ("funky-effect-disable-boss-blind-on-sell", args),
+        MPip::DisablesAllBossBlinds => ("funky-effect-disables-all-boss-blinds", args),
+        MPip::CashOnRoundEndGrowingOnBossDefeat(money, increase) => (
+            "funky-effect-cash-on-round-end-growing-on-boss-defeat",
+            args.n("money", money).n("increase", increase),
+        ),
+        MPip::GainMultTimesPerPlanetUsed(rate) => (
+            "funky-effect-gain-mult-times-per-planet-used",
+            args.fraction("mult", rate, 100),
+        ),
+        MPip::MultPlusPerTarotUsedThisRun(mult) => (
+            "funky-effect-mult-plus-per-tarot-used-this-run",
+            args.n("mult", mult),
+        ),
+        MPip::MultPlusPerReroll(mult) => {
+            ("funky-effect-mult-plus-per-reroll", args.n("mult", mult))
+        }
+        MPip::MultPlusPerPackSkipped(mult) => (
+            "funky-effect-mult-plus-per-pack-skipped",
+            args.n("mult", mult),
+        ),
+        MPip::CreateTarotOnPackOpen(numerator, denominator) => (
+            "funky-effect-create-tarot-on-pack-open",
+            args.n("numerator", numerator).n("denominator", denominator),
+        ),
+        MPip::CreateNegativeConsumableCopy => {
+            ("funky-effect-create-negative-consumable-copy", args)
+        }
+        MPip::GainMultTimesPerEnhancedPlayed(rate) => (
+            "funky-effect-gain-mult-times-per-enhanced-played",
+            args.fraction("mult", rate, 100),
+        ),
+        MPip::GainMultTimesPerLuckyProc(rate) => (
+            "funky-effect-gain-mult-times-per-lucky-proc",
+            args.fraction("mult", rate, 100),
+        ),
+        MPip::CopyJokerToRight => ("funky-effect-copy-joker-to-right", args),
+        MPip::CopyLeftmostJoker => ("funky-effect-copy-leftmost-joker", args),
+        MPip::Planet(count) => ("funky-effect-planet", args.n("count", count)),
+        MPip::RandomJoker(count) => ("funky-effect-random-joker", args.n("count", count)),
+        MPip::RandomTarot(count) => ("funky-effect-random-tarot", args.n("count", count)),
+        MPip::RetriggerCardsInHand(count) => (
+            "funky-effect-retrigger-cards-in-hand",
+            args.n("count", count),
+        ),
+        MPip::RetriggerPlayedCardsInFinalRound => {
+            ("funky-effect-retrigger-played-cards-in-final-round", args)
+        }
+        MPip::RetriggerPlayedRanks(count, ranks) => (
+            "funky-effect-retrigger-played-ranks",
+            args.n("count", count).ranks("ranks", &ranks),
+        ),
+        MPip::RetriggerPlayedFaces(count) => (
+            "funky-effect-retrigger-played-faces",
+            args.n("count", count),
+        ),
+        MPip::RetriggerFirstPlayed(count) => (
+            "funky-effect-retrigger-first-played",
+            args.n("count", count),
+        ),
+        MPip::RetriggerAllPlayedForHands(count, hands) => (
+            "funky-effect-retrigger-all-played-for-hands",
+            args.n("count", count).n("hands", hands),
+        ),
+        MPip::SellValueIncrement(money) => {
+            ("funky-effect-sell-value-increment", args.n("money", money))
+        }
+        MPip::Stone(chips) => ("funky-effect-stone", args.n("chips", chips)),
+        MPip::Strength => ("funky-effect-strength", args),
+        MPip::Odds1in(odds) => ("funky-effect-odds-1-in", args.n("odds", odds)),
+        MPip::Odds1inCashOn3Ranks(odds, money, ranks) => (
+            "funky-effect-odds-1-in-cash-on-ranks",
+            args.n("odds", odds)
+                .n("money", money)
+                .ranks("ranks", &ranks),
+        ),
+        MPip::Odds1inUpgradeHand(odds) => {
+            ("funky-effect-odds-1-in-upgrade-hand", args.n("odds", odds))
+        }
+        MPip::Wild(pip_type) => {
+            let wild = if pip_type == PipType::Rank {
+                "rank"
+            } else {
+                "suit"
+            };
+            ("funky-effect-wild", args.text("wild", wild))
+        }
+        MPip::Diamonds(count) => to_suit(args, count, 'D'),
+        MPip::Clubs(count) => to_suit(args, count, 'C'),
+        MPip::Hearts(count) => to_suit(args, count, 'H'),
+        MPip::Spades(count) => to_suit(args, count, 'S'),
+        MPip::Custom(id) => ("funky-effect-custom", args.text("effect", id.as_str())),
+    };
+    (id, args.done())
+}
+
+fn chips_on(args: Args<'_>, chips: usize, hand: HandType) -> (&'static str, Args<'_>) {
+    (
+        "funky-effect-chips-on-hand",
+        args.n("chips", chips).hand("hand", hand),
+    )
+}
+
+fn mult_plus_on(args: Args<'_>, mult: usize, hand: HandType) -> (&'static str, Args<'_>) {
+    (
+        "funky-effect-mult-plus-on-hand",
+        args.n("mult", mult).hand("hand", hand),
+    )
+}
+
+fn mult_times_on(args: Args<'_>, mult: usize, hand: HandType) -> (&'static str, Args<'_>) {
+    (
+        "funky-effect-mult-times-on-hand",
+        args.n("mult", mult).hand("hand", hand),
+    )
+}
+
+fn to_suit(args: Args<'_>, count: usize, suit: char) -> (&'static str, Args<'_>) {
+    (
+        "funky-effect-to-suit",
+        args.n("count", count).suit("suit", suit),
+    )
+}
+
+/// [`FluentArgs`] under construction, with the locale the names among them
+/// are looked up in.
+struct Args<'a> {
+    lid: &'a LanguageIdentifier,
+    args: FluentArgs,
+}
+
+impl<'a> Args<'a> {
+    fn new(lid: &'a LanguageIdentifier) -> Self {
+        Self {
+            lid,
+            args: FluentArgs::new(),
+        }
+    }
+
+    fn with(mut self, name: &'static str, value: FluentValue<'static>) -> Self {
+        self.args.insert(Cow::Borrowed(name), value);
+        self
+    }
+
+    fn n(self, name: &'static str, n: usize) -> Self {
+        self.with(name, n.into())
+    }
+
+    /// `n` over `denominator`, for the payloads kept in tenths or hundredths.
+    #[allow(clippy::cast_precision_loss)]
+    fn fraction(self, name: &'static str, n: usize, denominator: usize) -> Self {
+        self.with(name, (n as f64 / denominator as f64).into())
+    }
+
+    fn text(self, name: &'static str, text: &str) -> Self {
+        self.with(name, text.to_string().into())
+    }
+
+    fn rank(self, name: &'static str, rank: char) -> Self {
+        let rank = rank_name(rank, self.lid);
+        self.with(name, rank.into())
+    }
+
+    /// The ranks' names, comma separated.
+    fn ranks(self, name: &'static str, ranks: &[char]) -> Self {
+        let ranks = ranks
+            .iter()
+            .map(|rank| rank_name(*rank, self.lid))
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.with(name, ranks.into())
+    }
+
+    fn suit(self, name: &'static str, suit: char) -> Self {
+        let suit = suit_name(suit, self.lid);
+        self.with(name, suit.into())
+    }
+
+    fn hand(self, name: &'static str, hand: HandType) -> Self {
+        let id = format!("funky-hand-{}", kebab(&format!("{hand:?}")));
+        let hand = LOCALES.lookup(self.lid, &id);
+        self.with(name, hand.into())
+    }
+
+    fn card_type(self, name: &'static str, card_type: BCardType) -> Self {
+        let id = format!("funky-card-type-{}", kebab(&format!("{card_type:?}")));
+        let card_type = LOCALES.lookup(self.lid, &id);
+        self.with(name, card_type.into())
+    }
+
+    fn done(self) -> FluentArgs {
+        self.args
+    }
+}
+
+/// A French rank's name, from the basic decks' `french.ftl`.
+fn rank_name(rank: char, lid: &LanguageIdentifier) -> String {
+    LOCALES.lookup(
+        lid,
+        &format!("name-rank-french-{}", rank.to_ascii_lowercase()),
+    )
+}
+
+/// A French suit's name, from the basic decks' `french.ftl`.
+fn suit_name(suit: char, lid: &LanguageIdentifier) -> String {
+    LOCALES.lookup(
+        lid,
+        &format!("name-suit-french-{}", suit.to_ascii_lowercase()),
+    )
+}
+
+/// `ThreeOfAKind` as `three-of-a-kind`: a variant name as a Fluent id segment.
+fn kebab(name: &str) -> String {
+    let mut kebab = String::with_capacity(name.len() + 4);
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            kebab.push('-');
+        }
+        kebab.push(c.to_ascii_lowercase());
+    }
+    kebab
+}
+
+const JOKERS: &[(BuffoonCard, &str)] = &[
+    (joker::JOKER, "joker"),
+    (joker::GREEDY_JOKER, "greedy-joker"),
+    (joker::LUSTY_JOKER, "lusty-joker"),
+    (joker::WRATHFUL_JOKER, "wrathful-joker"),
+    (joker::GLUTTONOUS_JOKER, "gluttonous-joker"),
+    (joker::JOLLY_JOKER, "jolly-joker"),
+    (joker::ZANY_JOKER, "zany-joker"),
+    (joker::MAD_JOKER, "mad-joker"),
+    (joker::CRAZY_JOKER, "crazy-joker"),
+    (joker::DROLL_JOKER, "droll-joker"),
+    (joker::SLY_JOKER, "sly-joker"),
+    (joker::WILY_JOKER, "wily-joker"),
+    (joker::CLEVER_JOKER, "clever-joker"),
+    (joker::DEVIOUS_JOKER, "devious-joker"),
+    (joker::CRAFTY_JOKER, "crafty-joker"),
+    (joker::HALF_JOKER, "half-joker"),
+    (joker::JOKER_STENCIL, "joker-stencil"),
+    (joker::FOUR_FINGERS, "four-fingers"),
+    (joker::MIME, "mime"),
+    (joker::CREDIT_CARD, "credit-card"),
+    (joker::CEREMONIAL_DAGGER, "ceremonial-dagger"),
+    (joker::BANNER, "banner"),
+    (joker::MYSTIC_SUMMIT, "mystic-summit"),
+    (joker::MARBLE_JOKER, "marble-joker"),
+    (joker::LOYALTY_CARD, "loyalty-card"),
+    (joker::EIGHT_BALL, "eight-ball"),
+    (joker::MISPRINT, "misprint"),
+    (joker::DUSK, "dusk"),
+    (joker::RAISED_FIST, "raised-fist"),
+    (joker::CHAOS_THE_CLOWN, "chaos-the-clown"),
+    (joker::FIBONACCI, "fibonacci"),
+    (joker::STEEL_JOKER, "steel-joker"),
+    (joker::SCARY_FACE, "scary-face"),
+    (joker::ABSTRACT_JOKER, "abstract-joker"),
+    (joker::DELAYED_GRATIFICATION, "delayed-gratification"),
+    (joker::HACK, "hack"),
+    (joker::PAREIDOLIA, "pareidolia"),
+    (joker::GROS_MICHEL, "gros-michel"),
+    (joker::EVEN_STEVEN, "even-steven"),
+    (joker::ODD_TODD, "odd-todd"),
+    (joker::SCHOLAR, "scholar"),
+    (joker::BUSINESS_CARD, "business-card"),
+    (joker::SUPERNOVA, "supernova"),
+    (joker::RIDE_THE_BUS, "ride-the-bus"),
+    (joker::SPACE_JOKER, "space-joker"),
+    (joker::EGG, "egg"),
+    (joker::BURGLAR, "burglar"),
+    (joker::BLACKBOARD, "blackboard"),
+    (joker::RUNNER, "runner"),
+    (joker::ICE_CREAM, "ice-cream"),
+    (joker::DNA, "dna"),
+    (joker::SPLASH, "splash"),
+    (joker::BLUE_JOKER, "blue-joker"),
+    (joker::SIXTH_SENSE, "sixth-sense"),
+    (joker::CONSTELLATION, "constellation"),
+    (joker::HIKER, "hiker"),
+    (joker::FACELESS_JOKER, "faceless-joker"),
+    (joker::GREEN_JOKER, "green-joker"),
+    (joker::SUPERPOSITION, "superposition"),
+    (joker::TO_DO_LIST, "to-do-list"),
+    (joker::CAVENDISH, "cavendish"),
+    (joker::CARD_SHARP, "card-sharp"),
+    (joker::RED_CARD, "red-card"),
+    (joker::MADNESS, "madness"),
+    (joker::SQUARE_JOKER, "square-joker"),
+    (joker::SEANCE, "seance"),
+    (joker::RIFF_RAFF, "riff-raff"),
+    (joker::VAMPIRE, "vampire"),
+    (joker::SHORTCUT, "shortcut"),
+    (joker::HOLOGRAM, "hologram"),
+    (joker::VAGABOND, "vagabond"),
+    (joker::BARON, "baron"),
+    (joker::CLOUD_9, "cloud-9"),
+    (joker::ROCKET, "rocket"),
+    (joker::EROSION, "erosion"),
+    (joker::RESERVED_PARKING, "reserved-parking"),
+    (joker::MAIL_IN_REBATE, "mail-in-rebate"),
+    (joker::TO_THE_MOON, "to-the-moon"),
+    (joker::HALLUCINATION, "hallucination"),
+    (joker::FORTUNE_TELLER, "fortune-teller"),
+    (joker::JUGGLER, "juggler"),
+    (joker::DRUNKARD, "drunkard"),
+    (joker::STONE_JOKER, "stone-joker"),
+    (joker::GOLDEN_JOKER, "golden-joker"),
+    (joker::LUCKY_CAT, "lucky-cat"),
+    (joker::BASEBALL_CARD, "baseball-card"),
+    (joker::BULL, "bull"),
+    (joker::DIET_COLA, "diet-cola"),
+    (joker::TRADING_CARD, "trading-card"),
+    (joker::FLASH_CARD, "flash-card"),
+    (joker::POPCORN, "popcorn"),
+    (joker::SPARE_TROUSERS, "spare-trousers"),
+    (joker::ANCIENT_JOKER, "ancient-joker"),
+    (joker::RAMEN, "ramen"),
+    (joker::WALKIE_TALKIE, "walkie-talkie"),
+    (joker::SOCK_AND_BUSKIN, "sock-and-buskin"),
+    (joker::LUCHADOR, "luchador"),
+    (joker::MATADOR, "matador"),
+    (joker::SELTZER, "seltzer"),
+    (joker::SMEARED_JOKER, "smeared-joker"),
+    (joker::OOPS_ALL_6S, "oops-all-6s"),
+    (joker::HANGING_CHAD, "hanging-chad"),
+    (joker::THE_DUO, "the-duo"),
+    (joker::THE_TRIO, "the-trio"),
+    (joker::THE_FAMILY, "the-family"),
+    (joker::THE_ORDER, "the-order"),
+    (joker::THE_TRIBE, "the-tribe"),
+    (joker::BLUEPRINT, "blueprint"),
+    (joker::BRAINSTORM, "brainstorm"),
+    (joker::CANIO, "canio"),
+    (joker::TRIBOULET, "triboulet"),
+    (joker::YORICK, "yorick"),
+    (joker::CHICOT, "chicot"),
+    (joker::PERKEO, "perkeo"),
+];
+
+const TAROTS: &[(BuffoonCard, &str)] = &[
+    (tarot::FOOL, "fool"),
+    (tarot::MAGICIAN, "magician"),
+    (tarot::HIGH_PRIESTESS, "high-priestess"),
+    (tarot::EMPRESS, "empress"),
+    (tarot::EMPEROR, "emperor"),
+    (tarot::HIEROPHANT, "hierophant"),
+    (tarot::LOVERS, "lovers"),
+    (tarot::THE_CHARIOT, "chariot"),
+    (tarot::STRENGTH, "strength"),
+    (tarot::HERMIT, "hermit"),
+    (tarot::WHEEL_OF_FORTUNE, "wheel-of-fortune"),
+    (tarot::JUSTICE, "justice"),
+    (tarot::HANGED_MAN, "hanged-man"),
+    (tarot::DEATH, "death"),
+    (tarot::TEMPERANCE, "temperance"),
+    (tarot::DEVIL, "devil"),
+    (tarot::TOWER, "tower"),
+    (tarot::STAR, "star"),
+    (tarot::MOON, "moon"),
+    (tarot::SUN, "sun"),
+    (tarot::JUDGEMENT, "judgement"),
+    (tarot::WORLD, "world"),
+];
+
+const PLANETS: &[(BuffoonCard, &str)] = &[
+    (planet::PLUTO, "pluto"),
+    (planet::MERCURY, "mercury"),
+    (planet::URANUS, "uranus"),
+    (planet::VENUS, "venus"),
+    (planet::SATURN, "saturn"),
+    (planet::JUPITER, "jupiter"),
+    (planet::EARTH, "earth"),
+    (planet::MARS, "mars"),
+    (planet::NEPTUNE, "neptune"),
+    (planet::PLANET_X, "planet-x"),
+    (planet::CERES, "ceres"),
+    (planet::ERIS, "eris"),
+];
+
+const SPECTRALS: &[(BuffoonCard, &str)] = &[
+    (spectral::FAMILIAR, "familiar"),
+    (spectral::GRIM, "grim"),
+    (spectral::INCANTATION, "incantation"),
+    (spectral::TALISMAN, "talisman"),
+    (spectral::AURA, "aura"),
+    (spectral::WRAITH, "wraith"),
+    (spectral::SIGIL, "sigil"),
+    (spectral::OUIJA, "ouija"),
+    (spectral::ECTOPLASM, "ectoplasm"),
+    (spectral::IMMOLATE, "immolate"),
+    (spectral::ANKH, "ankh"),
+    (spectral::DEJA_VU, "deja-vu"),
+    (spectral::HEX, "hex"),
+    (spectral::TRANCE, "trance"),
+    (spectral::MEDIUM, "medium"),
+    (spectral::CRYPTID, "cryptid"),
+    (spectral::THE_SOUL, "the-soul"),
+    (spectral::BLACK_HOLE, "black-hole"),
+];
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod funky__types__localized_tests {
+    use super::*;
+    use crate::funky::decks::basic;
+    use crate::funky::types::custom_joker::CustomJoker;
+    use crate::funky::types::edition::Edition;
+
+    fn is_known(text: &str) -> bool {
+        !text.is_empty() && !text.starts_with("Unknown localization key")
+    }
+
+    fn cards() -> impl Iterator<Item = &'static (BuffoonCard, &'static str)> {
+        JOKERS.iter().chain(TAROTS).chain(PLANETS).chain(SPECTRALS)
+    }
+
+    #[test]
+    fn fluent_key__every_card_is_its_own() {
+        for (card, slug) in cards() {
+            assert!(card.fluent_key().ends_with(slug), "{slug}");
+        }
+    }
+
+    #[test]
+    fn fluent_name__every_card_voucher_and_boss() {
+        for lid in [FluentName::US_ENGLISH, FluentName::DEUTSCH] {
+            for (card, slug) in cards() {
+                assert!(is_known(&card.fluent_name(&lid)), "{lid} {slug}");
+            }
+            for voucher in Voucher::ALL {
+                assert!(is_known(&voucher.fluent_name(&lid)), "{lid} {voucher:?}");
+            }
+            for boss in BossBlind::ALL {
+                assert!(is_known(&boss.fluent_name(&lid)), "{lid} {boss:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn fluent_name__playing_card() {
+        assert_eq!(
+            basic::card::ACE_SPADES.fluent_name_default(),
+            "Ace of Spades"
+        );
+        assert_eq!(
+            basic::card::ACE_SPADES.fluent_name(&FluentName::DEUTSCH),
+            "Ass Spaten"
+        );
+    }
+
+    #[test]
+    fn fluent_name__tarot_from_the_tarot_deck() {
+        assert_eq!(tarot::FOOL.fluent_name_default(), "The Fool");
+        assert_eq!(
+            tarot::WHEEL_OF_FORTUNE.fluent_name_default(),
+            "Wheel of Fortune"
+        );
+    }
+
+    #[test]
+    fn fluent_name__custom_joker_by_its_symbol() {
+        let card = CustomJoker {
+            name: "Mime Artist".to_string(),
+            symbol: '🤹',
+            rarity: BCardType::UncommonJoker,
+            cost: 7,
+            effect: MPip::MultPlus(8),
+            edition: Edition::None,
+        }
+        .card()
+        .unwrap();
+
+        assert_eq!(card.fluent_key(), "funky-joker-custom");
+        assert_eq!(card.fluent_name_default(), "Joker 🤹");
+        assert_eq!(card.fluent_description_default(), "+8 Mult");
+    }
+
+    #[test]
+    fn fluent_name__falls_back_to_english() {
+        assert_eq!(
+            joker::GREEDY_JOKER.fluent_name(&FluentName::FRANCAIS),
+            "Greedy Joker"
+        );
+    }
+
+    #[test]
+    fn fluent_description__every_card_voucher_and_boss() {
+        for lid in [FluentName::US_ENGLISH, FluentName::DEUTSCH] {
+            for (card, slug) in cards() {
+                assert!(is_known(&card.fluent_description(&lid)), "{lid} {slug}");
+            }
+            for voucher in Voucher::ALL {
+                assert!(
+                    is_known(&voucher.fluent_description(&lid)),
+                    "{lid} {voucher:?}"
+                );
+            }
+            for boss in BossBlind::ALL {
+                assert!(is_known(&boss.fluent_description(&lid)), "{lid} {boss:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn fluent_description__fractions() {
+        assert_eq!(
+            joker::STEEL_JOKER.fluent_description_default(),
+            "Gains ×0.2 Mult for each Steel Card in the full deck"
+        );
+        assert_eq!(
+            joker::BARON.fluent_description_default(),
+            "Each King held in hand gives ×1.5 Mult"
+        );
+    }
+
+    #[test]
+    fn fluent_description__ranks() {
+        assert_eq!(
+            MPip::MultTimesPerScoredRank(2, ['K', 'Q']).fluent_description_default(),
+            "Each played King or Queen gives ×2 Mult when scored"
+        );
+        assert_eq!(
+            MPip::MultPlusChipsOnRank(4, 31, 'A').fluent_description(&FluentName::DEUTSCH),
+            "Jede gespielte Ass gibt beim Werten +31 Chips und +4 Mult"
+        );
+    }
+
+    #[test]
+    fn fluent_description__plural() {
+        assert_eq!(
+            MPip::FreeReroll(1).fluent_description_default(),
+            "1 free reroll per shop"
+        );
+        assert_eq!(
+            MPip::FreeReroll(2).fluent_description_default(),
+            "2 free rerolls per shop"
+        );
+    }
+
+    #[test]
+    fn fluent_description__tarot_refers_to_its_enhancement() {
+        assert_eq!(
+            tarot::MAGICIAN.fluent_description_default(),
+            "Enhances up to 2 selected cards into Lucky Cards: \
+             1 in 5 chance for +20 Mult, 1 in 15 chance to win $20"
+        );
+    }
+
+    #[test]
+    fn fluent_description__voucher_and_boss() {
+        assert_eq!(
+            Voucher::Grabber.fluent_description_default(),
+            "+1 hand every round"
+        );
+        assert_eq!(
+            BossBlind::TheWater.fluent_description(&FluentName::DEUTSCH),
+            "Ohne Abwürfe beginnen"
+        );
+    }
+
+    #[test]
+    fn fluent_name__every_action_error() {
+        let errors = [
+            ActionError::ShopClosed,
+            ActionError::NoSuchCard(0),
+            ActionError::NoSuchJoker(0),
+            ActionError::NoSuchConsumable(0),
+            ActionError::NoSuchTarget(0),
+            ActionError::NoSuchStock(0),
+            ActionError::NoSuchPack(0),
+            ActionError::NoVoucherOffered,
+            ActionError::VoucherRedeemed(Voucher::Grabber),
+            ActionError::VoucherRequires {
+                voucher: Voucher::NachoTong,
+                base: Voucher::Grabber,
+            },
+            ActionError::NotEnoughMoney { cost: 6, money: 4 },
+            ActionError::NoJokerRoom,
+            ActionError::NoConsumableRoom,
+            ActionError::NoHandsLeft,
+            ActionError::NoDiscardsLeft,
+            ActionError::Eternal(0),
+            ActionError::Pinned(0),
+            ActionError::NotABoss,
+            ActionError::NoBossReroll,
+        ];
+        for error in errors {
+            assert_eq!(error.fluent_name_default(), error.to_string());
+            let deutsch = error.fluent_name(&FluentName::DEUTSCH);
+            assert!(is_known(&deutsch), "{error:?}");
+            assert_ne!(deutsch, error.to_string(), "{error:?}");
+        }
+    }
+
+    #[test]
+    fn fluent_name__action_error_names_its_vouchers() {
+        let error = ActionError::VoucherRequires {
+            voucher: Voucher::Liquidation,
+            base: Voucher::ClearanceSale,
+        };
+
+        assert_eq!(
+            error.fluent_name(&FluentName::DEUTSCH),
+            format!(
+                "{} braucht zuerst {}.",
+                Voucher::Liquidation.fluent_name(&FluentName::DEUTSCH),
+                Voucher::ClearanceSale.fluent_name(&FluentName::DEUTSCH)
+            )
+        );
+    }
+
+    #[test]
+    fn kebab() {
+        assert_eq!(super::kebab("TheNeedle"), "the-needle");
+        assert_eq!(super::kebab("Grabber"), "grabber");
+    }
+}
@@ -0,0 +1,385 @@
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Mul};
+
+/// A non-negative number at Balatro scale, held **exactly** as a decimal:
+/// `mantissa × 10^exponent`.
+///
+/// [`Score`](crate::funky::types::score::Score) is `usize` chips × `usize`
+/// mult with its ×mult through `f32` and `ceil()`: fine for a hand in the
+/// thousands, but a late-game stack of ×mult jokers overflows it and rounds
+/// every factor on the way. A `Magnitude` does neither. Its exponent is an
+/// `i32`, far past Balatro's own `1e308` ceiling, and its factors are decimal,
+/// so ×1.5 is exactly 3/2 and ×0.25 exactly 1/4.
+///
+/// Exact up to [`DIGITS`](Self::DIGITS) significant digits. Past that, the
+/// lowest digit is rounded off, half up — the one place precision is given up,
+/// and only once a value is longer than any hand worth reading digit by digit.
+///
+/// The representation is normalised (no trailing zeros in the mantissa), so
+/// equal values are equal structurally and `Eq`/`Hash` are derived. A saved
+/// value is normalised again as it is read, so a hand-written `1500 × 10^0`
+/// still equals `15 × 10^2`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(from = "RawMagnitude")]
+pub struct Magnitude {
+    mantissa: u128,
+    exponent: i32,
+}
+
+impl Magnitude {
+    /// The significant digits a mantissa keeps exactly.
+    pub const DIGITS: u32 = 36;
+    const LIMIT: u128 = 10u128.pow(Self::DIGITS);
+
+    pub const ZERO: Self = Self {
+        mantissa: 0,
+        exponent: 0,
+    };
+    pub const ONE: Self = Self {
+        mantissa: 1,
+        exponent: 0,
+    };
+
+    /// `mantissa × 10^exponent`.
+    #[must_use]
+    pub fn new(mantissa: u128, exponent: i32) -> Self {
+        Self::normalise(mantissa, exponent)
+    }
+
+    /// The exact decimal an `f32` factor was written as.
+    ///
+    /// Read through the shortest decimal that round-trips, so `1.5` is 3/2
+    /// and `1.1` is 11/10 rather than the nearest binary fraction. Read in
+    /// scientific notation (`3.4028235e38`), so the digits never number more
+    /// than an `f32` has, however large or small the factor. A negative or
+    /// non-finite factor is zero, the floor
+    /// [`Score::multi_mult`](crate::funky::types::score::Score::multi_mult)
+    /// gives it.
+    #[must_use]
+    pub fn from_factor(factor: f32) -> Self {
+        if !factor.is_finite() || factor <= 0.0 {
+            return Self::ZERO;
+        }
+        let text = format!("{factor:e}");
+        let (digits, power) = text.split_once('e').unwrap_or((&text, "0"));
+        let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        let mantissa = format!("{whole}{fraction}").parse::<u128>().unwrap_or(0);
+        let power = power.parse::<i32>().unwrap_or(0);
+        let places = i32::try_from(fraction.len()).unwrap_or(i32::MAX);
+        Self::new(mantissa, power.saturating_sub(places))
+    }
+
+    /// The nearest `f64` — for plotting and ratios, not for further scoring.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn to_f64(self) -> f64 {
+        self.mantissa as f64 * 10f64.powi(self.exponent)
+    }
+
+    #[must_use]
+    pub fn is_zero(self) -> bool {
+        self.mantissa == 0
+    }
+
+    /// The power of ten of the leading digit: 2 for 123, −1 for 0.5.
+    /// Meaningless for zero.
+    fn leading_power(self) -> i64 {
+        i64::from(self.mantissa.ilog10()) + i64::from(self.exponent)
+    }
+
+    /// How many decimal digits `value` has. 1 for zero.
+    fn digits(value: u128) -> u32 {
+        value.checked_ilog10().map_or(1, |log| log + 1)
+    }
+
+    fn normalise(mut mantissa: u128, mut exponent: i32) -> Self {
+        if mantissa == 0 {
+            return Self::ZERO;
+        }
+        if mantissa >= Self::LIMIT {
+            let excess = Self::digits(mantissa) - Self::DIGITS;
+            mantissa = Self::shift_right(mantissa, excess);
+            exponent = exponent.saturating_add_unsigned(excess);
+        }
+        while mantissa % 10 == 0 {
+            mantissa /= 10;
+            exponent = exponent.saturating_add(1);
+        }
+        Self { mantissa, exponent }
+    }
+
+    /// `value / 10^places`, rounded half up — once, not digit by digit, so
+    /// 0.0049 does not creep up to 0.01.
+    fn shift_right(value: u128, places: u32) -> u128 {
+        let Some(scale) = 10u128.checked_pow(places) else {
+            return 0;
+        };
+        let (quotient, remainder) = (value / scale, value % scale);
+        quotient + u128::from(remainder >= scale - remainder)
+    }
+
+    /// `self` rounded half up to `places` decimal places, as a mantissa at
+    /// exponent `-places` (or at its own exponent, if that is coarser).
+    fn rounded_to(self, places: i32) -> (u128, i32) {
+        if self.exponent >= -places {
+            return (self.mantissa, self.exponent);
+        }
+        let shift = (-places).abs_diff(self.exponent);
+        (Self::shift_right(self.mantissa, shift), -places)
+    }
+}
+
+/// A [`Magnitude`] as saved, before [`Magnitude::new`] normalises it.
+#[derive(Deserialize)]
+struct RawMagnitude {
+    mantissa: u128,
+    exponent: i32,
+}
+
+impl From<RawMagnitude> for Magnitude {
+    fn from(raw: RawMagnitude) -> Self {
+        Self::new(raw.mantissa, raw.exponent)
+    }
+}
+
+impl From<usize> for Magnitude {
+    fn from(value: usize) -> Self {
+        Self::new(u128::try_from(value).unwrap_or(u128::MAX), 0)
+    }
+}
+
+impl Add for Magnitude {
+    type Output = Self;
+
+    /// Aligned on the larger exponent. The larger value is scaled up while its
+    /// mantissa has room, and only then is the smaller one rounded down to meet
+    /// it — so two values within [`DIGITS`](Self::DIGITS) of each other add
+    /// exactly.
+    fn add(self, other: Self) -> Self {
+        if self.is_zero() {
+            return other;
+        }
+        if other.is_zero() {
+            return self;
+        }
+        let (mut high, mut low) = if self.exponent >= other.exponent {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let gap = high.exponent.abs_diff(low.exponent);
+        let room = Self::DIGITS - Self::digits(high.mantissa);
+        let up = gap.min(room);
+        high.mantissa *= 10u128.pow(up);
+        high.exponent = high.exponent.saturating_sub_unsigned(up);
+        low.mantissa = Self::shift_right(low.mantissa, gap - up);
+        Self::normalise(high.mantissa + low.mantissa, high.exponent)
+    }
+}
+
+impl Mul for Magnitude {
+    type Output = Self;
+
+    /// Exact while the product's mantissa fits a `u128`. When it would not,
+    /// the longer operand sheds its lowest digits until it does.
+    fn mul(self, other: Self) -> Self {
+        let (mut a, mut b) = (self, other);
+        loop {
+            if let Some(mantissa) = a.mantissa.checked_mul(b.mantissa) {
+                return Self::normalise(mantissa, a.exponent.saturating_add(b.exponent));
+            }
+            // A product of `n` digits fits a `u128` when `n <= 38`.
+            let excess = (Self::digits(a.mantissa) + Self::digits(b.mantissa))
+                .saturating_sub(38)
+                .max(1);
+            let longer = if a.mantissa >= b.mantissa {
+                &mut a
+            } else {
+                &mut b
+            };
+            longer.mantissa = Self::shift_right(longer.mantissa, excess);
+            longer.exponent = longer.exponent.saturating_add_unsigned(excess);
+        }
+    }
+}
+
+impl Ord for Magnitude {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.is_zero(), other.is_zero()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => self
+                .leading_power()
+                .cmp(&other.leading_power())
+                .then_with(|| {
+                    // Same leading power: pad the shorter mantissa and compare.
+                    let (a, b) = (self.mantissa, other.mantissa);
+                    let (digits_a, digits_b) = (a.ilog10(), b.ilog10());
+                    if digits_a >= digits_b {
+                        a.cmp(&(b * 10u128.pow(digits_a - digits_b)))
+                    } else {
+                        (a * 10u128.pow(digits_b - digits_a)).cmp(&b)
+                    }
+                }),
+        }
+    }
+}
+
+impl PartialOrd for Magnitude {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Written out in full below `1e11` — Balatro's own cut-over — to at most two
+/// decimal places; from there on in `1.23e45` notation, three significant
+/// digits with trailing zeros dropped.
+impl Display for Magnitude {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if *self < Self::new(1, 11) {
+            let (mantissa, exponent) = self.rounded_to(2);
+            let digits = mantissa.to_string();
+            if exponent >= 0 {
+                let zeros = "0".repeat(usize::try_from(exponent).unwrap_or(0));
+                return write!(f, "{digits}{zeros}");
+            }
+            let places = usize::try_from(-exponent).unwrap_or(0);
+            let padded = format!("{digits:0>width$}", width = places + 1);
+            let (whole, fraction) = padded.split_at(padded.len() - places);
+            let fraction = fraction.trim_end_matches('0');
+            if fraction.is_empty() {
+                return write!(f, "{whole}");
+            }
+            return write!(f, "{whole}.{fraction}");
+        }
+
+        let (mut mantissa, mut exponent) = (self.mantissa, i64::from(self.exponent));
+        let excess = Self::digits(mantissa).saturating_sub(3);
+        mantissa = Self::shift_right(mantissa, excess);
+        exponent += i64::from(excess);
+        while mantissa % 10 == 0 {
+            mantissa /= 10;
+            exponent += 1;
+        }
+        let digits = mantissa.to_string();
+        let power = exponent + i64::try_from(digits.len()).unwrap_or(0) - 1;
+        let (lead, rest) = digits.split_at(1);
+        if rest.is_empty() {
+            write!(f, "{lead}e{power}")
+        } else {
+            write!(f, "{lead}.{rest}e{power}")
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod funky__types__magnitude_tests {
+    use super::*;
+
+    #[test]
+    fn new__normalises_trailing_zeros() {
+        assert_eq!(Magnitude::new(1500, 0), Magnitude::new(15, 2));
+        assert_eq!(Magnitude::new(0, 7), Magnitude::ZERO);
+    }
+
+    #[test]
+    fn from_factor__is_the_written_decimal() {
+        assert_eq!(Magnitude::from_factor(1.5), Magnitude::new(15, -1));
+        assert_eq!(Magnitude::from_factor(0.25), Magnitude::new(25, -2));
+        assert_eq!(Magnitude::from_factor(1.1), Magnitude::new(11, -1));
+        assert_eq!(Magnitude::from_factor(3.0), Magnitude::from(3));
+        assert_eq!(Magnitude::from_factor(-2.0), Magnitude::ZERO);
+        assert_eq!(Magnitude::from_factor(f32::NAN), Magnitude::ZERO);
+        assert_eq!(Magnitude::from_factor(f32::INFINITY), Magnitude::ZERO);
+    }
+
+    #[test]
+    fn from_factor__keeps_the_extremes() {
+        assert_eq!(Magnitude::from_factor(1e38), Magnitude::new(1, 38));
+        assert_eq!(
+            Magnitude::from_factor(f32::MAX),
+            Magnitude::new(34_028_235, 31)
+        );
+        assert_eq!(Magnitude::from_factor(1e-40), Magnitude::new(1, -40));
+    }
+
+    #[test]
+    fn deserialize__normalises() {
+        let read: Magnitude = serde_json::from_str(r#"{"mantissa":1500,"exponent":0}"#).unwrap();
+
+        assert_eq!(read, Magnitude::new(15, 2));
+        assert_eq!(
+            serde_json::from_str::<Magnitude>(&serde_json::to_string(&read).unwrap()).unwrap(),
+            read
+        );
+    }
+
+    #[test]
+    fn mul__ratios_are_exact() {
+        let mut mult = Magnitude::from(4);
+        for _ in 0..20 {
+            mult = mult * Magnitude::from_factor(1.5);
+        }
+        // 4 × (3/2)^20 = 3^20 / 2^18, exactly.
+        assert_eq!(mult, Magnitude::new(3_486_784_401 * 3_814_697_265_625, -18));
+
+        let quartered = Magnitude::from(10) * Magnitude::from_factor(0.25);
+        assert_eq!(quartered, Magnitude::new(25, -1));
+    }
+
+    #[test]
+    fn mul__past_usize_does_not_overflow() {
+        let mut mult = Magnitude::ONE;
+        for _ in 0..30 {
+            mult = mult * Magnitude::from(1_000_000);
+        }
+        assert_eq!(mult, Magnitude::new(1, 180));
+    }
+
+    #[test]
+    fn add__aligns_exponents() {
+        assert_eq!(
+            Magnitude::new(15, -1) + Magnitude::from(100),
+            Magnitude::new(1015, -1)
+        );
+        // Too far apart to matter: the smaller rounds away.
+        assert_eq!(
+            Magnitude::new(1, 100) + Magnitude::ONE,
+            Magnitude::new(1, 100)
+        );
+    }
+
+    #[test]
+    fn ord__by_value() {
+        assert!(Magnitude::new(15, -1) < Magnitude::from(2));
+        assert!(Magnitude::new(1, 50) > Magnitude::new(99, 47));
+        assert!(Magnitude::new(12, 0) < Magnitude::new(121, -1));
+        assert!(Magnitude::ZERO < Magnitude::new(1, -30));
+    }
+
+    #[test]
+    fn display__plain_below_1e11() {
+        assert_eq!(Magnitude::from(1500).to_string(), "1500");
+        assert_eq!(Magnitude::new(75, -1).to_string(), "7.5");
+        assert_eq!(Magnitude::new(5, -3).to_string(), "0.01");
+        assert_eq!(Magnitude::new(49, -4).to_string(), "0");
+        assert_eq!(Magnitude::new(1_000_000_001, -9).to_string(), "1");
+        assert_eq!(Magnitude::ZERO.to_string(), "0");
+    }
+
+    #[test]
+    fn display__scientific_from_1e11() {
+        assert_eq!(Magnitude::new(1, 11).to_string(), "1e11");
+        assert_eq!(Magnitude::new(12, 44).to_string(), "1.2e45");
+        assert_eq!(Magnitude::new(9996, 42).to_string(), "1e46");
+        assert_eq!(
+            Magnitude::new(123_456, 40).to_string(),
+            "1.23e45",
+            "rounded once, not digit by digit"
+        );
+    }
+}
@@ -1,13 +1,31 @@
+pub mod action;
 pub mod blind;
 pub mod board;
 pub mod buffoon_card;
 pub mod buffoon_pile;
+pub mod challenge;
+pub mod custom_joker;
 pub mod draws;
 pub mod edition;
 pub mod effect;
 pub mod hands;
+pub mod history;
+#[cfg(feature = "i18n")]
+pub mod localized;
+pub mod magnitude;
 pub mod mpip;
+pub mod odds;
+pub mod profile;
+pub mod run;
 pub mod score;
+#[cfg(feature = "scripting")]
+pub mod script;
+pub mod seed;
 pub mod shop;
+pub mod solver;
+pub mod stake;
+pub mod starter_deck;
+pub mod sticker;
 pub mod toggle;
+pub mod trace;
 pub mod voucher;
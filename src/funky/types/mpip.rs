@@ -1,3 +1,4 @@
+use crate::funky::types::effect::EffectId;
 use crate::funky::types::hands::HandType;
 use crate::prelude::PipType;
 use crate::preludes::funky::BCardType;
@@ -94,6 +95,8 @@ pub enum MPip {
     MultPlusOn5Ranks(usize, [char; 5]),
     MultPlusOnConsecutiveHandsNo3Ranks(usize, usize, [char; 3]),
     MultPlusOnFlush(usize),
+    /// Supernova: +1 mult per time the scoring poker hand has been played this
+    /// run, this one included. Reads `PokerHands`' `times_played`.
     MultPlusOnHandPlays,
     MultPlusOnPair(usize),
     MultPlusOn2Pair(usize),
@@ -106,6 +109,9 @@ pub enum MPip {
     MultPlusRandomTo(usize),
     MultPlusZeroDiscards(usize),
     MultTimes(usize),
+    /// Loyalty Card: ×`factor` on every `every`th hand played since it was
+    /// acquired — `MultTimesEveryXHands(4, 6)`. The hands are counted in its
+    /// `joker_state` slot.
     MultTimesEveryXHands(usize, usize),
     MultTimesOnEmptyJokerSlots(usize),
     MultTimes1Dot(usize),
@@ -362,6 +368,30 @@ pub enum MPip {
     /// ate, and the eaten enhancement does *not* — a Glass card Vampire eats
     /// gives neither its ×2 nor its chance to break.
     GainMultTimesPerEnhancedPlayed(usize),
+    /// Lucky Cat: gains `rate`/100 ×mult every time a **Lucky card procs**.
+    /// Base ×1.
+    ///
+    /// The proc happens inside the pure played-card fold, so no event hook can
+    /// see it. The counter is grown from a [`ScoreTrace`] instead: the traced
+    /// fold reads the procs it has recorded so far (so a proc lands on the very
+    /// hand that rolled it), and `BuffoonBoard::commit_trace` makes them
+    /// permanent afterwards.
+    ///
+    /// [`ScoreTrace`]: crate::funky::types::trace::ScoreTrace
+    GainMultTimesPerLuckyProc(usize),
+    /// Blueprint: runs the ability of the joker **to its right**. Nothing when
+    /// it is rightmost.
+    ///
+    /// Resolved by `BuffoonBoard` wherever a joker's ability is read — the
+    /// scoring fold, retriggers, payouts and creations — and followed through
+    /// chains, so a Blueprint pointing at a Brainstorm runs whatever that
+    /// Brainstorm copies. The copy reads the target's counter; it never grows
+    /// its own. Passive rule changes (Four Fingers, Pareidolia, Chicot, the
+    /// draw modifiers) are not copied, as Balatro marks them incompatible.
+    CopyJokerToRight,
+    /// Brainstorm: runs the ability of the **leftmost** joker. Nothing when it
+    /// is leftmost itself. Resolved as [`CopyJokerToRight`](Self::CopyJokerToRight).
+    CopyLeftmostJoker,
     Planet(usize),
     RandomJoker(usize),
     RandomTarot(usize),
@@ -398,8 +428,9 @@ pub enum MPip {
     Spades(usize),
     /// A mod-defined effect, resolved through an
     /// [`EffectRegistry`](crate::funky::types::effect::EffectRegistry) by this
-    /// id. Kept a plain `u32` so cards stay `Copy`, `const` and `Serialize`.
-    Custom(u32),
+    /// id. An [`EffectId`] is held inline, so cards stay `Copy`, `const` and
+    /// `Serialize`.
+    Custom(EffectId),
 }
 
 impl MPip {
@@ -603,6 +634,9 @@ impl Display for MPip {
             Self::GainMultTimesPerPlanetUsed(rate) => {
                 write!(f, "GainMultTimesPerPlanetUsed({rate})")
             }
+            Self::GainMultTimesPerLuckyProc(rate) => {
+                write!(f, "GainMultTimesPerLuckyProc({rate})")
+            }
             Self::CreateJokersWhenBlindSelected(n, rarity) => {
                 write!(f, "CreateJokersWhenBlindSelected({n}, {rarity:?})")
             }
@@ -665,6 +699,8 @@ impl Display for MPip {
                 write!(f, "CreateTarotOnPackOpen({num}, {den})")
             }
             Self::CreateNegativeConsumableCopy => write!(f, "CreateNegativeConsumableCopy"),
+            Self::CopyJokerToRight => write!(f, "CopyJokerToRight"),
+            Self::CopyLeftmostJoker => write!(f, "CopyLeftmostJoker"),
             Self::Planet(value) => write!(f, "Planet({value})"),
             Self::RandomJoker(value) => write!(f, "RandomJoker({value})"),
             Self::RandomTarot(value) => write!(f, "RandomTarot({value})"),
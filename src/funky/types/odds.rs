@@ -0,0 +1,832 @@
+use crate::funky::types::board::BuffoonBoard;
+use crate::funky::types::buffoon_card::BuffoonCard;
+use crate::funky::types::buffoon_pile::{BuffoonPile, HandRules};
+use crate::funky::types::hands::HandType;
+use crate::funky::types::solver::Play;
+use std::collections::BTreeMap;
+
+/// What a discard can draw into: for every hand type, the chance the refilled
+/// hand can be played as it.
+///
+/// Returned by [`BuffoonBoard::draw_odds`]. `chances` is the half a bot weighs
+/// discards with; `outs` is the half a hint UI prints ("9 outs to the
+/// flush").
+#[derive(Clone, Debug, PartialEq)]
+pub struct DrawOdds {
+    /// Cards the discard draws back.
+    pub draws: usize,
+    /// Cards left in the [`deck`](BuffoonBoard::deck) to draw them from.
+    pub deck: usize,
+    /// Per hand type, the deck cards that make it with the kept cards alone —
+    /// every card in the deck for a hand that is already made.
+    pub outs: BTreeMap<HandType, usize>,
+    /// Per hand type, the exact chance that the kept cards plus the draw hold
+    /// it in five cards or fewer.
+    pub chances: BTreeMap<HandType, f64>,
+}
+
+impl DrawOdds {
+    /// Every hand type a hand can be played as. Royal Flush is left out: the
+    /// board scores it as a [`StraightFlush`](HandType::StraightFlush), and
+    /// so do these odds.
+    pub const HAND_TYPES: [HandType; 12] = [
+        HandType::HighCard,
+        HandType::Pair,
+        HandType::TwoPair,
+        HandType::ThreeOfAKind,
+        HandType::Straight,
+        HandType::Flush,
+        HandType::FullHouse,
+        HandType::FourOfAKind,
+        HandType::StraightFlush,
+        HandType::FiveOfAKind,
+        HandType::FlushHouse,
+        HandType::FlushFive,
+    ];
+
+    /// The chance of `hand_type`, from 0 to 1.
+    #[must_use]
+    pub fn chance(&self, hand_type: HandType) -> f64 {
+        self.chances
+            .get(&Self::normalise(hand_type))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// The outs to `hand_type`.
+    #[must_use]
+    pub fn outs(&self, hand_type: HandType) -> usize {
+        self.outs
+            .get(&Self::normalise(hand_type))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    const fn normalise(hand_type: HandType) -> HandType {
+        match hand_type {
+            HandType::RoyalFlush => HandType::StraightFlush,
+            other => other,
+        }
+    }
+}
+
+/// # Odds
+///
+/// The chances are hypergeometric and exact, not sampled. The deck is grouped
+/// into the cards hand detection cannot tell apart — same rank, same suit (or
+/// same colour, under Smeared), and every Stone card as one — and each way of
+/// drawing from those groups is weighted by how many draws it stands for, so
+/// two copies of `K♠` are counted twice and looked at once. A hand type that
+/// reads only ranks, or only suits, is walked over those alone, and a walk
+/// goes no further once every hand type it asks about is held or out of reach.
+///
+/// A hand type is held when five cards or fewer from the hand form it under
+/// the board's [`HandRules`]: a straight is a run of ranks, each step within
+/// the Shortcut distance, with no Ace-low wrap; a straight flush is that run
+/// sharing its five cards with a flush.
+impl BuffoonBoard {
+    /// The odds of keeping the cards at `keep` and discarding the rest, as
+    /// [`discard_cards`](Self::discard_cards) then refilling to the hand size
+    /// would. `None` if an index is out of bounds, or the discard would be
+    /// refused: no discards left, or more than five cards thrown.
+    ///
+    /// Keeping the whole hand discards nothing and draws nothing, so every
+    /// chance is 0 or 1 — what the hand already holds.
+    ///
+    /// ```
+    /// use cardpack::preludes::funky::*;
+    ///
+    /// let mut board = BuffoonBoard::new(Draws::new(4, 3), bcards!("2S 3S 4D 5C 6H"));
+    /// board.in_hand = bcards!("AS KS QS JS 9H");
+    /// board.draws.hand_size = 5;
+    ///
+    /// let odds = board.draw_odds(&[0, 1, 2, 3]).unwrap();
+    ///
+    /// assert_eq!(odds.outs(HandType::Flush), 2);
+    /// assert!((odds.chance(HandType::Flush) - 0.4).abs() < 1e-9);
+    /// ```
+    #[must_use]
+    pub fn draw_odds(&self, keep: &[usize]) -> Option<DrawOdds> {
+        let mut keep = keep.to_vec();
+        keep.sort_unstable();
+        keep.dedup();
+        let mut kept = BuffoonPile::default();
+        for index in &keep {
+            kept.push(*self.in_hand.get(*index)?);
+        }
+        let thrown = self.in_hand.len() - kept.len();
+        if thrown > 0 && (self.discards_remaining() == 0 || thrown > Play::MAX_CARDS) {
+            return None;
+        }
+        let draws = if thrown == 0 {
+            0
+        } else {
+            self.draws
+                .hand_size
+                .saturating_sub(kept.len())
+                .min(self.deck.len())
+        };
+
+        let rules = self.hand_rules();
+        let base = Tally::of(&kept, rules, Reading::Cards);
+        let groups = Group::of(&self.deck, rules, Reading::Cards);
+
+        let mut outs: BTreeMap<HandType, usize> = BTreeMap::new();
+        for group in &groups {
+            let mut tally = base;
+            tally.add(group.key, 1);
+            let held = tally.holds(rules, EVERY_HAND);
+            for (bit, hand_type) in DrawOdds::HAND_TYPES.iter().enumerate() {
+                if held & 1 << bit != 0 {
+                    *outs.entry(*hand_type).or_default() += group.count;
+                }
+            }
+        }
+
+        let mut hits = [0_u64; 12];
+        for reading in Reading::ALL {
+            let groups = Group::of(&self.deck, rules, reading);
+            let mut deal = Deal::new(&groups, rules);
+            let base = Tally::of(&kept, rules, reading);
+            let held = base.holds(rules, reading.hands());
+            deal.count(held, 1, 0, draws);
+            deal.walk(0, draws, &base, 1, reading.hands() & !held);
+            for (hit, dealt) in hits.iter_mut().zip(deal.hits) {
+                *hit += dealt;
+            }
+        }
+        let total = Self::as_f64_u64(choose(self.deck.len(), draws));
+        let chances = DrawOdds::HAND_TYPES
+            .iter()
+            .zip(hits)
+            .map(|(hand_type, hit)| (*hand_type, Self::as_f64_u64(hit) / total))
+            .collect();
+
+        Some(DrawOdds {
+            draws,
+            deck: self.deck.len(),
+            outs,
+            chances,
+        })
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    const fn as_f64_u64(n: u64) -> f64 {
+        n as f64
+    }
+}
+
+/// How many ways to pick `k` of `n`, saturating rather than overflowing.
+fn choose(n: usize, k: usize) -> u64 {
+    if k > n {
+        return 0;
+    }
+    let k = k.min(n - k);
+    let mut ways: u64 = 1;
+    for i in 0..k {
+        let (top, bottom) = (
+            u64::try_from(n - i).unwrap_or(u64::MAX),
+            u64::try_from(i + 1).unwrap_or(u64::MAX),
+        );
+        ways = ways.saturating_mul(top) / bottom;
+    }
+    ways
+}
+
+/// A card as hand detection sees it: its rank (Two = 0 to Ace = 12) and its
+/// suit, or `None` for a card it cannot see — a Stone card, or one outside
+/// the French ranks and suits.
+type Key = Option<(usize, usize)>;
+
+fn key(card: &BuffoonCard, rules: HandRules) -> Key {
+    if card.is_stone() || card.rank.weight > 12 {
+        return None;
+    }
+    let suit = match (card.suit.index, rules.smeared) {
+        ('S', _) | ('C', true) => 0,
+        ('H', _) | ('D', true) => 1,
+        ('D', false) => 2,
+        ('C', false) => 3,
+        _ => return None,
+    };
+    Some((card.rank.weight, suit))
+}
+
+/// Cards of one [`Key`] in the deck.
+#[derive(Clone, Copy, Debug)]
+struct Group {
+    key: Key,
+    count: usize,
+}
+
+impl Group {
+    fn of(deck: &BuffoonPile, rules: HandRules, reading: Reading) -> Vec<Self> {
+        let mut counts: BTreeMap<Key, usize> = BTreeMap::new();
+        for card in deck {
+            *counts.entry(reading.read(key(card, rules))).or_default() += 1;
+        }
+        counts
+            .into_iter()
+            .map(|(key, count)| Self { key, count })
+            .collect()
+    }
+}
+
+/// Hand types as a set, one bit each in [`DrawOdds::HAND_TYPES`] order.
+type Held = u16;
+
+const EVERY_HAND: Held = (1 << DrawOdds::HAND_TYPES.len()) - 1;
+
+/// The hand types in `hand_types`, as a [`Held`].
+const fn held(hand_types: &[HandType]) -> Held {
+    let mut held = 0;
+    let mut bit = 0;
+    while bit < DrawOdds::HAND_TYPES.len() {
+        let mut i = 0;
+        while i < hand_types.len() {
+            if hand_types[i] as u8 == DrawOdds::HAND_TYPES[bit] as u8 {
+                held |= 1 << bit;
+            }
+            i += 1;
+        }
+        bit += 1;
+    }
+    held
+}
+
+/// What a walk tells the cards apart by. Most hand types read only ranks, or
+/// only suits, and walking them over 13 ranks rather than 52 cards is what
+/// keeps a five-card discard from looking at every one of a million draws.
+#[derive(Clone, Copy, Debug)]
+enum Reading {
+    Ranks,
+    Suits,
+    Cards,
+}
+
+impl Reading {
+    const ALL: [Self; 3] = [Self::Ranks, Self::Suits, Self::Cards];
+
+    /// The hand types this reading is the coarsest to decide — between them,
+    /// every one of [`DrawOdds::HAND_TYPES`], once.
+    fn hands(self) -> Held {
+        match self {
+            Self::Ranks => {
+                const {
+                    held(&[
+                        HandType::HighCard,
+                        HandType::Pair,
+                        HandType::TwoPair,
+                        HandType::ThreeOfAKind,
+                        HandType::Straight,
+                        HandType::FullHouse,
+                        HandType::FourOfAKind,
+                        HandType::FiveOfAKind,
+                    ])
+                }
+            }
+            Self::Suits => const { held(&[HandType::Flush]) },
+            Self::Cards => {
+                const {
+                    held(&[
+                        HandType::StraightFlush,
+                        HandType::FlushHouse,
+                        HandType::FlushFive,
+                    ])
+                }
+            }
+        }
+    }
+
+    /// `key` with what this reading cannot see set to 0.
+    const fn read(self, key: Key) -> Key {
+        match (self, key) {
+            (Self::Ranks, Some((rank, _))) => Some((rank, 0)),
+            (Self::Suits, Some((_, suit))) => Some((0, suit)),
+            (Self::Cards, key) | (_, key @ None) => key,
+        }
+    }
+}
+
+/// Every way of drawing from a deck's [`Group`]s, and how many of them hold
+/// each hand type.
+struct Deal<'a> {
+    groups: &'a [Group],
+    /// How many cards are in `groups[from..]`, by `from`.
+    left_in: Vec<usize>,
+    /// The cards in `groups[from..]` hand detection can see, by `from`.
+    seen_in: Vec<Tally>,
+    rules: HandRules,
+    hits: [u64; 12],
+}
+
+impl<'a> Deal<'a> {
+    fn new(groups: &'a [Group], rules: HandRules) -> Self {
+        let mut left_in = vec![0; groups.len() + 1];
+        let mut seen_in = vec![Tally::default(); groups.len() + 1];
+        for (from, group) in groups.iter().enumerate().rev() {
+            left_in[from] = left_in[from + 1] + group.count;
+            seen_in[from] = seen_in[from + 1];
+            seen_in[from].add(group.key, group.count);
+        }
+        Self {
+            groups,
+            left_in,
+            seen_in,
+            rules,
+            hits: [0; 12],
+        }
+    }
+
+    /// `open`, less the hand types that `tally` plus `left` more cards from
+    /// `groups[from..]` cannot make: too few of any rank or any suit, or too
+    /// many ranks missing from every run.
+    fn reachable(&self, from: usize, left: usize, tally: &Tally, open: Held) -> Held {
+        let rest = &self.seen_in[from];
+        let (mut most, mut next) = (0, 0);
+        for (ours, theirs) in tally.0.iter().zip(&rest.0) {
+            let reach = ours.iter().sum::<usize>() + left.min(theirs.iter().sum());
+            if reach > most {
+                (most, next) = (reach, most);
+            } else if reach > next {
+                next = reach;
+            }
+        }
+        let flush = (0..4)
+            .any(|suit| tally.suited(suit) + left.min(rest.suited(suit)) >= self.rules.flush_len);
+        let mut both = *tally;
+        for (ours, theirs) in both.0.iter_mut().zip(&rest.0) {
+            ours[0] += theirs.iter().sum::<usize>();
+        }
+        let straight = both.any_run(self.rules, |run| {
+            run.iter()
+                .filter(|rank| tally.0[**rank].iter().all(|n| *n == 0))
+                .count()
+                <= left
+        });
+
+        let mut unreachable = 0;
+        if !straight {
+            unreachable |= const { held(&[HandType::Straight, HandType::StraightFlush]) };
+        }
+        if !flush {
+            unreachable |= const {
+                held(&[
+                    HandType::Flush,
+                    HandType::StraightFlush,
+                    HandType::FlushHouse,
+                    HandType::FlushFive,
+                ])
+            };
+        }
+        if most < 5 {
+            unreachable |= const { held(&[HandType::FiveOfAKind, HandType::FlushFive]) };
+        }
+        if most < 4 {
+            unreachable |= const { held(&[HandType::FourOfAKind]) };
+        }
+        if most < 3 {
+            unreachable |= const {
+                held(&[
+                    HandType::ThreeOfAKind,
+                    HandType::FullHouse,
+                    HandType::FlushHouse,
+                ])
+            };
+        }
+        if most < 2 {
+            unreachable |= const { held(&[HandType::Pair]) };
+        }
+        if next < 2 {
+            unreachable |=
+                const { held(&[HandType::TwoPair, HandType::FullHouse, HandType::FlushHouse]) };
+        }
+        open & !unreachable
+    }
+
+    /// Counts `held` for every way of finishing a draw that stands for `ways`
+    /// so far and has `left` cards still to come from `groups[from..]`. More
+    /// cards never unmake a hand, so each of those finished hands holds it.
+    fn count(&mut self, held: Held, ways: u64, from: usize, left: usize) {
+        if held == 0 {
+            return;
+        }
+        let finished = ways.saturating_mul(choose(self.left_in[from], left));
+        for (bit, hit) in self.hits.iter_mut().enumerate() {
+            if held & 1 << bit != 0 {
+                *hit = hit.saturating_add(finished);
+            }
+        }
+    }
+
+    /// Walk every way of drawing `left` more cards from `groups[from..]` onto
+    /// `tally`, where `ways` is how many draws the walk so far stands for and
+    /// `open` the hand types it does not hold yet. A hand type is counted
+    /// where the walk first makes it, and the walk stops once none are open
+    /// or none of those can be made.
+    fn walk(&mut self, from: usize, left: usize, tally: &Tally, ways: u64, open: Held) {
+        if left == 0 || self.left_in[from] < left {
+            return;
+        }
+        let open = self.reachable(from, left, tally, open);
+        if open == 0 {
+            return;
+        }
+        let group = self.groups[from];
+        self.walk(from + 1, left, tally, ways, open);
+        for taken in 1..=group.count.min(left) {
+            let mut tally = *tally;
+            tally.add(group.key, taken);
+            let ways = ways.saturating_mul(choose(group.count, taken));
+            let held = tally.holds(self.rules, open);
+            self.count(held, ways, from + 1, left - taken);
+            self.walk(from + 1, left - taken, &tally, ways, open & !held);
+        }
+    }
+}
+
+/// A hand, counted by rank and suit.
+#[derive(Clone, Copy, Debug, Default)]
+struct Tally([[usize; 4]; 13]);
+
+impl Tally {
+    fn of(pile: &BuffoonPile, rules: HandRules, reading: Reading) -> Self {
+        let mut tally = Self::default();
+        for card in pile {
+            tally.add(reading.read(key(card, rules)), 1);
+        }
+        tally
+    }
+
+    fn add(&mut self, key: Key, n: usize) {
+        if let Some((rank, suit)) = key {
+            self.0[rank][suit] += n;
+        }
+    }
+
+    /// Which of the hand types in `wanted` the hand holds. The others are
+    /// not looked at.
+    fn holds(&self, rules: HandRules, wanted: Held) -> Held {
+        let mut ranks = [0; 13];
+        for (n, suits) in ranks.iter_mut().zip(&self.0) {
+            *n = suits.iter().sum::<usize>();
+        }
+        let most = ranks.iter().copied().max().unwrap_or_default();
+        let pairs = ranks.iter().filter(|n| **n >= 2).count();
+        let trips = ranks.iter().filter(|n| **n >= 3).count();
+
+        let mut held = 0;
+        for (bit, hand_type) in DrawOdds::HAND_TYPES.iter().enumerate() {
+            if wanted & 1 << bit == 0 {
+                continue;
+            }
+            let holds = match hand_type {
+                HandType::HighCard => true,
+                HandType::Pair => most >= 2,
+                HandType::TwoPair => pairs >= 2,
+                HandType::ThreeOfAKind => most >= 3,
+                HandType::Straight => self.any_run(rules, |_| true),
+                HandType::Flush => (0..4).any(|suit| self.suited(suit) >= rules.flush_len),
+                HandType::FullHouse => trips >= 1 && pairs >= 2,
+                HandType::FourOfAKind => most >= 4,
+                HandType::StraightFlush | HandType::RoyalFlush => self.straight_flush(rules),
+                HandType::FiveOfAKind => most >= 5,
+                HandType::FlushHouse => self.flush_house(rules),
+                HandType::FlushFive => self.flush_five(rules),
+            };
+            if holds {
+                held |= 1 << bit;
+            }
+        }
+        held
+    }
+
+    fn suited(&self, suit: usize) -> usize {
+        self.0.iter().map(|suits| suits[suit]).sum()
+    }
+
+    /// Whether `f` holds for any run of ranks long enough to be a straight,
+    /// given the run's ranks. A run need not take every rank between its
+    /// ends: under Shortcut, `2 4 6 8 T` is still a run with a `5` held.
+    fn any_run(&self, rules: HandRules, mut f: impl FnMut(&[usize]) -> bool) -> bool {
+        let mut present = [0; 13];
+        let mut len = 0;
+        for (rank, suits) in self.0.iter().enumerate() {
+            if suits.iter().any(|n| *n > 0) {
+                present[len] = rank;
+                len += 1;
+            }
+        }
+        let mut run = [0; 13];
+        rules.straight_connectors < len
+            && Self::extend_run(&present[..len], rules, &mut run, 0, &mut f)
+    }
+
+    /// Extends `run[..depth]` with each rank of `present` within reach of its
+    /// top, until it is long enough to hand to `f`.
+    fn extend_run(
+        present: &[usize],
+        rules: HandRules,
+        run: &mut [usize; 13],
+        depth: usize,
+        f: &mut impl FnMut(&[usize]) -> bool,
+    ) -> bool {
+        if depth > rules.straight_connectors {
+            return f(&run[..depth]);
+        }
+        for (i, rank) in present.iter().enumerate() {
+            if depth > 0 && rank - run[depth - 1] > rules.straight_distance {
+                break;
+            }
+            run[depth] = *rank;
+            if Self::extend_run(&present[i + 1..], rules, run, depth + 1, f) {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// A run and a flush in the same five cards: the run's cards of the suit,
+    /// topped up from the suit's other cards.
+    fn straight_flush(&self, rules: HandRules) -> bool {
+        (0..4)
+            .filter(|suit| self.suited(*suit) >= rules.flush_len)
+            .any(|suit| {
+                self.any_run(rules, |run| {
+                    let on_suit = run.iter().filter(|rank| self.0[**rank][suit] > 0).count();
+                    run.len() + rules.flush_len.saturating_sub(on_suit) <= Play::MAX_CARDS
+                })
+            })
+    }
+
+    fn flush_house(&self, rules: HandRules) -> bool {
+        (0..13).any(|high| {
+            (0..13).any(|low| {
+                low != high
+                    && (0..4).any(|suit| {
+                        let (trips, pair) = (self.0[high], self.0[low]);
+                        trips.iter().sum::<usize>() >= 3
+                            && pair.iter().sum::<usize>() >= 2
+                            && trips[suit].min(3) + pair[suit].min(2) >= rules.flush_len
+                    })
+            })
+        })
+    }
+
+    fn flush_five(&self, rules: HandRules) -> bool {
+        self.0.iter().any(|suits| {
+            suits.iter().sum::<usize>() >= 5 && suits.iter().any(|n| (*n).min(5) >= rules.flush_len)
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod funky__types__odds_tests {
+    use super::*;
+    use crate::funky::types::draws::Draws;
+    use crate::funky::types::mpip::MPip;
+    use crate::preludes::funky::*;
+
+    fn board(hand: &str, deck: &str) -> BuffoonBoard {
+        let mut board = BuffoonBoard::new(Draws::new(4, 3), bcards!(deck));
+        board.in_hand = bcards!(hand);
+        board.draws.hand_size = board.in_hand.len();
+        board
+    }
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    /// Whether five cards or fewer of `hand` form `hand_type`, asked of the
+    /// pile's own predicates one subset at a time.
+    fn brute_holds(hand: &BuffoonPile, hand_type: HandType, rules: HandRules) -> bool {
+        (1..=Play::MAX_CARDS.min(hand.len()))
+            .flat_map(|k| hand.index_combos(k))
+            .any(|indices| {
+                let pile: BuffoonPile = indices.iter().map(|i| *hand.get(*i).unwrap()).collect();
+                match hand_type {
+                    HandType::HighCard => true,
+                    HandType::Pair => pile.has_pair(),
+                    HandType::TwoPair => pile.has_2pair(),
+                    HandType::ThreeOfAKind => pile.has_trips(),
+                    HandType::Straight => pile.has_straight_with(rules),
+                    HandType::Flush => pile.has_flush_with(rules),
+                    HandType::FullHouse => pile.has_full_house(),
+                    HandType::FourOfAKind => pile.has_4_of_a_kind(),
+                    HandType::StraightFlush | HandType::RoyalFlush => {
+                        pile.has_straight_flush_with(rules)
+                    }
+                    HandType::FiveOfAKind => pile.has_5_of_a_kind(),
+                    HandType::FlushHouse => pile.has_flush_house_with(rules),
+                    HandType::FlushFive => pile.has_flush_five_with(rules),
+                }
+            })
+    }
+
+    #[test]
+    fn draw_odds__one_card_to_a_flush() {
+        let board = board("AS KS QS JS 9H", "2S 3S 4D 5C 6H 7H 8D 9C TD 2H");
+        let odds = board.draw_odds(&[0, 1, 2, 3]).unwrap();
+
+        assert_eq!(odds.draws, 1);
+        assert_eq!(odds.deck, 10);
+        assert_eq!(odds.outs(HandType::Flush), 2);
+        assert!(close(odds.chance(HandType::Flush), 0.2));
+        assert_eq!(odds.outs(HandType::StraightFlush), 0, "no TS in the deck");
+        assert_eq!(odds.outs(HandType::HighCard), 10);
+    }
+
+    #[test]
+    fn draw_odds__two_cards_to_a_flush_is_hypergeometric() {
+        // Three hearts kept, two drawn from ten of which four are hearts:
+        // C(4, 2) / C(10, 2) = 6 / 45.
+        let board = board("AH 9H 4H KC QD", "2H 3H 5H 6H 2C 3C 4C 5D 6D 7D");
+        let odds = board.draw_odds(&[0, 1, 2]).unwrap();
+
+        assert_eq!(odds.draws, 2);
+        assert!(close(odds.chance(HandType::Flush), 6.0 / 45.0));
+        assert_eq!(odds.outs(HandType::Flush), 0, "one card is not enough");
+    }
+
+    #[test]
+    fn draw_odds__draws_back_to_the_hand_size() {
+        let mut board = board("AS KD 7C", "2S 3S 4S 5S 6S 7S 8S 9S TS");
+        board.draws.hand_size = 5;
+        let odds = board.draw_odds(&[0]).unwrap();
+
+        assert_eq!(odds.draws, 4);
+        assert!(close(odds.chance(HandType::Flush), 1.0));
+    }
+
+    #[test]
+    fn draw_odds__keeping_everything_draws_nothing() {
+        let board = board("KS KH 2C 5D 9S", "KC KD");
+        let odds = board.draw_odds(&[0, 1, 2, 3, 4]).unwrap();
+
+        assert_eq!(odds.draws, 0);
+        assert!(close(odds.chance(HandType::Pair), 1.0));
+        assert!(close(odds.chance(HandType::ThreeOfAKind), 0.0));
+        assert_eq!(odds.outs(HandType::ThreeOfAKind), 2);
+        assert_eq!(odds.outs(HandType::FourOfAKind), 0);
+    }
+
+    #[test]
+    fn draw_odds__refuses_what_discard_cards_would() {
+        let mut board = board("KS KH 2C 5D 9S 3H 4H", "KC KD");
+
+        assert!(board.draw_odds(&[0, 9]).is_none(), "out of bounds");
+        assert!(board.draw_odds(&[0]).is_none(), "six cards thrown");
+        assert!(board.draw_odds(&[0, 1]).is_some());
+
+        board.discards_used = board.draws.discards;
+        assert!(board.draw_odds(&[0, 1]).is_none(), "no discards left");
+        assert!(board.draw_odds(&[0, 1, 2, 3, 4, 5, 6]).is_some());
+    }
+
+    #[test]
+    fn draw_odds__four_fingers_makes_four_cards_a_flush_and_a_straight() {
+        let mut board = board("AS KS QS JS 2H", "3D 4C");
+        assert!(close(
+            board
+                .draw_odds(&[0, 1, 2, 3])
+                .unwrap()
+                .chance(HandType::Flush),
+            0.0
+        ));
+
+        board.push_joker(FOUR_FINGERS);
+        let odds = board.draw_odds(&[0, 1, 2, 3]).unwrap();
+
+        assert!(close(odds.chance(HandType::Flush), 1.0));
+        assert!(close(odds.chance(HandType::Straight), 1.0));
+        assert!(close(odds.chance(HandType::RoyalFlush), 1.0));
+    }
+
+    #[test]
+    fn draw_odds__shortcut_counts_a_gapped_straight() {
+        let mut board = board("2S 4D 6C 8H KS", "TC KD");
+        let plain = board.draw_odds(&[0, 1, 2, 3]).unwrap();
+        assert_eq!(plain.outs(HandType::Straight), 0);
+
+        board.push_joker(SHORTCUT);
+        let gapped = board.draw_odds(&[0, 1, 2, 3]).unwrap();
+
+        assert_eq!(gapped.outs(HandType::Straight), 1, "2-4-6-8-T");
+        assert!(close(gapped.chance(HandType::Straight), 0.5));
+    }
+
+    #[test]
+    fn draw_odds__smeared_merges_the_colours() {
+        let mut board = board("AH KD QH JD 2C", "3H 4S");
+        assert!(close(
+            board
+                .draw_odds(&[0, 1, 2, 3])
+                .unwrap()
+                .chance(HandType::Flush),
+            0.0
+        ));
+
+        board.push_joker(SMEARED_JOKER);
+        let odds = board.draw_odds(&[0, 1, 2, 3]).unwrap();
+
+        assert_eq!(odds.outs(HandType::Flush), 1);
+        assert!(close(odds.chance(HandType::Flush), 0.5));
+    }
+
+    #[test]
+    fn draw_odds__a_stone_card_draws_into_nothing() {
+        let stone = BuffoonCard {
+            enhancement: MPip::Stone(50),
+            ..FIVE_SPADES
+        };
+        let mut board = board("AS KS QS JS 2H", "");
+        board.deck.push(stone);
+        board.deck.push(TEN_SPADES);
+        let odds = board.draw_odds(&[0, 1, 2, 3]).unwrap();
+
+        assert_eq!(odds.outs(HandType::Flush), 1, "the Stone is no spade");
+        assert!(close(odds.chance(HandType::StraightFlush), 0.5));
+    }
+
+    /// Asserts every chance of keeping `keep` against the pile predicates,
+    /// one draw at a time.
+    fn assert_draw_for_draw(board: &BuffoonBoard, keep: &[usize]) {
+        let odds = board.draw_odds(keep).unwrap();
+        let rules = board.hand_rules();
+
+        let kept: BuffoonPile = keep
+            .iter()
+            .map(|i| *board.in_hand.get(*i).unwrap())
+            .collect();
+        let draws = board.deck.index_combos(odds.draws);
+        for hand_type in DrawOdds::HAND_TYPES {
+            let hits = draws
+                .iter()
+                .filter(|drawn| {
+                    let mut hand = kept.clone();
+                    for i in *drawn {
+                        hand.push(*board.deck.get(*i).unwrap());
+                    }
+                    brute_holds(&hand, hand_type, rules)
+                })
+                .count();
+            let expected = f64::from(u32::try_from(hits).unwrap())
+                / f64::from(u32::try_from(draws.len()).unwrap());
+            assert!(
+                close(odds.chance(hand_type), expected),
+                "{hand_type:?}: {} != {expected}",
+                odds.chance(hand_type)
+            );
+        }
+    }
+
+    #[test]
+    fn draw_odds__matches_the_pile_predicates_draw_for_draw() {
+        let board = board(
+            "KS KH 7S 8S 9D 2C QS JS",
+            "TS TH 6S 9S 7C KD KC 3S 2D 8H AS",
+        );
+        assert_draw_for_draw(&board, &[0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn draw_odds__matches_the_pile_predicates_under_shortcut() {
+        let mut board = board(
+            "2S 4S 5H 9D KC QD 3H 7C",
+            "6S 8S TS 6H 5S 7D 3S AS 4H JS 8D",
+        );
+        board.jokers.push(SHORTCUT);
+        assert_draw_for_draw(&board, &[0, 1, 2]);
+    }
+
+    #[test]
+    fn draw_odds__a_card_between_a_shortcut_run_keeps_its_straight_flush() {
+        let mut board = board("2S 4S 6S 8S 5H 3C", "TS 7H");
+        board.jokers.push(SHORTCUT);
+        let rules = board.hand_rules();
+        let odds = board.draw_odds(&[0, 1, 2, 3, 4]).unwrap();
+
+        assert!(brute_holds(
+            &bcards!("2S 4S 6S 8S 5H TS"),
+            HandType::StraightFlush,
+            rules
+        ));
+        assert_eq!(odds.outs(HandType::StraightFlush), 1);
+        assert!(close(odds.chance(HandType::StraightFlush), 0.5));
+    }
+
+    #[test]
+    fn draw_odds__a_full_deck_ranks_the_hands_sensibly() {
+        let mut board = BuffoonBoard::new(Draws::new(4, 3), Deck::basic_buffoon_pile());
+        board.deal_to_hand_size();
+        let odds = board.draw_odds(&[0, 1, 2, 3, 4]).unwrap();
+
+        assert_eq!(odds.draws, 3);
+        assert_eq!(odds.deck, 44);
+        assert!(odds.chance(HandType::Pair) >= odds.chance(HandType::TwoPair));
+        assert!(odds.chance(HandType::ThreeOfAKind) >= odds.chance(HandType::FourOfAKind));
+        assert!(close(odds.chance(HandType::FiveOfAKind), 0.0));
+    }
+}
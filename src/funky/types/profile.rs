@@ -0,0 +1,424 @@
+//! Statistics kept across runs.
+//!
+//! A [`BuffoonBoard`](crate::funky::types::board::BuffoonBoard) forgets
+//! everything when its run ends; a [`Profile`] is where the runs go. Each run
+//! is filed as a [`RunStats`] — its deck, stake, how far it got, its best hand,
+//! the jokers it held, what it earned, what it played most — and the profile
+//! answers questions over them as a [`Summary`]: all runs, the runs on one
+//! deck or stake, or the runs that held a given joker, which is how a custom
+//! joker is measured against the rest.
+//!
+//! A profile is serde data, like every funky type. `to_yaml` and `from_yaml`
+//! (under `yaml`) hand back and take a string; `save` and `load` write and read
+//! that string at a path, under the opt-in `std-io` feature that is the crate's
+//! filesystem seam.
+
+use crate::funky::types::hands::HandType;
+use crate::funky::types::run::Run;
+use crate::funky::types::seed::RunSeed;
+use crate::funky::types::stake::Stake;
+use crate::funky::types::starter_deck::StarterDeck;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+#[cfg(feature = "yaml")]
+use std::error::Error;
+#[cfg(feature = "std-io")]
+#[allow(clippy::disallowed_types)] // The `std-io` filesystem seam, as in `BasicCard`.
+use std::fs::File;
+#[cfg(feature = "std-io")]
+use std::io::{Read, Write};
+
+/// One finished run, as a [`Profile`] keeps it. Made by [`Run::stats`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RunStats {
+    pub seed: RunSeed,
+    /// `None` for a run over a hand-built board.
+    pub deck: Option<StarterDeck>,
+    pub stake: Stake,
+    pub won: bool,
+    /// The furthest ante reached — one past the winning ante for a won run.
+    pub ante: usize,
+    /// The best single hand of the run.
+    pub best_hand: usize,
+    /// The symbol of every joker the board held, in the order they came —
+    /// bought, picked, created, or there from the start.
+    pub jokers: Vec<char>,
+    /// Every dollar the run gained, spending never taken off.
+    pub money_earned: usize,
+    /// `None` if no hand was played.
+    pub most_played: Option<HandType>,
+}
+
+/// Many runs, answered for as one.
+///
+/// The means are over the runs counted; an empty summary is all zeroes.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Summary {
+    pub runs: usize,
+    pub wins: usize,
+    pub furthest_ante: usize,
+    pub mean_ante: f64,
+    pub best_hand: usize,
+    pub mean_money_earned: f64,
+    /// The hand most runs played most, the higher hand on a tie.
+    pub most_played: Option<HandType>,
+}
+
+impl Summary {
+    /// Wins over runs, from 0 to 1.
+    #[must_use]
+    pub fn win_rate(&self) -> f64 {
+        if self.runs == 0 {
+            return 0.0;
+        }
+        Self::as_f64(self.wins) / Self::as_f64(self.runs)
+    }
+
+    fn of<'a>(runs: impl IntoIterator<Item = &'a RunStats>) -> Self {
+        let mut summary = Self::default();
+        let (mut antes, mut earned) = (0_usize, 0_usize);
+        let mut hands: BTreeMap<HandType, usize> = BTreeMap::new();
+        for run in runs {
+            summary.runs += 1;
+            summary.wins += usize::from(run.won);
+            summary.furthest_ante = summary.furthest_ante.max(run.ante);
+            summary.best_hand = summary.best_hand.max(run.best_hand);
+            antes = antes.saturating_add(run.ante);
+            earned = earned.saturating_add(run.money_earned);
+            if let Some(hand_type) = run.most_played {
+                *hands.entry(hand_type).or_default() += 1;
+            }
+        }
+        if summary.runs > 0 {
+            summary.mean_ante = Self::as_f64(antes) / Self::as_f64(summary.runs);
+            summary.mean_money_earned = Self::as_f64(earned) / Self::as_f64(summary.runs);
+        }
+        summary.most_played = hands
+            .into_iter()
+            .max_by_key(|(hand_type, count)| (*count, *hand_type))
+            .map(|(hand_type, _)| hand_type);
+        summary
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    const fn as_f64(n: usize) -> f64 {
+        n as f64
+    }
+}
+
+/// A player's — or a batch's — runs, oldest first.
+///
+/// ```
+/// use cardpack::funky::types::profile::Profile;
+/// use cardpack::preludes::funky::*;
+///
+/// let mut profile = Profile::new("balance".to_string());
+/// for seed in [1, 2] {
+///     let mut run = Run::with_starter_deck(StarterDeck::Red, seed);
+///     run.play(&mut Greedy::default());
+///     profile.record(&run);
+/// }
+///
+/// let red = &profile.by_deck()[&Some(StarterDeck::Red)];
+/// assert_eq!(red.runs, 2);
+/// assert_eq!(profile.summary().runs, 2);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Profile {
+    /// Format version. `1` for every profile this crate writes.
+    pub version: u8,
+    pub name: String,
+    pub runs: Vec<RunStats>,
+}
+
+impl Profile {
+    /// The only format version this crate writes.
+    pub const VERSION: u8 = 1;
+
+    #[must_use]
+    pub const fn new(name: String) -> Self {
+        Self {
+            version: Self::VERSION,
+            name,
+            runs: Vec::new(),
+        }
+    }
+
+    /// File `run` as it stands — call it once the run is over.
+    pub fn record(&mut self, run: &Run) {
+        self.runs.push(run.stats());
+    }
+
+    /// Every run.
+    #[must_use]
+    pub fn summary(&self) -> Summary {
+        Summary::of(&self.runs)
+    }
+
+    /// The runs `filter` keeps.
+    #[must_use]
+    pub fn summary_where<F: Fn(&RunStats) -> bool>(&self, filter: F) -> Summary {
+        Summary::of(self.runs.iter().filter(|run| filter(run)))
+    }
+
+    /// The runs on each deck, `None` for the hand-built boards.
+    #[must_use]
+    pub fn by_deck(&self) -> BTreeMap<Option<StarterDeck>, Summary> {
+        self.group_by(|run| vec![run.deck])
+    }
+
+    /// The runs at each stake.
+    #[must_use]
+    pub fn by_stake(&self) -> BTreeMap<Stake, Summary> {
+        self.group_by(|run| vec![run.stake])
+    }
+
+    /// The runs that held each joker, by its symbol. A run counts once for
+    /// every joker it held.
+    #[must_use]
+    pub fn by_joker(&self) -> BTreeMap<char, Summary> {
+        self.group_by(|run| run.jokers.clone())
+    }
+
+    fn group_by<K: Ord, F: Fn(&RunStats) -> Vec<K>>(&self, keys: F) -> BTreeMap<K, Summary> {
+        let mut groups: BTreeMap<K, Vec<&RunStats>> = BTreeMap::new();
+        for run in &self.runs {
+            for key in keys(run) {
+                groups.entry(key).or_default().push(run);
+            }
+        }
+        groups
+            .into_iter()
+            .map(|(key, runs)| (key, Summary::of(runs)))
+            .collect()
+    }
+
+    /// Serialize to a YAML document.
+    ///
+    /// # Errors
+    ///
+    /// Propagates the serializer's error, boxed as `DeckYaml` boxes it.
+    #[cfg(feature = "yaml")]
+    pub fn to_yaml(&self) -> Result<String, Box<dyn Error>> {
+        Ok(serde_norway::to_string(self)?)
+    }
+
+    /// Parse a YAML document.
+    ///
+    /// # Errors
+    ///
+    /// Malformed YAML, a document that is not a mapping
+    /// ([`CardError::YamlMalformed`](crate::common::errors::CardError::YamlMalformed)),
+    /// or one of a format version other than [`VERSION`](Self::VERSION)
+    /// ([`CardError::YamlUnknownVersion`](crate::common::errors::CardError::YamlUnknownVersion)).
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(yaml_str: &str) -> Result<Self, Box<dyn Error>> {
+        let value: serde_norway::Value = serde_norway::from_str(yaml_str)?;
+        if !value.is_mapping() {
+            return Err(Box::new(crate::common::errors::CardError::YamlMalformed));
+        }
+        let profile: Self = serde_norway::from_value(value)?;
+        if profile.version != Self::VERSION {
+            return Err(Box::new(
+                crate::common::errors::CardError::YamlUnknownVersion(profile.version),
+            ));
+        }
+        Ok(profile)
+    }
+
+    /// Write the profile to `file_path` as [`to_yaml`](Self::to_yaml)'s
+    /// document, replacing whatever was there.
+    ///
+    /// Requires the opt-in `std-io` feature.
+    ///
+    /// # Errors
+    ///
+    /// The file could not be created or written, or the serializer's error.
+    #[cfg(feature = "std-io")]
+    #[allow(clippy::disallowed_types)] // Intentional `std-io` filesystem seam.
+    pub fn save(&self, file_path: &str) -> Result<(), Box<dyn Error>> {
+        let yaml = self.to_yaml()?;
+        File::create(file_path)?.write_all(yaml.as_bytes())?;
+        Ok(())
+    }
+
+    /// Read a profile [`save`](Self::save) wrote to `file_path`.
+    ///
+    /// Requires the opt-in `std-io` feature.
+    ///
+    /// # Errors
+    ///
+    /// The file could not be read, or any of [`from_yaml`](Self::from_yaml)'s.
+    #[cfg(feature = "std-io")]
+    #[allow(clippy::disallowed_types)] // Intentional `std-io` filesystem seam.
+    pub fn load(file_path: &str) -> Result<Self, Box<dyn Error>> {
+        let mut contents = String::new();
+        File::open(file_path)?.read_to_string(&mut contents)?;
+        Self::from_yaml(&contents)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod funky__types__profile_tests {
+    use super::*;
+    use crate::preludes::funky::*;
+
+    fn stats(deck: StarterDeck, won: bool, ante: usize, jokers: &[char]) -> RunStats {
+        RunStats {
+            seed: RunSeed::from(1),
+            deck: Some(deck),
+            stake: Stake::White,
+            won,
+            ante,
+            best_hand: ante * 100,
+            jokers: jokers.to_vec(),
+            money_earned: ante * 10,
+            most_played: Some(HandType::Pair),
+        }
+    }
+
+    fn profile() -> Profile {
+        let mut profile = Profile::new("test".to_string());
+        profile.runs = vec![
+            stats(StarterDeck::Red, true, 9, &['j', '🤹']),
+            stats(StarterDeck::Red, false, 3, &['j']),
+            stats(StarterDeck::Blue, false, 2, &['🤹']),
+        ];
+        profile.runs[2].most_played = Some(HandType::Flush);
+        profile
+    }
+
+    #[test]
+    fn summary() {
+        let summary = profile().summary();
+
+        assert_eq!(summary.runs, 3);
+        assert_eq!(summary.wins, 1);
+        assert_eq!(summary.furthest_ante, 9);
+        assert!((summary.mean_ante - 14.0 / 3.0).abs() < 1e-9);
+        assert!((summary.mean_money_earned - 140.0 / 3.0).abs() < 1e-9);
+        assert_eq!(summary.best_hand, 900);
+        assert_eq!(summary.most_played, Some(HandType::Pair));
+        assert!((summary.win_rate() - 1.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn summary__of_nothing_is_zero() {
+        let summary = Profile::default().summary();
+
+        assert_eq!(summary, Summary::default());
+        assert!(summary.win_rate().abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn summary_where() {
+        let summary = profile().summary_where(|run| !run.won);
+
+        assert_eq!(summary.runs, 2);
+        assert_eq!(summary.furthest_ante, 3);
+        assert_eq!(summary.most_played, Some(HandType::Flush), "a tie");
+    }
+
+    #[test]
+    fn by_deck() {
+        let decks = profile().by_deck();
+
+        assert_eq!(decks.len(), 2);
+        assert_eq!(decks[&Some(StarterDeck::Red)].runs, 2);
+        assert_eq!(decks[&Some(StarterDeck::Blue)].wins, 0);
+    }
+
+    #[test]
+    fn by_stake() {
+        let stakes = profile().by_stake();
+
+        assert_eq!(stakes.len(), 1);
+        assert_eq!(stakes[&Stake::White].runs, 3);
+    }
+
+    #[test]
+    fn by_joker__counts_a_run_for_every_joker_it_held() {
+        let jokers = profile().by_joker();
+
+        assert_eq!(jokers[&'j'].runs, 2);
+        assert_eq!(jokers[&'🤹'].runs, 2);
+        assert!((jokers[&'🤹'].win_rate() - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn record__files_the_run() {
+        let mut run = Run::with_starter_deck(StarterDeck::Red, 42);
+        let report = run.play(&mut Greedy::default());
+        let mut profile = Profile::new("test".to_string());
+        profile.record(&run);
+
+        let stats = &profile.runs[0];
+        assert_eq!(stats.deck, Some(StarterDeck::Red));
+        assert_eq!(stats.won, report.won);
+        assert_eq!(stats.ante, report.ante);
+        assert_eq!(stats.best_hand, report.best_hand);
+        assert_eq!(stats.most_played, run.board().most_played_hand());
+        assert!(
+            run.board()
+                .jokers
+                .iter()
+                .all(|joker| stats.jokers.contains(&joker.rank.index))
+        );
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn yaml__roundtrips() {
+        let yml = profile().to_yaml().unwrap();
+
+        assert!(yml.starts_with("version: 1\nname: test\n"));
+        assert_eq!(Profile::from_yaml(&yml).unwrap(), profile());
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn from_yaml__a_sequence_is_malformed() {
+        let err = Profile::from_yaml("- run").unwrap_err();
+
+        assert_eq!(
+            *err.downcast_ref::<CardError>().unwrap(),
+            CardError::YamlMalformed
+        );
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn from_yaml__an_unknown_version_is_refused() {
+        let yml = profile()
+            .to_yaml()
+            .unwrap()
+            .replacen("version: 1", "version: 2", 1);
+        let err = Profile::from_yaml(&yml).unwrap_err();
+
+        assert_eq!(
+            *err.downcast_ref::<CardError>().unwrap(),
+            CardError::YamlUnknownVersion(2)
+        );
+    }
+
+    #[cfg(feature = "std-io")]
+    #[test]
+    fn save__load_roundtrips() {
+        let path =
+            std::env::temp_dir().join(format!("cardpack-profile-{}.yaml", std::process::id()));
+        let path = path.to_str().unwrap();
+
+        profile().save(path).unwrap();
+        let loaded = Profile::load(path);
+        std::fs::remove_file(path).unwrap();
+
+        assert_eq!(loaded.unwrap(), profile());
+    }
+
+    #[cfg(feature = "std-io")]
+    #[test]
+    fn load__a_missing_file_is_an_error() {
+        assert!(Profile::load("no/such/profile.yaml").is_err());
+    }
+}
@@ -0,0 +1,1632 @@
+use crate::common::errors::CardError;
+use crate::funky::types::action::Action;
+use crate::funky::types::blind::{Blind, BossBlind};
+use crate::funky::types::board::BuffoonBoard;
+use crate::funky::types::buffoon_card::{BCardType, BuffoonCard};
+use crate::funky::types::buffoon_pile::BuffoonPile;
+use crate::funky::types::challenge::Challenge;
+use crate::funky::types::effect::EffectRegistry;
+use crate::funky::types::profile::RunStats;
+use crate::funky::types::seed::{RunRngs, RunSeed, Stream};
+use crate::funky::types::shop::BoosterPack;
+use crate::funky::types::solver::Play;
+use crate::funky::types::stake::Stake;
+use crate::funky::types::starter_deck::StarterDeck;
+use rand::RngExt;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+/// The player in a [`Run`]: every choice the run loop cannot make on its own.
+///
+/// Each method reads the board as it stands and answers with positions, the
+/// same positions the board's own methods take. Only
+/// [`choose_play`](Self::choose_play) is required; the defaults never discard,
+/// leave every shop untouched and take nothing from a pack, which is a legal
+/// (if hopeless) way to play.
+pub trait Strategy {
+    /// The cards to play, as positions in
+    /// [`in_hand`](BuffoonBoard::in_hand). One to five of them; anything else
+    /// forfeits the round.
+    fn choose_play(&mut self, board: &BuffoonBoard) -> Vec<usize>;
+
+    /// The cards to discard before the next play, or none to play straight
+    /// away. Asked again after every discard, while the round has discards
+    /// left.
+    fn choose_discard(&mut self, _board: &BuffoonBoard) -> Vec<usize> {
+        Vec::new()
+    }
+
+    /// The next thing to do in the open shop. Asked until it answers
+    /// [`ShopChoice::Leave`].
+    fn choose_shop(&mut self, _board: &BuffoonBoard) -> ShopChoice {
+        ShopChoice::Leave
+    }
+
+    /// Which of an opened pack's `choices` to take, or `None` for none of them.
+    fn choose_pack_pick(
+        &mut self,
+        _board: &BuffoonBoard,
+        _choices: &[BuffoonCard],
+    ) -> Option<usize> {
+        None
+    }
+
+    /// Whether to reroll the ante's `boss` before selecting the next blind.
+    /// Asked again after every reroll the board accepts.
+    fn choose_boss_reroll(&mut self, _board: &BuffoonBoard, _boss: BossBlind) -> bool {
+        false
+    }
+}
+
+/// One move in the shop, as a [`Strategy`] names it.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum ShopChoice {
+    /// [`buy_stock`](BuffoonBoard::buy_stock) at this slot.
+    BuyStock(usize),
+    /// [`open_pack_with_rng`](BuffoonBoard::open_pack_with_rng) at this slot,
+    /// then ask for a pick.
+    OpenPack(usize),
+    /// [`skip_pack`](BuffoonBoard::skip_pack) at this slot.
+    SkipPack(usize),
+    /// [`reroll_with_rng`](BuffoonBoard::reroll_with_rng) the card slots.
+    Reroll,
+    /// [`redeem_shop_voucher`](BuffoonBoard::redeem_shop_voucher).
+    RedeemVoucher,
+    /// Close the shop and select the next blind.
+    Leave,
+}
+
+impl Display for ShopChoice {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BuyStock(index) => write!(f, "buy stock {index}"),
+            Self::OpenPack(index) => write!(f, "open pack {index}"),
+            Self::SkipPack(index) => write!(f, "skip pack {index}"),
+            Self::Reroll => write!(f, "reroll"),
+            Self::RedeemVoucher => write!(f, "redeem voucher"),
+            Self::Leave => write!(f, "leave"),
+        }
+    }
+}
+
+/// One entry in a [`Run`]'s log: a choice the strategy made, or a milestone
+/// the run reached, in the order they happened.
+///
+/// Cards are logged as cards rather than positions, so the log reads on its
+/// own without the board it was played on.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Decision {
+    /// A blind was selected, and what it takes to beat it.
+    BlindSelected {
+        ante: usize,
+        blind: Blind,
+        target: usize,
+    },
+    Discard {
+        cards: BuffoonPile,
+    },
+    Play {
+        cards: BuffoonPile,
+        score: usize,
+    },
+    /// The strategy asked for a play the board refused — the round is lost.
+    Forfeit {
+        indices: Vec<usize>,
+    },
+    RoundEnd {
+        won: bool,
+        round_score: usize,
+        money: isize,
+    },
+    /// A shop move, and whether the board accepted it. A refused move (too
+    /// poor, no room) changes nothing.
+    Shop {
+        choice: ShopChoice,
+        accepted: bool,
+    },
+    PackPick {
+        choices: Vec<BuffoonCard>,
+        pick: Option<BuffoonCard>,
+    },
+    Used {
+        card: BuffoonCard,
+    },
+    Sold {
+        joker: BuffoonCard,
+    },
+    /// The ante's Boss Blind was rerolled into this one.
+    BossRerolled {
+        boss: BossBlind,
+    },
+}
+
+impl Display for Decision {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BlindSelected {
+                ante,
+                blind,
+                target,
+            } => write!(f, "ante {ante}: {blind}, target {target}"),
+            Self::Discard { cards } => write!(f, "discard {cards}"),
+            Self::Play { cards, score } => write!(f, "play {cards} for {score}"),
+            Self::Forfeit { indices } => write!(f, "forfeit {indices:?}"),
+            Self::RoundEnd {
+                won,
+                round_score,
+                money,
+            } => {
+                let result = if *won { "won" } else { "lost" };
+                write!(f, "round {result} with {round_score}, ${money}")
+            }
+            Self::Shop { choice, accepted } => {
+                let result = if *accepted { "" } else { " (refused)" };
+                write!(f, "shop: {choice}{result}")
+            }
+            Self::PackPick { pick, .. } => match pick {
+                Some(card) => write!(f, "pick {card}"),
+                None => write!(f, "pick nothing"),
+            },
+            Self::Used { card } => write!(f, "use {card}"),
+            Self::Sold { joker } => write!(f, "sell {joker}"),
+            Self::BossRerolled { boss } => write!(f, "reroll the boss into {boss}"),
+        }
+    }
+}
+
+/// How a [`Run`] ended.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RunReport {
+    pub seed: RunSeed,
+    /// Whether the winning ante's Boss Blind was beaten.
+    pub won: bool,
+    /// The ante the run ended in — one past the winning ante for a won run.
+    pub ante: usize,
+    pub blinds_beaten: usize,
+    /// The best single hand of the run.
+    pub best_hand: usize,
+    /// Every hand's score, summed.
+    pub total_score: usize,
+    pub money: isize,
+    pub log: Vec<Decision>,
+}
+
+impl Display for RunReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let result = if self.won { "won" } else { "lost" };
+        write!(
+            f,
+            "seed {}: {result} at ante {}, {} blinds beaten, best hand {}, ${}",
+            self.seed, self.ante, self.blinds_beaten, self.best_hand, self.money
+        )
+    }
+}
+
+/// Where a [`Run`] is between actions — which actions it will take next.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum RunPhase {
+    /// Waiting for [`Action::SelectBlind`], or an [`Action::RerollBoss`]
+    /// first.
+    BlindSelect,
+    /// A round is being played.
+    Round,
+    /// The shop is open.
+    Shop,
+    /// A pack is open, offering these cards, and waiting for
+    /// [`Action::PickFromPack`].
+    Pack(Vec<BuffoonCard>),
+    /// The run is over, won or lost. Every action is refused.
+    Over,
+}
+
+/// A whole seeded run, driven headless.
+///
+/// Blind select, the deal, every play and discard, cash-out, and the shop, ante
+/// after ante, until a blind is lost or the
+/// [`winning_ante`](Self::winning_ante)'s boss falls.
+///
+/// The run loop is the orchestration a caller used to write by hand around
+/// [`on_blind_selected_with_rng`](BuffoonBoard::on_blind_selected_with_rng),
+/// [`deal_to_hand_size`](BuffoonBoard::deal_to_hand_size),
+/// [`play_hand_with_rng`](BuffoonBoard::play_hand_with_rng),
+/// [`on_round_end_with_rng`](BuffoonBoard::on_round_end_with_rng) and the shop.
+/// Every random draw comes from the seed, each kind from its own
+/// [`Stream`] — so rerolling a shop never changes the next shuffle — and every
+/// choice arrives as an [`Action`] through [`apply`](Self::apply) — from a
+/// [`Strategy`] in [`play`](Self::play), or from a recording in
+/// [`RunRecord::replay`]. So a seed and its actions are a run: the same pair
+/// always plays out the same way, which is what makes batch-balancing a custom
+/// joker over thousands of runs meaningful, and a recorded run a save game.
+///
+/// Each blind is played on a fresh shuffle of the
+/// [`full_deck`](BuffoonBoard::full_deck), as in Balatro. Each ante's boss is
+/// drawn as the ante opens, so with Director's Cut or Retcon it can be
+/// rerolled from any of the ante's blind selects. Not modelled: blind skipping
+/// and its tags. A picked Planet is used on the spot, as a pack does; a picked
+/// Tarot waits in its slot.
+///
+/// Custom jokers score, and their hooks fire, through the run's
+/// [`EffectRegistry`] — empty unless one is given with
+/// [`with_registry`](Self::with_registry), so a run of built-ins needs none.
+#[derive(Debug)]
+pub struct Run {
+    /// Private, so nothing but an action moves the run away from its
+    /// [`record`](Self::record).
+    board: BuffoonBoard,
+    pub seed: RunSeed,
+    /// The ante whose Boss Blind wins the run — Balatro's 8.
+    pub winning_ante: usize,
+    pub phase: RunPhase,
+    /// The blind [`Action::SelectBlind`] selects next.
+    pub upcoming: Blind,
+    /// This ante's Boss Blind, drawn as the ante opens and changed by
+    /// [`Action::RerollBoss`].
+    pub boss: BossBlind,
+    pub blinds_beaten: usize,
+    pub won: bool,
+    /// Every action applied, refused ones included, in order.
+    pub actions: Vec<Action>,
+    pub log: Vec<Decision>,
+    /// The board the run started from — what a replay starts from.
+    start: BuffoonBoard,
+    rngs: RunRngs,
+    registry: Arc<EffectRegistry>,
+    /// How many more picks the open pack allows.
+    pack_picks: usize,
+    /// Every dollar the run has gained, spending never taken off.
+    earned: usize,
+    /// The symbol of every joker the board has held, in the order they came.
+    jokers_seen: Vec<char>,
+}
+
+impl Run {
+    /// The ante whose Boss Blind wins a Balatro run.
+    pub const WINNING_ANTE: usize = 8;
+    /// What a Balatro run starts with in hand, before any deck's bonus.
+    pub const STARTING_MONEY: isize = 4;
+    /// How many moves a strategy gets in one shop. A guard, not a rule: a
+    /// strategy that never leaves is walked out rather than looping forever.
+    pub const MAX_SHOP_ACTIONS: usize = 64;
+
+    /// A run over `board` as it stands, with every draw seeded from `seed`.
+    #[must_use]
+    pub fn new(board: BuffoonBoard, seed: impl Into<RunSeed>) -> Self {
+        let seed = seed.into();
+        let jokers_seen = Self::symbols(&board);
+        let mut run = Self {
+            rngs: RunRngs::new(&seed),
+            registry: Arc::default(),
+            start: board.clone(),
+            board,
+            seed,
+            winning_ante: Self::WINNING_ANTE,
+            phase: RunPhase::BlindSelect,
+            upcoming: Blind::Small,
+            boss: BossBlind::default(),
+            blinds_beaten: 0,
+            won: false,
+            actions: Vec::new(),
+            log: Vec::new(),
+            pack_picks: 0,
+            earned: 0,
+            jokers_seen,
+        };
+        run.draw_boss();
+        run
+    }
+
+    /// A fresh run of `deck`: its board, plus Balatro's starting $4. Erratic's
+    /// cards come from the seed's [`Stream::Deck`].
+    #[must_use]
+    pub fn with_starter_deck(deck: StarterDeck, seed: impl Into<RunSeed>) -> Self {
+        let seed = seed.into();
+        let mut board = deck.board_with_rng(&mut seed.rng(Stream::Deck));
+        board.money = board.money.saturating_add(Self::STARTING_MONEY);
+        Self::new(board, seed)
+    }
+
+    /// A fresh run of `challenge`: its board, plus Balatro's starting $4 unless
+    /// the challenge sets the money itself.
+    ///
+    /// # Errors
+    ///
+    /// [`CardError::InvalidChallenge`] if the challenge does not hold up — see
+    /// [`Challenge::board_with_rng`].
+    pub fn with_challenge(
+        challenge: &Challenge,
+        seed: impl Into<RunSeed>,
+    ) -> Result<Self, CardError> {
+        let seed = seed.into();
+        let mut board = challenge.board_with_rng(&mut seed.rng(Stream::Deck))?;
+        if challenge.money.is_none() {
+            board.money = board.money.saturating_add(Self::STARTING_MONEY);
+        }
+        Ok(Self::new(board, seed))
+    }
+
+    /// This run, started over at `stake`: the same board and seed, with any
+    /// actions taken so far dropped — a stake is where a run starts, not
+    /// something to change halfway.
+    #[must_use]
+    pub fn with_stake(self, stake: Stake) -> Self {
+        let mut board = self.start;
+        board.stake = stake;
+        let mut run = Self::new(board, self.seed).with_registry(self.registry);
+        run.winning_ante = self.winning_ante;
+        run
+    }
+
+    /// This run, scoring `MPip::Custom` cards and jokers through `registry` and
+    /// firing their lifecycle hooks at every play, discard, purchase, blind
+    /// and cash-out — the `_with_registry` twins of the board's actions.
+    ///
+    /// An `Arc`, so one registry can serve a whole batch of runs, on as many
+    /// threads as it likes, and the strategies playing them:
+    ///
+    /// ```
+    /// use cardpack::funky::types::effect::EffectRegistry;
+    /// use cardpack::funky::types::run::{Greedy, Run};
+    /// use cardpack::funky::types::starter_deck::StarterDeck;
+    /// use std::sync::Arc;
+    ///
+    /// let registry = Arc::new(EffectRegistry::new());
+    /// let reports: Vec<_> = (0..2)
+    ///     .map(|seed| {
+    ///         Run::with_starter_deck(StarterDeck::Red, seed)
+    ///             .with_registry(Arc::clone(&registry))
+    ///             .play(&mut Greedy::default().with_registry(Arc::clone(&registry)))
+    ///     })
+    ///     .collect();
+    ///
+    /// assert_eq!(reports.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn with_registry(mut self, registry: impl Into<Arc<EffectRegistry>>) -> Self {
+        self.registry = registry.into();
+        self
+    }
+
+    /// The board as the run's actions have left it.
+    #[must_use]
+    pub const fn board(&self) -> &BuffoonBoard {
+        &self.board
+    }
+
+    /// One run of `deck` per seed, each with a fresh strategy from `strategy`.
+    pub fn batch<S, I, F>(deck: StarterDeck, seeds: I, mut strategy: F) -> Vec<RunReport>
+    where
+        S: Strategy,
+        I: IntoIterator,
+        I::Item: Into<RunSeed>,
+        F: FnMut() -> S,
+    {
+        seeds
+            .into_iter()
+            .map(|seed| Self::with_starter_deck(deck, seed).play(&mut strategy()))
+            .collect()
+    }
+
+    /// The score `blind` asks for in `ante`: the ante's
+    /// [base at this stake](Stake::ante_base), ×1 for the Small Blind, ×1.5
+    /// for the Big and ×2 for a Boss, then ×2 again on a Plasma Deck.
+    #[must_use]
+    pub fn blind_target(
+        ante: usize,
+        blind: Blind,
+        deck: Option<StarterDeck>,
+        stake: Stake,
+    ) -> usize {
+        let base = stake.ante_base(ante);
+        let sized = match blind {
+            Blind::Small => base,
+            Blind::Big => base + base / 2,
+            Blind::Boss(_) => base * 2,
+        };
+        sized * deck.map_or(1, StarterDeck::blind_target_multiplier)
+    }
+
+    /// Play the run to its end, asking `strategy` for every action, and report
+    /// it.
+    pub fn play<S: Strategy + ?Sized>(&mut self, strategy: &mut S) -> RunReport {
+        let mut shop_moves = 0;
+        loop {
+            match &self.phase {
+                RunPhase::Over => break,
+                RunPhase::BlindSelect => {
+                    shop_moves = 0;
+                    while strategy.choose_boss_reroll(&self.board, self.boss)
+                        && self.apply(Action::RerollBoss)
+                    {}
+                    self.apply(Action::SelectBlind);
+                }
+                RunPhase::Round => {
+                    while self.board.discards_remaining() > 0 {
+                        let indices = strategy.choose_discard(&self.board);
+                        if indices.is_empty() || !self.apply(Action::Discard(indices)) {
+                            break;
+                        }
+                    }
+                    let indices = strategy.choose_play(&self.board);
+                    self.apply(Action::PlayHand(indices));
+                }
+                RunPhase::Shop => {
+                    shop_moves += 1;
+                    let action = if shop_moves > Self::MAX_SHOP_ACTIONS {
+                        Action::LeaveShop
+                    } else {
+                        Action::from(strategy.choose_shop(&self.board))
+                    };
+                    self.apply(action);
+                }
+                RunPhase::Pack(choices) => {
+                    let pick = strategy.choose_pack_pick(&self.board, choices);
+                    self.apply(Action::PickFromPack(pick));
+                }
+            }
+        }
+        self.report()
+    }
+
+    /// Apply one action, record it, and log what came of it. Returns whether
+    /// it was accepted.
+    ///
+    /// Refused — changing nothing but the action record — when the run is not
+    /// in the [`phase`](Self::phase) the action belongs to, or when the board
+    /// refuses it (too poor, no room, no such slot). Playing and discarding
+    /// take one to five cards. A play the board refuses **forfeits** the
+    /// round, as a play that cannot be made ends a Balatro round: it is the
+    /// one refusal with a consequence.
+    ///
+    /// Consumables may be used and jokers sold in any phase but a pack's and
+    /// the end's.
+    pub fn apply(&mut self, action: Action) -> bool {
+        let money = self.board.money;
+        let accepted = self.act(action);
+        let gain = self.board.money.saturating_sub(money).max(0);
+        self.earned = self
+            .earned
+            .saturating_add(usize::try_from(gain).unwrap_or(0));
+        for symbol in Self::symbols(&self.board) {
+            if !self.jokers_seen.contains(&symbol) {
+                self.jokers_seen.push(symbol);
+            }
+        }
+        accepted
+    }
+
+    fn act(&mut self, action: Action) -> bool {
+        self.actions.push(action.clone());
+        let anytime = matches!(
+            self.phase,
+            RunPhase::BlindSelect | RunPhase::Round | RunPhase::Shop
+        );
+        let phase = self.phase.clone();
+        match (action, &phase) {
+            (Action::SelectBlind, RunPhase::BlindSelect) => {
+                self.select_blind();
+                true
+            }
+            (Action::RerollBoss, RunPhase::BlindSelect) => self.reroll_boss(),
+            (Action::PlayHand(indices), RunPhase::Round) => {
+                self.play_hand(indices);
+                true
+            }
+            (Action::Discard(indices), RunPhase::Round) => self.discard(&indices),
+            (Action::OpenPack(index), RunPhase::Shop) => {
+                let picks = self
+                    .board
+                    .shop
+                    .as_ref()
+                    .and_then(|shop| shop.packs.get(index))
+                    .map_or(0, BoosterPack::picks);
+                let opened = self.board.open_pack_with_rng(index, &mut self.rngs.pack);
+                self.log_shop(ShopChoice::OpenPack(index), opened.is_some());
+                if let Some(choices) = opened {
+                    self.pack_picks = picks;
+                    self.phase = RunPhase::Pack(choices);
+                }
+                self.phase != RunPhase::Shop
+            }
+            (Action::PickFromPack(pick), RunPhase::Pack(_)) => {
+                self.pick_from_pack(pick);
+                true
+            }
+            (Action::LeaveShop, RunPhase::Shop) => {
+                self.board.shop = None;
+                self.phase = RunPhase::BlindSelect;
+                true
+            }
+            (Action::UseConsumable { index, targets }, _) if anytime => {
+                let used = self
+                    .board
+                    .use_consumable_with_rng(index, &targets, &mut self.rngs.roll);
+                if let Some(card) = used {
+                    self.log.push(Decision::Used { card });
+                }
+                used.is_some()
+            }
+            (Action::SellJoker(index), _) if anytime => {
+                let sold = self.board.sell_joker(index);
+                if let Some(joker) = sold {
+                    self.log.push(Decision::Sold { joker });
+                }
+                sold.is_some()
+            }
+            (action, RunPhase::Shop) => {
+                let Some(choice) = Self::shop_choice(&action) else {
+                    return false;
+                };
+                let accepted = match choice {
+                    ShopChoice::BuyStock(index) => self
+                        .board
+                        .try_buy_stock_with_registry(index, &self.registry)
+                        .is_ok(),
+                    ShopChoice::SkipPack(index) => self.board.skip_pack(index),
+                    ShopChoice::Reroll => self.board.reroll_with_rng(&mut self.rngs.shop),
+                    ShopChoice::RedeemVoucher => self.board.redeem_shop_voucher(),
+                    ShopChoice::OpenPack(_) | ShopChoice::Leave => false,
+                };
+                self.log_shop(choice, accepted);
+                accepted
+            }
+            _ => false,
+        }
+    }
+
+    /// The record of the run so far: where it started, its seed, and every
+    /// action and decision since.
+    #[must_use]
+    pub fn record(&self) -> RunRecord {
+        RunRecord {
+            seed: self.seed.clone(),
+            winning_ante: self.winning_ante,
+            start: self.start.clone(),
+            actions: self.actions.clone(),
+            log: self.log.clone(),
+        }
+    }
+
+    /// The run so far as a [`Profile`](crate::funky::types::profile::Profile)
+    /// keeps it.
+    #[must_use]
+    pub fn stats(&self) -> RunStats {
+        let best_hand = self
+            .log
+            .iter()
+            .filter_map(|decision| match decision {
+                Decision::Play { score, .. } => Some(*score),
+                _ => None,
+            })
+            .max()
+            .unwrap_or_default();
+        RunStats {
+            seed: self.seed.clone(),
+            deck: self.board.starter_deck,
+            stake: self.board.stake,
+            won: self.won,
+            ante: self.board.ante,
+            best_hand,
+            jokers: self.jokers_seen.clone(),
+            money_earned: self.earned,
+            most_played: self.board.most_played_hand(),
+        }
+    }
+
+    fn symbols(board: &BuffoonBoard) -> Vec<char> {
+        board.jokers.iter().map(|joker| joker.rank.index).collect()
+    }
+
+    /// Draw the Boss Blind the ante just opened will end on.
+    fn draw_boss(&mut self) {
+        self.boss = BossBlind::ALL[self.rngs.boss.random_range(0..BossBlind::ALL.len())];
+    }
+
+    /// Reroll the ante's boss, paying the board, and put the new one wherever
+    /// the old one was waiting.
+    fn reroll_boss(&mut self) -> bool {
+        let Ok(boss) = self
+            .board
+            .try_reroll_upcoming_boss_with_rng(self.boss, &mut self.rngs.boss)
+        else {
+            return false;
+        };
+        self.boss = boss;
+        if self.upcoming.is_boss() {
+            self.upcoming = Blind::Boss(boss);
+        }
+        self.log.push(Decision::BossRerolled { boss });
+        true
+    }
+
+    /// Select the upcoming blind: price it, shuffle the full deck, fire the
+    /// blind-select hooks and deal.
+    fn select_blind(&mut self) {
+        let blind = self.upcoming;
+        let board = &mut self.board;
+        board.blind = blind;
+        board.blind_target = Self::blind_target(board.ante, blind, board.starter_deck, board.stake);
+        board.in_hand.clear();
+        board.played.clear();
+        board.discarded.clear();
+        board.deck = board.full_deck.shuffled_with_rng(&mut self.rngs.shuffle);
+        board.on_blind_selected_with_registry(&mut self.rngs.roll, &self.registry);
+        board.deal_to_hand_size();
+        self.log.push(Decision::BlindSelected {
+            ante: board.ante,
+            blind,
+            target: board.blind_target,
+        });
+        self.phase = RunPhase::Round;
+        if self.board.in_hand.is_empty() {
+            self.end_round();
+        }
+    }
+
+    fn play_hand(&mut self, indices: Vec<usize>) {
+        let cards = self.cards_at(&indices);
+        let score = if (1..=Play::MAX_CARDS).contains(&indices.len()) {
+            self.board
+                .try_play_hand_with_registry(&indices, &mut self.rngs.roll, &self.registry)
+                .ok()
+        } else {
+            None
+        };
+        let Some(score) = score else {
+            self.log.push(Decision::Forfeit { indices });
+            self.end_round();
+            return;
+        };
+        self.log.push(Decision::Play {
+            cards,
+            score: score.score(),
+        });
+        if self.board.round_is_won()
+            || self.board.hands_remaining() == 0
+            || self.board.in_hand.is_empty()
+        {
+            self.end_round();
+        }
+    }
+
+    fn discard(&mut self, indices: &[usize]) -> bool {
+        if indices.is_empty() || indices.len() > Play::MAX_CARDS {
+            return false;
+        }
+        let cards = self.cards_at(indices);
+        let discarded = self
+            .board
+            .try_discard_cards_with_registry(indices, &self.registry)
+            .is_ok();
+        if discarded {
+            self.log.push(Decision::Discard { cards });
+        }
+        discarded
+    }
+
+    /// Cash out, then move on: to the shop after a won blind — straight to the
+    /// next blind under a no-shop challenge — to the end after a lost one or
+    /// the winning boss.
+    fn end_round(&mut self) {
+        let won = self.board.round_is_won();
+        let round_score = self.board.round_score;
+        let final_blind = self.upcoming.is_boss() && self.board.ante >= self.winning_ante;
+        self.board
+            .on_round_end_with_registry(&mut self.rngs.roll, &self.registry);
+        self.log.push(Decision::RoundEnd {
+            won,
+            round_score,
+            money: self.board.money,
+        });
+        if !won {
+            self.phase = RunPhase::Over;
+            return;
+        }
+        self.blinds_beaten += 1;
+        if final_blind {
+            self.won = true;
+            self.phase = RunPhase::Over;
+            return;
+        }
+        self.upcoming = match self.upcoming {
+            Blind::Small => Blind::Big,
+            Blind::Big => Blind::Boss(self.boss),
+            Blind::Boss(_) => {
+                self.draw_boss();
+                Blind::Small
+            }
+        };
+        if self.board.challenge_rules.no_shop {
+            self.phase = RunPhase::BlindSelect;
+            return;
+        }
+        self.board.open_shop_with_rng(&mut self.rngs.shop);
+        self.phase = RunPhase::Shop;
+    }
+
+    /// The shop move an action is, for the moves that are only a board call.
+    const fn shop_choice(action: &Action) -> Option<ShopChoice> {
+        match action {
+            Action::BuyStock(index) => Some(ShopChoice::BuyStock(*index)),
+            Action::SkipPack(index) => Some(ShopChoice::SkipPack(*index)),
+            Action::Reroll => Some(ShopChoice::Reroll),
+            Action::RedeemVoucher => Some(ShopChoice::RedeemVoucher),
+            _ => None,
+        }
+    }
+
+    fn log_shop(&mut self, choice: ShopChoice, accepted: bool) {
+        self.log.push(Decision::Shop { choice, accepted });
+    }
+
+    /// Place the pick from the open pack: a joker onto the board if there is
+    /// room, a playing card into the deck, anything else into a consumable
+    /// slot — and a Planet is used at once, the way a Celestial pack plays it.
+    ///
+    /// Back to the shop, unless the pick landed and the pack allows another —
+    /// a Mega pack's second — when the rest of the choices stay open.
+    fn pick_from_pack(&mut self, pick: Option<usize>) {
+        let RunPhase::Pack(choices) = std::mem::replace(&mut self.phase, RunPhase::Shop) else {
+            return;
+        };
+        let placed = pick
+            .and_then(|index| choices.get(index).copied())
+            .filter(|card| {
+                if card.is_joker() {
+                    let room = self.board.has_joker_room();
+                    if room {
+                        self.board.push_joker(*card);
+                    }
+                    room
+                } else if matches!(card.card_type, BCardType::Basic | BCardType::Stone) {
+                    self.board.add_card_to_deck(*card);
+                    true
+                } else if self.board.create_consumable(*card) {
+                    if card.card_type == BCardType::Planet {
+                        let slot = self.board.consumables.len() - 1;
+                        self.board
+                            .use_consumable_with_rng(slot, &[], &mut self.rngs.roll);
+                    }
+                    true
+                } else {
+                    false
+                }
+            });
+        self.log.push(Decision::PackPick {
+            choices: choices.clone(),
+            pick: placed,
+        });
+        self.pack_picks = self.pack_picks.saturating_sub(1);
+        if let Some(index) = pick.filter(|_| placed.is_some() && self.pack_picks > 0) {
+            let mut rest = choices;
+            rest.remove(index);
+            if !rest.is_empty() {
+                self.phase = RunPhase::Pack(rest);
+            }
+        }
+    }
+
+    fn cards_at(&self, indices: &[usize]) -> BuffoonPile {
+        indices
+            .iter()
+            .filter_map(|index| self.board.in_hand.get(*index).copied())
+            .collect()
+    }
+
+    fn report(&self) -> RunReport {
+        let scores = self.log.iter().filter_map(|decision| match decision {
+            Decision::Play { score, .. } => Some(*score),
+            _ => None,
+        });
+        let (best_hand, total_score) = scores
+            .fold((0, 0), |(best, total): (usize, usize), score| {
+                (best.max(score), total.saturating_add(score))
+            });
+        RunReport {
+            seed: self.seed.clone(),
+            won: self.won,
+            ante: self.board.ante,
+            blinds_beaten: self.blinds_beaten,
+            best_hand,
+            total_score,
+            money: self.board.money,
+            log: self.log.clone(),
+        }
+    }
+}
+
+/// A run as a save game: the board it started from, its seed, and what was
+/// done to it.
+///
+/// [`replay`](Self::replay) plays the actions again and checks, action by
+/// action, that they come out as the `log` says they did — the same plays for
+/// the same scores, the same pack offers, the same refusals. The first action
+/// that does not is a [`Divergence`]: in a bug report, the step where two
+/// builds disagree; in a regression test, the step a change broke.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RunRecord {
+    pub seed: RunSeed,
+    pub winning_ante: usize,
+    pub start: BuffoonBoard,
+    pub actions: Vec<Action>,
+    pub log: Vec<Decision>,
+}
+
+impl RunRecord {
+    /// Replay the record from its start, returning the run at its end.
+    ///
+    /// # Errors
+    ///
+    /// The first [`Divergence`] between what the actions do now and what the
+    /// log recorded.
+    pub fn replay(&self) -> Result<Run, Divergence> {
+        self.replay_with_registry(EffectRegistry::new())
+    }
+
+    /// [`replay`](Self::replay) a run that held custom jokers, through the
+    /// registry it was played with — see [`Run::with_registry`].
+    ///
+    /// # Errors
+    ///
+    /// As [`replay`](Self::replay): a registry that scores differently is a
+    /// divergence like any other.
+    pub fn replay_with_registry(
+        &self,
+        registry: impl Into<Arc<EffectRegistry>>,
+    ) -> Result<Run, Divergence> {
+        let mut run = Run::new(self.start.clone(), self.seed.clone()).with_registry(registry);
+        run.winning_ante = self.winning_ante;
+        for (step, action) in self.actions.iter().enumerate() {
+            let before = run.log.len();
+            run.apply(action.clone());
+            let found = &run.log[before..];
+            let expected = self.log.get(before..run.log.len()).unwrap_or_default();
+            if found != expected {
+                return Err(Divergence {
+                    step,
+                    action: Some(action.clone()),
+                    expected: expected.to_vec(),
+                    found: found.to_vec(),
+                });
+            }
+        }
+        if run.log.len() < self.log.len() {
+            return Err(Divergence {
+                step: self.actions.len(),
+                action: None,
+                expected: self.log[run.log.len()..].to_vec(),
+                found: Vec::new(),
+            });
+        }
+        Ok(run)
+    }
+}
+
+/// Where a [`RunRecord::replay`] stopped agreeing with its log.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Divergence {
+    /// The position of the action in the record, or the record's length when
+    /// every action agreed but the log recorded more.
+    pub step: usize,
+    pub action: Option<Action>,
+    /// What the log recorded for the step.
+    pub expected: Vec<Decision>,
+    /// What the replay produced instead.
+    pub found: Vec<Decision>,
+}
+
+impl Display for Divergence {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "diverged at step {}", self.step)?;
+        if let Some(action) = &self.action {
+            write!(f, " ({action})")?;
+        }
+        let expected = self.expected.first().map(ToString::to_string);
+        let found = self.found.first().map(ToString::to_string);
+        write!(
+            f,
+            ": expected {}, found {}",
+            expected.as_deref().unwrap_or("nothing"),
+            found.as_deref().unwrap_or("nothing")
+        )
+    }
+}
+
+/// A [`Strategy`] built on the board's solver.
+///
+/// It plays the [best play](BuffoonBoard::best_plays), discards the
+/// [dead cards](BuffoonBoard::best_discard) while the best play falls short of
+/// the blind, and buys the first joker the board can afford and seat.
+///
+/// A baseline to measure a custom joker against, not a good player — it never
+/// opens a pack, sells, or plans past the hand in front of it. To see the
+/// custom joker at all, it needs the run's registry too — see
+/// [`with_registry`](Self::with_registry).
+#[derive(Clone, Debug, Default)]
+pub struct Greedy {
+    /// Seeded samples per play for the expected value; 0 plays the floor.
+    pub samples: u64,
+    registry: Arc<EffectRegistry>,
+}
+
+impl Greedy {
+    /// This strategy, pricing plays and discards with `MPip::Custom` cards and
+    /// jokers scored through `registry` — the one the [`Run`] was given with
+    /// [`Run::with_registry`], so the plays it picks are scored as the run
+    /// will score them.
+    #[must_use]
+    pub fn with_registry(mut self, registry: impl Into<Arc<EffectRegistry>>) -> Self {
+        self.registry = registry.into();
+        self
+    }
+}
+
+impl Strategy for Greedy {
+    fn choose_play(&mut self, board: &BuffoonBoard) -> Vec<usize> {
+        board
+            .best_plays_with_registry(1, self.samples, &self.registry)
+            .pop()
+            .map(|play| play.indices)
+            .unwrap_or_default()
+    }
+
+    fn choose_discard(&mut self, board: &BuffoonBoard) -> Vec<usize> {
+        let needed = board.blind_target.saturating_sub(board.round_score);
+        let enough = board
+            .best_plays_with_registry(1, self.samples, &self.registry)
+            .pop()
+            .is_some_and(|play| play.floor >= needed);
+        if enough {
+            Vec::new()
+        } else {
+            board.best_discard_with_registry(self.samples, &self.registry)
+        }
+    }
+
+    fn choose_shop(&mut self, board: &BuffoonBoard) -> ShopChoice {
+        if !board.has_joker_room() {
+            return ShopChoice::Leave;
+        }
+        let stock = board.shop.as_ref().map_or(&[][..], |shop| &shop.stock[..]);
+        stock
+            .iter()
+            .enumerate()
+            .find(|(index, card)| {
+                card.is_joker()
+                    && board.stock_cost(*index).is_some_and(|cost| {
+                        isize::try_from(cost).is_ok_and(|cost| cost <= board.money)
+                    })
+            })
+            .map_or(ShopChoice::Leave, |(index, _)| ShopChoice::BuyStock(index))
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod funky__types__run_tests {
+    use super::*;
+    use crate::bcard;
+    use crate::funky::decks::joker::card as joker;
+    use crate::funky::types::challenge::ChallengeRules;
+    use crate::funky::types::draws::Draws;
+    use crate::funky::types::effect::{Effect, EffectId, ScoreOp, ScoringContext};
+    use crate::funky::types::mpip::MPip;
+    use crate::funky::types::shop::{BoosterPack, PackKind, PackSize, Shop};
+    use crate::funky::types::voucher::Voucher;
+    use crate::preludes::funky::Deck;
+
+    /// Plays the first card, every time.
+    struct FirstCard;
+
+    impl Strategy for FirstCard {
+        fn choose_play(&mut self, _board: &BuffoonBoard) -> Vec<usize> {
+            vec![0]
+        }
+    }
+
+    /// Asks for a play no board accepts.
+    struct Nonsense;
+
+    impl Strategy for Nonsense {
+        fn choose_play(&mut self, _board: &BuffoonBoard) -> Vec<usize> {
+            vec![0, 1, 2, 3, 4, 5]
+        }
+    }
+
+    /// Rerolls forever.
+    struct Reroller;
+
+    impl Strategy for Reroller {
+        fn choose_play(&mut self, _board: &BuffoonBoard) -> Vec<usize> {
+            vec![0]
+        }
+
+        fn choose_shop(&mut self, _board: &BuffoonBoard) -> ShopChoice {
+            ShopChoice::Reroll
+        }
+    }
+
+    #[test]
+    fn blind_target__scales_by_blind_and_deck() {
+        let boss = Blind::Boss(BossBlind::TheWater);
+
+        assert_eq!(Run::blind_target(1, Blind::Small, None, Stake::White), 300);
+        assert_eq!(Run::blind_target(1, Blind::Big, None, Stake::White), 450);
+        assert_eq!(Run::blind_target(1, boss, None, Stake::White), 600);
+        assert_eq!(Run::blind_target(8, boss, None, Stake::White), 100_000);
+        assert_eq!(Run::blind_target(0, Blind::Small, None, Stake::White), 100);
+        assert_eq!(
+            Run::blind_target(12, Blind::Small, None, Stake::White),
+            50_000
+        );
+        assert_eq!(
+            Run::blind_target(1, Blind::Small, Some(StarterDeck::Plasma), Stake::White),
+            600
+        );
+        assert_eq!(Run::blind_target(8, boss, None, Stake::Gold), 400_000);
+    }
+
+    #[test]
+    fn with_starter_deck__adds_the_starting_money() {
+        assert_eq!(Run::with_starter_deck(StarterDeck::Red, 1).board.money, 4);
+        assert_eq!(
+            Run::with_starter_deck(StarterDeck::Yellow, 1).board.money,
+            14
+        );
+    }
+
+    #[test]
+    fn with_challenge__sets_the_money_or_adds_the_starting_money() {
+        let needle = &Challenge::balatro()[1];
+        let plain = Challenge::new("plain".to_string());
+
+        assert_eq!(Run::with_challenge(needle, 1).unwrap().board.money, 10);
+        assert_eq!(Run::with_challenge(&plain, 1).unwrap().board.money, 4);
+    }
+
+    #[test]
+    fn play__no_shop_goes_from_blind_to_blind() {
+        let challenge = Challenge {
+            draws: Some(Draws::new(10, 10)),
+            rules: ChallengeRules {
+                no_shop: true,
+                ..ChallengeRules::default()
+            },
+            ..Challenge::new("no shop".to_string())
+        };
+        let mut run = Run::with_challenge(&challenge, 7).unwrap();
+
+        let report = run.play(&mut Greedy::default());
+
+        assert!(report.blinds_beaten > 0);
+        assert!(run.board.shop.is_none());
+        assert!(
+            !run.log
+                .iter()
+                .any(|decision| matches!(decision, Decision::Shop { .. }))
+        );
+    }
+
+    #[test]
+    fn play__a_hopeless_strategy_loses_the_first_blind() {
+        let report = Run::with_starter_deck(StarterDeck::Red, 7).play(&mut FirstCard);
+
+        assert!(!report.won);
+        assert_eq!(report.ante, 1);
+        assert_eq!(report.blinds_beaten, 0);
+        assert!(matches!(
+            report.log[0],
+            Decision::BlindSelected {
+                ante: 1,
+                blind: Blind::Small,
+                target: 300
+            }
+        ));
+        let plays = report
+            .log
+            .iter()
+            .filter(|decision| matches!(decision, Decision::Play { .. }))
+            .count();
+        assert_eq!(plays, 4, "every hand the round grants");
+        assert!(matches!(
+            report.log.last(),
+            Some(Decision::RoundEnd { won: false, .. })
+        ));
+    }
+
+    #[test]
+    fn play__a_refused_play_forfeits() {
+        let report = Run::with_starter_deck(StarterDeck::Red, 7).play(&mut Nonsense);
+
+        assert!(!report.won);
+        assert!(matches!(report.log[1], Decision::Forfeit { .. }));
+        assert_eq!(report.total_score, 0);
+    }
+
+    #[test]
+    fn play__the_same_seed_is_the_same_run() {
+        let first = Run::with_starter_deck(StarterDeck::Red, 42).play(&mut Greedy::default());
+        let again = Run::with_starter_deck(StarterDeck::Red, 42).play(&mut Greedy::default());
+
+        assert_eq!(first, again);
+        assert!(first.blinds_beaten > 0);
+        assert!(first.best_hand > 0);
+        assert!(first.total_score >= first.best_hand);
+    }
+
+    #[test]
+    fn play__wins_at_the_winning_ante() {
+        let mut run = Run::with_starter_deck(StarterDeck::Red, 3);
+        run.winning_ante = 1;
+        for _ in 0..3 {
+            run.board.push_joker(joker::CAVENDISH);
+        }
+        let report = run.play(&mut Greedy::default());
+
+        assert!(report.won);
+        assert_eq!(report.blinds_beaten, 3);
+        assert_eq!(report.ante, 2);
+    }
+
+    #[test]
+    fn play__a_shop_that_is_never_left_is_closed() {
+        let mut run = Run::with_starter_deck(StarterDeck::Red, 3);
+        run.winning_ante = 1;
+        run.board.money = 1_000;
+        for _ in 0..3 {
+            run.board.push_joker(joker::CAVENDISH);
+        }
+        run.play(&mut Reroller);
+
+        let first_shop = run
+            .log
+            .iter()
+            .skip_while(|decision| !matches!(decision, Decision::RoundEnd { .. }))
+            .skip(1)
+            .take_while(|decision| !matches!(decision, Decision::BlindSelected { .. }))
+            .count();
+        assert_eq!(first_shop, Run::MAX_SHOP_ACTIONS);
+        assert_eq!(run.actions.last(), Some(&Action::PlayHand(vec![0])));
+    }
+
+    #[test]
+    fn apply__refuses_an_action_out_of_phase() {
+        let mut run = Run::with_starter_deck(StarterDeck::Red, 1);
+
+        assert!(!run.apply(Action::PlayHand(vec![0])));
+        assert!(!run.apply(Action::LeaveShop));
+        assert_eq!(run.actions.len(), 2, "refusals are recorded");
+        assert!(run.log.is_empty());
+        assert_eq!(run.phase, RunPhase::BlindSelect);
+
+        assert!(run.apply(Action::SelectBlind));
+        assert_eq!(run.phase, RunPhase::Round);
+        assert_eq!(run.board.in_hand.len(), 8);
+    }
+
+    #[test]
+    fn apply__sells_a_joker_between_blinds() {
+        let mut run = Run::with_starter_deck(StarterDeck::Red, 1);
+        run.board.push_joker(joker::JOKER);
+
+        assert!(run.apply(Action::SellJoker(0)));
+        assert!(run.board.jokers.is_empty());
+        assert_eq!(
+            run.log,
+            vec![Decision::Sold {
+                joker: joker::JOKER
+            }]
+        );
+    }
+
+    #[test]
+    fn stats__count_money_earned_not_spent_and_every_joker_held() {
+        let mut run = Run::with_starter_deck(StarterDeck::Red, 1);
+        run.board.money = 10;
+        run.board.shop = Some(Shop {
+            packs: vec![BoosterPack::new(PackKind::Buffoon, PackSize::Normal)],
+            ..Shop::default()
+        });
+        run.phase = RunPhase::Shop;
+
+        assert!(run.apply(Action::OpenPack(0)));
+        assert!(run.apply(Action::PickFromPack(Some(0))));
+        let picked = *run.board.jokers.get(0).unwrap();
+        assert_eq!(run.stats().money_earned, 0, "a pack is spending");
+        assert!(run.apply(Action::SellJoker(0)));
+
+        let stats = run.stats();
+        assert_eq!(stats.money_earned, picked.resell_value);
+        assert_eq!(stats.jokers, vec![picked.rank.index], "sold, but held");
+        assert_eq!(stats.deck, Some(StarterDeck::Red));
+        assert_eq!(stats.most_played, None);
+    }
+
+    #[test]
+    fn apply__opens_a_pack_and_takes_the_pick() {
+        let mut run = Run::with_starter_deck(StarterDeck::Red, 1);
+        run.board.money = 10;
+        run.board.shop = Some(Shop {
+            packs: vec![BoosterPack::new(PackKind::Buffoon, PackSize::Normal)],
+            ..Shop::default()
+        });
+        run.phase = RunPhase::Shop;
+
+        assert!(run.apply(Action::OpenPack(0)));
+        let RunPhase::Pack(choices) = run.phase.clone() else {
+            panic!("the pack is open");
+        };
+        assert_eq!(choices.len(), 2);
+
+        assert!(run.apply(Action::PickFromPack(Some(1))));
+        assert_eq!(run.phase, RunPhase::Shop);
+        assert_eq!(run.board.jokers.len(), 1);
+        assert_eq!(run.board.money, 6);
+    }
+
+    #[test]
+    fn apply__a_mega_pack_takes_two_picks() {
+        let mut run = Run::with_starter_deck(StarterDeck::Red, 1);
+        run.board.money = 10;
+        run.board.shop = Some(Shop {
+            packs: vec![BoosterPack::new(PackKind::Standard, PackSize::Mega)],
+            ..Shop::default()
+        });
+        run.phase = RunPhase::Shop;
+        let deck = run.board.full_deck.len();
+
+        assert!(run.apply(Action::OpenPack(0)));
+        let RunPhase::Pack(choices) = run.phase.clone() else {
+            panic!("the pack is open");
+        };
+        assert_eq!(choices.len(), 5);
+
+        assert!(run.apply(Action::PickFromPack(Some(0))));
+        let RunPhase::Pack(rest) = run.phase.clone() else {
+            panic!("a second pick is owed");
+        };
+        assert_eq!(rest, choices[1..]);
+
+        assert!(run.apply(Action::PickFromPack(Some(0))));
+        assert_eq!(run.phase, RunPhase::Shop);
+        assert_eq!(
+            run.board.full_deck.len(),
+            deck + 2,
+            "playing cards join the deck"
+        );
+        assert_eq!(run.board.money, 2);
+    }
+
+    #[test]
+    fn replay__reproduces_the_run() {
+        let mut run = Run::with_starter_deck(StarterDeck::Red, 42);
+        run.play(&mut Greedy::default());
+        let record = run.record();
+
+        let replayed = record.replay().unwrap();
+
+        assert_eq!(replayed.board, run.board);
+        assert_eq!(replayed.log, run.log);
+        assert!(replayed.actions.len() > 10);
+    }
+
+    #[test]
+    fn replay__survives_a_save_game() {
+        let mut run = Run::with_starter_deck(StarterDeck::Erratic, 5);
+        run.play(&mut Greedy::default());
+
+        let saved = serde_json::to_string(&run.record()).unwrap();
+        let loaded: RunRecord = serde_json::from_str(&saved).unwrap();
+
+        assert_eq!(loaded.replay().unwrap().board, run.board);
+    }
+
+    #[test]
+    fn replay__finds_the_first_divergence() {
+        let mut run = Run::with_starter_deck(StarterDeck::Red, 42);
+        run.play(&mut Greedy::default());
+        let mut record = run.record();
+        let step = record
+            .actions
+            .iter()
+            .position(|action| matches!(action, Action::PlayHand(_)))
+            .unwrap();
+        record.actions[step] = Action::PlayHand(vec![7]);
+
+        let divergence = record.replay().unwrap_err();
+
+        assert_eq!(divergence.step, step);
+        assert_eq!(divergence.action, Some(Action::PlayHand(vec![7])));
+        assert!(matches!(divergence.expected[0], Decision::Play { .. }));
+        assert_ne!(divergence.expected, divergence.found);
+    }
+
+    #[test]
+    fn replay__a_different_start_diverges() {
+        let mut run = Run::with_starter_deck(StarterDeck::Red, 42);
+        run.play(&mut Greedy::default());
+        let mut record = run.record();
+        record.start.money += 100;
+
+        let divergence = record.replay().unwrap_err();
+
+        assert!(matches!(
+            divergence.expected.last(),
+            Some(Decision::RoundEnd { .. })
+        ));
+        assert_eq!(
+            divergence.expected[0], divergence.found[0],
+            "the hand agrees"
+        );
+    }
+
+    #[test]
+    fn replay__a_log_longer_than_its_actions_diverges() {
+        let mut run = Run::with_starter_deck(StarterDeck::Red, 7);
+        run.play(&mut FirstCard);
+        let mut record = run.record();
+        record.actions.pop();
+
+        let divergence = record.replay().unwrap_err();
+
+        assert_eq!(divergence.step, record.actions.len());
+        assert_eq!(divergence.action, None);
+        assert!(divergence.found.is_empty());
+        assert_eq!(
+            divergence.to_string(),
+            format!(
+                "diverged at step {}: expected {}, found nothing",
+                record.actions.len(),
+                divergence.expected[0]
+            )
+        );
+    }
+
+    #[test]
+    fn with_stake__applies_from_the_first_blind() {
+        let mut run = Run::with_starter_deck(StarterDeck::Red, 7).with_stake(Stake::Blue);
+        run.apply(Action::SelectBlind);
+
+        assert_eq!(run.board.stake, Stake::Blue);
+        assert_eq!(run.board.discards_remaining(), 3, "Red's 4, less Blue's 1");
+        assert_eq!(run.record().start.stake, Stake::Blue);
+        assert!(run.record().replay().is_ok());
+    }
+
+    #[test]
+    fn with_stake__starts_over() {
+        let mut run = Run::with_starter_deck(StarterDeck::Red, 7);
+        run.apply(Action::SelectBlind);
+        let run = run.with_stake(Stake::Green);
+
+        assert_eq!(run.phase, RunPhase::BlindSelect);
+        assert!(run.actions.is_empty());
+    }
+
+    #[test]
+    fn batch__one_report_per_seed() {
+        let reports = Run::batch(StarterDeck::Red, 0..3, || FirstCard);
+
+        assert_eq!(reports.len(), 3);
+        assert_eq!(
+            reports
+                .iter()
+                .map(|report| report.seed.to_string())
+                .collect::<Vec<_>>(),
+            vec!["11111111", "11111112", "11111113"]
+        );
+    }
+
+    #[test]
+    fn apply__a_reroll_does_not_move_the_next_shuffle() {
+        let seed: RunSeed = "7LB2WVPK".parse().unwrap();
+        let [kept, rerolled] = [false, true].map(|reroll| {
+            let mut run = Run::with_starter_deck(StarterDeck::Red, seed.clone());
+            for _ in 0..3 {
+                run.board.push_joker(joker::CAVENDISH);
+            }
+            run.board.money = 100;
+            run.apply(Action::SelectBlind);
+            while run.phase == RunPhase::Round {
+                run.apply(Action::PlayHand(vec![0, 1, 2, 3, 4]));
+            }
+            assert_eq!(run.phase, RunPhase::Shop);
+            if reroll {
+                assert!(run.apply(Action::Reroll));
+            }
+            run.apply(Action::LeaveShop);
+            run.apply(Action::SelectBlind);
+            run
+        });
+
+        assert_eq!(kept.board.in_hand, rerolled.board.in_hand);
+        assert_eq!(kept.boss, rerolled.boss);
+    }
+
+    #[test]
+    fn apply__rerolls_the_boss_at_blind_select() {
+        let mut run = Run::with_starter_deck(StarterDeck::Red, 3);
+        let drawn = run.boss;
+        assert!(!run.apply(Action::RerollBoss), "no voucher");
+
+        run.board.vouchers.push(Voucher::DirectorsCut);
+        run.board.money = 20;
+        assert!(run.apply(Action::RerollBoss));
+
+        assert_ne!(run.boss, drawn);
+        assert_eq!(run.board.money, 10);
+        assert_eq!(run.log, vec![Decision::BossRerolled { boss: run.boss }]);
+        assert!(!run.apply(Action::RerollBoss), "once an ante");
+        run.apply(Action::SelectBlind);
+        assert!(!run.apply(Action::RerollBoss), "not mid-round");
+    }
+
+    #[test]
+    fn apply__a_reroll_before_the_boss_changes_the_upcoming_blind() {
+        let mut run = Run::with_starter_deck(StarterDeck::Red, 3);
+        run.upcoming = Blind::Boss(run.boss);
+        run.board.vouchers.push(Voucher::Retcon);
+        run.board.money = 30;
+
+        assert!(run.apply(Action::RerollBoss));
+        assert!(run.apply(Action::RerollBoss));
+
+        assert_eq!(run.upcoming, Blind::Boss(run.boss));
+        assert_eq!(run.board.boss_rerolls_this_ante, 2);
+        run.apply(Action::SelectBlind);
+        assert_eq!(run.board.blind, Blind::Boss(run.boss));
+    }
+
+    #[test]
+    fn play__a_strategy_can_reroll_the_boss() {
+        struct Rerolls;
+        impl Strategy for Rerolls {
+            fn choose_play(&mut self, _board: &BuffoonBoard) -> Vec<usize> {
+                vec![0]
+            }
+
+            fn choose_boss_reroll(&mut self, _board: &BuffoonBoard, _boss: BossBlind) -> bool {
+                true
+            }
+        }
+        let mut board = Run::with_starter_deck(StarterDeck::Red, 3).board;
+        board.vouchers.push(Voucher::Retcon);
+        board.money = 25;
+        let mut run = Run::new(board, 3);
+        run.play(&mut Rerolls);
+
+        let rerolls = run
+            .log
+            .iter()
+            .filter(|decision| matches!(decision, Decision::BossRerolled { .. }))
+            .count();
+        assert_eq!(rerolls, 2, "until the money runs out");
+        assert!(run.record().replay().is_ok());
+    }
+
+    #[test]
+    fn with_registry__custom_jokers_score_in_a_run() {
+        const PLUS_50: EffectId = EffectId::new("test:plus_50");
+        struct Plus50;
+        impl Effect for Plus50 {
+            fn score(&self, _ctx: &ScoringContext<'_>) -> ScoreOp {
+                ScoreOp::AddMult(50)
+            }
+        }
+        let mut registry = EffectRegistry::new();
+        registry.register(PLUS_50, Plus50).unwrap();
+        let registry = Arc::new(registry);
+        let mut board = Run::with_starter_deck(StarterDeck::Red, 3).board;
+        board.push_joker(BuffoonCard {
+            enhancement: MPip::Custom(PLUS_50),
+            ..joker::JOKER
+        });
+        let first_play = |run: &Run| {
+            run.log.iter().find_map(|decision| match decision {
+                Decision::Play { score, .. } => Some(*score),
+                _ => None,
+            })
+        };
+
+        let mut plain = Run::new(board.clone(), 3);
+        plain.apply(Action::SelectBlind);
+        plain.apply(Action::PlayHand(vec![0]));
+        let mut custom = Run::new(board, 3).with_registry(Arc::clone(&registry));
+        custom.apply(Action::SelectBlind);
+        custom.apply(Action::PlayHand(vec![0]));
+
+        assert!(first_play(&custom) > first_play(&plain));
+        let record = custom.record();
+        assert!(record.replay_with_registry(registry).is_ok());
+        assert!(record.replay().is_err(), "scored without the registry");
+    }
+
+    #[test]
+    fn record__carries_the_seed_string() {
+        let seed: RunSeed = "7lb2wvpk".parse().unwrap();
+        let mut run = Run::with_starter_deck(StarterDeck::Red, seed);
+        run.play(&mut FirstCard);
+        let json = serde_json::to_string(&run.record()).unwrap();
+
+        assert!(json.contains("\"seed\":\"7LB2WVPK\""));
+        let record: RunRecord = serde_json::from_str(&json).unwrap();
+        assert!(record.replay().is_ok());
+    }
+
+    #[test]
+    fn greedy__plays_as_the_run_scores_with_its_registry() {
+        const ZERO: EffectId = EffectId::new("test:zero");
+        struct Zero;
+        impl Effect for Zero {
+            fn score(&self, _ctx: &ScoringContext<'_>) -> ScoreOp {
+                ScoreOp::TimesMult(0.0)
+            }
+        }
+        let mut registry = EffectRegistry::new();
+        registry.register(ZERO, Zero).unwrap();
+        let mut board = BuffoonBoard::new(Draws::new(4, 3), Deck::basic_buffoon_pile());
+        board.in_hand = BuffoonPile::from(vec![
+            bcard!(AS),
+            BuffoonCard {
+                enhancement: MPip::Custom(ZERO),
+                ..bcard!(2D)
+            },
+        ]);
+
+        assert_eq!(Greedy::default().choose_play(&board), vec![0, 1]);
+        let mut greedy = Greedy::default().with_registry(registry);
+        assert_eq!(greedy.choose_play(&board), vec![0]);
+    }
+
+    #[test]
+    fn run__can_move_to_another_thread() {
+        const fn is_send<T: Send>() {}
+        is_send::<Run>();
+        is_send::<Greedy>();
+    }
+
+    #[test]
+    fn greedy__buys_the_first_affordable_joker() {
+        let mut board = BuffoonBoard::new(Draws::new(4, 3), Deck::basic_buffoon_pile());
+        board.money = 3;
+        board.shop = Some(Shop::with_stock(vec![joker::CAVENDISH, joker::JOKER]));
+
+        assert_eq!(
+            Greedy::default().choose_shop(&board),
+            ShopChoice::BuyStock(1)
+        );
+
+        board.money = 0;
+        assert_eq!(Greedy::default().choose_shop(&board), ShopChoice::Leave);
+    }
+
+    #[test]
+    fn decision__display() {
+        assert_eq!(
+            Decision::BlindSelected {
+                ante: 2,
+                blind: Blind::Big,
+                target: 1_200
+            }
+            .to_string(),
+            "ante 2: Big Blind, target 1200"
+        );
+        assert_eq!(
+            Decision::Shop {
+                choice: ShopChoice::BuyStock(1),
+                accepted: false
+            }
+            .to_string(),
+            "shop: buy stock 1 (refused)"
+        );
+        assert_eq!(
+            Decision::RoundEnd {
+                won: true,
+                round_score: 320,
+                money: 9
+            }
+            .to_string(),
+            "round won with 320, $9"
+        );
+    }
+}
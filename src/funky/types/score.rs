@@ -1,7 +1,14 @@
+use crate::funky::types::magnitude::Magnitude;
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 use std::ops::{Add, AddAssign};
 
+/// A hand's chips and mult, and their product.
+///
+/// `usize` on both sides, with ×mult rounded up through `f32` — the engine's
+/// everyday score. Arithmetic saturates rather than overflowing, so a run
+/// that outgrows it pins at `usize::MAX` instead of panicking; [`BigScore`]
+/// is the same hand at any size.
 #[derive(
     Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize,
 )]
@@ -18,7 +25,7 @@ impl Score {
 
     #[must_use]
     pub fn add_chips(&self, chips: usize) -> Self {
-        let chips = self.chips + chips;
+        let chips = self.chips.saturating_add(chips);
         Self {
             chips,
             mult: self.mult,
@@ -27,7 +34,7 @@ impl Score {
 
     #[must_use]
     pub fn add_mult(&mut self, mult: usize) -> Self {
-        let mult = self.mult + mult;
+        let mult = self.mult.saturating_add(mult);
         Self {
             chips: self.chips,
             mult,
@@ -50,7 +57,7 @@ impl Score {
 
     #[must_use]
     pub fn score(&self) -> usize {
-        self.chips * self.mult
+        self.chips.saturating_mul(self.mult)
     }
 }
 
@@ -59,16 +66,16 @@ impl Add for Score {
 
     fn add(self, other: Self) -> Self {
         Self {
-            chips: self.chips + other.chips,
-            mult: self.mult + other.mult,
+            chips: self.chips.saturating_add(other.chips),
+            mult: self.mult.saturating_add(other.mult),
         }
     }
 }
 
 impl AddAssign for Score {
     fn add_assign(&mut self, other: Self) {
-        self.chips += other.chips;
-        self.mult += other.mult;
+        self.chips = self.chips.saturating_add(other.chips);
+        self.mult = self.mult.saturating_add(other.mult);
     }
 }
 
@@ -84,6 +91,65 @@ impl Display for Score {
     }
 }
 
+/// A [`Score`] at Balatro scale: chips and mult as exact [`Magnitude`]s, so a
+/// late-game ×mult stack neither overflows nor rounds.
+///
+/// Built by replaying a hand's [`ScoreOp`]s through
+/// [`ScoreOp::apply_big`] — the same ops, the same order, exact arithmetic.
+/// That replay is
+/// [`ScoreTrace::big_score`](crate::funky::types::trace::ScoreTrace::big_score),
+/// and [`BuffoonBoard::score_big`](crate::funky::types::board::BuffoonBoard::score_big)
+/// runs it for a board.
+///
+/// [`ScoreOp`]: crate::funky::types::effect::ScoreOp
+/// [`ScoreOp::apply_big`]: crate::funky::types::effect::ScoreOp::apply_big
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct BigScore {
+    pub chips: Magnitude,
+    pub mult: Magnitude,
+}
+
+impl BigScore {
+    #[must_use]
+    pub fn new(chips: Magnitude, mult: Magnitude) -> Self {
+        Self { chips, mult }
+    }
+
+    #[must_use]
+    pub fn score(&self) -> Magnitude {
+        self.chips * self.mult
+    }
+}
+
+impl From<Score> for BigScore {
+    fn from(score: Score) -> Self {
+        Self::new(Magnitude::from(score.chips), Magnitude::from(score.mult))
+    }
+}
+
+impl Add for BigScore {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            chips: self.chips + other.chips,
+            mult: self.mult + other.mult,
+        }
+    }
+}
+
+impl Display for BigScore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "BigScore {{ {} chips times {} mult }} = {}",
+            self.chips,
+            self.mult,
+            self.score()
+        )
+    }
+}
+
 #[cfg(test)]
 #[allow(non_snake_case, unused_imports)]
 mod funky__types__score_tests {
@@ -139,4 +205,27 @@ mod funky__types__score_tests {
         let score = Score::new(2, 2);
         assert_eq!(score.to_string(), "Score { 2 chips times 2 mult } = 4");
     }
+
+    #[test]
+    fn add__saturates() {
+        let full = Score::new(usize::MAX, usize::MAX);
+        assert_eq!(full + Score::new(1, 1), full);
+        assert_eq!(full.score(), usize::MAX);
+    }
+
+    #[test]
+    fn big_score__from_score() {
+        let big = BigScore::from(Score::new(100, 15));
+        assert_eq!(big.score(), Magnitude::from(1500));
+        assert_eq!(big + big, BigScore::from(Score::new(200, 30)));
+    }
+
+    #[test]
+    fn big_score__display() {
+        let big = BigScore::new(Magnitude::from(120), Magnitude::new(5, 44));
+        assert_eq!(
+            big.to_string(),
+            "BigScore { 120 chips times 5e44 mult } = 6e46"
+        );
+    }
 }
@@ -0,0 +1,349 @@
+//! Custom effects written as [Rhai](https://rhai.rs) scripts.
+//!
+//! An [`Effect`] is normally a Rust type in a mod crate. A [`ScriptEffect`] is
+//! the same thing as a few lines of script, compiled when it is registered —
+//! so a designer can add a joker's scoring without a toolchain. The script
+//! reads the board through constants and evaluates to its contribution:
+//!
+//! | Constant | Type            | Value                                              |
+//! |----------|-----------------|----------------------------------------------------|
+//! | `played` | array of string | The played cards, as `"AS"`, `"TH"`, …             |
+//! | `held`   | array of string | The cards held in hand                             |
+//! | `hand`   | string          | The scoring hand type, as `"Flush"`, `"Pair"`, …   |
+//! | `money`  | int             | The run's money                                    |
+//! | `state`  | int             | The joker's counter ([`ScoringContext::state`])    |
+//! | `source` | string          | The card or joker carrying the effect              |
+//!
+//! The value the script ends on is built with `add_chips(n)`, `add_mult(n)` and
+//! `times_mult(x)`; an array of them applies in order, and `()` — a script that
+//! ends on nothing — contributes nothing. A negative `n`, or an `x` that is
+//! negative, infinite or NaN, is a script error. Scripts score only: the
+//! lifecycle hooks stay Rust.
+//!
+//! Every script runs sandboxed: it cannot import modules, `eval` or print, and
+//! it is stopped after [`MAX_OPERATIONS`](ScriptEffect::MAX_OPERATIONS) steps,
+//! so a runaway loop in a mod costs a card its effect and not the run.
+
+use crate::common::errors::CardError;
+use crate::funky::types::buffoon_card::BuffoonCard;
+use crate::funky::types::buffoon_pile::BuffoonPile;
+use crate::funky::types::effect::{Effect, EffectId, EffectRegistry, ScoreOp, ScoringContext};
+use rhai::module_resolvers::DummyModuleResolver;
+use rhai::{AST, Array, Dynamic, Engine, EvalAltResult, Scope};
+
+/// An [`Effect`] backed by a compiled script.
+///
+/// ```
+/// use cardpack::preludes::funky::*;
+///
+/// const FLUSH_BONUS: EffectId = EffectId::new("designer:flush_bonus");
+///
+/// let mut registry = EffectRegistry::new();
+/// registry
+///     .register_script(
+///         FLUSH_BONUS,
+///         r#"if hand == "Flush" { [add_chips(30), times_mult(1.5)] }"#,
+///     )
+///     .unwrap();
+///
+/// assert!(registry.contains(FLUSH_BONUS));
+/// assert!(registry.register_script(EffectId::new("designer:typo"), "if {").is_err());
+/// ```
+pub struct ScriptEffect {
+    engine: Engine,
+    ast: AST,
+}
+
+impl ScriptEffect {
+    /// How many steps one evaluation may take before it is stopped. Enough for
+    /// a loop over every card on the board many times over.
+    pub const MAX_OPERATIONS: u64 = 10_000;
+    /// How deeply a script's own functions may call each other.
+    pub const MAX_CALL_LEVELS: usize = 16;
+    /// The longest string, and the largest array or map, a script may build.
+    pub const MAX_SIZE: usize = 1_024;
+
+    /// Compile `source` into an effect.
+    ///
+    /// # Errors
+    ///
+    /// [`CardError::Script`] with the parser's message if `source` does not
+    /// compile.
+    pub fn new(source: &str) -> Result<Self, CardError> {
+        let engine = Self::sandbox(Self::MAX_OPERATIONS);
+        let ast = engine
+            .compile(source)
+            .map_err(|e| CardError::Script(e.to_string()))?;
+        Ok(Self { engine, ast })
+    }
+
+    /// The same effect with a different step limit.
+    #[must_use]
+    pub fn with_max_operations(mut self, operations: u64) -> Self {
+        self.engine.set_max_operations(operations);
+        self
+    }
+
+    /// Run the script against `ctx`.
+    ///
+    /// [`score`](Effect::score) is this with the error dropped; call it
+    /// directly to see why a script contributed nothing.
+    ///
+    /// # Errors
+    ///
+    /// [`CardError::Script`] if the script fails, runs past its limits, or
+    /// ends on a value that is not a contribution.
+    pub fn eval(&self, ctx: &ScoringContext<'_>) -> Result<ScoreOp, CardError> {
+        let mut scope = Scope::new();
+        scope.push_constant("played", Self::cards(&ctx.board.played));
+        scope.push_constant("held", Self::cards(&ctx.board.in_hand));
+        scope.push_constant("hand", format!("{:?}", ctx.board.scoring_hand_type()));
+        scope.push_constant("money", i64::try_from(ctx.board.money).unwrap_or_default());
+        scope.push_constant("state", i64::from(ctx.state));
+        scope.push_constant("source", Self::card(ctx.source));
+
+        let value = self
+            .engine
+            .eval_ast_with_scope::<Dynamic>(&mut scope, &self.ast)
+            .map_err(|e| CardError::Script(e.to_string()))?;
+        Self::score_op(value)
+    }
+
+    /// The engine every script runs in: the standard functions plus the
+    /// contribution builders, with everything that reaches outside the
+    /// script turned off.
+    fn sandbox(operations: u64) -> Engine {
+        let mut engine = Engine::new();
+        engine
+            .set_module_resolver(DummyModuleResolver::new())
+            .disable_symbol("eval")
+            .on_print(|_| {})
+            .on_debug(|_, _, _| {})
+            .set_max_operations(operations)
+            .set_max_call_levels(Self::MAX_CALL_LEVELS)
+            .set_max_string_size(Self::MAX_SIZE)
+            .set_max_array_size(Self::MAX_SIZE)
+            .set_max_map_size(Self::MAX_SIZE);
+        engine
+            .register_type_with_name::<ScoreOp>("ScoreOp")
+            .register_fn("add_chips", |chips: i64| {
+                Self::count("add_chips", chips).map(ScoreOp::AddChips)
+            })
+            .register_fn("add_mult", |mult: i64| {
+                Self::count("add_mult", mult).map(ScoreOp::AddMult)
+            })
+            .register_fn("times_mult", |factor: f64| {
+                #[allow(clippy::cast_possible_truncation)]
+                Self::factor(factor as f32)
+            })
+            .register_fn("times_mult", |factor: i64| {
+                #[allow(clippy::cast_precision_loss)]
+                Self::factor(factor as f32)
+            })
+            .register_fn("to_string", |op: &mut ScoreOp| op.to_string());
+        engine
+    }
+
+    /// The chips or mult `n` a script adds, which cannot be negative.
+    fn count(builder: &str, n: i64) -> Result<usize, Box<EvalAltResult>> {
+        usize::try_from(n).map_err(|_| format!("{builder}({n}): must not be negative").into())
+    }
+
+    /// The `×mult` a script applies, which must be finite and not negative.
+    fn factor(factor: f32) -> Result<ScoreOp, Box<EvalAltResult>> {
+        if factor.is_finite() && factor >= 0.0 {
+            Ok(ScoreOp::TimesMult(factor))
+        } else {
+            Err(format!("times_mult({factor}): must be finite and not negative").into())
+        }
+    }
+
+    fn cards(pile: &BuffoonPile) -> Array {
+        pile.iter().map(|card| Self::card(*card).into()).collect()
+    }
+
+    /// A card as a script sees it: its rank and suit indexes, whatever it is
+    /// enhanced with.
+    fn card(card: BuffoonCard) -> String {
+        format!("{}{}", card.rank.index, card.suit.index)
+    }
+
+    fn score_op(value: Dynamic) -> Result<ScoreOp, CardError> {
+        if value.is_unit() {
+            return Ok(ScoreOp::Nothing);
+        }
+        if value.is::<ScoreOp>() {
+            return Ok(value.cast::<ScoreOp>());
+        }
+        if value.is_array() {
+            return value
+                .cast::<Array>()
+                .into_iter()
+                .map(Self::score_op)
+                .collect::<Result<_, _>>()
+                .map(ScoreOp::Seq);
+        }
+        Err(CardError::Script(format!(
+            "a script must end on a contribution, not `{}`",
+            value.type_name()
+        )))
+    }
+}
+
+impl Effect for ScriptEffect {
+    fn score(&self, ctx: &ScoringContext<'_>) -> ScoreOp {
+        self.eval(ctx).unwrap_or_else(|e| {
+            log::warn!("{}: {e}", Self::card(ctx.source));
+            ScoreOp::Nothing
+        })
+    }
+}
+
+impl EffectRegistry {
+    /// Compiles `source` as a [`ScriptEffect`] and registers it under `id`.
+    ///
+    /// # Errors
+    ///
+    /// [`CardError::Script`] if `source` does not compile, and
+    /// [`CardError::DuplicateEffectId`] as for [`register`](Self::register).
+    pub fn register_script(&mut self, id: EffectId, source: &str) -> Result<(), CardError> {
+        self.register(id, ScriptEffect::new(source)?)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod funky__types__script_tests {
+    use super::*;
+    use crate::funky::decks::joker::card;
+    use crate::funky::types::board::BuffoonBoard;
+    use crate::funky::types::draws::Draws;
+    use crate::funky::types::mpip::MPip;
+    use crate::funky::types::score::Score;
+    use rstest::rstest;
+
+    fn board() -> BuffoonBoard {
+        let mut board = BuffoonBoard::new(Draws::default(), BuffoonPile::default());
+        board.played = BuffoonPile::forgiving_from_str("AH KH QH JH 9H");
+        board.in_hand = BuffoonPile::forgiving_from_str("2S 3S");
+        board.money = 12;
+        board
+    }
+
+    fn eval(source: &str) -> Result<ScoreOp, CardError> {
+        let board = board();
+        let ctx = ScoringContext {
+            board: &board,
+            source: board.played.get(0).copied().unwrap_or_default(),
+            state: 3,
+        };
+        ScriptEffect::new(source)?.eval(&ctx)
+    }
+
+    #[rstest]
+    #[case("()", ScoreOp::Nothing)]
+    #[case("add_chips(30)", ScoreOp::AddChips(30))]
+    #[case("times_mult(2)", ScoreOp::TimesMult(2.0))]
+    #[case(
+        "[add_mult(2), times_mult(1.5)]",
+        ScoreOp::Seq(vec![ScoreOp::AddMult(2), ScoreOp::TimesMult(1.5)])
+    )]
+    fn eval__contributions(#[case] source: &str, #[case] expected: ScoreOp) {
+        assert_eq!(eval(source).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case(r#"if hand == "Flush" { add_mult(1) }"#, ScoreOp::AddMult(1))]
+    #[case("add_chips(played.len() * 10 + held.len())", ScoreOp::AddChips(52))]
+    #[case(
+        r#"if held.contains("2S") { add_chips(money) }"#,
+        ScoreOp::AddChips(12)
+    )]
+    #[case("add_mult(state)", ScoreOp::AddMult(3))]
+    #[case(r#"if source == "AH" { add_chips(11) }"#, ScoreOp::AddChips(11))]
+    fn eval__reads_the_board(#[case] source: &str, #[case] expected: ScoreOp) {
+        assert_eq!(eval(source).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case("if {")]
+    #[case("42")]
+    #[case("loop {}")]
+    #[case(r#"import "os" as os; ()"#)]
+    #[case(r#"eval("add_chips(1)")"#)]
+    #[case("add_mult(-4)")]
+    #[case("add_chips(-1)")]
+    #[case("times_mult(-2)")]
+    #[case("times_mult(-0.5)")]
+    #[case("times_mult(1.0 / 0.0)")]
+    #[case("times_mult(0.0 / 0.0)")]
+    #[case("times_mult(1e300)")]
+    fn eval__failures(#[case] source: &str) {
+        assert!(matches!(eval(source), Err(CardError::Script(_))));
+    }
+
+    #[test]
+    fn with_max_operations() {
+        let board = board();
+        let ctx = ScoringContext {
+            board: &board,
+            source: BuffoonCard::default(),
+            state: 0,
+        };
+        let source = "let n = 0; for i in 0..200 { n += i; } add_chips(n)";
+
+        assert_eq!(
+            ScriptEffect::new(source).unwrap().eval(&ctx),
+            Ok(ScoreOp::AddChips(19_900))
+        );
+        assert!(
+            ScriptEffect::new(source)
+                .unwrap()
+                .with_max_operations(100)
+                .eval(&ctx)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn score__a_failing_script_contributes_nothing() {
+        let board = board();
+        let ctx = ScoringContext {
+            board: &board,
+            source: BuffoonCard::default(),
+            state: 0,
+        };
+
+        assert_eq!(
+            ScriptEffect::new("loop {}").unwrap().score(&ctx),
+            ScoreOp::Nothing
+        );
+        assert_eq!(
+            ScriptEffect::new("add_mult(4)")
+                .unwrap()
+                .score(&ctx)
+                .apply(Score::new(10, 1)),
+            Score::new(10, 5)
+        );
+    }
+
+    #[test]
+    fn score_with_registry__scripted_joker_scores() {
+        const FLUSH_DOUBLER: EffectId = EffectId::new("test:flush_doubler");
+        let mut registry = EffectRegistry::new();
+        registry
+            .register_script(FLUSH_DOUBLER, r#"if hand == "Flush" { times_mult(2) }"#)
+            .unwrap();
+
+        let mut board = board();
+        board.in_hand = BuffoonPile::default();
+        board.jokers.push(BuffoonCard {
+            enhancement: MPip::Custom(FLUSH_DOUBLER),
+            ..card::JOKER
+        });
+
+        // Flush 35/4 + cards (11+10+10+10+9 = 50) = 85/4, doubled by the script.
+        assert_eq!(board.score_with_registry(&registry), Score::new(85, 8));
+        assert_eq!(board.score(), Score::new(85, 4));
+    }
+}
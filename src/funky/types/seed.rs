@@ -0,0 +1,338 @@
+use crate::common::errors::CardError;
+use rand::rngs::ChaCha12Rng;
+use rand::{Rng, RngExt, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// One independent source of randomness in a run.
+///
+/// Balatro keys each kind of draw to its own stream of the run seed, so a
+/// reroll in the shop does not change the next shuffle, and a Lucky card
+/// rolling does not change the next boss. A [`RunSeed`] derives one RNG per
+/// stream for the same reason: two players who share a seed see the same
+/// deck and the same bosses even when one of them rerolls and the other does
+/// not.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum Stream {
+    /// Building the starting deck (Erratic's random cards).
+    Deck,
+    /// Shuffling the full deck at each blind.
+    Shuffle,
+    /// Drawing each ante's Boss Blind, and rerolling it.
+    Boss,
+    /// Drawing the shop's stock and vouchers, and rerolling it.
+    Shop,
+    /// Drawing a booster pack's choices.
+    Pack,
+    /// Every probability roll: Lucky cards, Misprint, the destruction
+    /// chances, the creators, consumables.
+    Roll,
+}
+
+impl Stream {
+    pub const ALL: [Self; 6] = [
+        Self::Deck,
+        Self::Shuffle,
+        Self::Boss,
+        Self::Shop,
+        Self::Pack,
+        Self::Roll,
+    ];
+
+    /// The stream's name — mixed into its seed, so it must never change.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Deck => "deck",
+            Self::Shuffle => "shuffle",
+            Self::Boss => "boss",
+            Self::Shop => "shop",
+            Self::Pack => "pack",
+            Self::Roll => "roll",
+        }
+    }
+}
+
+/// A Balatro-style run seed: one to eight letters and digits, like
+/// `7LB2WVPK`.
+///
+/// Parsed case-insensitively and held upper-case, so `7lb2wvpk` is the same
+/// seed. The seed is the **string**: every stream's `u64` is derived from its
+/// characters with FNV-1a, a hash fixed by its definition rather than by a
+/// toolchain, and seeds a [`ChaCha12Rng`] — a named algorithm, where rand's
+/// `StdRng` may change between rand releases. So a seed shared between tools
+/// — or between releases — opens the same run everywhere.
+///
+/// ```
+/// use cardpack::preludes::funky::*;
+///
+/// let seed: RunSeed = "7lb2wvpk".parse().unwrap();
+/// assert_eq!(seed.to_string(), "7LB2WVPK");
+/// assert_ne!(seed.stream_seed(Stream::Shop), seed.stream_seed(Stream::Shuffle));
+/// assert!("TOO-LONG!".parse::<RunSeed>().is_err());
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct RunSeed(String);
+
+impl RunSeed {
+    /// The longest seed Balatro accepts.
+    pub const MAX_LEN: usize = 8;
+    /// The characters a generated seed is drawn from: digits and capitals
+    /// without `0` and `O`, which read alike when a seed is shared aloud.
+    pub const ALPHABET: &'static [u8; 34] = b"123456789ABCDEFGHIJKLMNPQRSTUVWXYZ";
+
+    /// A random eight-character seed.
+    #[must_use]
+    pub fn random() -> Self {
+        Self::random_with_rng(&mut rand::rng())
+    }
+
+    /// A random eight-character seed drawn from `rng`.
+    #[must_use]
+    pub fn random_with_rng<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self(
+            (0..Self::MAX_LEN)
+                .map(|_| char::from(Self::ALPHABET[rng.random_range(0..Self::ALPHABET.len())]))
+                .collect(),
+        )
+    }
+
+    /// The `index`-th seed, written in [`ALPHABET`](Self::ALPHABET)'s digits
+    /// — what a batch of runs numbered `0..n` is seeded with. The space is
+    /// 34⁸ seeds wide, and an `index` past it wraps.
+    #[must_use]
+    pub fn from_index(index: u64) -> Self {
+        let base = Self::ALPHABET.len() as u64;
+        let mut rest = index % base.pow(8);
+        let mut chars = [Self::ALPHABET[0]; Self::MAX_LEN];
+        for slot in chars.iter_mut().rev() {
+            *slot = Self::ALPHABET[usize::try_from(rest % base).unwrap_or(0)];
+            rest /= base;
+        }
+        Self(chars.iter().map(|byte| char::from(*byte)).collect())
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The `u64` seed of one stream — for the `*_with_seed` APIs
+    /// ([`shuffle_with_seed`](crate::funky::types::buffoon_pile::BuffoonPile::shuffle_with_seed),
+    /// [`score_with_seed`](crate::funky::types::board::BuffoonBoard::score_with_seed)).
+    #[must_use]
+    pub fn stream_seed(&self, stream: Stream) -> u64 {
+        Self::fnv1a(
+            self.0
+                .bytes()
+                .chain(std::iter::once(b'/'))
+                .chain(stream.label().bytes()),
+        )
+    }
+
+    /// A fresh RNG for one stream, at the start of it.
+    #[must_use]
+    pub fn rng(&self, stream: Stream) -> ChaCha12Rng {
+        ChaCha12Rng::seed_from_u64(self.stream_seed(stream))
+    }
+
+    /// 64-bit FNV-1a.
+    fn fnv1a(bytes: impl Iterator<Item = u8>) -> u64 {
+        bytes.fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        })
+    }
+}
+
+impl Display for RunSeed {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for RunSeed {
+    type Err = CardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let seed = s.trim().to_uppercase();
+        if seed.is_empty()
+            || seed.len() > Self::MAX_LEN
+            || !seed.chars().all(|c| c.is_ascii_alphanumeric())
+        {
+            return Err(CardError::InvalidSeed(s.to_string()));
+        }
+        Ok(Self(seed))
+    }
+}
+
+impl TryFrom<String> for RunSeed {
+    type Error = CardError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<RunSeed> for String {
+    fn from(seed: RunSeed) -> Self {
+        seed.0
+    }
+}
+
+/// The `index`-th seed — see [`RunSeed::from_index`].
+impl From<u64> for RunSeed {
+    fn from(index: u64) -> Self {
+        Self::from_index(index)
+    }
+}
+
+/// One RNG per [`Stream`], all derived from one [`RunSeed`].
+///
+/// Each field is drawn from only by the draws its stream names, so consuming
+/// one never moves another: the shop can be rerolled ten times and the next
+/// blind still shuffles the deck it would have.
+#[derive(Debug, Eq, PartialEq)]
+pub struct RunRngs {
+    pub deck: ChaCha12Rng,
+    pub shuffle: ChaCha12Rng,
+    pub boss: ChaCha12Rng,
+    pub shop: ChaCha12Rng,
+    pub pack: ChaCha12Rng,
+    pub roll: ChaCha12Rng,
+}
+
+impl RunRngs {
+    #[must_use]
+    pub fn new(seed: &RunSeed) -> Self {
+        Self {
+            deck: seed.rng(Stream::Deck),
+            shuffle: seed.rng(Stream::Shuffle),
+            boss: seed.rng(Stream::Boss),
+            shop: seed.rng(Stream::Shop),
+            pack: seed.rng(Stream::Pack),
+            roll: seed.rng(Stream::Roll),
+        }
+    }
+
+    /// The RNG for `stream`.
+    pub fn stream(&mut self, stream: Stream) -> &mut ChaCha12Rng {
+        match stream {
+            Stream::Deck => &mut self.deck,
+            Stream::Shuffle => &mut self.shuffle,
+            Stream::Boss => &mut self.boss,
+            Stream::Shop => &mut self.shop,
+            Stream::Pack => &mut self.pack,
+            Stream::Roll => &mut self.roll,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod funky__types__seed_tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("7LB2WVPK", "7LB2WVPK")]
+    #[case("7lb2wvpk", "7LB2WVPK")]
+    #[case(" abc ", "ABC")]
+    #[case("1", "1")]
+    fn from_str(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(RunSeed::from_str(input).unwrap().as_str(), expected);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("123456789")]
+    #[case("7LB2-VPK")]
+    #[case("ÄBC")]
+    fn from_str__invalid(#[case] input: &str) {
+        assert_eq!(
+            RunSeed::from_str(input),
+            Err(CardError::InvalidSeed(input.to_string()))
+        );
+    }
+
+    #[test]
+    fn stream_seed__is_fixed_by_the_string() {
+        let seed = RunSeed::from_str("7LB2WVPK").unwrap();
+
+        assert_eq!(
+            seed.stream_seed(Stream::Shuffle),
+            RunSeed::from_str("7lb2wvpk")
+                .unwrap()
+                .stream_seed(Stream::Shuffle)
+        );
+        assert_eq!(
+            RunSeed::from_str("A").unwrap().stream_seed(Stream::Deck),
+            RunSeed::fnv1a("A/deck".bytes())
+        );
+        // The published FNV-1a test vectors: a pin against the hash drifting.
+        assert_eq!(RunSeed::fnv1a("".bytes()), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(RunSeed::fnv1a("a".bytes()), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn rng__is_pinned() {
+        let seed = RunSeed::from_str("7LB2WVPK").unwrap();
+        let first: u64 = seed.rng(Stream::Shuffle).random();
+
+        // A pin against the generator drifting: ChaCha12, seeded from the
+        // FNV-1a stream seed, draws this and only this.
+        assert_eq!(first, 6_508_726_029_694_678_958);
+    }
+
+    #[test]
+    fn stream_seed__every_stream_differs() {
+        let seed = RunSeed::from_str("7LB2WVPK").unwrap();
+        let mut seeds: Vec<u64> = Stream::ALL.iter().map(|s| seed.stream_seed(*s)).collect();
+        seeds.sort_unstable();
+        seeds.dedup();
+
+        assert_eq!(seeds.len(), Stream::ALL.len());
+    }
+
+    #[test]
+    fn run_rngs__streams_are_independent() {
+        let seed = RunSeed::from_str("7LB2WVPK").unwrap();
+        let mut busy = RunRngs::new(&seed);
+        let mut idle = RunRngs::new(&seed);
+        for _ in 0..100 {
+            let _: u64 = busy.stream(Stream::Shop).random();
+        }
+
+        let busy_shuffle: u64 = busy.stream(Stream::Shuffle).random();
+        let idle_shuffle: u64 = idle.stream(Stream::Shuffle).random();
+        assert_eq!(busy_shuffle, idle_shuffle);
+        assert_ne!(busy.shop, idle.shop);
+    }
+
+    #[test]
+    fn from_index() {
+        assert_eq!(RunSeed::from_index(0).as_str(), "11111111");
+        assert_eq!(RunSeed::from_index(1).as_str(), "11111112");
+        assert_eq!(RunSeed::from_index(34).as_str(), "11111121");
+        assert_eq!(RunSeed::from(34u64.pow(8)), RunSeed::from_index(0));
+    }
+
+    #[test]
+    fn random__parses_back() {
+        let seed = RunSeed::random();
+
+        assert_eq!(seed.as_str().len(), RunSeed::MAX_LEN);
+        assert_eq!(RunSeed::from_str(seed.as_str()).unwrap(), seed);
+    }
+
+    #[test]
+    fn serde__as_the_string() {
+        let seed = RunSeed::from_str("7LB2WVPK").unwrap();
+        let json = serde_json::to_string(&seed).unwrap();
+
+        assert_eq!(json, "\"7LB2WVPK\"");
+        assert_eq!(serde_json::from_str::<RunSeed>(&json).unwrap(), seed);
+        assert!(serde_json::from_str::<RunSeed>("\"NOT A SEED\"").is_err());
+    }
+}
@@ -4,32 +4,119 @@ use serde::{Deserialize, Serialize};
 
 /// Which family of cards a [`BoosterPack`] opens into.
 ///
-/// The three base packs whose contents this engine can already draw: a Buffoon
-/// pack offers jokers, an Arcana pack tarots, a Celestial pack planets. The
-/// Standard (playing-card) and Spectral packs are out of scope — playing-card
-/// shop stock is voucher-gated, and spectral cards do not exist (EPIC-01
-/// Story 3).
+/// Balatro's five packs: a Buffoon pack offers jokers, an Arcana pack tarots,
+/// a Celestial pack planets, a Spectral pack spectrals, and a Standard pack
+/// playing cards, which may arrive enhanced or edited. How many each offers
+/// depends on its [`PackSize`] — see [`BoosterPack::choices`].
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub enum PackKind {
-    /// Choose one of two jokers, rarity-rolled.
+    /// Jokers, rarity-rolled.
     Buffoon,
-    /// Choose one of three tarots.
+    /// Tarots.
     Arcana,
-    /// Choose one of three planets.
+    /// Planets.
     Celestial,
+    /// Spectrals.
+    Spectral,
+    /// Playing cards, for the deck.
+    Standard,
 }
 
-/// A booster pack on offer in the [`Shop`] — a `kind` and what it costs to open.
+/// A booster pack's tier: how much it costs and how much it offers.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum PackSize {
+    /// $4, choose one.
+    #[default]
+    Normal,
+    /// $6, choose one of more.
+    Jumbo,
+    /// $8, choose two.
+    Mega,
+}
+
+impl PackSize {
+    /// The tier's price, before the shop-discount vouchers.
+    #[must_use]
+    pub const fn cost(self) -> usize {
+        match self {
+            Self::Normal => 4,
+            Self::Jumbo => 6,
+            Self::Mega => 8,
+        }
+    }
+}
+
+/// A booster pack on offer in the [`Shop`] — a `kind`, a `size`, and what it
+/// costs to open.
 ///
-/// `cost` is a plain field rather than a constant so the Jumbo ($6) and Mega
-/// ($8) tiers are data, not new mechanics; this engine stocks the base tier at
-/// $4.
+/// `cost` is a plain field rather than read from `size` so a caller can price
+/// a pack as it likes; [`new`](Self::new) charges the tier's price. A pack
+/// saved before tiers existed reads back as [`PackSize::Normal`].
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub struct BoosterPack {
     pub kind: PackKind,
+    #[serde(default)]
+    pub size: PackSize,
     pub cost: usize,
 }
 
+impl BoosterPack {
+    /// Balatro's shop-slot weights for every pack, out of their sum. The
+    /// Arcana, Celestial and Standard packs are common, the Buffoon pack rarer
+    /// and the Spectral pack rarest, and each kind's Jumbo is half as likely as
+    /// its Normal and its Mega an eighth — the wiki's weights, ×100.
+    pub const WEIGHTS: [(PackKind, PackSize, usize); 15] = [
+        (PackKind::Arcana, PackSize::Normal, 400),
+        (PackKind::Arcana, PackSize::Jumbo, 200),
+        (PackKind::Arcana, PackSize::Mega, 50),
+        (PackKind::Celestial, PackSize::Normal, 400),
+        (PackKind::Celestial, PackSize::Jumbo, 200),
+        (PackKind::Celestial, PackSize::Mega, 50),
+        (PackKind::Standard, PackSize::Normal, 400),
+        (PackKind::Standard, PackSize::Jumbo, 200),
+        (PackKind::Standard, PackSize::Mega, 50),
+        (PackKind::Buffoon, PackSize::Normal, 120),
+        (PackKind::Buffoon, PackSize::Jumbo, 60),
+        (PackKind::Buffoon, PackSize::Mega, 15),
+        (PackKind::Spectral, PackSize::Normal, 60),
+        (PackKind::Spectral, PackSize::Jumbo, 30),
+        (PackKind::Spectral, PackSize::Mega, 7),
+    ];
+
+    /// A pack of `kind` and `size` at the tier's price.
+    #[must_use]
+    pub const fn new(kind: PackKind, size: PackSize) -> Self {
+        Self {
+            kind,
+            size,
+            cost: size.cost(),
+        }
+    }
+
+    /// How many cards the pack offers. Buffoon and Spectral packs offer **2**,
+    /// or **4** at Jumbo and Mega; the others **3**, or **5**.
+    #[must_use]
+    pub const fn choices(&self) -> usize {
+        let small = matches!(self.kind, PackKind::Buffoon | PackKind::Spectral);
+        match (self.size, small) {
+            (PackSize::Normal, true) => 2,
+            (PackSize::Normal, false) => 3,
+            (_, true) => 4,
+            (_, false) => 5,
+        }
+    }
+
+    /// How many of the [`choices`](Self::choices) the player takes: **2** from
+    /// a Mega pack, **1** from any other.
+    #[must_use]
+    pub const fn picks(&self) -> usize {
+        match self.size {
+            PackSize::Mega => 2,
+            PackSize::Normal | PackSize::Jumbo => 1,
+        }
+    }
+}
+
 /// The between-rounds **shop** — the seam money is spent through.
 ///
 /// Held on the board as an `Option<Shop>`: `None` is a closed shop (the state
@@ -0,0 +1,438 @@
+use crate::funky::types::board::BuffoonBoard;
+use crate::funky::types::buffoon_pile::BuffoonPile;
+use crate::funky::types::effect::EffectRegistry;
+use crate::funky::types::hands::HandType;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+
+/// One way to play from the hand, scored against the board as it stands.
+///
+/// Returned by [`BuffoonBoard::best_plays`] and
+/// [`BuffoonBoard::evaluate_play`]. `indices` is the half a bot needs — hand it
+/// straight to [`play_hand`](BuffoonBoard::play_hand) — and the rest is the
+/// half a hint UI shows.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Play {
+    /// Positions in [`in_hand`](BuffoonBoard::in_hand), ascending.
+    pub indices: Vec<usize>,
+    pub cards: BuffoonPile,
+    pub hand_type: HandType,
+    /// The score with every roll lost: the pure path, where a Lucky card never
+    /// procs and Misprint adds nothing. What the play is **guaranteed** to make.
+    pub floor: usize,
+    /// The mean score over the seeded samples — an estimate of the floor plus
+    /// what the Lucky cards and Misprint are worth on average, not an exact
+    /// expectation. Equal to `floor` when no samples were asked for.
+    pub expected: f64,
+}
+
+impl Play {
+    /// Balatro's limit on both a play and a discard.
+    pub const MAX_CARDS: usize = 5;
+
+    /// Best first: higher expected value, then the higher floor (the safer of
+    /// two equal bets), then fewer cards, then hand order — so the ranking is
+    /// total and the same board always ranks the same way.
+    fn rank(&self, other: &Self) -> Ordering {
+        other
+            .expected
+            .total_cmp(&self.expected)
+            .then_with(|| other.floor.cmp(&self.floor))
+            .then_with(|| self.indices.len().cmp(&other.indices.len()))
+            .then_with(|| self.indices.cmp(&other.indices))
+    }
+}
+
+impl Display for Play {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} ({:?}): {} floor, {:.1} expected",
+            self.cards, self.hand_type, self.floor, self.expected
+        )
+    }
+}
+
+/// # Solver
+///
+/// Every play is scored the way [`play_hand`](Self::play_hand) would score it,
+/// on a clone: the cards are taken from the hand,
+/// [`on_scored`](Self::on_scored) runs, and the traced fold scores them with
+/// the rest of the hand held — so Baron, Steel cards and the retrigger jokers
+/// all count, and the board itself is never touched. The `_with_registry`
+/// twins score `MPip::Custom` cards and jokers through an [`EffectRegistry`]
+/// as well, as [`try_play_hand_with_registry`](Self::try_play_hand_with_registry)
+/// would.
+///
+/// The probabilistic effects are valued by Monte Carlo sampling rather than by
+/// formula, so `expected` is an estimate whose error shrinks with the square
+/// root of `samples`. Each sample rescores the play with a `StdRng` seeded
+/// `0..samples`, and every play is measured against the **same** seeds, so two
+/// plays differ only by what they are, not by their luck. A play's cards are
+/// taken and [`on_scored`](Self::on_scored) run once, on one scratch board;
+/// the samples only rescore it, which the fold does without touching it. The rolls the fold knows about are the
+/// Lucky card and Misprint; Bloodstone is not in the joker deck yet, and will
+/// be valued for free once its roll is.
+impl BuffoonBoard {
+    /// Score the cards at `indices` without playing them. `None` if an index
+    /// is out of bounds or `indices` is empty.
+    ///
+    /// Does not check the hand limit or the hands left in the round — it
+    /// answers "what would this score", not "may I play it".
+    #[must_use]
+    pub fn evaluate_play(&self, indices: &[usize], samples: u64) -> Option<Play> {
+        self.evaluate_play_inner(indices, samples, None)
+    }
+
+    /// [`evaluate_play`](Self::evaluate_play), with custom effects scored
+    /// through `registry`.
+    #[must_use]
+    pub fn evaluate_play_with_registry(
+        &self,
+        indices: &[usize],
+        samples: u64,
+        registry: &EffectRegistry,
+    ) -> Option<Play> {
+        self.evaluate_play_inner(indices, samples, Some(registry))
+    }
+
+    fn evaluate_play_inner(
+        &self,
+        indices: &[usize],
+        samples: u64,
+        registry: Option<&EffectRegistry>,
+    ) -> Option<Play> {
+        if indices.is_empty() {
+            return None;
+        }
+        let mut board = self.clone();
+        let cards = board.take_from_hand(indices)?;
+        board.played = cards.clone();
+        board.on_scored();
+        let hand_type = board.scoring_hand_type();
+        let floor = board.score_traced_inner::<StdRng>(None, registry).0.score();
+
+        let expected = if samples == 0 {
+            Self::as_f64(floor)
+        } else {
+            let total: f64 = (0..samples)
+                .map(|seed| {
+                    let mut rng = StdRng::seed_from_u64(seed);
+                    Self::as_f64(board.score_traced_inner(Some(&mut rng), registry).0.score())
+                })
+                .sum();
+            total / Self::as_f64(usize::try_from(samples).unwrap_or(usize::MAX))
+        };
+
+        let mut indices = indices.to_vec();
+        indices.sort_unstable();
+        indices.dedup();
+        Some(Play {
+            indices,
+            cards,
+            hand_type,
+            floor,
+            expected,
+        })
+    }
+
+    /// The `top` best plays in the hand, best first.
+    ///
+    /// Enumerates every 1- to 5-card subset of [`in_hand`](Self::in_hand) with
+    /// [`BuffoonPile::index_combos`] — 218 plays for a hand of eight — and
+    /// ranks them by expected value, with `samples` seeded samples each (0 for
+    /// the floor alone, which is exact on a board without random effects).
+    #[must_use]
+    pub fn best_plays(&self, top: usize, samples: u64) -> Vec<Play> {
+        self.best_plays_inner(top, samples, None)
+    }
+
+    /// [`best_plays`](Self::best_plays), with custom effects scored through
+    /// `registry`.
+    #[must_use]
+    pub fn best_plays_with_registry(
+        &self,
+        top: usize,
+        samples: u64,
+        registry: &EffectRegistry,
+    ) -> Vec<Play> {
+        self.best_plays_inner(top, samples, Some(registry))
+    }
+
+    fn best_plays_inner(
+        &self,
+        top: usize,
+        samples: u64,
+        registry: Option<&EffectRegistry>,
+    ) -> Vec<Play> {
+        let mut plays: Vec<Play> = (1..=Play::MAX_CARDS.min(self.in_hand.len()))
+            .flat_map(|k| self.in_hand.index_combos(k))
+            .filter_map(|indices| self.evaluate_play_inner(&indices, samples, registry))
+            .collect();
+        plays.sort_by(Play::rank);
+        plays.truncate(top);
+        plays
+    }
+
+    /// The held cards the best play has no use for — up to five positions in
+    /// [`in_hand`](Self::in_hand), ascending, ready for
+    /// [`discard_cards`](Self::discard_cards). Empty if the round has no
+    /// discards left.
+    ///
+    /// A card is dead when the best play scores no worse without it. Candidates
+    /// are tried lowest rank first, and each is kept only if the best play
+    /// still scores as well with **all** the kept cards gone, so a held card
+    /// that only pays in company (Baron's Kings, a Steel card) stays in hand.
+    ///
+    /// This prices a discard by what it costs the hand now, not by what the
+    /// refill might bring: the draw side is a question about the deck, which
+    /// [`draw_odds`](Self::draw_odds) answers.
+    #[must_use]
+    pub fn best_discard(&self, samples: u64) -> Vec<usize> {
+        self.best_discard_inner(samples, None)
+    }
+
+    /// [`best_discard`](Self::best_discard), with custom effects scored
+    /// through `registry`.
+    #[must_use]
+    pub fn best_discard_with_registry(
+        &self,
+        samples: u64,
+        registry: &EffectRegistry,
+    ) -> Vec<usize> {
+        self.best_discard_inner(samples, Some(registry))
+    }
+
+    fn best_discard_inner(&self, samples: u64, registry: Option<&EffectRegistry>) -> Vec<usize> {
+        if self.discards_remaining() == 0 {
+            return Vec::new();
+        }
+        let Some(best) = self.best_plays_inner(1, samples, registry).pop() else {
+            return Vec::new();
+        };
+
+        let mut candidates: Vec<usize> = (0..self.in_hand.len())
+            .filter(|index| !best.indices.contains(index))
+            .collect();
+        candidates.sort_by_key(|index| self.in_hand.get(*index).map_or(0, |card| card.rank.weight));
+
+        let mut dead: Vec<usize> = Vec::new();
+        for candidate in candidates {
+            if dead.len() == Play::MAX_CARDS {
+                break;
+            }
+            let mut trial = dead.clone();
+            trial.push(candidate);
+            if self.without_held(&trial, &best.indices, samples, registry) >= best.expected {
+                dead = trial;
+            }
+        }
+        dead.sort_unstable();
+        dead
+    }
+
+    /// The expected value of the play at `play` once the held cards at `gone`
+    /// are out of the hand. Both are positions in the current hand.
+    fn without_held(
+        &self,
+        gone: &[usize],
+        play: &[usize],
+        samples: u64,
+        registry: Option<&EffectRegistry>,
+    ) -> f64 {
+        let mut board = self.clone();
+        let mut slots = gone.to_vec();
+        slots.sort_unstable();
+        for slot in slots.iter().rev() {
+            board.in_hand.remove(*slot);
+        }
+        // Each removal ahead of a played card shifts it one place left.
+        let shifted: Vec<usize> = play
+            .iter()
+            .map(|index| index - slots.iter().filter(|slot| *slot < index).count())
+            .collect();
+        board
+            .evaluate_play_inner(&shifted, samples, registry)
+            .map_or(0.0, |play| play.expected)
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    const fn as_f64(n: usize) -> f64 {
+        n as f64
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod funky__types__solver_tests {
+    use super::*;
+    use crate::funky::types::draws::Draws;
+    use crate::funky::types::mpip::MPip;
+    use crate::preludes::funky::*;
+
+    fn board_holding(index: &str) -> BuffoonBoard {
+        let mut board = BuffoonBoard::new(Draws::new(4, 3), Deck::basic_buffoon_pile());
+        board.in_hand = bcards!(index);
+        board
+    }
+
+    #[test]
+    fn evaluate_play__scores_like_play_hand() {
+        let board = board_holding("KS KH 2C 5D 9S");
+        let play = board.evaluate_play(&[1, 0], 0).unwrap();
+
+        let mut played = board.clone();
+        let score = played.play_hand(&[0, 1]).unwrap();
+
+        assert_eq!(play.indices, vec![0, 1]);
+        assert_eq!(play.hand_type, HandType::Pair);
+        assert_eq!(play.floor, score.score());
+        assert!((play.expected - BuffoonBoard::as_f64(play.floor)).abs() < f64::EPSILON);
+        assert_eq!(board.in_hand.len(), 5, "the board is not touched");
+    }
+
+    /// Wipes out the mult of any hand it is played in.
+    struct Zero;
+
+    impl Effect for Zero {
+        fn score(&self, _ctx: &ScoringContext<'_>) -> ScoreOp {
+            ScoreOp::TimesMult(0.0)
+        }
+    }
+
+    #[test]
+    fn best_plays__scores_custom_effects_through_a_registry() {
+        const ZERO: EffectId = EffectId::new("test:zero");
+        let mut registry = EffectRegistry::new();
+        registry.register(ZERO, Zero).unwrap();
+        let mut board = board_holding("AS 2D");
+        board.in_hand = BuffoonPile::from(vec![
+            bcard!(AS),
+            BuffoonCard {
+                enhancement: MPip::Custom(ZERO),
+                ..bcard!(2D)
+            },
+        ]);
+
+        assert_eq!(board.best_plays(1, 0)[0].indices, vec![0, 1]);
+        let best = &board.best_plays_with_registry(1, 0, &registry)[0];
+        assert_eq!(best.indices, vec![0]);
+        assert_eq!(
+            board
+                .evaluate_play_with_registry(&[0, 1], 8, &registry)
+                .unwrap()
+                .floor,
+            0
+        );
+    }
+
+    #[test]
+    fn evaluate_play__refuses_empty_and_out_of_bounds() {
+        let board = board_holding("KS KH 2C");
+
+        assert!(board.evaluate_play(&[], 8).is_none());
+        assert!(board.evaluate_play(&[0, 3], 8).is_none());
+    }
+
+    #[test]
+    fn evaluate_play__lucky_card_is_worth_more_than_its_floor() {
+        let lucky = BuffoonCard {
+            enhancement: MPip::Lucky(5, 15),
+            ..FIVE_HEARTS
+        };
+        let mut board = board_holding("2C");
+        board.in_hand.insert(0, lucky);
+
+        let play = board.evaluate_play(&[0], 64).unwrap();
+        let plain = board_holding("5H").evaluate_play(&[0], 64).unwrap();
+
+        assert_eq!(play.floor, plain.floor, "a lost roll is a plain card");
+        assert!(play.expected > BuffoonBoard::as_f64(play.floor));
+    }
+
+    #[test]
+    fn evaluate_play__misprint_is_worth_more_than_its_floor() {
+        let mut board = board_holding("KS KH");
+        board.push_joker(MISPRINT);
+
+        let play = board.evaluate_play(&[0, 1], 32).unwrap();
+
+        assert!(play.expected > BuffoonBoard::as_f64(play.floor));
+    }
+
+    #[test]
+    fn best_plays__enumerates_every_subset_of_up_to_five() {
+        let board = board_holding("AS KH 9C 7D 5S 3H 2C 4D");
+
+        assert_eq!(board.best_plays(usize::MAX, 0).len(), 218);
+        assert_eq!(board.best_plays(3, 0).len(), 3);
+    }
+
+    #[test]
+    fn best_plays__best_first() {
+        let board = board_holding("KS 2C KH 5D 9S 7C 3H JD");
+        let plays = board.best_plays(10, 0);
+
+        let best = &plays[0];
+        assert!(best.indices.contains(&0) && best.indices.contains(&2));
+        assert_eq!(best.hand_type, HandType::Pair);
+        assert!(plays.windows(2).all(|w| w[0].expected >= w[1].expected));
+    }
+
+    #[test]
+    fn best_plays__finds_the_flush() {
+        let board = board_holding("2H 9H KS 5H JH KD QH 3C");
+        let best = board.best_plays(1, 0).pop().unwrap();
+
+        assert_eq!(best.hand_type, HandType::Flush);
+        assert_eq!(best.indices, vec![0, 1, 3, 4, 6]);
+    }
+
+    #[test]
+    fn best_discard__keeps_the_best_play() {
+        let board = board_holding("KS 2C KH 5D 9S 7C 3H JD");
+        let best = board.best_plays(1, 0).pop().unwrap();
+        let discard = board.best_discard(0);
+
+        assert!(!discard.is_empty());
+        assert!(discard.len() <= Play::MAX_CARDS);
+        assert!(discard.iter().all(|index| !best.indices.contains(index)));
+
+        let mut board = board;
+        assert!(board.discard_cards(&discard));
+    }
+
+    #[test]
+    fn best_discard__keeps_what_baron_pays_for() {
+        let mut board = board_holding("2S 2C KH 5D 9S 7C 3H JD");
+        board.push_joker(BARON);
+        let discard = board.best_discard(0);
+
+        let king = 2;
+        assert!(!discard.contains(&king));
+    }
+
+    #[test]
+    fn best_discard__empty_without_discards() {
+        let mut board = board_holding("KS 2C KH 5D 9S 7C 3H JD");
+        board.draws = Draws::new(4, 0);
+
+        assert!(board.best_discard(0).is_empty());
+    }
+
+    #[test]
+    fn display() {
+        let board = board_holding("KS KH");
+        let play = board.evaluate_play(&[0, 1], 0).unwrap();
+
+        assert_eq!(
+            play.to_string(),
+            format!(
+                "{} (Pair): {} floor, {}.0 expected",
+                play.cards, play.floor, play.floor
+            )
+        );
+    }
+}
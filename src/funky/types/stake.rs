@@ -0,0 +1,218 @@
+use crate::funky::types::sticker::Stickers;
+use rand::{Rng, RngExt};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// A Balatro **stake** — the run's difficulty, White through Gold.
+///
+/// Each stake keeps every modifier of the stakes below it and adds one:
+///
+/// | Stake  | Adds                                                  |
+/// |--------|-------------------------------------------------------|
+/// | White  | nothing — the base game                               |
+/// | Red    | the Small Blind pays no reward money                  |
+/// | Green  | required scores scale faster per ante                 |
+/// | Black  | 30% of shop jokers are Eternal                        |
+/// | Blue   | −1 discard                                            |
+/// | Purple | required scores scale faster still                    |
+/// | Orange | 30% of shop jokers are Perishable                     |
+/// | Gold   | 30% of shop jokers are Rental                         |
+///
+/// The variants are declared in that order, so `stake >= Stake::Black` reads
+/// as "Black Stake or harder". Held by the board as
+/// [`stake`](crate::funky::types::board::BuffoonBoard::stake) and read live, the
+/// voucher shape: [`White`](Self::White) is the default, and inert.
+#[derive(
+    Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize,
+)]
+pub enum Stake {
+    #[default]
+    White,
+    Red,
+    Green,
+    Black,
+    Blue,
+    Purple,
+    Orange,
+    Gold,
+}
+
+impl Stake {
+    pub const ALL: [Self; 8] = [
+        Self::White,
+        Self::Red,
+        Self::Green,
+        Self::Black,
+        Self::Blue,
+        Self::Purple,
+        Self::Orange,
+        Self::Gold,
+    ];
+
+    /// The base score requirement of antes 0 through 8 at White and Red Stake —
+    /// what a Small Blind asks. Ante 0 is only reachable through Hieroglyph.
+    pub const ANTE_BASES: [usize; 9] =
+        [100, 300, 800, 2_000, 5_000, 11_000, 20_000, 35_000, 50_000];
+    /// The ante bases at Green and Blue Stake.
+    pub const GREEN_ANTE_BASES: [usize; 9] =
+        [100, 300, 900, 2_600, 8_000, 20_000, 36_000, 60_000, 100_000];
+    /// The ante bases at Purple Stake and above.
+    pub const PURPLE_ANTE_BASES: [usize; 9] = [
+        100, 300, 1_000, 3_200, 9_000, 25_000, 60_000, 110_000, 200_000,
+    ];
+
+    /// The chance, in tenths, that a shop joker rolls each sticker its stake
+    /// allows.
+    const STICKER_CHANCE: usize = 3;
+
+    /// The base score requirement of `ante` at this stake. Antes past 8 keep
+    /// ante 8's base; Endless Mode's growth is not modelled.
+    #[must_use]
+    pub fn ante_base(self, ante: usize) -> usize {
+        let bases = if self >= Self::Purple {
+            &Self::PURPLE_ANTE_BASES
+        } else if self >= Self::Green {
+            &Self::GREEN_ANTE_BASES
+        } else {
+            &Self::ANTE_BASES
+        };
+        bases[ante.min(bases.len() - 1)]
+    }
+
+    /// Whether beating the Small Blind pays its reward — not from Red Stake up.
+    /// The per-hand money and interest are paid either way.
+    #[must_use]
+    pub fn small_blind_pays(self) -> bool {
+        self < Self::Red
+    }
+
+    /// How many discards the stake takes away: one from Blue Stake up.
+    #[must_use]
+    pub fn discard_penalty(self) -> usize {
+        usize::from(self >= Self::Blue)
+    }
+
+    /// Roll the stickers a shop joker arrives with.
+    ///
+    /// Eternal (from Black) and Perishable (from Orange) share one roll, so a
+    /// joker is never both: 30% Eternal, and a separate 30% band Perishable.
+    /// Rental (Gold) is a second, independent roll. Below Black Stake nothing
+    /// is drawn at all, so a White Stake run's RNG stream is exactly what it was
+    /// before stakes existed; from Black up it is always two draws, whatever
+    /// the stake allows, so the stream does not shift between those stakes.
+    pub fn roll_stickers<R: Rng + ?Sized>(self, rng: &mut R) -> Stickers {
+        if self < Self::Black {
+            return Stickers::NONE;
+        }
+        let lasting = rng.random_range(0..10);
+        let rent = rng.random_range(0..10);
+        Stickers {
+            eternal: lasting < Self::STICKER_CHANCE,
+            perishable: (self >= Self::Orange
+                && (Self::STICKER_CHANCE..2 * Self::STICKER_CHANCE).contains(&lasting))
+            .then_some(Stickers::PERISHABLE_ROUNDS),
+            rental: self >= Self::Gold && rent < Self::STICKER_CHANCE,
+            pinned: false,
+        }
+    }
+}
+
+impl Display for Stake {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::White => "White",
+            Self::Red => "Red",
+            Self::Green => "Green",
+            Self::Black => "Black",
+            Self::Blue => "Blue",
+            Self::Purple => "Purple",
+            Self::Orange => "Orange",
+            Self::Gold => "Gold",
+        };
+        write!(f, "{name} Stake")
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod funky__types__stake_tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Stake::White, 2, 800)]
+    #[case(Stake::Red, 8, 50_000)]
+    #[case(Stake::Green, 2, 900)]
+    #[case(Stake::Blue, 8, 100_000)]
+    #[case(Stake::Purple, 2, 1_000)]
+    #[case(Stake::Gold, 8, 200_000)]
+    #[case(Stake::Gold, 12, 200_000)]
+    #[case(Stake::Gold, 0, 100)]
+    fn ante_base(#[case] stake: Stake, #[case] ante: usize, #[case] expected: usize) {
+        assert_eq!(stake.ante_base(ante), expected);
+    }
+
+    #[test]
+    fn small_blind_pays() {
+        assert!(Stake::White.small_blind_pays());
+        assert!(!Stake::Red.small_blind_pays());
+        assert!(!Stake::Gold.small_blind_pays());
+    }
+
+    #[test]
+    fn discard_penalty() {
+        assert_eq!(Stake::Black.discard_penalty(), 0);
+        assert_eq!(Stake::Blue.discard_penalty(), 1);
+        assert_eq!(Stake::Gold.discard_penalty(), 1);
+    }
+
+    /// How many of 1,000 seeded rolls at `stake` carry each sticker.
+    fn tally(stake: Stake) -> [usize; 3] {
+        let mut rng = StdRng::seed_from_u64(31);
+        let mut counts = [0; 3];
+        for _ in 0..1_000 {
+            let stickers = stake.roll_stickers(&mut rng);
+            assert!(!(stickers.eternal && stickers.perishable.is_some()));
+            counts[0] += usize::from(stickers.eternal);
+            counts[1] += usize::from(stickers.perishable.is_some());
+            counts[2] += usize::from(stickers.rental);
+        }
+        counts
+    }
+
+    #[test]
+    fn roll_stickers__by_stake() {
+        assert_eq!(tally(Stake::Green), [0, 0, 0]);
+
+        let [eternal, perishable, rental] = tally(Stake::Black);
+        assert!((250..350).contains(&eternal));
+        assert_eq!((perishable, rental), (0, 0));
+
+        let [_, perishable, rental] = tally(Stake::Orange);
+        assert!((250..350).contains(&perishable));
+        assert_eq!(rental, 0);
+
+        let [eternal, perishable, rental] = tally(Stake::Gold);
+        assert!((250..350).contains(&eternal));
+        assert!((250..350).contains(&perishable));
+        assert!((250..350).contains(&rental));
+    }
+
+    #[test]
+    fn roll_stickers__draws_nothing_below_black() {
+        let mut rolled = StdRng::seed_from_u64(5);
+        let untouched = StdRng::seed_from_u64(5);
+        let _ = Stake::Green.roll_stickers(&mut rolled);
+
+        assert_eq!(rolled, untouched);
+        let _ = Stake::Black.roll_stickers(&mut rolled);
+        assert_ne!(rolled, untouched);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Stake::Gold.to_string(), "Gold Stake");
+    }
+}
@@ -0,0 +1,348 @@
+use crate::funky::decks::basic::Deck;
+use crate::funky::decks::spectral::card as spectral_card;
+use crate::funky::decks::tarot::card as tarot_card;
+use crate::funky::types::board::BuffoonBoard;
+use crate::funky::types::buffoon_pile::BuffoonPile;
+use crate::funky::types::draws::Draws;
+use crate::funky::types::voucher::Voucher;
+use rand::rngs::StdRng;
+use rand::{Rng, RngExt, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// The deck a run is started with — Balatro's **starter decks**, each a
+/// run-start configuration of the [`BuffoonBoard`].
+///
+/// A dedicated enum rather than a [`Deck`] constant, because most starter decks
+/// are not a different set of cards: Red is the standard 52 with one more
+/// discard, Zodiac the standard 52 with three vouchers already redeemed. What a
+/// starter deck changes is *board configuration*, which is the [`Voucher`]
+/// shape. The few that do change the cards (Abandoned, Checkered, Erratic)
+/// build their pile from [`Deck`]'s constants.
+///
+/// Most of a deck is applied once, at [`board_with_rng`](Self::board_with_rng),
+/// and then is simply the board's state: the draws land in
+/// [`starting_draws`](BuffoonBoard::starting_draws) so the per-blind recompute
+/// keeps them, and the slots, money, vouchers and consumables are the run's
+/// opening position. The three decks whose rule outlives run start — Green's
+/// cash-out, Ghost's shop, Plasma's scoring — are read **live** from
+/// [`starter_deck`](BuffoonBoard::starter_deck).
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum StarterDeck {
+    /// +1 discard every round.
+    Red,
+    /// +1 hand every round.
+    Blue,
+    /// Start with an extra $10.
+    Yellow,
+    /// At cash-out, $2 per remaining hand and $1 per remaining discard; no
+    /// interest is earned.
+    Green,
+    /// +1 joker slot, −1 hand every round.
+    Black,
+    /// Start with the Crystal Ball voucher and 2 copies of The Fool.
+    Magic,
+    /// Start with the Telescope voucher; −1 consumable slot.
+    Nebula,
+    /// Spectral cards may appear in the shop; start with a Hex card.
+    Ghost,
+    /// Start with no face cards — [`Deck::ABANDONED_DECK`]'s 40.
+    Abandoned,
+    /// Start with 26 Spades and 26 Hearts — [`Deck::CHECKERED_DECK`].
+    Checkered,
+    /// Start with Tarot Merchant, Planet Merchant and Overstock.
+    Zodiac,
+    /// +2 hand size, −1 joker slot.
+    Painted,
+    /// A Double Tag after each Boss Blind is defeated.
+    ///
+    /// Tags are not modelled, so an Anaglyph run is a standard run. The variant
+    /// exists so a caller can name the deck, and so the Tags work has somewhere
+    /// to land.
+    Anaglyph,
+    /// Chips and mult are balanced after the jokers score; base blind size ×2
+    /// (see [`blind_target_multiplier`](Self::blind_target_multiplier)).
+    Plasma,
+    /// Every rank and suit is randomized — 52 cards drawn with replacement from
+    /// [`Deck::DECK`].
+    Erratic,
+}
+
+impl StarterDeck {
+    /// Every starter deck, in Balatro's collection order.
+    pub const ALL: [Self; 15] = [
+        Self::Red,
+        Self::Blue,
+        Self::Yellow,
+        Self::Green,
+        Self::Black,
+        Self::Magic,
+        Self::Nebula,
+        Self::Ghost,
+        Self::Abandoned,
+        Self::Checkered,
+        Self::Zodiac,
+        Self::Painted,
+        Self::Anaglyph,
+        Self::Plasma,
+        Self::Erratic,
+    ];
+
+    /// The round configuration every deck starts from: Balatro's 4 hands,
+    /// 3 discards and hand size 8.
+    pub const BASE_DRAWS: Draws = Draws {
+        hands_to_play: 4,
+        discards: 3,
+        hand_size: Draws::DEFAULT_HAND_SIZE,
+    };
+
+    /// A fresh board configured for this deck, with Erratic's pile drawn from a
+    /// `u64` seed. See [`board_with_rng`](Self::board_with_rng).
+    #[must_use]
+    pub fn board_with_seed(self, seed: u64) -> BuffoonBoard {
+        self.board_with_rng(&mut StdRng::seed_from_u64(seed))
+    }
+
+    /// A fresh board configured for this deck.
+    ///
+    /// Only Erratic draws from `rng`; every other deck is deterministic and
+    /// leaves it untouched. There is deliberately no RNG-free constructor —
+    /// the [`open_shop_with_rng`](BuffoonBoard::open_shop_with_rng) rule: a
+    /// deck that can be randomized takes its randomness from the caller.
+    #[must_use]
+    pub fn board_with_rng<R: Rng + ?Sized>(self, rng: &mut R) -> BuffoonBoard {
+        let mut board = BuffoonBoard::new(self.draws(), self.pile(rng));
+        board.starter_deck = Some(self);
+        match self {
+            Self::Yellow => board.money += 10,
+            Self::Black => board.joker_slots += 1,
+            Self::Magic => {
+                board.apply_voucher(Voucher::CrystalBall);
+                board.create_consumable(tarot_card::FOOL);
+                board.create_consumable(tarot_card::FOOL);
+            }
+            Self::Nebula => {
+                board.apply_voucher(Voucher::Telescope);
+                board.consumable_slots -= 1;
+            }
+            Self::Ghost => {
+                board.create_consumable(spectral_card::HEX);
+            }
+            Self::Zodiac => {
+                for voucher in [
+                    Voucher::TarotMerchant,
+                    Voucher::PlanetMerchant,
+                    Voucher::Overstock,
+                ] {
+                    board.apply_voucher(voucher);
+                }
+            }
+            Self::Painted => board.joker_slots -= 1,
+            _ => {}
+        }
+        board
+    }
+
+    /// This deck's starting [`Draws`], from [`BASE_DRAWS`](Self::BASE_DRAWS).
+    #[must_use]
+    pub fn draws(self) -> Draws {
+        let mut draws = Self::BASE_DRAWS;
+        match self {
+            Self::Red => draws.discards += 1,
+            Self::Blue => draws.hands_to_play += 1,
+            Self::Black => draws.hands_to_play -= 1,
+            Self::Painted => draws.hand_size += 2,
+            _ => {}
+        }
+        draws
+    }
+
+    /// How much this deck scales a blind's score requirement: **×2** for Plasma,
+    /// ×1 otherwise.
+    ///
+    /// A multiplier rather than a write to
+    /// [`blind_target`](BuffoonBoard::blind_target), because the board does not
+    /// set its own target — the caller does, and this is the factor it applies.
+    #[must_use]
+    pub fn blind_target_multiplier(self) -> usize {
+        match self {
+            Self::Plasma => 2,
+            _ => 1,
+        }
+    }
+
+    fn pile<R: Rng + ?Sized>(self, rng: &mut R) -> BuffoonPile {
+        match self {
+            Self::Abandoned => BuffoonPile::from(Deck::ABANDONED_DECK.to_vec()),
+            Self::Checkered => BuffoonPile::from(Deck::CHECKERED_DECK.to_vec()),
+            Self::Erratic => BuffoonPile::from(
+                (0..Deck::DECK_SIZE)
+                    .map(|_| Deck::DECK[rng.random_range(0..Deck::DECK_SIZE)])
+                    .collect::<Vec<_>>(),
+            ),
+            _ => Deck::basic_buffoon_pile(),
+        }
+    }
+}
+
+impl Display for StarterDeck {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Red => "Red Deck",
+            Self::Blue => "Blue Deck",
+            Self::Yellow => "Yellow Deck",
+            Self::Green => "Green Deck",
+            Self::Black => "Black Deck",
+            Self::Magic => "Magic Deck",
+            Self::Nebula => "Nebula Deck",
+            Self::Ghost => "Ghost Deck",
+            Self::Abandoned => "Abandoned Deck",
+            Self::Checkered => "Checkered Deck",
+            Self::Zodiac => "Zodiac Deck",
+            Self::Painted => "Painted Deck",
+            Self::Anaglyph => "Anaglyph Deck",
+            Self::Plasma => "Plasma Deck",
+            Self::Erratic => "Erratic Deck",
+        };
+        write!(f, "{name}")
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod funky__types__starter_deck_tests {
+    use super::*;
+    use crate::funky::types::buffoon_card::BCardType;
+
+    #[test]
+    fn all__lists_every_variant_once() {
+        let mut seen = StarterDeck::ALL.to_vec();
+        seen.sort_unstable();
+        seen.dedup();
+        assert_eq!(seen.len(), StarterDeck::ALL.len());
+        assert_eq!(StarterDeck::ALL.len(), 15);
+    }
+
+    #[test]
+    fn draws__red_blue_black_and_painted() {
+        assert_eq!(StarterDeck::Red.draws().discards, 4);
+        assert_eq!(StarterDeck::Blue.draws().hands_to_play, 5);
+        assert_eq!(StarterDeck::Black.draws().hands_to_play, 3);
+        assert_eq!(StarterDeck::Painted.draws().hand_size, 10);
+        assert_eq!(StarterDeck::Zodiac.draws(), StarterDeck::BASE_DRAWS);
+    }
+
+    #[test]
+    fn board_with_seed__draws_survive_the_blind_recompute() {
+        // The bonus lands in `starting_draws`, so selecting a blind keeps it.
+        let mut board = StarterDeck::Red.board_with_seed(1);
+        board.on_blind_selected();
+        assert_eq!(board.draws.discards, 4);
+    }
+
+    #[test]
+    fn board_with_seed__yellow_starts_with_ten_dollars() {
+        assert_eq!(StarterDeck::Yellow.board_with_seed(1).money, 10);
+        assert_eq!(StarterDeck::Red.board_with_seed(1).money, 0);
+    }
+
+    #[test]
+    fn board_with_seed__slots() {
+        let black = StarterDeck::Black.board_with_seed(1);
+        assert_eq!(black.joker_slots, BuffoonBoard::DEFAULT_JOKER_SLOTS + 1);
+
+        let painted = StarterDeck::Painted.board_with_seed(1);
+        assert_eq!(painted.joker_slots, BuffoonBoard::DEFAULT_JOKER_SLOTS - 1);
+
+        let nebula = StarterDeck::Nebula.board_with_seed(1);
+        assert_eq!(
+            nebula.consumable_slots,
+            BuffoonBoard::DEFAULT_CONSUMABLE_SLOTS - 1
+        );
+        assert_eq!(nebula.vouchers, vec![Voucher::Telescope]);
+    }
+
+    #[test]
+    fn board_with_seed__magic_holds_crystal_ball_and_two_fools() {
+        let board = StarterDeck::Magic.board_with_seed(1);
+        assert_eq!(board.vouchers, vec![Voucher::CrystalBall]);
+        assert_eq!(
+            board.consumable_slots,
+            BuffoonBoard::DEFAULT_CONSUMABLE_SLOTS + 1
+        );
+        assert_eq!(board.consumables.len(), 2);
+        assert!(
+            board
+                .consumables
+                .iter()
+                .all(|card| *card == tarot_card::FOOL)
+        );
+        assert!(board.has_consumable_room(), "the third slot is still free");
+    }
+
+    #[test]
+    fn board_with_seed__ghost_holds_a_hex() {
+        let board = StarterDeck::Ghost.board_with_seed(1);
+        assert_eq!(board.consumables.len(), 1);
+        assert_eq!(
+            board.consumables.iter().next().map(|card| card.card_type),
+            Some(BCardType::Spectral)
+        );
+    }
+
+    #[test]
+    fn board_with_seed__zodiac_holds_its_three_vouchers() {
+        let board = StarterDeck::Zodiac.board_with_seed(1);
+        assert_eq!(
+            board.vouchers,
+            vec![
+                Voucher::TarotMerchant,
+                Voucher::PlanetMerchant,
+                Voucher::Overstock
+            ]
+        );
+    }
+
+    #[test]
+    fn board_with_seed__abandoned_and_checkered_piles() {
+        let abandoned = StarterDeck::Abandoned.board_with_seed(1);
+        assert_eq!(abandoned.deck.len(), Deck::ABANDONED_DECK_SIZE);
+        assert_eq!(abandoned.starting_deck_size, 40, "Erosion reads 40, not 52");
+
+        let checkered = StarterDeck::Checkered.board_with_seed(1);
+        assert!(
+            checkered
+                .full_deck
+                .iter()
+                .all(|card| card.suit.index == 'S' || card.suit.index == 'H')
+        );
+    }
+
+    #[test]
+    fn board_with_seed__erratic_is_seeded_and_not_standard() {
+        let one = StarterDeck::Erratic.board_with_seed(7);
+        let again = StarterDeck::Erratic.board_with_seed(7);
+        assert_eq!(one.full_deck, again.full_deck, "deterministic per seed");
+        assert_eq!(one.full_deck.len(), Deck::DECK_SIZE);
+        assert_ne!(one.full_deck, Deck::basic_buffoon_pile());
+    }
+
+    #[test]
+    fn board_with_seed__records_the_deck() {
+        for deck in StarterDeck::ALL {
+            assert_eq!(deck.board_with_seed(1).starter_deck, Some(deck));
+        }
+    }
+
+    #[test]
+    fn blind_target_multiplier__plasma_doubles() {
+        assert_eq!(StarterDeck::Plasma.blind_target_multiplier(), 2);
+        assert_eq!(StarterDeck::Red.blind_target_multiplier(), 1);
+    }
+
+    #[test]
+    fn display__reads_as_the_wiki_name() {
+        assert_eq!(StarterDeck::Abandoned.to_string(), "Abandoned Deck");
+        assert_eq!(StarterDeck::Plasma.to_string(), "Plasma Deck");
+    }
+}
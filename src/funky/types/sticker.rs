@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// The **stickers** a joker can carry: Eternal, Perishable and Rental, and the
+/// Pinned flag a challenge sets.
+///
+/// A dedicated field on [`BuffoonCard`] for the reason
+/// [`Edition`](crate::funky::types::edition::Edition) is one: a sticker sits
+/// alongside the joker's ability and its edition, so it cannot share either
+/// slot. The three are independent flags, except that Balatro never puts
+/// Eternal and Perishable on the same joker — see
+/// [`Stake::roll_stickers`](crate::funky::types::stake::Stake::roll_stickers),
+/// the only place a run acquires them.
+///
+/// [`BuffoonCard`]: crate::funky::types::buffoon_card::BuffoonCard
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct Stickers {
+    /// Can't be sold or destroyed.
+    pub eternal: bool,
+    /// Rounds left before the joker is debuffed, or `None` if it is not
+    /// Perishable. Counted down by
+    /// [`on_round_end`](crate::funky::types::board::BuffoonBoard::on_round_end);
+    /// at `Some(0)` the joker has perished.
+    pub perishable: Option<usize>,
+    /// Costs [`RENTAL_COST`](Self::RENTAL_COST) at the end of every round, and
+    /// only $1 to buy.
+    pub rental: bool,
+    /// Stays in its slot: it can't be moved, and no joker is moved past it.
+    /// Only a challenge starts a joker Pinned — On a Knife's Edge's Ceremonial
+    /// Dagger, leftmost.
+    #[serde(default)]
+    pub pinned: bool,
+}
+
+impl Stickers {
+    /// No stickers — what every card starts with.
+    pub const NONE: Self = Self {
+        eternal: false,
+        perishable: None,
+        rental: false,
+        pinned: false,
+    };
+    /// How many rounds a Perishable joker lasts before it is debuffed.
+    pub const PERISHABLE_ROUNDS: usize = 5;
+    /// What a Rental joker charges at the end of each round.
+    pub const RENTAL_COST: isize = 3;
+
+    #[must_use]
+    pub const fn eternal() -> Self {
+        Self {
+            eternal: true,
+            ..Self::NONE
+        }
+    }
+
+    /// A fresh Perishable sticker, with all its rounds to go.
+    #[must_use]
+    pub const fn perishable() -> Self {
+        Self {
+            perishable: Some(Self::PERISHABLE_ROUNDS),
+            ..Self::NONE
+        }
+    }
+
+    #[must_use]
+    pub const fn rental() -> Self {
+        Self {
+            rental: true,
+            ..Self::NONE
+        }
+    }
+
+    #[must_use]
+    pub const fn pinned() -> Self {
+        Self {
+            pinned: true,
+            ..Self::NONE
+        }
+    }
+
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        !self.eternal && self.perishable.is_none() && !self.rental && !self.pinned
+    }
+
+    /// Whether a Perishable sticker has run out.
+    #[must_use]
+    pub const fn has_perished(self) -> bool {
+        matches!(self.perishable, Some(0))
+    }
+}
+
+impl Display for Stickers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut names = Vec::new();
+        if self.eternal {
+            names.push("Eternal".to_string());
+        }
+        if let Some(rounds) = self.perishable {
+            names.push(format!("Perishable ({rounds})"));
+        }
+        if self.rental {
+            names.push("Rental".to_string());
+        }
+        if self.pinned {
+            names.push("Pinned".to_string());
+        }
+        write!(f, "{}", names.join(", "))
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod funky__types__sticker_tests {
+    use super::*;
+
+    #[test]
+    fn is_empty() {
+        assert!(Stickers::NONE.is_empty());
+        assert!(Stickers::default().is_empty());
+        assert!(!Stickers::eternal().is_empty());
+        assert!(!Stickers::perishable().is_empty());
+        assert!(!Stickers::rental().is_empty());
+        assert!(!Stickers::pinned().is_empty());
+    }
+
+    #[test]
+    fn has_perished() {
+        assert!(!Stickers::perishable().has_perished());
+        assert!(
+            Stickers {
+                perishable: Some(0),
+                ..Stickers::NONE
+            }
+            .has_perished()
+        );
+        assert!(!Stickers::NONE.has_perished());
+    }
+
+    #[test]
+    fn display() {
+        let stickers = Stickers {
+            rental: true,
+            ..Stickers::perishable()
+        };
+
+        assert_eq!(stickers.to_string(), "Perishable (5), Rental");
+        assert_eq!(Stickers::eternal().to_string(), "Eternal");
+        assert_eq!(Stickers::pinned().to_string(), "Pinned");
+        assert_eq!(Stickers::NONE.to_string(), "");
+    }
+}
@@ -0,0 +1,339 @@
+use crate::funky::types::buffoon_card::BuffoonCard;
+use crate::funky::types::effect::ScoreOp;
+use crate::funky::types::score::{BigScore, Score};
+use std::fmt::{Display, Formatter};
+
+/// Which of the four scoring phases a [`ScoreStep`] was taken in.
+///
+/// The phases are Balatro's order, and the order the board folds them in:
+/// [`BuffoonBoard::score`](crate::funky::types::board::BuffoonBoard::score)
+/// documents each.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ScoringPhase {
+    /// Phase 1: the played hand type's base chips and mult.
+    PreScoring,
+    /// Phase 2: each played card, per trigger.
+    PlayedCards,
+    /// Phase 3: each card held in hand.
+    HeldCards,
+    /// Phase 4: each joker, left to right.
+    Jokers,
+}
+
+impl Display for ScoringPhase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::PreScoring => write!(f, "base"),
+            Self::PlayedCards => write!(f, "played"),
+            Self::HeldCards => write!(f, "held"),
+            Self::Jokers => write!(f, "joker"),
+        }
+    }
+}
+
+/// One [`ScoreOp`] the fold applied: where, from what, and what the running
+/// score was **after** it.
+///
+/// `running` is the point of the record. A wrong total is a wrong step, and the
+/// step is found by reading down the list for the first running score that
+/// disagrees with the hand worked by hand — the same list a UI animates chip by
+/// chip.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScoreStep {
+    pub phase: ScoringPhase,
+    /// The card or joker the op came from — `None` for the phase-1 base, which
+    /// comes from the hand type.
+    pub source: Option<BuffoonCard>,
+    pub op: ScoreOp,
+    pub running: Score,
+}
+
+impl Display for ScoreStep {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.phase)?;
+        if let Some(source) = self.source {
+            write!(f, " {source}")?;
+        }
+        write!(
+            f,
+            ": {} -> {} x {}",
+            self.op, self.running.chips, self.running.mult
+        )
+    }
+}
+
+/// One thing that happened inside a traced scoring fold.
+///
+/// Scoring is a pure `&self` fold, so whatever a hand *does* to the board — a
+/// Lucky card procs and Lucky Cat should grow, a retriggered card should collect
+/// Hiker's chips again — cannot be written back from inside it. A traced fold
+/// records those happenings instead, and
+/// [`BuffoonBoard::commit_trace`](crate::funky::types::board::BuffoonBoard::commit_trace)
+/// applies them afterwards. The events are facts about what the fold *saw*, not
+/// instructions, so committing them never rolls the RNG a second time.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ProcEvent {
+    /// The played card at `index` scored — once per **trigger**, so a card
+    /// Hack retriggers appears twice, with `pass` 0 and then 1. `card` is the
+    /// card as it scored on that pass.
+    CardScored {
+        index: usize,
+        pass: usize,
+        card: BuffoonCard,
+    },
+    /// The Lucky card at `index` won its 1-in-5 roll for +20 mult, its 1-in-15
+    /// roll for $20, or both — Lucky Cat's trigger, once per trigger of the
+    /// card. A losing roll records only its [`RngDraw`](Self::RngDraw).
+    LuckyProc { index: usize, card: BuffoonCard },
+    /// The Lucky card at `index` won its roll for $20, paid by
+    /// [`commit_trace`](crate::funky::types::board::BuffoonBoard::commit_trace).
+    /// Recorded before that trigger's [`LuckyProc`](Self::LuckyProc).
+    LuckyMoney { index: usize, card: BuffoonCard },
+    /// The joker at `index` contributed something other than
+    /// [`ScoreOp::Nothing`](crate::funky::types::effect::ScoreOp::Nothing).
+    JokerFired { index: usize, joker: BuffoonCard },
+    /// One draw from the RNG: `value` out of `0..range`. Recorded so a trace
+    /// accounts for every draw the fold consumed, in order.
+    RngDraw { range: usize, value: usize },
+}
+
+/// The events and steps a scoring fold produced, in the order it produced
+/// them.
+///
+/// Returned alongside the [`Score`](crate::funky::types::score::Score) by
+/// [`BuffoonBoard::score_traced`](crate::funky::types::board::BuffoonBoard::score_traced)
+/// and its seeded twin.
+///
+/// The trace is the seam between scoring and mutation: the fold stays `&self`
+/// and only *reports*, and
+/// [`BuffoonBoard::commit_trace`](crate::funky::types::board::BuffoonBoard::commit_trace)
+/// is the one place the report is turned into state. That split is what lets a
+/// mutating joker like Lucky Cat work without the fold taking `&mut self` — and
+/// without re-rolling anything to find out what happened.
+///
+/// Its [`steps`](Self::steps) are the other half: the explanation. Every
+/// `ScoreOp` that changed the running score, with its source, renders (through
+/// `Display`) as a Balatro-style step list — one line per contribution, ending
+/// on the hand's final chips and mult.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ScoreTrace {
+    pub events: Vec<ProcEvent>,
+    /// Every op the fold applied, in order. Ops that contribute
+    /// [`ScoreOp::Nothing`] are left out — a plain card's empty enhancement is
+    /// not a step.
+    pub steps: Vec<ScoreStep>,
+}
+
+impl ScoreTrace {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, event: ProcEvent) {
+        self.events.push(event);
+    }
+
+    /// Record `op`, taken in `phase` from `source`, leaving the score at
+    /// `running`. A [`ScoreOp::Nothing`] is not recorded.
+    pub fn record(
+        &mut self,
+        phase: ScoringPhase,
+        source: Option<BuffoonCard>,
+        op: &ScoreOp,
+        running: Score,
+    ) {
+        if *op != ScoreOp::Nothing {
+            self.steps.push(ScoreStep {
+                phase,
+                source,
+                op: op.clone(),
+                running,
+            });
+        }
+    }
+
+    /// The steps taken in `phase`, in order.
+    pub fn steps_in(&self, phase: ScoringPhase) -> impl Iterator<Item = &ScoreStep> {
+        self.steps.iter().filter(move |step| step.phase == phase)
+    }
+
+    /// The hand at Balatro scale: every step's op replayed, in order, over a
+    /// [`BigScore`]. The same hand the `running` scores describe, without their
+    /// `usize` ceiling or their rounded ×mult.
+    #[must_use]
+    pub fn big_score(&self) -> BigScore {
+        self.steps
+            .iter()
+            .fold(BigScore::default(), |score, step| step.op.apply_big(score))
+    }
+
+    /// How many Lucky card triggers won either roll — what Lucky Cat grows by.
+    #[must_use]
+    pub fn lucky_procs(&self) -> usize {
+        self.events
+            .iter()
+            .filter(|event| matches!(event, ProcEvent::LuckyProc { .. }))
+            .count()
+    }
+
+    /// How many Lucky card triggers won $20.
+    #[must_use]
+    pub fn lucky_money_procs(&self) -> usize {
+        self.events
+            .iter()
+            .filter(|event| matches!(event, ProcEvent::LuckyMoney { .. }))
+            .count()
+    }
+
+    /// How many draws the fold took from the RNG.
+    #[must_use]
+    pub fn rng_draws(&self) -> usize {
+        self.events
+            .iter()
+            .filter(|event| matches!(event, ProcEvent::RngDraw { .. }))
+            .count()
+    }
+
+    /// How many times the played card at `index` scored: 1, plus its
+    /// retriggers. 0 for an index that did not score.
+    #[must_use]
+    pub fn triggers_of(&self, index: usize) -> usize {
+        self.events
+            .iter()
+            .filter(|event| matches!(event, ProcEvent::CardScored { index: i, .. } if *i == index))
+            .count()
+    }
+}
+
+/// The step list, one step per line.
+impl Display for ScoreTrace {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (index, step) in self.steps.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{step}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod funky__types__trace_tests {
+    use super::*;
+    use crate::funky::decks::basic::card as basic;
+    use crate::funky::decks::joker::card;
+    use crate::funky::types::magnitude::Magnitude;
+
+    #[test]
+    fn counters__read_only_their_own_events() {
+        let mut trace = ScoreTrace::new();
+        trace.push(ProcEvent::CardScored {
+            index: 0,
+            pass: 0,
+            card: basic::FIVE_HEARTS,
+        });
+        trace.push(ProcEvent::RngDraw { range: 5, value: 0 });
+        trace.push(ProcEvent::LuckyProc {
+            index: 0,
+            card: basic::FIVE_HEARTS,
+        });
+        trace.push(ProcEvent::CardScored {
+            index: 0,
+            pass: 1,
+            card: basic::FIVE_HEARTS,
+        });
+        trace.push(ProcEvent::JokerFired {
+            index: 0,
+            joker: card::HACK,
+        });
+
+        assert_eq!(trace.lucky_procs(), 1);
+        assert_eq!(trace.rng_draws(), 1);
+        assert_eq!(trace.triggers_of(0), 2);
+        assert_eq!(trace.triggers_of(1), 0);
+    }
+
+    #[test]
+    fn default__is_empty() {
+        let trace = ScoreTrace::default();
+
+        assert!(trace.events.is_empty());
+        assert!(trace.steps.is_empty());
+        assert_eq!(trace.lucky_procs(), 0);
+    }
+
+    #[test]
+    fn record__skips_nothing() {
+        let mut trace = ScoreTrace::new();
+        trace.record(
+            ScoringPhase::PreScoring,
+            None,
+            &ScoreOp::Add(Score::new(5, 1)),
+            Score::new(5, 1),
+        );
+        trace.record(
+            ScoringPhase::PlayedCards,
+            Some(basic::FIVE_HEARTS),
+            &ScoreOp::Nothing,
+            Score::new(5, 1),
+        );
+
+        assert_eq!(trace.steps.len(), 1);
+        assert_eq!(trace.steps_in(ScoringPhase::PlayedCards).count(), 0);
+    }
+
+    #[test]
+    fn display__one_step_per_line() {
+        let mut trace = ScoreTrace::new();
+        trace.record(
+            ScoringPhase::PreScoring,
+            None,
+            &ScoreOp::Add(Score::new(5, 1)),
+            Score::new(5, 1),
+        );
+        trace.record(
+            ScoringPhase::PlayedCards,
+            Some(basic::FIVE_HEARTS),
+            &ScoreOp::AddChips(5),
+            Score::new(10, 1),
+        );
+        trace.record(
+            ScoringPhase::HeldCards,
+            Some(basic::FIVE_HEARTS),
+            &ScoreOp::TimesMult(1.5),
+            Score::new(10, 2),
+        );
+
+        assert_eq!(
+            trace.to_string(),
+            "base: +5 chips +1 mult -> 5 x 1\n\
+             played 5H: +5 chips -> 10 x 1\n\
+             held 5H: ×1.5 mult -> 10 x 2"
+        );
+    }
+
+    #[test]
+    fn big_score__replays_the_steps() {
+        let mut trace = ScoreTrace::new();
+        trace.record(
+            ScoringPhase::PreScoring,
+            None,
+            &ScoreOp::Add(Score::new(5, 1)),
+            Score::new(5, 1),
+        );
+        trace.record(
+            ScoringPhase::Jokers,
+            Some(card::JOKER),
+            &ScoreOp::TimesMult(1.5),
+            Score::new(5, 2),
+        );
+
+        let big = trace.big_score();
+        assert_eq!(big.chips, Magnitude::from(5));
+        assert_eq!(big.mult, Magnitude::new(15, -1), "not rounded up to 2");
+        assert_eq!(ScoreTrace::new().big_score(), BigScore::default());
+    }
+}
@@ -38,11 +38,7 @@ pub enum Voucher {
     OverstockPlus,
     /// +1 consumable slot.
     CrystalBall,
-    /// +1 joker slot.
-    ///
-    /// Balatro gates Antimatter behind the Blank voucher (which does nothing);
-    /// Blank is out of scope here, so Antimatter is a base voucher with no
-    /// prerequisite. The elision is invisible — Blank had no effect to lose.
+    /// +1 joker slot. Requires [`Blank`](Self::Blank).
     Antimatter,
     /// Rerolls cost $2 less.
     RerollSurplus,
@@ -64,12 +60,34 @@ pub enum Voucher {
     PlanetMerchant,
     /// Planets appear four times as often in the shop.
     PlanetTycoon,
+    /// Foil, Holographic and Polychrome jokers appear twice as often.
+    Hone,
+    /// Foil, Holographic and Polychrome jokers appear four times as often.
+    GlowUp,
+    /// −1 ante, −1 hand every round.
+    Hieroglyph,
+    /// −1 ante, −1 discard every round.
+    Petroglyph,
+    /// Reroll the Boss Blind once per ante, $10 per roll.
+    DirectorsCut,
+    /// Reroll the Boss Blind as often as you like, $10 per roll.
+    Retcon,
+    /// Celestial packs always hold the planet for your most played hand.
+    Telescope,
+    /// Each planet held in a consumable slot gives ×1.5 mult to its own hand.
+    Observatory,
+    /// Playing cards can be bought from the shop.
+    MagicTrick,
+    /// Shop playing cards may carry an enhancement or an edition.
+    Illusion,
+    /// Does nothing — it exists to be Antimatter's prerequisite.
+    Blank,
 }
 
 impl Voucher {
     /// Every voucher this engine models, in tier order — the pool the shop draws
     /// its slot from.
-    pub const ALL: [Self; 20] = [
+    pub const ALL: [Self; 31] = [
         Self::Grabber,
         Self::NachoTong,
         Self::Wasteful,
@@ -90,6 +108,17 @@ impl Voucher {
         Self::TarotTycoon,
         Self::PlanetMerchant,
         Self::PlanetTycoon,
+        Self::Hone,
+        Self::GlowUp,
+        Self::Hieroglyph,
+        Self::Petroglyph,
+        Self::DirectorsCut,
+        Self::Retcon,
+        Self::Telescope,
+        Self::Observatory,
+        Self::MagicTrick,
+        Self::Illusion,
+        Self::Blank,
     ];
 
     /// The base voucher this one requires, or `None` if it is itself a base.
@@ -108,6 +137,12 @@ impl Voucher {
             Self::MoneyTree => Some(Self::SeedMoney),
             Self::TarotTycoon => Some(Self::TarotMerchant),
             Self::PlanetTycoon => Some(Self::PlanetMerchant),
+            Self::GlowUp => Some(Self::Hone),
+            Self::Petroglyph => Some(Self::Hieroglyph),
+            Self::Retcon => Some(Self::DirectorsCut),
+            Self::Observatory => Some(Self::Telescope),
+            Self::Illusion => Some(Self::MagicTrick),
+            Self::Antimatter => Some(Self::Blank),
             _ => None,
         }
     }
@@ -136,6 +171,17 @@ impl Display for Voucher {
             Self::TarotTycoon => "Tarot Tycoon",
             Self::PlanetMerchant => "Planet Merchant",
             Self::PlanetTycoon => "Planet Tycoon",
+            Self::Hone => "Hone",
+            Self::GlowUp => "Glow Up",
+            Self::Hieroglyph => "Hieroglyph",
+            Self::Petroglyph => "Petroglyph",
+            Self::DirectorsCut => "Director's Cut",
+            Self::Retcon => "Retcon",
+            Self::Telescope => "Telescope",
+            Self::Observatory => "Observatory",
+            Self::MagicTrick => "Magic Trick",
+            Self::Illusion => "Illusion",
+            Self::Blank => "Blank",
         };
         write!(f, "{name}")
     }
@@ -154,7 +200,7 @@ mod funky__types__voucher_tests {
         seen.sort_unstable();
         seen.dedup();
         assert_eq!(seen.len(), Voucher::ALL.len(), "no duplicates in ALL");
-        assert_eq!(Voucher::ALL.len(), 20);
+        assert_eq!(Voucher::ALL.len(), 31);
     }
 
     #[test]
@@ -168,7 +214,8 @@ mod funky__types__voucher_tests {
         // Bases have no prerequisite.
         assert_eq!(Voucher::Grabber.requires(), None);
         assert_eq!(Voucher::CrystalBall.requires(), None);
-        assert_eq!(Voucher::Antimatter.requires(), None);
+        assert_eq!(Voucher::Antimatter.requires(), Some(Voucher::Blank));
+        assert_eq!(Voucher::Blank.requires(), None);
     }
 
     #[test]
@@ -187,5 +234,7 @@ mod funky__types__voucher_tests {
         assert_eq!(Voucher::NachoTong.to_string(), "Nacho Tong");
         assert_eq!(Voucher::OverstockPlus.to_string(), "Overstock Plus");
         assert_eq!(Voucher::CrystalBall.to_string(), "Crystal Ball");
+        assert_eq!(Voucher::GlowUp.to_string(), "Glow Up");
+        assert_eq!(Voucher::DirectorsCut.to_string(), "Director's Cut");
     }
 }
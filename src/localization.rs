@@ -11,6 +11,9 @@ static_loader! {
         fallback_language: "en-US",
         // A fluent resource that is shared with every locale.
         core_locales: "./src/localization/locales/core.ftl",
+        // No Unicode isolation marks around placeables: the funky effect
+        // descriptions interpolate numbers and names into plain strings.
+        customise: |bundle| bundle.set_use_isolating(false),
     };
 }
 
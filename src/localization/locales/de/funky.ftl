@@ -0,0 +1,493 @@
+# de/funky.ftl — German (deutsch) translation
+#
+# Status: DRAFT — MEDIUM CONFIDENCE. Names are literal translations; Balatro
+# has no official German card names to follow. The scoring terms Chips and
+# Mult stay as they are, as do the poker hands German players use in English
+# (Flush, Full House, Straight Flush, …).
+
+## Spielkarten
+
+funky-playing-card = { $card-rank } { $card-suit }
+funky-stone-card = Steinkarte
+funky-card-custom = { $symbol }
+
+## Hände und Kartentypen
+
+funky-hand-high-card = Höchste Karte
+funky-hand-pair = Paar
+funky-hand-two-pair = Zwei Paare
+funky-hand-three-of-a-kind = Drilling
+funky-hand-straight = Straße
+funky-hand-flush = Flush
+funky-hand-full-house = Full House
+funky-hand-four-of-a-kind = Vierling
+funky-hand-straight-flush = Straight Flush
+funky-hand-royal-flush = Royal Flush
+funky-hand-five-of-a-kind = Fünfling
+funky-hand-flush-house = Flush House
+funky-hand-flush-five = Flush Five
+
+funky-card-type-basic = Spielkarte
+funky-card-type-stone = Steinkarte
+funky-card-type-common-joker = Gewöhnlicher Joker
+funky-card-type-uncommon-joker = Ungewöhnlicher Joker
+funky-card-type-rare-joker = Seltener Joker
+funky-card-type-legendary-joker = Legendärer Joker
+funky-card-type-planet = Planetenkarte
+funky-card-type-spectral = Spektralkarte
+funky-card-type-tarot = Tarotkarte
+funky-card-type-voucher = Gutschein
+
+## Effekte
+
+funky-effect-blank = { "" }
+funky-effect-add-base-chips = +{ $chips } Basis-Chips
+funky-effect-add-card-type-when-blind-selected = Fügt dem Deck eine Karte vom Typ { $card-type } hinzu, wenn ein Blind gewählt wird
+funky-effect-chance-destroyed = { $numerator } zu { $denominator } Chance, dass diese Karte am Rundenende zerstört wird
+funky-effect-chips = +{ $chips } Chips
+funky-effect-chips-mult-plus = +{ $chips } Chips und +{ $mult } Mult
+funky-effect-chips-mult-plus-on-hand = Verbessert { $hand }: +{ $mult } Mult und +{ $chips } Chips
+funky-effect-chips-on-hand = +{ $chips } Chips, wenn die gespielte Hand ein { $hand } enthält
+funky-effect-chips-per-remaining-discard = +{ $chips } Chips für jeden verbleibenden Abwurf
+funky-effect-chips-plus-on-ranks = Gespielte Karten mit Rang { $ranks } geben beim Werten +{ $chips } Chips
+funky-effect-create-card-on-rank-play = 1 zu { $odds } Chance, dass jede gespielte { $rank } beim Werten eine Karte vom Typ { $card-type } erzeugt
+funky-effect-credit = Bis zu -{ $money } $ Schulden machen
+funky-effect-death = Verwandelt { $count ->
+        [one] die linke gewählte Karte
+       *[other] { $count } gewählte Karten
+    } in die rechte gewählte Karte
+funky-effect-double-money = Verdoppelt das Geld, bis zu { $money } $
+funky-effect-four-flush-and-straight = Alle Flushes und Straßen können mit 4 Karten gebildet werden
+funky-effect-gapped-straight = Straßen dürfen Lücken von 1 Rang haben
+funky-effect-all-cards-are-faces = Alle Karten gelten als Bildkarten
+funky-effect-smeared-suits = Herzen und Diamanten gelten als dieselbe Farbe, ebenso Spaten und Klee
+funky-effect-all-played-cards-score = Jede gespielte Karte wird gewertet
+funky-effect-double-odds = Verdoppelt alle angegebenen Wahrscheinlichkeiten
+funky-effect-free-reroll = { $count ->
+        [one] 1 kostenloser Reroll
+       *[other] { $count } kostenlose Rerolls
+    } pro Shop
+funky-effect-glass = ×{ $mult } Mult, 1 zu { $odds } Chance, die Karte zu zerstören
+funky-effect-gold = { $money } $, wenn diese Karte am Rundenende auf der Hand ist
+funky-effect-hanged = Zerstört bis zu { $count } gewählte Karten
+funky-effect-jokers-value = Gibt den Verkaufswert aller aktuellen Joker, bis zu { $money } $
+funky-effect-lucky = 1 zu { $mult-odds } Chance auf +20 Mult, 1 zu { $money-odds } Chance auf 20 $
+funky-effect-mult-plus = +{ $mult } Mult
+funky-effect-mult-plus-chance-destroyed = +{ $mult } Mult, { $numerator } zu { $denominator } Chance, dass diese Karte am Rundenende zerstört wird
+funky-effect-mult-times-chance-destroyed = ×{ $mult } Mult, { $numerator } zu { $denominator } Chance, dass diese Karte am Rundenende zerstört wird
+funky-effect-mult-plus-chips-on-rank = Jede gespielte { $rank } gibt beim Werten +{ $chips } Chips und +{ $mult } Mult
+funky-effect-mult-plus-double-value-destroy-joker-on-right = Zerstört beim Wählen eines Blinds den Joker rechts und addiert dauerhaft seinen doppelten Verkaufswert zu diesem Mult, beginnend bei +{ $mult }
+funky-effect-mult-plus-on-ranks = Gespielte Karten mit Rang { $ranks } geben beim Werten +{ $mult } Mult
+funky-effect-mult-plus-on-consecutive-hands-without-ranks = Beginnt bei +{ $mult } Mult und erhält +{ $increment } Mult für jede Hand in Folge ohne gewertete { $ranks }
+funky-effect-mult-plus-on-hand = +{ $mult } Mult, wenn die gespielte Hand ein { $hand } enthält
+funky-effect-mult-plus-on-hand-plays = Addiert zum Mult, wie oft die gespielte Pokerhand in diesem Lauf gespielt wurde
+funky-effect-mult-plus-on-suit = Gespielte Karten der Farbe { $suit } geben beim Werten +{ $mult } Mult
+funky-effect-mult-plus-on-up-to-x-cards = +{ $mult } Mult, wenn die gespielte Hand höchstens { $count } Karten enthält
+funky-effect-mult-plus-on-zero-discards = +{ $mult } Mult, wenn keine Abwürfe mehr übrig sind
+funky-effect-mult-plus-x-on-lowest-rank-in-hand = Addiert das { $factor }-fache des Rangs der niedrigsten Handkarte zum Mult
+funky-effect-mult-plus-random-to = +0 bis +{ $mult } Mult
+funky-effect-mult-times = ×{ $mult } Mult
+funky-effect-mult-times-every-x-hands = ×{ $mult } Mult alle { $hands } gespielten Hände
+funky-effect-mult-times-on-empty-joker-slots = ×{ $mult } Mult für jeden leeren Joker-Platz
+funky-effect-mult-times-held = ×{ $mult } Mult, solange diese Karte auf der Hand bleibt
+funky-effect-mult-times-on-hand = ×{ $mult } Mult, wenn die gespielte Hand ein { $hand } enthält
+funky-effect-mult-times-per-scored-rank = Jede gespielte { $first } oder { $second } gibt beim Werten ×{ $mult } Mult
+funky-effect-mult-plus-per-joker = +{ $mult } Mult für jede Jokerkarte
+funky-effect-chips-per-deck-card = +{ $chips } Chips für jede verbleibende Karte im Deck
+funky-effect-mult-times-plus-per-full-deck-steel = Erhält ×{ $mult } Mult für jede Stahlkarte im vollen Deck
+funky-effect-chips-per-full-deck-stone = +{ $chips } Chips für jede Steinkarte im vollen Deck
+funky-effect-mult-plus-per-missing-deck-card = +{ $mult } Mult für jede Karte unter der Startgröße des Decks
+funky-effect-mult-times-per-held-rank = Jede { $rank } auf der Hand gibt ×{ $mult } Mult
+funky-effect-chips-plus-per-scored-face = Gespielte Bildkarten geben beim Werten +{ $chips } Chips
+funky-effect-chips-mult-plus-per-scored-ranks = Jede gespielte { $first } oder { $second } gibt beim Werten +{ $chips } Chips und +{ $mult } Mult
+funky-effect-mult-times-per-uncommon-joker = Ungewöhnliche Joker geben je ×{ $mult } Mult
+funky-effect-mult-times-if-held-all-suits = ×{ $mult } Mult, wenn alle Handkarten { $first } oder { $second } sind
+funky-effect-chips-per-dollar = +{ $chips } Chips für jeden Dollar, den du hast
+funky-effect-cash-on-round-end = { $money } $ am Rundenende
+funky-effect-cash-per-discard-if-none-used = { $money } $ pro Abwurf, wenn bis zum Rundenende kein Abwurf genutzt wird
+funky-effect-cash-per-full-deck-rank = { $money } $ am Rundenende für jede { $rank } im vollen Deck
+funky-effect-extra-interest = Zusätzlich { $money } $ Zinsen für je 5 $ am Rundenende
+funky-effect-cash-on-faces-discarded = { $money } $, wenn { $count } oder mehr Bildkarten gleichzeitig abgeworfen werden
+funky-effect-hand-size-increment = +{ $count } Handgröße
+funky-effect-discard-increment = +{ $count ->
+        [one] 1 Abwurf
+       *[other] { $count } Abwürfe
+    } pro Runde
+funky-effect-gain-hands-lose-discards-when-blind-selected = Beim Wählen eines Blinds +{ $count } Hände, alle Abwürfe gehen verloren
+funky-effect-gain-mult-per-hand-less-discard = +{ $mult } Mult pro gespielter Hand, -{ $mult } Mult pro Abwurf
+funky-effect-lose-mult-times-per-discard = ×{ $mult } Mult, verliert ×{ $loss } Mult pro abgeworfener Karte
+funky-effect-lose-chips-per-hand = +{ $chips } Chips, -{ $loss } Chips pro gespielter Hand
+funky-effect-gain-chips-per-card-count-hand = Erhält +{ $chips } Chips, wenn die gespielte Hand genau { $count } Karten hat
+funky-effect-gain-mult-per-hand = Erhält +{ $mult } Mult, wenn die gespielte Hand ein { $hand } enthält
+funky-effect-gain-chips-per-hand = Erhält +{ $chips } Chips, wenn die gespielte Hand ein { $hand } enthält
+funky-effect-gain-chips-on-scored = Jede gespielte Karte erhält beim Werten dauerhaft +{ $chips } Chips
+funky-effect-lose-mult-per-round = +{ $mult } Mult, -{ $loss } Mult pro gespielter Runde
+funky-effect-gain-mult-times-per-discarded-cards = Erhält ×{ $mult } Mult alle { $count } abgeworfenen Karten
+funky-effect-gain-mult-times-per-card-added = Erhält ×{ $mult } Mult, wenn dem Deck eine Spielkarte hinzugefügt wird
+funky-effect-gain-mult-times-per-face-destroyed = Erhält ×{ $mult } Mult, wenn eine Bildkarte zerstört wird
+funky-effect-create-jokers-when-blind-selected = Erzeugt beim Wählen eines Blinds { $count } Karten vom Typ { $card-type } (Platz nötig)
+funky-effect-create-tarot-on-ace-straight = Erzeugt eine Tarotkarte, wenn die gespielte Hand ein Ass und eine Straße enthält (Platz nötig)
+funky-effect-create-tarot-on-low-money = Erzeugt eine Tarotkarte, wenn eine Hand mit höchstens { $money } $ gespielt wird (Platz nötig)
+funky-effect-create-spectral-on-straight-flush = Erzeugt eine zufällige Spektralkarte, wenn die gespielte Hand einen Straight Flush enthält (Platz nötig)
+funky-effect-create-spectral-on-first-single-six = Ist die erste Hand der Runde eine einzelne 6, wird sie zerstört und eine Spektralkarte erzeugt (Platz nötig)
+funky-effect-spectral-level-all-hands = Verbessert jede Pokerhand um 1 Stufe
+funky-effect-spectral-create-legendary-joker = Erzeugt einen legendären Joker (Platz nötig)
+funky-effect-spectral-create-rare-joker-zero-money = Erzeugt einen zufälligen seltenen Joker (Platz nötig) und setzt das Geld auf 0 $
+funky-effect-spectral-negative-random-joker-minus-hand-size = Macht einen zufälligen Joker negativ, -1 Handgröße
+funky-effect-spectral-polychrome-random-joker-destroy-others = Macht einen zufälligen Joker polychrom und zerstört alle anderen Joker
+funky-effect-spectral-copy-random-joker-destroy-others = Kopiert einen zufälligen Joker und zerstört alle anderen Joker
+funky-effect-spectral-edition-random-hand-card = Gibt 1 gewählten Handkarte Folie, Hologramm oder Polychrom
+funky-effect-spectral-hand-to-random-suit = Verwandelt alle Handkarten in eine einzige zufällige Farbe
+funky-effect-spectral-hand-to-random-rank-minus-hand-size = Verwandelt alle Handkarten in einen einzigen zufälligen Rang, -1 Handgröße
+funky-effect-spectral-destroy-random-hand-gain-money = Zerstört { $count } zufällige Handkarten, erhalte { $money } $
+funky-effect-spectral-destroy-one-add-enhanced-faces = Zerstört 1 zufällige Handkarte und fügt der Hand { $count } zufällige verbesserte Bildkarten hinzu
+funky-effect-spectral-destroy-one-add-enhanced-aces = Zerstört 1 zufällige Handkarte und fügt der Hand { $count } zufällige verbesserte Asse hinzu
+funky-effect-spectral-destroy-one-add-enhanced-numbered = Zerstört 1 zufällige Handkarte und fügt der Hand { $count } zufällige verbesserte Zahlenkarten hinzu
+funky-effect-spectral-copy-selected-hand-card = Erzeugt { $count } Kopien von 1 gewählten Handkarte
+funky-effect-mult-times-on-repeated-hand-this-round = ×{ $mult } Mult, wenn die gespielte Pokerhand in dieser Runde schon gespielt wurde
+funky-effect-mult-times-per-scored-ancient-suit = Jede gespielte Karte der Rundenfarbe gibt beim Werten ×{ $mult } Mult; die Farbe wechselt am Rundenende
+funky-effect-gain-mult-times-on-non-boss-blind-destroying-joker = Erhält beim Wählen eines Small oder Big Blinds ×{ $mult } Mult und zerstört einen zufälligen Joker
+funky-effect-disable-boss-blind-on-sell = Verkaufe diese Karte, um den aktuellen Boss-Blind aufzuheben
+funky-effect-disables-all-boss-blinds = Hebt den Effekt jedes Boss-Blinds auf
+funky-effect-cash-on-round-end-growing-on-boss-defeat = { $money } $ am Rundenende; die Auszahlung steigt um { $increase } $, wenn ein Boss-Blind besiegt wird
+funky-effect-gain-mult-times-per-planet-used = Erhält ×{ $mult } Mult, wenn eine Planetenkarte benutzt wird
+funky-effect-mult-plus-per-tarot-used-this-run = +{ $mult } Mult pro in diesem Lauf benutzter Tarotkarte
+funky-effect-mult-plus-per-reroll = Erhält +{ $mult } Mult pro Reroll im Shop
+funky-effect-mult-plus-per-pack-skipped = Erhält +{ $mult } Mult, wenn ein Booster-Pack übersprungen wird
+funky-effect-create-tarot-on-pack-open = { $numerator } zu { $denominator } Chance, beim Öffnen eines Booster-Packs eine Tarotkarte zu erzeugen (Platz nötig)
+funky-effect-create-negative-consumable-copy = Erzeugt am Rundenende eine negative Kopie eines zufälligen Verbrauchsguts
+funky-effect-gain-mult-times-per-enhanced-played = Erhält ×{ $mult } Mult pro gewerteter verbesserter Karte und entfernt deren Verbesserung
+funky-effect-gain-mult-times-per-lucky-proc = Erhält ×{ $mult } Mult, wenn eine Glückskarte erfolgreich auslöst
+funky-effect-copy-joker-to-right = Kopiert die Fähigkeit des Jokers rechts
+funky-effect-copy-leftmost-joker = Kopiert die Fähigkeit des Jokers ganz links
+funky-effect-planet = Erzeugt bis zu { $count } zufällige Planetenkarten (Platz nötig)
+funky-effect-random-joker = Erzeugt { $count ->
+        [one] eine zufällige Jokerkarte
+       *[other] { $count } zufällige Jokerkarten
+    } (Platz nötig)
+funky-effect-random-tarot = Erzeugt bis zu { $count } zufällige Tarotkarten (Platz nötig)
+funky-effect-retrigger-cards-in-hand = Löst die Fähigkeiten aller Handkarten { $count ->
+        [one] erneut
+       *[other] { $count }-mal erneut
+    } aus
+funky-effect-retrigger-played-cards-in-final-round = Löst alle gespielten Karten der letzten Hand der Runde erneut aus
+funky-effect-retrigger-played-ranks = Löst jede gespielte { $ranks } { $count ->
+        [one] erneut
+       *[other] { $count }-mal erneut
+    } aus
+funky-effect-retrigger-played-faces = Löst alle gespielten Bildkarten { $count ->
+        [one] erneut
+       *[other] { $count }-mal erneut
+    } aus
+funky-effect-retrigger-first-played = Löst die erste gespielte Karte { $count ->
+        [one] erneut
+       *[other] { $count }-mal erneut
+    } aus
+funky-effect-retrigger-all-played-for-hands = Löst alle gespielten Karten der nächsten { $hands } Hände erneut aus
+funky-effect-sell-value-increment = Erhält am Rundenende { $money } $ Verkaufswert
+funky-effect-stone = +{ $chips } Chips, kein Rang und keine Farbe
+funky-effect-strength = Erhöht den Rang von bis zu 2 gewählten Karten um 1
+funky-effect-odds-1-in = 1 zu { $odds } Chance, einem zufälligen Joker Folie, Hologramm oder Polychrom zu geben
+funky-effect-odds-1-in-cash-on-ranks = Jede gespielte { $ranks } hat beim Werten eine 1 zu { $odds } Chance auf { $money } $
+funky-effect-odds-1-in-upgrade-hand = 1 zu { $odds } Chance, die gespielte Pokerhand zu verbessern
+funky-effect-wild = { $wild ->
+        [rank] Kann als beliebiger Rang benutzt werden
+       *[suit] Kann als beliebige Farbe benutzt werden
+    }
+funky-effect-to-suit = Verwandelt bis zu { $count } gewählte Karten in { $suit }
+funky-effect-custom = Mod-Effekt `{ $effect }`
+
+## Joker
+
+funky-joker-joker = Joker
+funky-joker-greedy-joker = Gieriger Joker
+funky-joker-lusty-joker = Lüsterner Joker
+funky-joker-wrathful-joker = Zorniger Joker
+funky-joker-gluttonous-joker = Gefräßiger Joker
+funky-joker-jolly-joker = Fröhlicher Joker
+funky-joker-zany-joker = Alberner Joker
+funky-joker-mad-joker = Verrückter Joker
+funky-joker-crazy-joker = Irrer Joker
+funky-joker-droll-joker = Drolliger Joker
+funky-joker-sly-joker = Schlauer Joker
+funky-joker-wily-joker = Listiger Joker
+funky-joker-clever-joker = Kluger Joker
+funky-joker-devious-joker = Hinterhältiger Joker
+funky-joker-crafty-joker = Gerissener Joker
+funky-joker-half-joker = Halber Joker
+funky-joker-joker-stencil = Joker-Schablone
+funky-joker-four-fingers = Vier Finger
+funky-joker-mime = Pantomime
+funky-joker-credit-card = Kreditkarte
+funky-joker-ceremonial-dagger = Zeremoniendolch
+funky-joker-banner = Banner
+funky-joker-mystic-summit = Mystischer Gipfel
+funky-joker-marble-joker = Marmorjoker
+funky-joker-loyalty-card = Treuekarte
+funky-joker-eight-ball = 8er-Kugel
+funky-joker-misprint = Fehldruck
+funky-joker-dusk = Dämmerung
+funky-joker-raised-fist = Erhobene Faust
+funky-joker-chaos-the-clown = Chaos der Clown
+funky-joker-fibonacci = Fibonacci
+funky-joker-steel-joker = Stahljoker
+funky-joker-scary-face = Gruselgesicht
+funky-joker-abstract-joker = Abstrakter Joker
+funky-joker-delayed-gratification = Aufgeschobene Belohnung
+funky-joker-hack = Hack
+funky-joker-pareidolia = Pareidolie
+funky-joker-gros-michel = Gros Michel
+funky-joker-even-steven = Gerader Gerd
+funky-joker-odd-todd = Ungerader Udo
+funky-joker-scholar = Gelehrter
+funky-joker-business-card = Visitenkarte
+funky-joker-supernova = Supernova
+funky-joker-ride-the-bus = Busfahrt
+funky-joker-space-joker = Weltraumjoker
+funky-joker-egg = Ei
+funky-joker-burglar = Einbrecher
+funky-joker-blackboard = Tafel
+funky-joker-runner = Läufer
+funky-joker-ice-cream = Eiscreme
+funky-joker-dna = DNS
+    .description = Ist die erste Hand der Runde eine einzelne Karte, wird eine dauerhafte Kopie davon dem Deck hinzugefügt und auf die Hand gezogen
+funky-joker-splash = Platsch
+funky-joker-blue-joker = Blauer Joker
+funky-joker-sixth-sense = Sechster Sinn
+funky-joker-constellation = Sternbild
+funky-joker-hiker = Wanderer
+funky-joker-faceless-joker = Gesichtsloser Joker
+funky-joker-green-joker = Grüner Joker
+funky-joker-superposition = Superposition
+funky-joker-to-do-list = To-do-Liste
+    .description = 4 $, wenn die gespielte Pokerhand die Zielhand der Runde ist; das Ziel wechselt am Rundenende
+funky-joker-cavendish = Cavendish
+funky-joker-card-sharp = Falschspieler
+funky-joker-red-card = Rote Karte
+funky-joker-madness = Wahnsinn
+funky-joker-square-joker = Quadratischer Joker
+funky-joker-seance = Séance
+funky-joker-riff-raff = Gesindel
+funky-joker-vampire = Vampir
+funky-joker-shortcut = Abkürzung
+funky-joker-hologram = Hologramm
+funky-joker-vagabond = Vagabund
+funky-joker-baron = Baron
+funky-joker-cloud-9 = Wolke 9
+funky-joker-rocket = Rakete
+funky-joker-erosion = Erosion
+funky-joker-reserved-parking = Reservierter Parkplatz
+    .description = Jede Bildkarte auf der Hand hat eine 1 zu 2 Chance auf 1 $
+funky-joker-mail-in-rebate = Rabattgutschein
+    .description = 5 $ für jede abgeworfene Karte des Zielrangs der Runde; der Rang wechselt jede Runde
+funky-joker-to-the-moon = Zum Mond
+funky-joker-hallucination = Halluzination
+funky-joker-fortune-teller = Wahrsagerin
+funky-joker-juggler = Jongleur
+funky-joker-drunkard = Trunkenbold
+funky-joker-stone-joker = Steinjoker
+funky-joker-golden-joker = Goldener Joker
+funky-joker-lucky-cat = Glückskatze
+funky-joker-baseball-card = Baseballkarte
+funky-joker-bull = Bulle
+funky-joker-diet-cola = Diät-Cola
+    .description = Verkaufe diese Karte, um ein kostenloses Doppel-Tag zu erzeugen
+funky-joker-trading-card = Sammelkarte
+    .description = Ist der erste Abwurf der Runde eine einzelne Karte, wird sie zerstört und bringt 3 $
+funky-joker-flash-card = Karteikarte
+funky-joker-popcorn = Popcorn
+funky-joker-spare-trousers = Ersatzhose
+funky-joker-ancient-joker = Uralter Joker
+funky-joker-ramen = Ramen
+funky-joker-walkie-talkie = Walkie-Talkie
+funky-joker-sock-and-buskin = Soccus und Kothurn
+funky-joker-luchador = Luchador
+funky-joker-matador = Matador
+    .description = 8 $, wenn die gespielte Hand die Fähigkeit des Boss-Blinds auslöst
+funky-joker-seltzer = Selters
+funky-joker-smeared-joker = Verschmierter Joker
+funky-joker-oops-all-6s = Hoppla! Nur 6en
+funky-joker-hanging-chad = Hängendes Stanzloch
+funky-joker-the-duo = Das Duo
+funky-joker-the-trio = Das Trio
+funky-joker-the-family = Die Familie
+funky-joker-the-order = Der Orden
+funky-joker-the-tribe = Der Stamm
+funky-joker-blueprint = Blaupause
+funky-joker-brainstorm = Geistesblitz
+funky-joker-canio = Canio
+funky-joker-triboulet = Triboulet
+funky-joker-yorick = Yorick
+funky-joker-chicot = Chicot
+funky-joker-perkeo = Perkeo
+funky-joker-custom = Joker { $symbol }
+
+## Tarot
+
+funky-tarot-fool = { name-rank-tarot-special-0 }
+    .description = Erzeugt die zuletzt in diesem Lauf benutzte Tarot- oder Planetenkarte, außer dem Narren
+funky-tarot-magician = { name-rank-tarot-special-1 }
+    .description = Verwandelt bis zu 2 gewählte Karten in Glückskarten: { funky-effect-lucky }
+funky-tarot-high-priestess = { name-rank-tarot-special-2 }
+funky-tarot-empress = { name-rank-tarot-special-3 }
+    .description = Verwandelt bis zu 2 gewählte Karten in Mult-Karten: { funky-effect-mult-plus }
+funky-tarot-emperor = { name-rank-tarot-special-4 }
+funky-tarot-hierophant = { name-rank-tarot-special-5 }
+    .description = Verwandelt bis zu 2 gewählte Karten in Bonuskarten: { funky-effect-chips }
+funky-tarot-lovers = { name-rank-tarot-special-6 }
+    .description = Verwandelt 1 gewählte Karte in eine Wildkarte: { funky-effect-wild }
+funky-tarot-chariot = { name-rank-tarot-special-7 }
+    .description = Verwandelt 1 gewählte Karte in eine Stahlkarte: { funky-effect-mult-times-held }
+funky-tarot-strength = { name-rank-tarot-special-8 }
+funky-tarot-hermit = { name-rank-tarot-special-9 }
+funky-tarot-wheel-of-fortune = { name-rank-tarot-special-a }
+funky-tarot-justice = { name-rank-tarot-special-b }
+    .description = Verwandelt 1 gewählte Karte in eine Glaskarte: { funky-effect-glass }
+funky-tarot-hanged-man = { name-rank-tarot-special-c }
+funky-tarot-death = { name-rank-tarot-special-d }
+funky-tarot-temperance = { name-rank-tarot-special-e }
+funky-tarot-devil = { name-rank-tarot-special-f }
+    .description = Verwandelt 1 gewählte Karte in eine Goldkarte: { funky-effect-gold }
+funky-tarot-tower = { name-rank-tarot-special-g }
+    .description = Verwandelt 1 gewählte Karte in eine Steinkarte: { funky-effect-stone }
+funky-tarot-star = { name-rank-tarot-special-h }
+funky-tarot-moon = { name-rank-tarot-special-i }
+funky-tarot-sun = { name-rank-tarot-special-j }
+funky-tarot-judgement = { name-rank-tarot-special-k }
+funky-tarot-world = { name-rank-tarot-special-l }
+
+## Planeten
+
+funky-planet-pluto = Pluto
+funky-planet-mercury = Merkur
+funky-planet-uranus = Uranus
+funky-planet-venus = Venus
+funky-planet-saturn = Saturn
+funky-planet-jupiter = Jupiter
+funky-planet-earth = Erde
+funky-planet-mars = Mars
+funky-planet-neptune = Neptun
+funky-planet-planet-x = Planet X
+funky-planet-ceres = Ceres
+funky-planet-eris = Eris
+
+## Spektralkarten
+
+funky-spectral-familiar = Vertrauter
+funky-spectral-grim = Grimm
+funky-spectral-incantation = Beschwörung
+funky-spectral-talisman = Talisman
+    .description = Gibt 1 gewählten Handkarte ein Goldsiegel
+funky-spectral-aura = Aura
+funky-spectral-wraith = Gespenst
+funky-spectral-sigil = Siegel
+funky-spectral-ouija = Ouija
+funky-spectral-ectoplasm = Ektoplasma
+funky-spectral-immolate = Opferfeuer
+funky-spectral-ankh = Anch
+funky-spectral-deja-vu = Déjà-vu
+    .description = Gibt 1 gewählten Handkarte ein rotes Siegel
+funky-spectral-hex = Fluch
+funky-spectral-trance = Trance
+    .description = Gibt 1 gewählten Handkarte ein blaues Siegel
+funky-spectral-medium = Medium
+    .description = Gibt 1 gewählten Handkarte ein violettes Siegel
+funky-spectral-cryptid = Kryptid
+funky-spectral-the-soul = Die Seele
+funky-spectral-black-hole = Schwarzes Loch
+
+## Gutscheine
+
+funky-voucher-grabber = Greifer
+    .description = +1 Hand pro Runde
+funky-voucher-nacho-tong = Nachozange
+    .description = +1 Hand pro Runde
+funky-voucher-wasteful = Verschwenderisch
+    .description = +1 Abwurf pro Runde
+funky-voucher-recyclomancy = Recyclomantie
+    .description = +1 Abwurf pro Runde
+funky-voucher-paint-brush = Pinsel
+    .description = +1 Handgröße
+funky-voucher-palette = Palette
+    .description = +1 Handgröße
+funky-voucher-overstock = Überbestand
+    .description = +1 Kartenplatz im Shop
+funky-voucher-overstock-plus = Überbestand Plus
+    .description = +1 Kartenplatz im Shop
+funky-voucher-crystal-ball = Kristallkugel
+    .description = +1 Platz für Verbrauchsgüter
+funky-voucher-antimatter = Antimaterie
+    .description = +1 Joker-Platz
+funky-voucher-reroll-surplus = Reroll-Überschuss
+    .description = Rerolls kosten 2 $ weniger
+funky-voucher-reroll-glut = Reroll-Schwemme
+    .description = Rerolls kosten noch einmal 2 $ weniger
+funky-voucher-clearance-sale = Räumungsverkauf
+    .description = Alle Karten und Packs im Shop sind 25 % günstiger
+funky-voucher-liquidation = Liquidation
+    .description = Alle Karten und Packs im Shop sind 50 % günstiger
+funky-voucher-seed-money = Startkapital
+    .description = Hebt die Obergrenze der Zinsen pro Runde auf 10 $
+funky-voucher-money-tree = Geldbaum
+    .description = Hebt die Obergrenze der Zinsen pro Runde auf 20 $
+funky-voucher-tarot-merchant = Tarothändler
+    .description = Tarotkarten erscheinen 2× so oft im Shop
+funky-voucher-tarot-tycoon = Tarotmagnat
+    .description = Tarotkarten erscheinen 4× so oft im Shop
+funky-voucher-planet-merchant = Planetenhändler
+    .description = Planetenkarten erscheinen 2× so oft im Shop
+funky-voucher-planet-tycoon = Planetenmagnat
+    .description = Planetenkarten erscheinen 4× so oft im Shop
+funky-voucher-hone = Schliff
+    .description = Folien-, Hologramm- und Polychromkarten erscheinen 2× so oft
+funky-voucher-glow-up = Aufpolieren
+    .description = Folien-, Hologramm- und Polychromkarten erscheinen 4× so oft
+funky-voucher-hieroglyph = Hieroglyphe
+    .description = -1 Ante, -1 Hand pro Runde
+funky-voucher-petroglyph = Petroglyphe
+    .description = -1 Ante, -1 Abwurf pro Runde
+funky-voucher-directors-cut = Director's Cut
+    .description = Den Boss-Blind einmal pro Ante neu würfeln, 10 $ pro Wurf
+funky-voucher-retcon = Retcon
+    .description = Den Boss-Blind beliebig oft neu würfeln, 10 $ pro Wurf
+funky-voucher-telescope = Teleskop
+    .description = Himmelspacks enthalten immer die Planetenkarte deiner meistgespielten Pokerhand
+funky-voucher-observatory = Observatorium
+    .description = Planetenkarten im Verbrauchsbereich geben ×1.5 Mult für ihre Pokerhand
+funky-voucher-magic-trick = Zaubertrick
+    .description = Spielkarten können im Shop gekauft werden
+funky-voucher-illusion = Illusion
+    .description = Spielkarten im Shop können eine Verbesserung oder Edition haben
+funky-voucher-blank = Leer
+    .description = Tut nichts?
+
+## Boss-Blinds
+
+funky-boss-the-needle = Die Nadel
+    .description = Nur 1 Hand spielen
+funky-boss-the-water = Das Wasser
+    .description = Ohne Abwürfe beginnen
+funky-boss-the-manacle = Die Handschelle
+    .description = -1 Handgröße
+
+## Fehler bei Aktionen, nach `ActionError::key`
+
+funky-action-shop-closed = Der Shop ist geschlossen.
+funky-action-no-such-card = An Position { $position } liegt keine Karte.
+funky-action-no-such-joker = An Position { $position } liegt kein Joker.
+funky-action-no-such-consumable = An Position { $position } liegt kein Verbrauchsgut.
+funky-action-no-such-target = An Position { $position } im Deck liegt keine Karte für die Tarotkarte.
+funky-action-no-such-stock = In Fach { $position } wird nichts verkauft.
+funky-action-no-such-pack = In Fach { $position } liegt kein Booster-Pack.
+funky-action-no-voucher-offered = Der Shop bietet keinen Gutschein an.
+funky-action-voucher-redeemed = { $voucher } ist bereits eingelöst.
+funky-action-voucher-requires = { $voucher } braucht zuerst { $base }.
+funky-action-not-enough-money = Nicht genug Geld: Es kostet { $cost } $, und es sind { $money } $ da.
+funky-action-no-joker-room = Es ist kein Platz für einen weiteren Joker.
+funky-action-no-consumable-room = Es ist kein Platz für ein weiteres Verbrauchsgut.
+funky-action-no-hands-left = In dieser Runde sind keine Hände mehr übrig.
+funky-action-no-discards-left = In dieser Runde sind keine Abwürfe mehr übrig.
+funky-action-eternal = Der Joker an Position { $position } ist Ewig.
+funky-action-pinned = Der Joker an Position { $position } ist angepinnt.
+funky-action-not-a-boss = Der Blind ist kein Boss-Blind.
+funky-action-no-boss-reroll = Den Boss neu zu würfeln braucht Retcon, oder einmal pro Ante Director's Cut.
@@ -0,0 +1,496 @@
+# Names and effect descriptions for the funky (Balatro) cards, vouchers and
+# boss blinds. See `cardpack::funky::types::localized`.
+#
+# A message's value is the name; its `.description`, where it has one, the
+# description. Anything else is described by its effect, one
+# `funky-effect-*` message per `MPip`, with variables from the payload.
+
+## Playing cards
+
+funky-playing-card = { $card-rank } of { $card-suit }
+funky-stone-card = Stone Card
+funky-card-custom = { $symbol }
+
+## Hands and card types, as the effects name them
+
+funky-hand-high-card = High Card
+funky-hand-pair = Pair
+funky-hand-two-pair = Two Pair
+funky-hand-three-of-a-kind = Three of a Kind
+funky-hand-straight = Straight
+funky-hand-flush = Flush
+funky-hand-full-house = Full House
+funky-hand-four-of-a-kind = Four of a Kind
+funky-hand-straight-flush = Straight Flush
+funky-hand-royal-flush = Royal Flush
+funky-hand-five-of-a-kind = Five of a Kind
+funky-hand-flush-house = Flush House
+funky-hand-flush-five = Flush Five
+
+funky-card-type-basic = Playing
+funky-card-type-stone = Stone
+funky-card-type-common-joker = Common Joker
+funky-card-type-uncommon-joker = Uncommon Joker
+funky-card-type-rare-joker = Rare Joker
+funky-card-type-legendary-joker = Legendary Joker
+funky-card-type-planet = Planet
+funky-card-type-spectral = Spectral
+funky-card-type-tarot = Tarot
+funky-card-type-voucher = Voucher
+
+## Effects
+
+funky-effect-blank = { "" }
+funky-effect-add-base-chips = +{ $chips } base Chips
+funky-effect-add-card-type-when-blind-selected = Adds a { $card-type } card to the deck when a Blind is selected
+funky-effect-chance-destroyed = { $numerator } in { $denominator } chance this card is destroyed at end of round
+funky-effect-chips = +{ $chips } Chips
+funky-effect-chips-mult-plus = +{ $chips } Chips and +{ $mult } Mult
+funky-effect-chips-mult-plus-on-hand = Levels up { $hand }: +{ $mult } Mult and +{ $chips } Chips
+funky-effect-chips-on-hand = +{ $chips } Chips if played hand contains a { $hand }
+funky-effect-chips-per-remaining-discard = +{ $chips } Chips for each remaining discard
+funky-effect-chips-plus-on-ranks = Played cards ranked { $ranks } give +{ $chips } Chips when scored
+funky-effect-create-card-on-rank-play = 1 in { $odds } chance for each played { $rank } to create a { $card-type } card when scored
+funky-effect-credit = Go up to -${ $money } in debt
+funky-effect-death = Converts { $count ->
+        [one] the left selected card
+       *[other] { $count } selected cards
+    } into the right selected card
+funky-effect-double-money = Doubles money, up to ${ $money }
+funky-effect-four-flush-and-straight = All Flushes and Straights can be made with 4 cards
+funky-effect-gapped-straight = Straights can be made with gaps of 1 rank
+funky-effect-all-cards-are-faces = All cards are considered face cards
+funky-effect-smeared-suits = Hearts and Diamonds count as the same suit, as do Spades and Clubs
+funky-effect-all-played-cards-score = Every played card counts in scoring
+funky-effect-double-odds = Doubles all listed probabilities
+funky-effect-free-reroll = { $count ->
+        [one] 1 free reroll
+       *[other] { $count } free rerolls
+    } per shop
+funky-effect-glass = ×{ $mult } Mult, 1 in { $odds } chance to destroy the card
+funky-effect-gold = Earn ${ $money } if this card is held in hand at end of round
+funky-effect-hanged = Destroys up to { $count } selected cards
+funky-effect-jokers-value = Gives the total sell value of all current Jokers, up to ${ $money }
+funky-effect-lucky = 1 in { $mult-odds } chance for +20 Mult, 1 in { $money-odds } chance to win $20
+funky-effect-mult-plus = +{ $mult } Mult
+funky-effect-mult-plus-chance-destroyed = +{ $mult } Mult, { $numerator } in { $denominator } chance this card is destroyed at end of round
+funky-effect-mult-times-chance-destroyed = ×{ $mult } Mult, { $numerator } in { $denominator } chance this card is destroyed at end of round
+funky-effect-mult-plus-chips-on-rank = Each played { $rank } gives +{ $chips } Chips and +{ $mult } Mult when scored
+funky-effect-mult-plus-double-value-destroy-joker-on-right = When a Blind is selected, destroys the Joker to the right and permanently adds double its sell value to this Mult, starting at +{ $mult }
+funky-effect-mult-plus-on-ranks = Played cards ranked { $ranks } give +{ $mult } Mult when scored
+funky-effect-mult-plus-on-consecutive-hands-without-ranks = Starts at +{ $mult } Mult and gains +{ $increment } Mult per consecutive hand played without a scoring { $ranks }
+funky-effect-mult-plus-on-hand = +{ $mult } Mult if played hand contains a { $hand }
+funky-effect-mult-plus-on-hand-plays = Adds the number of times the played poker hand has been played this run to Mult
+funky-effect-mult-plus-on-suit = Played cards with { $suit } suit give +{ $mult } Mult when scored
+funky-effect-mult-plus-on-up-to-x-cards = +{ $mult } Mult if played hand contains { $count } or fewer cards
+funky-effect-mult-plus-on-zero-discards = +{ $mult } Mult when 0 discards remain
+funky-effect-mult-plus-x-on-lowest-rank-in-hand = Adds { $factor } times the rank of the lowest card held in hand to Mult
+funky-effect-mult-plus-random-to = +0 to +{ $mult } Mult
+funky-effect-mult-times = ×{ $mult } Mult
+funky-effect-mult-times-every-x-hands = ×{ $mult } Mult every { $hands } hands played
+funky-effect-mult-times-on-empty-joker-slots = ×{ $mult } Mult for each empty Joker slot
+funky-effect-mult-times-held = ×{ $mult } Mult while this card stays in hand
+funky-effect-mult-times-on-hand = ×{ $mult } Mult if played hand contains a { $hand }
+funky-effect-mult-times-per-scored-rank = Each played { $first } or { $second } gives ×{ $mult } Mult when scored
+funky-effect-mult-plus-per-joker = +{ $mult } Mult for each Joker card
+funky-effect-chips-per-deck-card = +{ $chips } Chips for each card remaining in the deck
+funky-effect-mult-times-plus-per-full-deck-steel = Gains ×{ $mult } Mult for each Steel Card in the full deck
+funky-effect-chips-per-full-deck-stone = +{ $chips } Chips for each Stone Card in the full deck
+funky-effect-mult-plus-per-missing-deck-card = +{ $mult } Mult for each card below the deck's starting size
+funky-effect-mult-times-per-held-rank = Each { $rank } held in hand gives ×{ $mult } Mult
+funky-effect-chips-plus-per-scored-face = Played face cards give +{ $chips } Chips when scored
+funky-effect-chips-mult-plus-per-scored-ranks = Each played { $first } or { $second } gives +{ $chips } Chips and +{ $mult } Mult when scored
+funky-effect-mult-times-per-uncommon-joker = Uncommon Jokers each give ×{ $mult } Mult
+funky-effect-mult-times-if-held-all-suits = ×{ $mult } Mult if all cards held in hand are { $first } or { $second }
+funky-effect-chips-per-dollar = +{ $chips } Chips for each $1 you have
+funky-effect-cash-on-round-end = Earn ${ $money } at end of round
+funky-effect-cash-per-discard-if-none-used = Earn ${ $money } per discard if no discards are used by end of round
+funky-effect-cash-per-full-deck-rank = Earn ${ $money } for each { $rank } in the full deck at end of round
+funky-effect-extra-interest = Earn an extra ${ $money } of interest for every $5 you have at end of round
+funky-effect-cash-on-faces-discarded = Earn ${ $money } if { $count } or more face cards are discarded at the same time
+funky-effect-hand-size-increment = +{ $count } hand size
+funky-effect-discard-increment = +{ $count ->
+        [one] 1 discard
+       *[other] { $count } discards
+    } each round
+funky-effect-gain-hands-lose-discards-when-blind-selected = When a Blind is selected, gain +{ $count } hands and lose all discards
+funky-effect-gain-mult-per-hand-less-discard = +{ $mult } Mult per hand played, -{ $mult } Mult per discard
+funky-effect-lose-mult-times-per-discard = ×{ $mult } Mult, loses ×{ $loss } Mult per card discarded
+funky-effect-lose-chips-per-hand = +{ $chips } Chips, -{ $loss } Chips for every hand played
+funky-effect-gain-chips-per-card-count-hand = Gains +{ $chips } Chips if played hand has exactly { $count } cards
+funky-effect-gain-mult-per-hand = Gains +{ $mult } Mult if played hand contains a { $hand }
+funky-effect-gain-chips-per-hand = Gains +{ $chips } Chips if played hand contains a { $hand }
+funky-effect-gain-chips-on-scored = Every played card permanently gains +{ $chips } Chips when scored
+funky-effect-lose-mult-per-round = +{ $mult } Mult, -{ $loss } Mult per round played
+funky-effect-gain-mult-times-per-discarded-cards = Gains ×{ $mult } Mult every { $count } cards discarded
+funky-effect-gain-mult-times-per-card-added = Gains ×{ $mult } Mult every time a playing card is added to the deck
+funky-effect-gain-mult-times-per-face-destroyed = Gains ×{ $mult } Mult when a face card is destroyed
+funky-effect-create-jokers-when-blind-selected = When a Blind is selected, create { $count } { $card-type } cards (must have room)
+funky-effect-create-tarot-on-ace-straight = Create a Tarot card if the played hand contains an Ace and a Straight (must have room)
+funky-effect-create-tarot-on-low-money = Create a Tarot card if a hand is played with ${ $money } or less (must have room)
+funky-effect-create-spectral-on-straight-flush = Create a random Spectral card if the played hand contains a Straight Flush (must have room)
+funky-effect-create-spectral-on-first-single-six = If the first hand of the round is a single 6, destroy it and create a Spectral card (must have room)
+funky-effect-spectral-level-all-hands = Upgrade every poker hand by 1 level
+funky-effect-spectral-create-legendary-joker = Creates a Legendary Joker (must have room)
+funky-effect-spectral-create-rare-joker-zero-money = Creates a random Rare Joker (must have room) and sets money to $0
+funky-effect-spectral-negative-random-joker-minus-hand-size = Adds Negative to a random Joker, -1 hand size
+funky-effect-spectral-polychrome-random-joker-destroy-others = Adds Polychrome to a random Joker and destroys all other Jokers
+funky-effect-spectral-copy-random-joker-destroy-others = Creates a copy of a random Joker and destroys all other Jokers
+funky-effect-spectral-edition-random-hand-card = Adds Foil, Holographic or Polychrome to 1 selected card in hand
+funky-effect-spectral-hand-to-random-suit = Converts all cards in hand to a single random suit
+funky-effect-spectral-hand-to-random-rank-minus-hand-size = Converts all cards in hand to a single random rank, -1 hand size
+funky-effect-spectral-destroy-random-hand-gain-money = Destroys { $count } random cards in hand, gain ${ $money }
+funky-effect-spectral-destroy-one-add-enhanced-faces = Destroys 1 random card in hand, adds { $count } random Enhanced face cards to the hand
+funky-effect-spectral-destroy-one-add-enhanced-aces = Destroys 1 random card in hand, adds { $count } random Enhanced Aces to the hand
+funky-effect-spectral-destroy-one-add-enhanced-numbered = Destroys 1 random card in hand, adds { $count } random Enhanced numbered cards to the hand
+funky-effect-spectral-copy-selected-hand-card = Creates { $count } copies of 1 selected card in hand
+funky-effect-mult-times-on-repeated-hand-this-round = ×{ $mult } Mult if the played poker hand has already been played this round
+funky-effect-mult-times-per-scored-ancient-suit = Each played card of the round's suit gives ×{ $mult } Mult when scored; the suit changes at end of round
+funky-effect-gain-mult-times-on-non-boss-blind-destroying-joker = When a Small or Big Blind is selected, gains ×{ $mult } Mult and destroys a random Joker
+funky-effect-disable-boss-blind-on-sell = Sell this card to disable the current Boss Blind
+funky-effect-disables-all-boss-blinds = Disables the effect of every Boss Blind
+funky-effect-cash-on-round-end-growing-on-boss-defeat = Earn ${ $money } at end of round; the payout increases by ${ $increase } when a Boss Blind is defeated
+funky-effect-gain-mult-times-per-planet-used = Gains ×{ $mult } Mult every time a Planet card is used
+funky-effect-mult-plus-per-tarot-used-this-run = +{ $mult } Mult per Tarot card used this run
+funky-effect-mult-plus-per-reroll = Gains +{ $mult } Mult per reroll in the shop
+funky-effect-mult-plus-per-pack-skipped = Gains +{ $mult } Mult when any Booster Pack is skipped
+funky-effect-create-tarot-on-pack-open = { $numerator } in { $denominator } chance to create a Tarot card when any Booster Pack is opened (must have room)
+funky-effect-create-negative-consumable-copy = Creates a Negative copy of 1 random consumable held at end of round
+funky-effect-gain-mult-times-per-enhanced-played = Gains ×{ $mult } Mult per scoring Enhanced card played, and removes its Enhancement
+funky-effect-gain-mult-times-per-lucky-proc = Gains ×{ $mult } Mult every time a Lucky card successfully triggers
+funky-effect-copy-joker-to-right = Copies the ability of the Joker to the right
+funky-effect-copy-leftmost-joker = Copies the ability of the leftmost Joker
+funky-effect-planet = Creates up to { $count } random Planet cards (must have room)
+funky-effect-random-joker = Creates { $count ->
+        [one] a random Joker card
+       *[other] { $count } random Joker cards
+    } (must have room)
+funky-effect-random-tarot = Creates up to { $count } random Tarot cards (must have room)
+funky-effect-retrigger-cards-in-hand = Retriggers the abilities of all cards held in hand { $count ->
+        [one] once
+       *[other] { $count } times
+    }
+funky-effect-retrigger-played-cards-in-final-round = Retriggers all played cards in the final hand of the round
+funky-effect-retrigger-played-ranks = Retriggers each played { $ranks } { $count ->
+        [one] once
+       *[other] { $count } times
+    }
+funky-effect-retrigger-played-faces = Retriggers all played face cards { $count ->
+        [one] once
+       *[other] { $count } times
+    }
+funky-effect-retrigger-first-played = Retriggers the first played card { $count ->
+        [one] once
+       *[other] { $count } times
+    }
+funky-effect-retrigger-all-played-for-hands = Retriggers all played cards for the next { $hands } hands
+funky-effect-sell-value-increment = Gains ${ $money } of sell value at end of round
+funky-effect-stone = +{ $chips } Chips, no rank or suit
+funky-effect-strength = Increases the rank of up to 2 selected cards by 1
+funky-effect-odds-1-in = 1 in { $odds } chance to add Foil, Holographic or Polychrome to a random Joker
+funky-effect-odds-1-in-cash-on-ranks = Each played { $ranks } has a 1 in { $odds } chance to give ${ $money } when scored
+funky-effect-odds-1-in-upgrade-hand = 1 in { $odds } chance to upgrade the level of the played poker hand
+funky-effect-wild = { $wild ->
+        [rank] Can be used as any rank
+       *[suit] Can be used as any suit
+    }
+funky-effect-to-suit = Converts up to { $count } selected cards to { $suit }
+funky-effect-custom = Mod effect `{ $effect }`
+
+## Jokers
+
+funky-joker-joker = Joker
+funky-joker-greedy-joker = Greedy Joker
+funky-joker-lusty-joker = Lusty Joker
+funky-joker-wrathful-joker = Wrathful Joker
+funky-joker-gluttonous-joker = Gluttonous Joker
+funky-joker-jolly-joker = Jolly Joker
+funky-joker-zany-joker = Zany Joker
+funky-joker-mad-joker = Mad Joker
+funky-joker-crazy-joker = Crazy Joker
+funky-joker-droll-joker = Droll Joker
+funky-joker-sly-joker = Sly Joker
+funky-joker-wily-joker = Wily Joker
+funky-joker-clever-joker = Clever Joker
+funky-joker-devious-joker = Devious Joker
+funky-joker-crafty-joker = Crafty Joker
+funky-joker-half-joker = Half Joker
+funky-joker-joker-stencil = Joker Stencil
+funky-joker-four-fingers = Four Fingers
+funky-joker-mime = Mime
+funky-joker-credit-card = Credit Card
+funky-joker-ceremonial-dagger = Ceremonial Dagger
+funky-joker-banner = Banner
+funky-joker-mystic-summit = Mystic Summit
+funky-joker-marble-joker = Marble Joker
+funky-joker-loyalty-card = Loyalty Card
+funky-joker-eight-ball = 8 Ball
+funky-joker-misprint = Misprint
+funky-joker-dusk = Dusk
+funky-joker-raised-fist = Raised Fist
+funky-joker-chaos-the-clown = Chaos the Clown
+funky-joker-fibonacci = Fibonacci
+funky-joker-steel-joker = Steel Joker
+funky-joker-scary-face = Scary Face
+funky-joker-abstract-joker = Abstract Joker
+funky-joker-delayed-gratification = Delayed Gratification
+funky-joker-hack = Hack
+funky-joker-pareidolia = Pareidolia
+funky-joker-gros-michel = Gros Michel
+funky-joker-even-steven = Even Steven
+funky-joker-odd-todd = Odd Todd
+funky-joker-scholar = Scholar
+funky-joker-business-card = Business Card
+funky-joker-supernova = Supernova
+funky-joker-ride-the-bus = Ride the Bus
+funky-joker-space-joker = Space Joker
+funky-joker-egg = Egg
+funky-joker-burglar = Burglar
+funky-joker-blackboard = Blackboard
+funky-joker-runner = Runner
+funky-joker-ice-cream = Ice Cream
+funky-joker-dna = DNA
+    .description = If the first hand of the round is a single card, adds a permanent copy of it to the deck and draws it to hand
+funky-joker-splash = Splash
+funky-joker-blue-joker = Blue Joker
+funky-joker-sixth-sense = Sixth Sense
+funky-joker-constellation = Constellation
+funky-joker-hiker = Hiker
+funky-joker-faceless-joker = Faceless Joker
+funky-joker-green-joker = Green Joker
+funky-joker-superposition = Superposition
+funky-joker-to-do-list = To Do List
+    .description = Earn $4 if the played poker hand is the round's target hand; the target changes at end of round
+funky-joker-cavendish = Cavendish
+funky-joker-card-sharp = Card Sharp
+funky-joker-red-card = Red Card
+funky-joker-madness = Madness
+funky-joker-square-joker = Square Joker
+funky-joker-seance = Séance
+funky-joker-riff-raff = Riff-Raff
+funky-joker-vampire = Vampire
+funky-joker-shortcut = Shortcut
+funky-joker-hologram = Hologram
+funky-joker-vagabond = Vagabond
+funky-joker-baron = Baron
+funky-joker-cloud-9 = Cloud 9
+funky-joker-rocket = Rocket
+funky-joker-erosion = Erosion
+funky-joker-reserved-parking = Reserved Parking
+    .description = Each face card held in hand has a 1 in 2 chance to give $1
+funky-joker-mail-in-rebate = Mail-In Rebate
+    .description = Earn $5 for each discarded card of the round's target rank; the rank changes every round
+funky-joker-to-the-moon = To the Moon
+funky-joker-hallucination = Hallucination
+funky-joker-fortune-teller = Fortune Teller
+funky-joker-juggler = Juggler
+funky-joker-drunkard = Drunkard
+funky-joker-stone-joker = Stone Joker
+funky-joker-golden-joker = Golden Joker
+funky-joker-lucky-cat = Lucky Cat
+funky-joker-baseball-card = Baseball Card
+funky-joker-bull = Bull
+funky-joker-diet-cola = Diet Cola
+    .description = Sell this card to create a free Double Tag
+funky-joker-trading-card = Trading Card
+    .description = If the first discard of the round is a single card, destroys it and earns $3
+funky-joker-flash-card = Flash Card
+funky-joker-popcorn = Popcorn
+funky-joker-spare-trousers = Spare Trousers
+funky-joker-ancient-joker = Ancient Joker
+funky-joker-ramen = Ramen
+funky-joker-walkie-talkie = Walkie Talkie
+funky-joker-sock-and-buskin = Sock and Buskin
+funky-joker-luchador = Luchador
+funky-joker-matador = Matador
+    .description = Earn $8 if the played hand triggers the Boss Blind's ability
+funky-joker-seltzer = Seltzer
+funky-joker-smeared-joker = Smeared Joker
+funky-joker-oops-all-6s = Oops! All 6s
+funky-joker-hanging-chad = Hanging Chad
+funky-joker-the-duo = The Duo
+funky-joker-the-trio = The Trio
+funky-joker-the-family = The Family
+funky-joker-the-order = The Order
+funky-joker-the-tribe = The Tribe
+funky-joker-blueprint = Blueprint
+funky-joker-brainstorm = Brainstorm
+funky-joker-canio = Canio
+funky-joker-triboulet = Triboulet
+funky-joker-yorick = Yorick
+funky-joker-chicot = Chicot
+funky-joker-perkeo = Perkeo
+funky-joker-custom = Joker { $symbol }
+
+## Tarots
+##
+## The names are the basic tarot deck's. A tarot that enhances a card says so,
+## then gives the enhancement's own effect.
+
+funky-tarot-fool = { name-rank-tarot-special-0 }
+    .description = Creates the last Tarot or Planet card used during this run, The Fool excluded
+funky-tarot-magician = { name-rank-tarot-special-1 }
+    .description = Enhances up to 2 selected cards into Lucky Cards: { funky-effect-lucky }
+funky-tarot-high-priestess = { name-rank-tarot-special-2 }
+funky-tarot-empress = { name-rank-tarot-special-3 }
+    .description = Enhances up to 2 selected cards into Mult Cards: { funky-effect-mult-plus }
+funky-tarot-emperor = { name-rank-tarot-special-4 }
+funky-tarot-hierophant = { name-rank-tarot-special-5 }
+    .description = Enhances up to 2 selected cards into Bonus Cards: { funky-effect-chips }
+funky-tarot-lovers = { name-rank-tarot-special-6 }
+    .description = Enhances 1 selected card into a Wild Card: { funky-effect-wild }
+funky-tarot-chariot = { name-rank-tarot-special-7 }
+    .description = Enhances 1 selected card into a Steel Card: { funky-effect-mult-times-held }
+funky-tarot-strength = { name-rank-tarot-special-8 }
+funky-tarot-hermit = { name-rank-tarot-special-9 }
+funky-tarot-wheel-of-fortune = { name-rank-tarot-special-a }
+funky-tarot-justice = { name-rank-tarot-special-b }
+    .description = Enhances 1 selected card into a Glass Card: { funky-effect-glass }
+funky-tarot-hanged-man = { name-rank-tarot-special-c }
+funky-tarot-death = { name-rank-tarot-special-d }
+funky-tarot-temperance = { name-rank-tarot-special-e }
+funky-tarot-devil = { name-rank-tarot-special-f }
+    .description = Enhances 1 selected card into a Gold Card: { funky-effect-gold }
+funky-tarot-tower = { name-rank-tarot-special-g }
+    .description = Enhances 1 selected card into a Stone Card: { funky-effect-stone }
+funky-tarot-star = { name-rank-tarot-special-h }
+funky-tarot-moon = { name-rank-tarot-special-i }
+funky-tarot-sun = { name-rank-tarot-special-j }
+funky-tarot-judgement = { name-rank-tarot-special-k }
+funky-tarot-world = { name-rank-tarot-special-l }
+
+## Planets
+
+funky-planet-pluto = Pluto
+funky-planet-mercury = Mercury
+funky-planet-uranus = Uranus
+funky-planet-venus = Venus
+funky-planet-saturn = Saturn
+funky-planet-jupiter = Jupiter
+funky-planet-earth = Earth
+funky-planet-mars = Mars
+funky-planet-neptune = Neptune
+funky-planet-planet-x = Planet X
+funky-planet-ceres = Ceres
+funky-planet-eris = Eris
+
+## Spectrals
+
+funky-spectral-familiar = Familiar
+funky-spectral-grim = Grim
+funky-spectral-incantation = Incantation
+funky-spectral-talisman = Talisman
+    .description = Adds a Gold Seal to 1 selected card in hand
+funky-spectral-aura = Aura
+funky-spectral-wraith = Wraith
+funky-spectral-sigil = Sigil
+funky-spectral-ouija = Ouija
+funky-spectral-ectoplasm = Ectoplasm
+funky-spectral-immolate = Immolate
+funky-spectral-ankh = Ankh
+funky-spectral-deja-vu = Deja Vu
+    .description = Adds a Red Seal to 1 selected card in hand
+funky-spectral-hex = Hex
+funky-spectral-trance = Trance
+    .description = Adds a Blue Seal to 1 selected card in hand
+funky-spectral-medium = Medium
+    .description = Adds a Purple Seal to 1 selected card in hand
+funky-spectral-cryptid = Cryptid
+funky-spectral-the-soul = The Soul
+funky-spectral-black-hole = Black Hole
+
+## Vouchers
+
+funky-voucher-grabber = Grabber
+    .description = +1 hand every round
+funky-voucher-nacho-tong = Nacho Tong
+    .description = +1 hand every round
+funky-voucher-wasteful = Wasteful
+    .description = +1 discard every round
+funky-voucher-recyclomancy = Recyclomancy
+    .description = +1 discard every round
+funky-voucher-paint-brush = Paint Brush
+    .description = +1 hand size
+funky-voucher-palette = Palette
+    .description = +1 hand size
+funky-voucher-overstock = Overstock
+    .description = +1 card slot in the shop
+funky-voucher-overstock-plus = Overstock Plus
+    .description = +1 card slot in the shop
+funky-voucher-crystal-ball = Crystal Ball
+    .description = +1 consumable slot
+funky-voucher-antimatter = Antimatter
+    .description = +1 Joker slot
+funky-voucher-reroll-surplus = Reroll Surplus
+    .description = Rerolls cost $2 less
+funky-voucher-reroll-glut = Reroll Glut
+    .description = Rerolls cost another $2 less
+funky-voucher-clearance-sale = Clearance Sale
+    .description = All cards and packs in the shop are 25% off
+funky-voucher-liquidation = Liquidation
+    .description = All cards and packs in the shop are 50% off
+funky-voucher-seed-money = Seed Money
+    .description = Raises the cap on interest earned each round to $10
+funky-voucher-money-tree = Money Tree
+    .description = Raises the cap on interest earned each round to $20
+funky-voucher-tarot-merchant = Tarot Merchant
+    .description = Tarot cards appear 2× more often in the shop
+funky-voucher-tarot-tycoon = Tarot Tycoon
+    .description = Tarot cards appear 4× more often in the shop
+funky-voucher-planet-merchant = Planet Merchant
+    .description = Planet cards appear 2× more often in the shop
+funky-voucher-planet-tycoon = Planet Tycoon
+    .description = Planet cards appear 4× more often in the shop
+funky-voucher-hone = Hone
+    .description = Foil, Holographic and Polychrome cards appear 2× more often
+funky-voucher-glow-up = Glow Up
+    .description = Foil, Holographic and Polychrome cards appear 4× more often
+funky-voucher-hieroglyph = Hieroglyph
+    .description = -1 Ante, -1 hand every round
+funky-voucher-petroglyph = Petroglyph
+    .description = -1 Ante, -1 discard every round
+funky-voucher-directors-cut = Director's Cut
+    .description = Reroll the Boss Blind once per Ante, $10 per roll
+funky-voucher-retcon = Retcon
+    .description = Reroll the Boss Blind an unlimited number of times, $10 per roll
+funky-voucher-telescope = Telescope
+    .description = Celestial Packs always contain the Planet card for your most played poker hand
+funky-voucher-observatory = Observatory
+    .description = Planet cards in your consumable area give ×1.5 Mult for their poker hand
+funky-voucher-magic-trick = Magic Trick
+    .description = Playing cards can be purchased from the shop
+funky-voucher-illusion = Illusion
+    .description = Playing cards in the shop may have an Enhancement or an Edition
+funky-voucher-blank = Blank
+    .description = Does nothing?
+
+## Boss Blinds
+
+funky-boss-the-needle = The Needle
+    .description = Play only 1 hand
+funky-boss-the-water = The Water
+    .description = Start with 0 discards
+funky-boss-the-manacle = The Manacle
+    .description = -1 hand size
+
+## Action errors, by `ActionError::key`
+
+funky-action-shop-closed = The shop is closed.
+funky-action-no-such-card = There is no card at position { $position }.
+funky-action-no-such-joker = There is no joker at position { $position }.
+funky-action-no-such-consumable = There is no consumable at position { $position }.
+funky-action-no-such-target = There is no card in the deck at position { $position } to use the tarot on.
+funky-action-no-such-stock = There is nothing for sale in slot { $position }.
+funky-action-no-such-pack = There is no booster pack in slot { $position }.
+funky-action-no-voucher-offered = The shop offers no voucher.
+funky-action-voucher-redeemed = { $voucher } is already redeemed.
+funky-action-voucher-requires = { $voucher } needs { $base } first.
+funky-action-not-enough-money = Not enough money: it costs ${ $cost }, and there is ${ $money }.
+funky-action-no-joker-room = There is no room for another joker.
+funky-action-no-consumable-room = There is no room for another consumable.
+funky-action-no-hands-left = There are no hands left this round.
+funky-action-no-discards-left = There are no discards left this round.
+funky-action-eternal = The joker at position { $position } is Eternal.
+funky-action-pinned = The joker at position { $position } is Pinned.
+funky-action-not-a-boss = The blind is not a Boss Blind.
+funky-action-no-boss-reroll = Rerolling the boss needs Retcon, or Director's Cut once an ante.
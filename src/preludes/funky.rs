@@ -11,18 +11,40 @@ pub use crate::funky::decks::spectral::Spectral;
 pub use crate::funky::decks::spectral::card::*;
 pub use crate::funky::decks::tarot::MajorArcana;
 pub use crate::funky::decks::tarot::card::*;
+pub use crate::funky::types::action::{Action, ActionError};
 pub use crate::funky::types::blind::{Blind, BossBlind};
 pub use crate::funky::types::board::BuffoonBoard;
 pub use crate::funky::types::buffoon_card::{BCardType, BuffoonCard};
 pub use crate::funky::types::buffoon_pile::{BuffoonPile, HandRules};
+pub use crate::funky::types::challenge::{Challenge, ChallengeRules, StartingJoker};
+pub use crate::funky::types::custom_joker::{CustomJoker, JokerYaml};
 pub use crate::funky::types::draws::Draws;
 pub use crate::funky::types::edition::Edition;
-pub use crate::funky::types::effect::{Effect, EffectRegistry, ScoreOp, ScoringContext};
+pub use crate::funky::types::effect::{
+    Effect, EffectContext, EffectEvent, EffectId, EffectRegistry, ScoreOp, ScoringContext,
+};
 pub use crate::funky::types::hands::{HandType, PokerHand, PokerHands};
+pub use crate::funky::types::history::{BoardHistory, SeededRng};
+#[cfg(feature = "i18n")]
+pub use crate::funky::types::localized::Localized;
+pub use crate::funky::types::magnitude::Magnitude;
 pub use crate::funky::types::mpip::*;
-pub use crate::funky::types::score::Score;
-pub use crate::funky::types::shop::{BoosterPack, PackKind, Shop};
+pub use crate::funky::types::odds::DrawOdds;
+pub use crate::funky::types::profile::{Profile, RunStats, Summary};
+pub use crate::funky::types::run::{
+    Decision, Divergence, Greedy, Run, RunPhase, RunRecord, RunReport, ShopChoice, Strategy,
+};
+pub use crate::funky::types::score::{BigScore, Score};
+#[cfg(feature = "scripting")]
+pub use crate::funky::types::script::ScriptEffect;
+pub use crate::funky::types::seed::{RunRngs, RunSeed, Stream};
+pub use crate::funky::types::shop::{BoosterPack, PackKind, PackSize, Shop};
+pub use crate::funky::types::solver::Play;
+pub use crate::funky::types::stake::Stake;
+pub use crate::funky::types::starter_deck::StarterDeck;
+pub use crate::funky::types::sticker::Stickers;
 pub use crate::funky::types::toggle::{Toggle, ToggleCard};
+pub use crate::funky::types::trace::{ProcEvent, ScoreStep, ScoreTrace, ScoringPhase};
 pub use crate::funky::types::voucher::Voucher;
 
 pub use crate::bcard;